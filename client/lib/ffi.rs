@@ -419,6 +419,65 @@ pub extern "C" fn casper_get_deploy(
     })
 }
 
+/// Retrieves the status of a `Deploy` from the network.
+///
+/// See [super::get_deploy_status](super::get_deploy_status) for more details.
+#[no_mangle]
+pub extern "C" fn casper_get_deploy_status(
+    maybe_rpc_id: *const c_char,
+    node_address: *const c_char,
+    verbosity_level: u64,
+    deploy_hash: *const c_char,
+    response_buf: *mut c_uchar,
+    response_buf_len: usize,
+) -> casper_error_t {
+    let mut runtime = RUNTIME.lock().expect("should lock");
+    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
+    let node_address = try_unsafe_arg!(node_address);
+    let deploy_hash = try_unsafe_arg!(deploy_hash);
+    runtime.block_on(async move {
+        let result =
+            super::get_deploy_status(maybe_rpc_id, node_address, verbosity_level, deploy_hash);
+        let response = try_unwrap_rpc!(result);
+        copy_str_to_buf(&response, response_buf, response_buf_len);
+        casper_error_t::CASPER_SUCCESS
+    })
+}
+
+/// Retrieves the `Deploy`s pending inclusion in a `Block` from the network.
+///
+/// See [super::get_pending_deploys](super::get_pending_deploys) for more details.
+#[no_mangle]
+pub extern "C" fn casper_get_pending_deploys(
+    maybe_rpc_id: *const c_char,
+    node_address: *const c_char,
+    verbosity_level: u64,
+    maybe_cursor: *const c_char,
+    maybe_limit: *const c_char,
+    response_buf: *mut c_uchar,
+    response_buf_len: usize,
+) -> casper_error_t {
+    let mut runtime = RUNTIME.lock().expect("should lock");
+    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
+    let node_address = try_unsafe_arg!(node_address);
+    let maybe_cursor = try_unsafe_arg!(maybe_cursor);
+    let maybe_limit = try_unsafe_arg!(maybe_limit);
+    runtime.block_on(async move {
+        let result = super::get_pending_deploys(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            maybe_cursor,
+            maybe_limit,
+        );
+        let response = try_unwrap_rpc!(result);
+        copy_str_to_buf(&response, response_buf, response_buf_len);
+        casper_error_t::CASPER_SUCCESS
+    })
+}
+
 /// Retrieves a `Block` from the network.
 ///
 /// See [super::get_block](super::get_block) for more details.
@@ -532,6 +591,45 @@ pub extern "C" fn casper_get_item(
     })
 }
 
+/// Retrieves a page of stored values whose keys match a prefix from the network.
+///
+/// See [super::get_keys_with_prefix](super::get_keys_with_prefix) for more details.
+#[no_mangle]
+pub extern "C" fn casper_get_keys_with_prefix(
+    maybe_rpc_id: *const c_char,
+    node_address: *const c_char,
+    verbosity_level: u64,
+    state_root_hash: *const c_char,
+    prefix: *const c_char,
+    maybe_cursor: *const c_char,
+    maybe_limit: *const c_char,
+    response_buf: *mut c_uchar,
+    response_buf_len: usize,
+) -> casper_error_t {
+    let mut runtime = RUNTIME.lock().expect("should lock");
+    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
+    let node_address = try_unsafe_arg!(node_address);
+    let state_root_hash = try_unsafe_arg!(state_root_hash);
+    let prefix = try_unsafe_arg!(prefix);
+    let maybe_cursor = try_unsafe_arg!(maybe_cursor);
+    let maybe_limit = try_unsafe_arg!(maybe_limit);
+    runtime.block_on(async move {
+        let result = super::get_keys_with_prefix(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            state_root_hash,
+            prefix,
+            maybe_cursor,
+            maybe_limit,
+        );
+        let response = try_unwrap_rpc!(result);
+        copy_str_to_buf(&response, response_buf, response_buf_len);
+        casper_error_t::CASPER_SUCCESS
+    })
+}
+
 /// Retrieves a purse's balance from the network.
 ///
 /// See [super::get_balance](super::get_balance) for more details.
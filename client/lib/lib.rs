@@ -242,6 +242,56 @@ pub fn get_deploy(
     RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_deploy(deploy_hash)
 }
 
+/// Retrieves the status of a `Deploy` from the network, e.g. whether it is pending inclusion in a
+/// `Block` or has been finalized.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `deploy_hash` must be a hex-encoded, 32-byte hash digest.
+pub fn get_deploy_status(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_hash: &str,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_deploy_status(deploy_hash)
+}
+
+/// Retrieves the `Deploy`s pending inclusion in a `Block` from the network.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `maybe_cursor` is the `next_cursor` returned in a previous response, i.e. a hex-encoded,
+///   32-byte deploy hash.  If empty, the first page will be retrieved.
+/// * `maybe_limit` is the maximum number of deploys to be returned.  If empty, the node's maximum
+///   page size will be used.
+pub fn get_pending_deploys(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_cursor: &str,
+    maybe_limit: &str,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .get_pending_deploys(maybe_cursor, maybe_limit)
+}
+
 /// Retrieves a `Block` from the network.
 ///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
@@ -347,6 +397,42 @@ pub fn get_item(
     RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_item(state_root_hash, key, path)
 }
 
+/// Retrieves a page of stored values whose keys match a prefix from the network.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `state_root_hash` must be a hex-encoded, 32-byte hash digest.
+/// * `prefix` is the hex-encoded prefix of the serialized [`Key`](https://docs.rs/casper-types/latest/casper-types/enum.Key.html)s
+///   to be listed, e.g. `"03"` for all `Key::Transfer`s or `"04"` for all `Key::DeployInfo`s.
+/// * `maybe_cursor` is the `next_cursor` returned in a previous response, formatted as a `Key`. If
+///   empty, the first page will be retrieved.
+/// * `maybe_limit` is the maximum number of keys to be returned.  If empty, the node's maximum page
+///   size will be used.
+pub fn get_keys_with_prefix(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    state_root_hash: &str,
+    prefix: &str,
+    maybe_cursor: &str,
+    maybe_limit: &str,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_keys_with_prefix(
+        state_root_hash,
+        prefix,
+        maybe_cursor,
+        maybe_limit,
+    )
+}
+
 /// Retrieves a purse's balance from the network.
 ///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
@@ -17,8 +17,14 @@ use casper_node::{
             GetEraInfoBySwitchBlock, GetEraInfoParams, GetStateRootHash, GetStateRootHashParams,
         },
         docs::ListRpcs,
-        info::{GetDeploy, GetDeployParams},
-        state::{GetAuctionInfo, GetBalance, GetBalanceParams, GetItem, GetItemParams},
+        info::{
+            GetDeploy, GetDeployParams, GetDeployStatus, GetDeployStatusParams, GetPendingDeploys,
+            GetPendingDeploysParams,
+        },
+        state::{
+            GetAuctionInfo, GetBalance, GetBalanceParams, GetItem, GetItemParams,
+            GetKeysWithPrefix, GetKeysWithPrefixParams,
+        },
         RpcWithOptionalParams, RpcWithParams, RpcWithoutParams, RPC_API_PATH,
     },
     types::{BlockHash, Deploy, DeployHash},
@@ -87,6 +93,45 @@ impl RpcCall {
         GetDeploy::request_with_map_params(self, params)
     }
 
+    pub(crate) fn get_deploy_status(self, deploy_hash: &str) -> Result<JsonRpc> {
+        let hash = Digest::from_hex(deploy_hash).map_err(|error| Error::CryptoError {
+            context: "deploy_hash",
+            error,
+        })?;
+        let params = GetDeployStatusParams {
+            deploy_hash: DeployHash::new(hash),
+        };
+        GetDeployStatus::request_with_map_params(self, params)
+    }
+
+    pub(crate) fn get_pending_deploys(
+        self,
+        maybe_cursor: &str,
+        maybe_limit: &str,
+    ) -> Result<JsonRpc> {
+        let cursor = if maybe_cursor.is_empty() {
+            None
+        } else {
+            let hash = Digest::from_hex(maybe_cursor).map_err(|error| Error::CryptoError {
+                context: "cursor",
+                error,
+            })?;
+            Some(DeployHash::new(hash))
+        };
+
+        let limit = if maybe_limit.is_empty() {
+            None
+        } else {
+            let limit = maybe_limit
+                .parse()
+                .map_err(|error| Error::FailedToParseInt("limit", error))?;
+            Some(limit)
+        };
+
+        let params = GetPendingDeploysParams { cursor, limit };
+        GetPendingDeploys::request_with_map_params(self, params)
+    }
+
     pub(crate) fn get_item(self, state_root_hash: &str, key: &str, path: &str) -> Result<JsonRpc> {
         let state_root_hash =
             Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
@@ -120,6 +165,48 @@ impl RpcCall {
         Ok(response)
     }
 
+    pub(crate) fn get_keys_with_prefix(
+        self,
+        state_root_hash: &str,
+        prefix: &str,
+        maybe_cursor: &str,
+        maybe_limit: &str,
+    ) -> Result<JsonRpc> {
+        let state_root_hash =
+            Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
+                context: "state_root_hash",
+                error,
+            })?;
+
+        if let Err(error) = hex::decode(prefix) {
+            return Err(Error::InvalidArgument("prefix", error.to_string()));
+        }
+
+        let cursor = if maybe_cursor.is_empty() {
+            None
+        } else {
+            let key = Key::from_formatted_str(maybe_cursor).map_err(|_| Error::FailedToParseKey)?;
+            Some(key.to_formatted_string())
+        };
+
+        let limit = if maybe_limit.is_empty() {
+            None
+        } else {
+            let limit = maybe_limit
+                .parse()
+                .map_err(|error| Error::FailedToParseInt("limit", error))?;
+            Some(limit)
+        };
+
+        let params = GetKeysWithPrefixParams {
+            state_root_hash,
+            prefix: prefix.to_string(),
+            cursor,
+            limit,
+        };
+        GetKeysWithPrefix::request_with_map_params(self, params)
+    }
+
     pub(crate) fn get_state_root_hash(self, maybe_block_identifier: &str) -> Result<JsonRpc> {
         match Self::block_identifier(maybe_block_identifier)? {
             Some(block_identifier) => {
@@ -358,10 +445,22 @@ impl RpcClient for GetItem {
     const RPC_METHOD: &'static str = <Self as RpcWithParams>::METHOD;
 }
 
+impl RpcClient for GetKeysWithPrefix {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
 impl RpcClient for GetEraInfoBySwitchBlock {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
 
+impl RpcClient for GetDeployStatus {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
+impl RpcClient for GetPendingDeploys {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
 impl RpcClient for GetAuctionInfo {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
@@ -387,7 +486,9 @@ impl IntoJsonMap for GetBlockParams {}
 impl IntoJsonMap for GetBlockTransfersParams {}
 impl IntoJsonMap for GetStateRootHashParams {}
 impl IntoJsonMap for GetDeployParams {}
+impl IntoJsonMap for GetDeployStatusParams {}
 impl IntoJsonMap for GetBalanceParams {}
 impl IntoJsonMap for GetItemParams {}
+impl IntoJsonMap for GetKeysWithPrefixParams {}
 impl IntoJsonMap for GetEraInfoParams {}
 impl IntoJsonMap for ListRpcs {}
@@ -56,6 +56,14 @@ pub enum ValidateResponseError {
     /// Block height was not as requested.
     #[error("block height was not as requested")]
     UnexpectedBlockHeight,
+
+    /// The balance proof is a pair of proofs, as returned by nodes from before balances were
+    /// stored under `Key::Balance`.
+    #[error(
+        "balance proof is in the format of a node running an older protocol version, which can't \
+        be validated"
+    )]
+    LegacyBalanceProof,
 }
 
 impl From<bytesrepr::Error> for ValidateResponseError {
@@ -187,10 +195,7 @@ pub(crate) fn validate_get_balance_response(
         .as_object()
         .ok_or(ValidateResponseError::ValidateResponseFailedToParse)?;
 
-    let (purse_proof, balance_proof): (
-        TrieMerkleProof<Key, StoredValue>,
-        TrieMerkleProof<Key, StoredValue>,
-    ) = {
+    let balance_proof: TrieMerkleProof<Key, StoredValue> = {
         let proof = object
             .get(GET_ITEM_RESULT_MERKLE_PROOF)
             .ok_or(ValidateResponseError::ValidateResponseFailedToParse)?;
@@ -199,7 +204,23 @@ pub(crate) fn validate_get_balance_response(
             .ok_or(ValidateResponseError::ValidateResponseFailedToParse)?;
         let proof_bytes = hex::decode(proof_str)
             .map_err(|_| ValidateResponseError::ValidateResponseFailedToParse)?;
-        bytesrepr::deserialize(proof_bytes)?
+        match bytesrepr::deserialize(proof_bytes.clone()) {
+            Ok(balance_proof) => balance_proof,
+            Err(error) => {
+                let legacy_proofs: Result<
+                    (
+                        TrieMerkleProof<Key, StoredValue>,
+                        TrieMerkleProof<Key, StoredValue>,
+                    ),
+                    _,
+                > = bytesrepr::deserialize(proof_bytes);
+                return Err(if legacy_proofs.is_ok() {
+                    ValidateResponseError::LegacyBalanceProof
+                } else {
+                    error.into()
+                });
+            }
+        }
     };
 
     let balance: U512 = {
@@ -215,7 +236,6 @@ pub(crate) fn validate_get_balance_response(
 
     core::validate_balance_proof(
         &state_root_hash.to_owned().into(),
-        &purse_proof,
         &balance_proof,
         *key,
         &balance,
@@ -2,9 +2,11 @@ mod creation_common;
 mod get;
 mod list;
 mod make;
+mod pending;
 mod put;
 mod send;
 mod sign;
+mod status;
 mod transfer;
 
 pub use transfer::Transfer;
@@ -0,0 +1,77 @@
+use std::str;
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use casper_node::rpcs::info::GetDeployStatus;
+
+use crate::{command::ClientCommand, common};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    DeployHash,
+}
+
+/// Handles providing the arg for and retrieval of the deploy hash.
+mod deploy_hash {
+    use super::*;
+
+    const ARG_NAME: &str = "deploy-hash";
+    const ARG_VALUE_NAME: &str = "HEX STRING";
+    const ARG_HELP: &str = "Hex-encoded deploy hash";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::DeployHash as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+impl<'a, 'b> ClientCommand<'a, 'b> for GetDeployStatus {
+    const NAME: &'static str = "get-deploy-status";
+    const ABOUT: &'static str =
+        "Retrieves the status of a deploy from the network, e.g. whether it is pending inclusion \
+        in a block or has been finalized";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(deploy_hash::arg())
+    }
+
+    fn run(matches: &ArgMatches<'_>) {
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let mut verbosity_level = common::verbose::get(matches);
+        let deploy_hash = deploy_hash::get(matches);
+
+        let response = casper_client::get_deploy_status(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            deploy_hash,
+        )
+        .unwrap_or_else(|error| panic!("response error: {}", error));
+
+        if verbosity_level == 0 {
+            verbosity_level += 1
+        }
+        casper_client::pretty_print_at_level(&response, verbosity_level);
+    }
+}
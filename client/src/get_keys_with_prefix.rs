@@ -0,0 +1,146 @@
+use std::str;
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use casper_node::rpcs::state::GetKeysWithPrefix;
+
+use crate::{command::ClientCommand, common};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    StateRootHash,
+    Prefix,
+    Cursor,
+    Limit,
+}
+
+/// Handles providing the arg for and retrieval of the key prefix.
+mod prefix {
+    use super::*;
+
+    const ARG_NAME: &str = "prefix";
+    const ARG_SHORT: &str = "p";
+    const ARG_VALUE_NAME: &str = common::ARG_HEX_STRING;
+    const ARG_HELP: &str =
+        "Hex-encoded prefix of the serialized keys to be listed. The first byte identifies the key \
+        type, e.g. \"03\" lists all transfers and \"04\" lists all deploy infos";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Prefix as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+/// Handles providing the arg for and retrieval of the pagination cursor.
+mod cursor {
+    use super::*;
+
+    const ARG_NAME: &str = "cursor";
+    const ARG_SHORT: &str = "c";
+    const ARG_VALUE_NAME: &str = "FORMATTED STRING";
+    const ARG_HELP: &str =
+        "The \"next_cursor\" value returned in a previous response. If provided, only keys after \
+        this one are listed";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Cursor as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the page size.
+mod limit {
+    use super::*;
+
+    const ARG_NAME: &str = "limit";
+    const ARG_SHORT: &str = "l";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_HELP: &str =
+        "The maximum number of keys to list. If not provided, the node's maximum page size is used";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Limit as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+impl<'a, 'b> ClientCommand<'a, 'b> for GetKeysWithPrefix {
+    const NAME: &'static str = "get-keys-with-prefix";
+    const ABOUT: &'static str =
+        "Retrieves a page of stored values whose keys match a prefix from the network";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(common::state_root_hash::arg(
+                DisplayOrder::StateRootHash as usize,
+            ))
+            .arg(prefix::arg())
+            .arg(cursor::arg())
+            .arg(limit::arg())
+    }
+
+    fn run(matches: &ArgMatches<'_>) {
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let mut verbosity_level = common::verbose::get(matches);
+        let state_root_hash = common::state_root_hash::get(matches);
+        let prefix = prefix::get(matches);
+        let maybe_cursor = cursor::get(matches);
+        let maybe_limit = limit::get(matches);
+
+        let response = casper_client::get_keys_with_prefix(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            state_root_hash,
+            prefix,
+            maybe_cursor,
+            maybe_limit,
+        )
+        .unwrap_or_else(|error| panic!("response error: {}", error));
+
+        if verbosity_level == 0 {
+            verbosity_level += 1
+        }
+        casper_client::pretty_print_at_level(&response, verbosity_level);
+    }
+}
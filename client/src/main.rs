@@ -8,6 +8,7 @@ mod generate_completion;
 mod get_auction_info;
 mod get_balance;
 mod get_era_info_by_switch_block;
+mod get_keys_with_prefix;
 mod get_state_hash;
 mod keygen;
 mod query_state;
@@ -18,8 +19,8 @@ use casper_node::rpcs::{
     account::PutDeploy,
     chain::{GetBlock, GetBlockTransfers, GetEraInfoBySwitchBlock, GetStateRootHash},
     docs::ListRpcs,
-    info::GetDeploy,
-    state::{GetAuctionInfo, GetBalance, GetItem as QueryState},
+    info::{GetDeploy, GetDeployStatus, GetPendingDeploys},
+    state::{GetAuctionInfo, GetBalance, GetItem as QueryState, GetKeysWithPrefix},
 };
 
 use deploy::{ListDeploys, MakeDeploy, SendDeploy, SignDeploy};
@@ -40,11 +41,14 @@ enum DisplayOrder {
     SendDeploy,
     Transfer,
     GetDeploy,
+    GetDeployStatus,
+    GetPendingDeploys,
     GetBlock,
     GetBlockTransfers,
     ListDeploys,
     GetStateRootHash,
     QueryState,
+    GetKeysWithPrefix,
     GetBalance,
     GetEraInfo,
     GetAuctionInfo,
@@ -64,6 +68,12 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         .subcommand(SendDeploy::build(DisplayOrder::SendDeploy as usize))
         .subcommand(Transfer::build(DisplayOrder::Transfer as usize))
         .subcommand(GetDeploy::build(DisplayOrder::GetDeploy as usize))
+        .subcommand(GetDeployStatus::build(
+            DisplayOrder::GetDeployStatus as usize,
+        ))
+        .subcommand(GetPendingDeploys::build(
+            DisplayOrder::GetPendingDeploys as usize,
+        ))
         .subcommand(GetBlock::build(DisplayOrder::GetBlock as usize))
         .subcommand(GetBlockTransfers::build(
             DisplayOrder::GetBlockTransfers as usize,
@@ -74,6 +84,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             DisplayOrder::GetStateRootHash as usize,
         ))
         .subcommand(QueryState::build(DisplayOrder::QueryState as usize))
+        .subcommand(GetKeysWithPrefix::build(
+            DisplayOrder::GetKeysWithPrefix as usize,
+        ))
         .subcommand(GetEraInfoBySwitchBlock::build(
             DisplayOrder::GetEraInfo as usize,
         ))
@@ -96,12 +109,15 @@ async fn main() {
         (SendDeploy::NAME, Some(matches)) => SendDeploy::run(matches),
         (Transfer::NAME, Some(matches)) => Transfer::run(matches),
         (GetDeploy::NAME, Some(matches)) => GetDeploy::run(matches),
+        (GetDeployStatus::NAME, Some(matches)) => GetDeployStatus::run(matches),
+        (GetPendingDeploys::NAME, Some(matches)) => GetPendingDeploys::run(matches),
         (GetBlock::NAME, Some(matches)) => GetBlock::run(matches),
         (GetBlockTransfers::NAME, Some(matches)) => GetBlockTransfers::run(matches),
         (ListDeploys::NAME, Some(matches)) => ListDeploys::run(matches),
         (GetBalance::NAME, Some(matches)) => GetBalance::run(matches),
         (GetStateRootHash::NAME, Some(matches)) => GetStateRootHash::run(matches),
         (QueryState::NAME, Some(matches)) => QueryState::run(matches),
+        (GetKeysWithPrefix::NAME, Some(matches)) => GetKeysWithPrefix::run(matches),
         (GetEraInfoBySwitchBlock::NAME, Some(matches)) => GetEraInfoBySwitchBlock::run(matches),
         (GetAuctionInfo::NAME, Some(matches)) => GetAuctionInfo::run(matches),
         (Keygen::NAME, Some(matches)) => Keygen::run(matches),
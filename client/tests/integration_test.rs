@@ -131,6 +131,18 @@ impl MockServerHandle {
             .map_err(ErrWrapper)
     }
 
+    fn get_deploy_status(&self, deploy_hash: &str) -> Result<(), ErrWrapper> {
+        casper_client::get_deploy_status("1", &self.url(), 0, deploy_hash)
+            .map(|_| ())
+            .map_err(ErrWrapper)
+    }
+
+    fn get_pending_deploys(&self, maybe_cursor: &str, maybe_limit: &str) -> Result<(), ErrWrapper> {
+        casper_client::get_pending_deploys("1", &self.url(), 0, maybe_cursor, maybe_limit)
+            .map(|_| ())
+            .map_err(ErrWrapper)
+    }
+
     fn get_state_root_hash(&self, maybe_block_id: &str) -> Result<(), ErrWrapper> {
         casper_client::get_state_root_hash("1", &self.url(), 0, maybe_block_id)
             .map(|_| ())
@@ -149,6 +161,26 @@ impl MockServerHandle {
             .map_err(ErrWrapper)
     }
 
+    fn get_keys_with_prefix(
+        &self,
+        state_root_hash: &str,
+        prefix: &str,
+        maybe_cursor: &str,
+        maybe_limit: &str,
+    ) -> Result<(), ErrWrapper> {
+        casper_client::get_keys_with_prefix(
+            "1",
+            &self.url(),
+            0,
+            state_root_hash,
+            prefix,
+            maybe_cursor,
+            maybe_limit,
+        )
+        .map(|_| ())
+        .map_err(ErrWrapper)
+    }
+
     fn transfer(
         &self,
         amount: &str,
@@ -492,6 +524,65 @@ mod get_item {
     }
 }
 
+mod get_keys_with_prefix {
+    use casper_node::rpcs::state::{GetKeysWithPrefix, GetKeysWithPrefixParams};
+
+    use super::*;
+
+    const VALID_CURSOR: &str =
+        "transfer-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_succeed_with_valid_arguments() {
+        let server_handle =
+            MockServerHandle::spawn::<GetKeysWithPrefixParams>(GetKeysWithPrefix::METHOD);
+        assert_eq!(
+            server_handle.get_keys_with_prefix(VALID_STATE_ROOT_HASH, "03", VALID_CURSOR, "10"),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_succeed_without_cursor_and_limit() {
+        let server_handle =
+            MockServerHandle::spawn::<GetKeysWithPrefixParams>(GetKeysWithPrefix::METHOD);
+        assert_eq!(
+            server_handle.get_keys_with_prefix(VALID_STATE_ROOT_HASH, "03", "", ""),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_invalid_prefix() {
+        let server_handle =
+            MockServerHandle::spawn::<GetKeysWithPrefixParams>(GetKeysWithPrefix::METHOD);
+        assert_eq!(
+            server_handle.get_keys_with_prefix(VALID_STATE_ROOT_HASH, "0", "", ""),
+            Err(Error::InvalidArgument("prefix", FromHexError::OddLength.to_string()).into())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_invalid_cursor() {
+        let server_handle =
+            MockServerHandle::spawn::<GetKeysWithPrefixParams>(GetKeysWithPrefix::METHOD);
+        assert_eq!(
+            server_handle.get_keys_with_prefix(VALID_STATE_ROOT_HASH, "03", "invalid key", ""),
+            Err(Error::FailedToParseKey.into())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_invalid_limit() {
+        let server_handle =
+            MockServerHandle::spawn::<GetKeysWithPrefixParams>(GetKeysWithPrefix::METHOD);
+        assert_eq!(
+            server_handle.get_keys_with_prefix(VALID_STATE_ROOT_HASH, "03", "", "ten"),
+            Err(Error::FailedToParseInt("limit", "ten".parse::<u32>().unwrap_err()).into())
+        );
+    }
+}
+
 mod get_deploy {
     use super::*;
 
@@ -519,6 +610,73 @@ mod get_deploy {
     }
 }
 
+mod get_deploy_status {
+    use casper_node::rpcs::info::{GetDeployStatus, GetDeployStatusParams};
+
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_succeed_with_valid_hash() {
+        let server_handle =
+            MockServerHandle::spawn::<GetDeployStatusParams>(GetDeployStatus::METHOD);
+        assert_eq!(
+            server_handle.get_deploy_status(
+                "09dcee4b212cfd53642ab323fbef07dafafc6f945a80a00147f62910a915c4e6"
+            ),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_invalid_hash() {
+        let server_handle =
+            MockServerHandle::spawn::<GetDeployStatusParams>(GetDeployStatus::METHOD);
+        assert_eq!(
+            server_handle.get_deploy_status("012345"),
+            Err(Error::CryptoError {
+                context: "deploy_hash",
+                error: CryptoError::FromHex(FromHexError::InvalidStringLength)
+            }
+            .into())
+        );
+    }
+}
+
+mod get_pending_deploys {
+    use casper_node::rpcs::info::{GetPendingDeploys, GetPendingDeploysParams};
+
+    use super::*;
+
+    const VALID_CURSOR: &str = "09dcee4b212cfd53642ab323fbef07dafafc6f945a80a00147f62910a915c4e6";
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_succeed_with_valid_arguments() {
+        let server_handle =
+            MockServerHandle::spawn::<GetPendingDeploysParams>(GetPendingDeploys::METHOD);
+        assert_eq!(
+            server_handle.get_pending_deploys(VALID_CURSOR, "10"),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_succeed_without_cursor_and_limit() {
+        let server_handle =
+            MockServerHandle::spawn::<GetPendingDeploysParams>(GetPendingDeploys::METHOD);
+        assert_eq!(server_handle.get_pending_deploys("", ""), Ok(()));
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_with_invalid_limit() {
+        let server_handle =
+            MockServerHandle::spawn::<GetPendingDeploysParams>(GetPendingDeploys::METHOD);
+        assert_eq!(
+            server_handle.get_pending_deploys("", "ten"),
+            Err(Error::FailedToParseInt("limit", "ten".parse::<u32>().unwrap_err()).into())
+        );
+    }
+}
+
 mod get_auction_info {
     use super::*;
 
@@ -1,7 +1,10 @@
+use super::wasm_module_cache::DEFAULT_MAX_CACHED_WASM_MODULES;
+
 /// The runtime configuration of the execution engine
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone)]
 pub struct EngineConfig {
     // feature flags go here
+    max_cached_wasm_modules: usize,
 }
 
 impl EngineConfig {
@@ -9,4 +12,24 @@ impl EngineConfig {
     pub fn new() -> EngineConfig {
         Default::default()
     }
+
+    /// Returns the maximum number of deserialized stored contract modules kept in memory.
+    pub fn max_cached_wasm_modules(&self) -> usize {
+        self.max_cached_wasm_modules
+    }
+
+    /// Sets the maximum number of deserialized stored contract modules kept in memory.  Zero
+    /// disables caching.
+    pub fn with_max_cached_wasm_modules(mut self, max_cached_wasm_modules: usize) -> EngineConfig {
+        self.max_cached_wasm_modules = max_cached_wasm_modules;
+        self
+    }
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        EngineConfig {
+            max_cached_wasm_modules: DEFAULT_MAX_CACHED_WASM_MODULES,
+        }
+    }
 }
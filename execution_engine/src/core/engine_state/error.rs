@@ -52,6 +52,8 @@ pub enum Error {
     ProtocolUpgrade(ProtocolUpgradeError),
     #[error("Unsupported deploy item variant: {0}")]
     InvalidDeployItemVariant(String),
+    #[error("Invalid view target: {0}")]
+    InvalidViewTarget(String),
 }
 
 impl From<execution::Error> for Error {
@@ -29,7 +29,7 @@ use casper_types::{
 use super::error;
 use crate::{
     core::{
-        engine_state::{Error, ExecError, MAX_PAYMENT_AMOUNT},
+        engine_state::{Error, ExecError, WasmModuleCache, MAX_PAYMENT_AMOUNT},
         execution,
         tracking_copy::{TrackingCopy, TrackingCopyExt},
     },
@@ -147,6 +147,7 @@ impl ExecutableDeployItem {
         account: &Account,
         correlation_id: CorrelationId,
         preprocessor: &Preprocessor,
+        wasm_module_cache: &WasmModuleCache,
         protocol_version: &ProtocolVersion,
         protocol_data: &ProtocolData,
         phase: Phase,
@@ -282,11 +283,18 @@ impl ExecutableDeployItem {
             });
         }
 
+        // The contract Wasm is always read so that the effects of execution don't depend on the
+        // state of the cache.
         let contract_wasm = tracking_copy
             .borrow_mut()
             .get_contract_wasm(correlation_id, contract.contract_wasm_hash())?;
 
-        let module = wasm_prep::deserialize(contract_wasm.bytes())?;
+        let module = wasm_module_cache.get_or_insert_with(
+            contract.contract_wasm_hash(),
+            *protocol_version,
+            contract_wasm.bytes().len(),
+            || wasm_prep::deserialize(contract_wasm.bytes()),
+        )?;
 
         match entry_point.entry_point_type() {
             EntryPointType::Session => Ok(DeployMetadata::Session {
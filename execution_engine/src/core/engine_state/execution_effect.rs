@@ -3,15 +3,54 @@ use casper_types::Key;
 use super::op::Op;
 use crate::shared::{additive_map::AdditiveMap, transform::Transform};
 
+/// A message emitted by a contract via the `casper_emit_message` host function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Message {
+    /// The key of the account or contract in whose context the message was emitted.
+    pub emitter: Key,
+    pub topic: String,
+    pub payload: Vec<u8>,
+}
+
+impl Message {
+    pub fn new(emitter: Key, topic: String, payload: Vec<u8>) -> Self {
+        Message {
+            emitter,
+            topic,
+            payload,
+        }
+    }
+}
+
+impl From<&Message> for casper_types::Message {
+    fn from(message: &Message) -> Self {
+        casper_types::Message {
+            emitter: message.emitter.to_formatted_string(),
+            topic: message.topic.clone(),
+            payload: base16::encode_lower(&message.payload),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ExecutionEffect {
     pub ops: AdditiveMap<Key, Op>,
     pub transforms: AdditiveMap<Key, Transform>,
+    pub messages: Vec<Message>,
 }
 
 impl ExecutionEffect {
     pub fn new(ops: AdditiveMap<Key, Op>, transforms: AdditiveMap<Key, Transform>) -> Self {
-        ExecutionEffect { ops, transforms }
+        ExecutionEffect {
+            ops,
+            transforms,
+            messages: Vec::new(),
+        }
+    }
+
+    pub fn with_messages(mut self, messages: Vec<Message>) -> Self {
+        self.messages = messages;
+        self
     }
 }
 
@@ -34,6 +73,7 @@ impl From<&ExecutionEffect> for casper_types::ExecutionEffect {
                     transform: transform.into(),
                 })
                 .collect(),
+            messages: effect.messages.iter().map(Into::into).collect(),
         }
     }
 }
@@ -2,7 +2,11 @@ use std::collections::VecDeque;
 
 use casper_types::{bytesrepr::FromBytes, CLTyped, CLValue, CLValueError, Key, TransferAddr};
 
-use super::{error, execution_effect::ExecutionEffect, op::Op};
+use super::{
+    error,
+    execution_effect::{ExecutionEffect, Message},
+    op::Op,
+};
 use crate::{
     shared::{
         additive_map::AdditiveMap, gas::Gas, motes::Motes, newtypes::CorrelationId,
@@ -390,6 +394,7 @@ impl ExecutionResultBuilder {
         let cost = self.total_cost();
         let mut ops = AdditiveMap::new();
         let mut transforms = AdditiveMap::new();
+        let mut messages = Vec::new();
 
         let mut ret: ExecutionResult = ExecutionResult::Success {
             effect: Default::default(),
@@ -402,7 +407,7 @@ impl ExecutionResultBuilder {
                 if result.is_failure() {
                     return Ok(result);
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(&mut ops, &mut transforms, &mut messages, result.effect());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingPaymentExecutionResult),
@@ -415,7 +420,7 @@ impl ExecutionResultBuilder {
                 if result.is_failure() {
                     ret = result.with_cost(cost);
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(&mut ops, &mut transforms, &mut messages, result.effect());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingSessionExecutionResult),
@@ -429,14 +434,15 @@ impl ExecutionResultBuilder {
                         error::Error::Finalization,
                     ));
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(&mut ops, &mut transforms, &mut messages, result.effect());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingFinalizeExecutionResult),
         }
 
         // Remove redundant writes to allow more opportunity to commute
-        let reduced_effect = Self::reduce_identity_writes(ops, transforms, reader, correlation_id);
+        let reduced_effect = Self::reduce_identity_writes(ops, transforms, reader, correlation_id)
+            .with_messages(messages);
 
         Ok(ret.with_effect(reduced_effect))
     }
@@ -444,6 +450,7 @@ impl ExecutionResultBuilder {
     fn add_effects(
         ops: &mut AdditiveMap<Key, Op>,
         transforms: &mut AdditiveMap<Key, Transform>,
+        messages: &mut Vec<Message>,
         effect: &ExecutionEffect,
     ) {
         for (k, op) in effect.ops.iter() {
@@ -452,6 +459,7 @@ impl ExecutionResultBuilder {
         for (k, t) in effect.transforms.iter() {
             transforms.insert_add(*k, t.clone())
         }
+        messages.extend(effect.messages.iter().cloned());
     }
 
     /// In the case we are writing the same value as was there originally,
@@ -20,15 +20,17 @@ use casper_types::{
     system::{
         auction::{
             Bid, Bids, DelegationRate, SeigniorageRecipient, SeigniorageRecipients,
-            SeigniorageRecipientsSnapshot, UnbondingPurses, ValidatorWeights, ARG_DELEGATION_RATE,
-            ARG_DELEGATOR, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_PUBLIC_KEY, ARG_REWARD_FACTORS,
-            ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEY, AUCTION_DELAY_KEY, BIDS_KEY,
+            SeigniorageRecipientsSnapshot, ValidatorWeights, ARG_DELEGATION_RATE, ARG_DELEGATOR,
+            ARG_ERA_END_TIMESTAMP_MILLIS, ARG_NEW_VALIDATOR, ARG_PUBLIC_KEY, ARG_REWARD_FACTORS,
+            ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEY, AUCTION_DELAY_KEY,
             ERA_END_TIMESTAMP_MILLIS_KEY, ERA_ID_KEY, INITIAL_ERA_END_TIMESTAMP_MILLIS,
-            INITIAL_ERA_ID, LOCKED_FUNDS_PERIOD_KEY, METHOD_ACTIVATE_BID, METHOD_ADD_BID,
-            METHOD_DELEGATE, METHOD_DISTRIBUTE, METHOD_GET_ERA_VALIDATORS, METHOD_READ_ERA_ID,
-            METHOD_READ_SEIGNIORAGE_RECIPIENTS, METHOD_RUN_AUCTION, METHOD_SLASH,
-            METHOD_UNDELEGATE, METHOD_WITHDRAW_BID, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY,
-            UNBONDING_DELAY_KEY, UNBONDING_PURSES_KEY, VALIDATOR_SLOTS_KEY,
+            INITIAL_ERA_ID, LOCKED_FUNDS_PERIOD_KEY, MAX_DELEGATORS_PER_VALIDATOR_KEY,
+            METHOD_ACTIVATE_BID, METHOD_ADD_BID, METHOD_DELEGATE, METHOD_DISTRIBUTE,
+            METHOD_GET_ERA_VALIDATORS, METHOD_READ_ERA_ID, METHOD_READ_SEIGNIORAGE_RECIPIENTS,
+            METHOD_REDELEGATE, METHOD_RUN_AUCTION, METHOD_SLASH, METHOD_UNDELEGATE,
+            METHOD_WITHDRAW_BID, MINIMUM_DELEGATION_AMOUNT_KEY, REDELEGATION_DELAY_KEY,
+            SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, SLASHING_RATE_KEY, UNBONDING_DELAY_KEY,
+            VALIDATOR_SLOTS_KEY,
         },
         mint::{
             self, ARG_AMOUNT, ARG_ID, ARG_PURSE, ARG_ROUND_SEIGNIORAGE_RATE, ARG_SOURCE,
@@ -51,7 +53,7 @@ use casper_types::{
 use super::SYSTEM_ACCOUNT_ADDR;
 use crate::{
     core::{
-        engine_state::{execution_effect::ExecutionEffect, EngineConfig},
+        engine_state::{execution_effect::ExecutionEffect, EngineConfig, WasmModuleCache},
         execution,
         execution::{AddressGenerator, Executor},
         tracking_copy::TrackingCopy,
@@ -314,6 +316,10 @@ pub struct ExecConfig {
     locked_funds_period_millis: u64,
     round_seigniorage_rate: Ratio<u64>,
     unbonding_delay: u64,
+    redelegation_delay: u64,
+    minimum_delegation_amount: u64,
+    max_delegators_per_validator: u32,
+    slashing_rate: Ratio<u64>,
     genesis_timestamp_millis: u64,
 }
 
@@ -328,6 +334,10 @@ impl ExecConfig {
         locked_funds_period_millis: u64,
         round_seigniorage_rate: Ratio<u64>,
         unbonding_delay: u64,
+        redelegation_delay: u64,
+        minimum_delegation_amount: u64,
+        max_delegators_per_validator: u32,
+        slashing_rate: Ratio<u64>,
         genesis_timestamp_millis: u64,
     ) -> ExecConfig {
         ExecConfig {
@@ -339,6 +349,10 @@ impl ExecConfig {
             locked_funds_period_millis,
             round_seigniorage_rate,
             unbonding_delay,
+            redelegation_delay,
+            minimum_delegation_amount,
+            max_delegators_per_validator,
+            slashing_rate,
             genesis_timestamp_millis,
         }
     }
@@ -385,6 +399,22 @@ impl ExecConfig {
         self.unbonding_delay
     }
 
+    pub fn redelegation_delay(&self) -> u64 {
+        self.redelegation_delay
+    }
+
+    pub fn minimum_delegation_amount(&self) -> u64 {
+        self.minimum_delegation_amount
+    }
+
+    pub fn max_delegators_per_validator(&self) -> u32 {
+        self.max_delegators_per_validator
+    }
+
+    pub fn slashing_rate(&self) -> Ratio<u64> {
+        self.slashing_rate
+    }
+
     pub fn genesis_timestamp_millis(&self) -> u64 {
         self.genesis_timestamp_millis
     }
@@ -413,6 +443,15 @@ impl Distribution<ExecConfig> for Standard {
 
         let unbonding_delay = rng.gen();
 
+        let redelegation_delay = rng.gen();
+
+        let minimum_delegation_amount = rng.gen();
+
+        let max_delegators_per_validator = rng.gen();
+
+        let slashing_denom = rng.gen_range(1, 1_000_000_000);
+        let slashing_rate = Ratio::new(rng.gen_range(0, slashing_denom + 1), slashing_denom);
+
         let genesis_timestamp_millis = rng.gen();
 
         ExecConfig {
@@ -424,6 +463,10 @@ impl Distribution<ExecConfig> for Standard {
             locked_funds_period_millis,
             round_seigniorage_rate,
             unbonding_delay,
+            redelegation_delay,
+            minimum_delegation_amount,
+            max_delegators_per_validator,
+            slashing_rate,
             genesis_timestamp_millis,
         }
     }
@@ -470,11 +513,12 @@ where
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
         engine_config: EngineConfig,
+        wasm_module_cache: WasmModuleCache,
         exec_config: ExecConfig,
         tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
         system_module: Module,
     ) -> Self {
-        let executor = Executor::new(engine_config);
+        let executor = Executor::new(engine_config, wasm_module_cache);
 
         let phase = Phase::System;
         let genesis_config_hash_bytes = genesis_config_hash.as_ref();
@@ -649,8 +693,12 @@ where
                 )?;
                 let release_timestamp_millis =
                     genesis_timestamp_millis + locked_funds_period_millis;
-                let founding_validator =
-                    Bid::locked(purse_uref, *staked_amount, release_timestamp_millis);
+                let founding_validator = Bid::locked(
+                    *public_key,
+                    purse_uref,
+                    *staked_amount,
+                    release_timestamp_millis,
+                );
                 validators.insert(*public_key, founding_validator);
             }
             validators
@@ -703,31 +751,12 @@ where
             initial_seigniorage_recipients_uref.into(),
         );
 
-        let bids_uref = self
-            .uref_address_generator
-            .borrow_mut()
-            .new_uref(AccessRights::READ_ADD_WRITE);
-        self.tracking_copy.borrow_mut().write(
-            bids_uref.into(),
-            StoredValue::CLValue(
-                CLValue::from_t(validators)
-                    .map_err(|_| GenesisError::CLValue(BIDS_KEY.to_string()))?,
-            ),
-        );
-        named_keys.insert(BIDS_KEY.into(), bids_uref.into());
-
-        let unbonding_purses_uref = self
-            .uref_address_generator
-            .borrow_mut()
-            .new_uref(AccessRights::READ_ADD_WRITE);
-        self.tracking_copy.borrow_mut().write(
-            unbonding_purses_uref.into(),
-            StoredValue::CLValue(
-                CLValue::from_t(UnbondingPurses::new())
-                    .map_err(|_| GenesisError::CLValue(UNBONDING_PURSES_KEY.to_string()))?,
-            ),
-        );
-        named_keys.insert(UNBONDING_PURSES_KEY.into(), unbonding_purses_uref.into());
+        for (public_key, bid) in validators {
+            self.tracking_copy.borrow_mut().write(
+                Key::Bid(public_key.to_account_hash()),
+                StoredValue::Bid(Box::new(bid)),
+            );
+        }
 
         let validator_slots = self.exec_config.validator_slots();
         let validator_slots_uref = self
@@ -786,6 +815,71 @@ where
         );
         named_keys.insert(UNBONDING_DELAY_KEY.into(), unbonding_delay_uref.into());
 
+        let redelegation_delay = self.exec_config.redelegation_delay();
+        let redelegation_delay_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            redelegation_delay_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(redelegation_delay)
+                    .map_err(|_| GenesisError::CLValue(REDELEGATION_DELAY_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(
+            REDELEGATION_DELAY_KEY.into(),
+            redelegation_delay_uref.into(),
+        );
+
+        let minimum_delegation_amount = self.exec_config.minimum_delegation_amount();
+        let minimum_delegation_amount_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            minimum_delegation_amount_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(minimum_delegation_amount).map_err(|_| {
+                    GenesisError::CLValue(MINIMUM_DELEGATION_AMOUNT_KEY.to_string())
+                })?,
+            ),
+        );
+        named_keys.insert(
+            MINIMUM_DELEGATION_AMOUNT_KEY.into(),
+            minimum_delegation_amount_uref.into(),
+        );
+
+        let max_delegators_per_validator = self.exec_config.max_delegators_per_validator();
+        let max_delegators_per_validator_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            max_delegators_per_validator_uref.into(),
+            StoredValue::CLValue(CLValue::from_t(max_delegators_per_validator).map_err(|_| {
+                GenesisError::CLValue(MAX_DELEGATORS_PER_VALIDATOR_KEY.to_string())
+            })?),
+        );
+        named_keys.insert(
+            MAX_DELEGATORS_PER_VALIDATOR_KEY.into(),
+            max_delegators_per_validator_uref.into(),
+        );
+
+        let slashing_rate = self.exec_config.slashing_rate();
+        let slashing_rate_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            slashing_rate_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(slashing_rate)
+                    .map_err(|_| GenesisError::CLValue(SLASHING_RATE_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(SLASHING_RATE_KEY.into(), slashing_rate_uref.into());
+
         let entry_points = self.auction_entry_points();
 
         let access_key = self
@@ -1163,6 +1257,8 @@ where
         );
         entry_points.add_entry_point(entry_point);
 
+        entry_points.add_entry_point(redelegate_entry_point());
+
         let entry_point = EntryPoint::new(
             METHOD_RUN_AUCTION,
             vec![Parameter::new(ARG_ERA_END_TIMESTAMP_MILLIS, u64::cl_type())],
@@ -1236,6 +1332,24 @@ where
     }
 }
 
+/// Returns the auction contract's `redelegate` entry point.
+///
+/// Also used to add the entry point to auction contracts installed before it existed.
+pub(crate) fn redelegate_entry_point() -> EntryPoint {
+    EntryPoint::new(
+        METHOD_REDELEGATE,
+        vec![
+            Parameter::new(ARG_DELEGATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+            Parameter::new(ARG_NEW_VALIDATOR, PublicKey::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,59 @@
+use casper_types::Key;
+
+use crate::shared::{newtypes::Blake2bHash, stored_value::StoredValue};
+
+#[derive(Debug)]
+pub enum KeysWithPrefixResult {
+    RootNotFound,
+    Success {
+        /// The keys matching the prefix, along with their stored values, in ascending order of
+        /// their serialized form.
+        keys_and_values: Vec<(Key, StoredValue)>,
+        /// The last key of this page if further matching keys exist, to be passed as the cursor
+        /// of the next request.
+        next_cursor: Option<Key>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeysWithPrefixRequest {
+    state_hash: Blake2bHash,
+    prefix: Vec<u8>,
+    cursor: Option<Key>,
+    limit: usize,
+}
+
+impl KeysWithPrefixRequest {
+    /// Creates a request for at most `limit` keys whose serialized form starts with `prefix`.
+    ///
+    /// If `cursor` is given, only keys strictly greater than it are returned.
+    pub fn new(
+        state_hash: Blake2bHash,
+        prefix: Vec<u8>,
+        cursor: Option<Key>,
+        limit: usize,
+    ) -> Self {
+        KeysWithPrefixRequest {
+            state_hash,
+            prefix,
+            cursor,
+            limit,
+        }
+    }
+
+    pub fn state_hash(&self) -> Blake2bHash {
+        self.state_hash
+    }
+
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    pub fn cursor(&self) -> Option<&Key> {
+        self.cursor.as_ref()
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
@@ -8,6 +8,7 @@ pub mod execute_request;
 pub mod execution_effect;
 pub mod execution_result;
 pub mod genesis;
+pub mod keys_with_prefix;
 pub mod op;
 pub mod put_trie;
 pub mod query;
@@ -15,12 +16,16 @@ pub mod run_genesis_request;
 pub mod step;
 pub mod system_contract_cache;
 mod transfer;
+pub mod transfer_scheduler;
 pub mod upgrade;
+pub mod view;
+pub mod wasm_module_cache;
 
 use std::{cell::RefCell, collections::BTreeSet, convert::TryFrom, iter::FromIterator, rc::Rc};
 
 use num_rational::Ratio;
 use once_cell::sync::Lazy;
+use rayon::prelude::*;
 use tracing::{debug, error};
 
 use casper_types::{
@@ -31,7 +36,9 @@ use casper_types::{
         auction::{
             EraValidators, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS,
             ARG_REWARD_FACTORS, ARG_VALIDATOR_PUBLIC_KEYS, AUCTION_DELAY_KEY,
-            LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
+            LOCKED_FUNDS_PERIOD_KEY, MAX_DELEGATORS_PER_VALIDATOR_KEY,
+            MINIMUM_DELEGATION_AMOUNT_KEY, SLASHING_RATE_KEY, UNBONDING_DELAY_KEY,
+            VALIDATOR_SLOTS_KEY,
         },
         mint::{self, ROUND_SEIGNIORAGE_RATE_KEY},
         proof_of_stake,
@@ -51,20 +58,24 @@ pub use self::{
     execution::Error as ExecError,
     execution_result::{ExecutionResult, ExecutionResults, ForcedTransferResult},
     genesis::{ExecConfig, GenesisAccount, GenesisResult, POS_PAYMENT_PURSE},
+    keys_with_prefix::{KeysWithPrefixRequest, KeysWithPrefixResult},
     query::{QueryRequest, QueryResult},
     step::{RewardItem, SlashItem, StepRequest, StepResult},
     system_contract_cache::SystemContractCache,
     transfer::{TransferArgs, TransferRuntimeArgsBuilder, TransferTargetMode},
+    transfer_scheduler::ExecuteTransfersResult,
     upgrade::{UpgradeConfig, UpgradeResult},
+    view::{ViewRequest, ViewResult},
+    wasm_module_cache::{WasmModuleCache, WasmModuleCacheStats},
 };
 use crate::{
     core::{
         engine_state::{
             executable_deploy_item::DeployMetadata, execution_result::ExecutionResultBuilder,
             genesis::GenesisInstaller, put_trie::InsertedTrieKeyAndMissingDescendants,
-            upgrade::SystemUpgrader,
+            transfer_scheduler::TransferFootprint, upgrade::SystemUpgrader,
         },
-        execution::{self, DirectSystemContractCall, Executor},
+        execution::{self, DirectSystemContractCall, ExecutionTrace, ExecutionTracer, Executor},
         tracking_copy::{TrackingCopy, TrackingCopyExt},
     },
     shared::{
@@ -78,7 +89,7 @@ use crate::{
         wasm_prep::Preprocessor,
     },
     storage::{
-        global_state::{CommitResult, StateProvider},
+        global_state::{CommitResult, StateProvider, StateReader},
         protocol_data::ProtocolData,
         trie::Trie,
     },
@@ -97,6 +108,7 @@ pub const WASMLESS_TRANSFER_FIXED_GAS_PRICE: u64 = 1;
 pub struct EngineState<S> {
     config: EngineConfig,
     system_contract_cache: SystemContractCache,
+    wasm_module_cache: WasmModuleCache,
     state: S,
 }
 
@@ -107,9 +119,11 @@ where
 {
     pub fn new(state: S, config: EngineConfig) -> EngineState<S> {
         let system_contract_cache = Default::default();
+        let wasm_module_cache = WasmModuleCache::new(config.max_cached_wasm_modules());
         EngineState {
             config,
             system_contract_cache,
+            wasm_module_cache,
             state,
         }
     }
@@ -118,6 +132,11 @@ where
         &self.config
     }
 
+    /// Returns the cache of deserialized stored contract modules.
+    pub fn wasm_module_cache(&self) -> &WasmModuleCache {
+        &self.wasm_module_cache
+    }
+
     pub fn get_protocol_data(
         &self,
         protocol_version: ProtocolVersion,
@@ -160,6 +179,7 @@ where
             protocol_version,
             correlation_id,
             self.config,
+            self.wasm_module_cache.clone(),
             ee_config.clone(),
             tracking_copy,
             system_module,
@@ -257,19 +277,65 @@ where
             return Err(Error::InvalidProtocolVersion(new_protocol_version));
         }
 
+        let system_upgrader: SystemUpgrader<S> = SystemUpgrader::new(
+            pre_state_hash,
+            new_protocol_version,
+            current_protocol_data,
+            tracking_copy.clone(),
+        );
+
         // 3.1.1.1.1.5 bump system contract major versions
         if upgrade_check_result.is_major_version() {
-            let system_upgrader: SystemUpgrader<S> = SystemUpgrader::new(
-                new_protocol_version,
-                current_protocol_data,
-                tracking_copy.clone(),
-            );
-
             system_upgrader
                 .upgrade_system_contracts_major_version(correlation_id)
                 .map_err(Error::ProtocolUpgrade)?;
         }
 
+        // move auction bids and unbonding purses to their own keys, if not done already
+        system_upgrader
+            .migrate_auction_bids(correlation_id)
+            .map_err(Error::ProtocolUpgrade)?;
+
+        // move purse balances out of the mint's named keys, if not done already
+        system_upgrader
+            .migrate_purse_balances(correlation_id)
+            .map_err(Error::ProtocolUpgrade)?;
+
+        if let Some(new_redelegation_delay) = upgrade_config.new_redelegation_delay() {
+            system_upgrader
+                .set_redelegation_delay(correlation_id, new_redelegation_delay)
+                .map_err(Error::ProtocolUpgrade)?;
+        }
+
+        if let Some(new_minimum_delegation_amount) = upgrade_config.new_minimum_delegation_amount()
+        {
+            system_upgrader
+                .set_auction_parameter(
+                    correlation_id,
+                    MINIMUM_DELEGATION_AMOUNT_KEY,
+                    new_minimum_delegation_amount,
+                )
+                .map_err(Error::ProtocolUpgrade)?;
+        }
+
+        if let Some(new_max_delegators_per_validator) =
+            upgrade_config.new_max_delegators_per_validator()
+        {
+            system_upgrader
+                .set_auction_parameter(
+                    correlation_id,
+                    MAX_DELEGATORS_PER_VALIDATOR_KEY,
+                    new_max_delegators_per_validator,
+                )
+                .map_err(Error::ProtocolUpgrade)?;
+        }
+
+        if let Some(new_slashing_rate) = upgrade_config.new_slashing_rate() {
+            system_upgrader
+                .set_auction_parameter(correlation_id, SLASHING_RATE_KEY, new_slashing_rate)
+                .map_err(Error::ProtocolUpgrade)?;
+        }
+
         // 3.1.1.1.1.6 resolve wasm CostTable for new protocol version
         let new_wasm_config = match upgrade_config.wasm_config() {
             Some(new_wasm_costs) => new_wasm_costs,
@@ -388,6 +454,9 @@ where
             )
             .map_err(Into::into)?;
 
+        // the upgrade may have overwritten stored contract wasm, so drop any cached modules
+        self.wasm_module_cache.clear();
+
         // return result and effects
         Ok(UpgradeResult::from_commit_result(commit_result, effects))
     }
@@ -420,49 +489,335 @@ where
             .into())
     }
 
+    /// Calls an entry point of a stored contract without a deploy, returning the value passed to
+    /// `runtime::ret()`.
+    ///
+    /// The call runs in a read-only context, so any attempt to write to global state fails, and
+    /// it may consume at most the request's gas limit.  Nothing is charged to the caller.
+    pub fn run_view(
+        &self,
+        correlation_id: CorrelationId,
+        view_request: ViewRequest,
+    ) -> Result<ViewResult, Error> {
+        let protocol_version = view_request.protocol_version();
+        let protocol_data = match self.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => return Err(Error::InvalidProtocolVersion(protocol_version)),
+        };
+
+        let tracking_copy = match self.tracking_copy(view_request.state_hash())? {
+            Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
+            None => return Ok(ViewResult::RootNotFound),
+        };
+
+        let account = tracking_copy
+            .borrow_mut()
+            .get_account(correlation_id, view_request.caller())?;
+
+        let target = view_request.target();
+        match target {
+            ExecutableDeployItem::StoredContractByHash { .. }
+            | ExecutableDeployItem::StoredVersionedContractByHash { .. } => {}
+            _ => {
+                return Err(Error::InvalidViewTarget(String::from(
+                    "only contracts stored by hash can be viewed",
+                )))
+            }
+        }
+        let args = target.args().clone();
+
+        let preprocessor = Preprocessor::new(*protocol_data.wasm_config());
+        let (module, base_key, contract, contract_package, entry_point) = match target
+            .get_deploy_metadata(
+                Rc::clone(&tracking_copy),
+                &account,
+                correlation_id,
+                &preprocessor,
+                &self.wasm_module_cache,
+                &protocol_version,
+                &protocol_data,
+                Phase::Session,
+            )? {
+            DeployMetadata::Contract {
+                module,
+                base_key,
+                contract,
+                contract_package,
+                entry_point,
+            } => (module, base_key, contract, contract_package, entry_point),
+            DeployMetadata::Session { .. } => {
+                return Err(Error::InvalidViewTarget(String::from(
+                    "only entry points of type contract can be viewed",
+                )))
+            }
+            DeployMetadata::System { .. } => {
+                return Err(Error::InvalidViewTarget(String::from(
+                    "system contracts cannot be viewed",
+                )))
+            }
+        };
+
+        let executor = Executor::new(self.config, self.wasm_module_cache.clone());
+        let mut named_keys = contract.named_keys().clone();
+        let (value, cost) = executor.exec_view(
+            module,
+            entry_point,
+            args,
+            base_key,
+            &account,
+            &mut named_keys,
+            BlockTime::new(view_request.block_time()),
+            view_request.gas_limit(),
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            protocol_data,
+            SystemContractCache::clone(&self.system_contract_cache),
+            &contract_package,
+        )?;
+
+        Ok(ViewResult::Success { value, cost })
+    }
+
+    /// Returns a page of the keys and values stored under keys matching the requested prefix.
+    ///
+    /// Only the part of the trie following the cursor is walked, and the walk stops once the page
+    /// is full.  A request with a `limit` of zero returns no keys and hands back its cursor.
+    pub fn get_keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        request: KeysWithPrefixRequest,
+    ) -> Result<KeysWithPrefixResult, Error> {
+        let reader = match self
+            .state
+            .checkout(request.state_hash())
+            .map_err(Into::into)?
+        {
+            Some(reader) => reader,
+            None => return Ok(KeysWithPrefixResult::RootNotFound),
+        };
+
+        if request.limit() == 0 {
+            return Ok(KeysWithPrefixResult::Success {
+                keys_and_values: Vec::new(),
+                next_cursor: request.cursor().copied(),
+            });
+        }
+
+        // Fetch one key beyond the page to learn whether another page follows.
+        let mut keys = reader
+            .keys_with_prefix_after(
+                correlation_id,
+                request.prefix(),
+                request.cursor(),
+                request.limit().saturating_add(1),
+            )
+            .map_err(Into::into)?;
+        let has_more = keys.len() > request.limit();
+        keys.truncate(request.limit());
+
+        let mut keys_and_values = Vec::with_capacity(keys.len());
+        for key in &keys {
+            if let Some(value) = reader.read(correlation_id, key).map_err(Into::into)? {
+                keys_and_values.push((*key, value));
+            }
+        }
+
+        let next_cursor = if has_more { keys.last().copied() } else { None };
+
+        Ok(KeysWithPrefixResult::Success {
+            keys_and_values,
+            next_cursor,
+        })
+    }
+
     pub fn run_execute(
         &self,
         correlation_id: CorrelationId,
         mut exec_request: ExecuteRequest,
     ) -> Result<ExecutionResults, RootNotFound> {
-        let executor = Executor::new(self.config);
+        let executor = Executor::new(self.config, self.wasm_module_cache.clone());
 
         let deploys = exec_request.take_deploys();
         let mut results = ExecutionResults::with_capacity(deploys.len());
 
         for deploy_item in deploys {
-            let result = match deploy_item {
-                Err(exec_result) => Ok(exec_result),
-                Ok(deploy_item) => match deploy_item.session {
-                    ExecutableDeployItem::Transfer { .. } => self.transfer(
+            let result =
+                self.execute_deploy_item(correlation_id, &executor, &exec_request, deploy_item)?;
+            results.push_back(result);
+        }
+
+        Ok(results)
+    }
+
+    /// Executes the deploys in the same way as `run_execute`, additionally recording a trace of
+    /// the Wasm code run by each deploy.
+    pub fn run_execute_traced(
+        &self,
+        correlation_id: CorrelationId,
+        mut exec_request: ExecuteRequest,
+    ) -> Result<Vec<(ExecutionResult, ExecutionTrace)>, RootNotFound> {
+        let tracer = ExecutionTracer::new();
+        let executor =
+            Executor::new(self.config, self.wasm_module_cache.clone()).with_tracer(tracer.clone());
+
+        let deploys = exec_request.take_deploys();
+        let mut results = Vec::with_capacity(deploys.len());
+
+        for deploy_item in deploys {
+            let result =
+                self.execute_deploy_item(correlation_id, &executor, &exec_request, deploy_item)?;
+            results.push((result, tracer.take_trace()));
+        }
+
+        Ok(results)
+    }
+
+    /// Executes the deploys in the same way as `run_execute`, expecting them to be wasm-less
+    /// transfers, and commits their effects.
+    ///
+    /// Unlike `run_execute`, each deploy is executed against the state left by the previous ones,
+    /// as if the deploys were executed and committed one at a time.  Transfers which don't touch
+    /// the same accounts or purses are executed in parallel.
+    pub fn run_execute_transfers(
+        &self,
+        correlation_id: CorrelationId,
+        mut exec_request: ExecuteRequest,
+    ) -> Result<ExecuteTransfersResult, Error>
+    where
+        S: Sync,
+        Error: From<S::Error>,
+    {
+        let deploys = exec_request.take_deploys();
+
+        let footprints = {
+            let mut tracking_copy = match self.tracking_copy(exec_request.parent_state_hash)? {
+                Some(tracking_copy) => tracking_copy,
+                None => return Ok(ExecuteTransfersResult::RootNotFound),
+            };
+            let shared = self
+                .get_protocol_data(exec_request.protocol_version)?
+                .and_then(|protocol_data| {
+                    transfer_scheduler::shared_entities(
                         correlation_id,
-                        &executor,
-                        exec_request.protocol_version,
-                        exec_request.parent_state_hash,
-                        BlockTime::new(exec_request.block_time),
-                        deploy_item,
+                        &mut tracking_copy,
+                        &protocol_data,
                         exec_request.proposer,
+                    )
+                });
+            deploys
+                .iter()
+                .map(|deploy_item| match &shared {
+                    Some(shared) => transfer_scheduler::transfer_footprint(
+                        correlation_id,
+                        &mut tracking_copy,
+                        deploy_item,
+                        shared,
                     ),
-                    _ => self.deploy(
+                    None => TransferFootprint::Exclusive,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut deploys = deploys.into_iter().map(Some).collect::<Vec<_>>();
+        let mut results: Vec<Option<ExecutionResult>> = vec![None; deploys.len()];
+        let mut state_hash = exec_request.parent_state_hash;
+        let block_time = exec_request.block_time;
+        let protocol_version = exec_request.protocol_version;
+        let proposer = exec_request.proposer;
+
+        for wave in transfer_scheduler::schedule(&footprints) {
+            let wave_deploys = wave
+                .iter()
+                .filter_map(|index| deploys[*index].take().map(|deploy| (*index, deploy)))
+                .collect::<Vec<_>>();
+            let wave_results = wave_deploys
+                .into_par_iter()
+                .map(|(index, deploy_item)| {
+                    // `Executor` isn't `Send`, so each transfer gets its own.
+                    let executor = Executor::new(self.config, self.wasm_module_cache.clone());
+                    let transfer_exec_request = ExecuteRequest::new(
+                        state_hash,
+                        block_time,
+                        Vec::new(),
+                        protocol_version,
+                        proposer,
+                    );
+                    self.execute_deploy_item(
                         correlation_id,
                         &executor,
-                        exec_request.protocol_version,
-                        exec_request.parent_state_hash,
-                        BlockTime::new(exec_request.block_time),
+                        &transfer_exec_request,
                         deploy_item,
-                        exec_request.proposer,
-                    ),
-                },
+                    )
+                    .map(|result| (index, result))
+                })
+                .collect::<Result<Vec<_>, RootNotFound>>();
+            let wave_results = match wave_results {
+                Ok(wave_results) => wave_results,
+                Err(_) => return Ok(ExecuteTransfersResult::RootNotFound),
             };
-            match result {
-                Ok(result) => results.push_back(result),
-                Err(error) => {
-                    return Err(error);
+
+            // Merge the effects in the order of the deploys, so the result doesn't depend on
+            // which transfer finished first.
+            let mut effects = AdditiveMap::new();
+            for (index, result) in wave_results {
+                for (key, transform) in result.effect().transforms.iter() {
+                    effects.insert_add(*key, transform.clone());
+                }
+                results[index] = Some(result);
+            }
+
+            state_hash = match self.apply_effect(correlation_id, state_hash, effects)? {
+                CommitResult::Success { state_root } => state_root,
+                CommitResult::RootNotFound => return Ok(ExecuteTransfersResult::RootNotFound),
+                CommitResult::KeyNotFound(key) => {
+                    return Ok(ExecuteTransfersResult::KeyNotFound(key))
+                }
+                CommitResult::TypeMismatch(type_mismatch) => {
+                    return Ok(ExecuteTransfersResult::TypeMismatch(type_mismatch))
+                }
+                CommitResult::Serialization(error) => {
+                    return Ok(ExecuteTransfersResult::Serialization(error))
                 }
             };
         }
 
-        Ok(results)
+        Ok(ExecuteTransfersResult::Success {
+            post_state_hash: state_hash,
+            execution_results: results.into_iter().flatten().collect(),
+        })
+    }
+
+    fn execute_deploy_item(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        exec_request: &ExecuteRequest,
+        deploy_item: Result<DeployItem, ExecutionResult>,
+    ) -> Result<ExecutionResult, RootNotFound> {
+        match deploy_item {
+            Err(exec_result) => Ok(exec_result),
+            Ok(deploy_item) => match deploy_item.session {
+                ExecutableDeployItem::Transfer { .. } => self.transfer(
+                    correlation_id,
+                    executor,
+                    exec_request.protocol_version,
+                    exec_request.parent_state_hash,
+                    BlockTime::new(exec_request.block_time),
+                    deploy_item,
+                    exec_request.proposer,
+                ),
+                _ => self.deploy(
+                    correlation_id,
+                    executor,
+                    exec_request.protocol_version,
+                    exec_request.parent_state_hash,
+                    BlockTime::new(exec_request.block_time),
+                    deploy_item,
+                    exec_request.proposer,
+                ),
+            },
+        }
     }
 
     fn get_authorized_account(
@@ -505,18 +860,12 @@ where
             Some(tracking_copy) => tracking_copy,
             None => return Ok(BalanceResult::RootNotFound),
         };
-        let (purse_balance_key, purse_proof) =
-            tracking_copy.get_purse_balance_key_with_proof(correlation_id, purse_uref.into())?;
-        let (balance, balance_proof) =
-            tracking_copy.get_purse_balance_with_proof(correlation_id, purse_balance_key)?;
-        let purse_proof = Box::new(purse_proof);
-        let balance_proof = Box::new(balance_proof);
+        let balance_key = Key::Balance(purse_uref.addr());
+        let (balance, proof) =
+            tracking_copy.get_purse_balance_with_proof(correlation_id, balance_key)?;
+        let proof = Box::new(proof);
         let motes = balance.value();
-        Ok(BalanceResult::Success {
-            motes,
-            purse_proof,
-            balance_proof,
-        })
+        Ok(BalanceResult::Success { motes, proof })
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1143,6 +1492,7 @@ where
             &account,
             correlation_id,
             &preprocessor,
+            &self.wasm_module_cache,
             &protocol_version,
             &protocol_data,
             Phase::Session,
@@ -1222,6 +1572,7 @@ where
                 &account,
                 correlation_id,
                 &preprocessor,
+                &self.wasm_module_cache,
                 &protocol_version,
                 &protocol_data,
                 phase,
@@ -1735,7 +2086,7 @@ where
                 .map_err(Error::from)?
         };
 
-        let executor = Executor::new(self.config);
+        let executor = Executor::new(self.config, self.wasm_module_cache.clone());
 
         let mut named_keys = auction_contract.named_keys().to_owned();
         let base_key = Key::from(protocol_data.auction());
@@ -1808,7 +2159,7 @@ where
             Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
         };
 
-        let executor = Executor::new(self.config);
+        let executor = Executor::new(self.config, self.wasm_module_cache.clone());
 
         let preprocessor = {
             let wasm_config = protocol_data.wasm_config();
@@ -1997,7 +2348,7 @@ where
             }
         }
 
-        let effects = tracking_copy.borrow().effect();
+        let execution_effect = tracking_copy.borrow().effect();
 
         // commit
         let commit_result = self
@@ -2005,7 +2356,7 @@ where
             .commit(
                 correlation_id,
                 step_request.pre_state_hash,
-                effects.transforms,
+                execution_effect.transforms.clone(),
             )
             .map_err(Into::into)?;
 
@@ -2044,6 +2395,7 @@ where
         Ok(StepResult::Success {
             post_state_hash,
             next_era_validators,
+            execution_effect,
         })
     }
 }
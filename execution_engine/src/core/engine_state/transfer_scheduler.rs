@@ -0,0 +1,280 @@
+//! Scheduling of wasm-less transfers for parallel execution.
+//!
+//! Transfers which touch disjoint sets of accounts and purses don't affect each other's outcome,
+//! so they can be executed against the same pre-state and their effects merged.  The only keys
+//! every transfer writes to are the proposer's main purse, to which the fee is added, and the
+//! payment purse, which is drained again by each transfer.  Both of these writes commute, unless a
+//! transfer also moves funds in or out of one of those purses, in which case it's scheduled on its
+//! own.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use casper_types::{
+    account::AccountHash, bytesrepr, system::mint, CLType, Key, PublicKey, URef, URefAddr,
+};
+
+use crate::{
+    core::{
+        engine_state::{
+            executable_deploy_item::ExecutableDeployItem, DeployItem, ExecutionResult,
+            ExecutionResults, POS_PAYMENT_PURSE,
+        },
+        execution,
+        tracking_copy::{TrackingCopy, TrackingCopyExt},
+    },
+    shared::{
+        newtypes::{Blake2bHash, CorrelationId},
+        stored_value::StoredValue,
+        TypeMismatch,
+    },
+    storage::{global_state::StateReader, protocol_data::ProtocolData},
+};
+
+/// The result of executing and committing a set of wasm-less transfers.
+#[derive(Debug)]
+pub enum ExecuteTransfersResult {
+    RootNotFound,
+    KeyNotFound(Key),
+    TypeMismatch(TypeMismatch),
+    Serialization(bytesrepr::Error),
+    Success {
+        /// The state root hash after committing the effects of all the transfers.
+        post_state_hash: Blake2bHash,
+        /// The results of executing the transfers, in the order of the request's deploys.
+        execution_results: ExecutionResults,
+    },
+}
+
+/// An account or purse read or written by a transfer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum TouchedEntity {
+    Account(AccountHash),
+    Purse(URefAddr),
+}
+
+/// The accounts and purses a transfer may read or write.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum TransferFootprint {
+    /// The transfer must not be executed in parallel with any other.
+    Exclusive,
+    /// The transfer only conflicts with others touching any of the given entities.
+    Touches(BTreeSet<TouchedEntity>),
+}
+
+/// Returns the entities touched by every transfer: the proposer's account and main purse, and the
+/// payment purse.
+///
+/// Returns `None` if any of these can't be read, in which case all transfers should be treated as
+/// exclusive.
+pub(crate) fn shared_entities<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    protocol_data: &ProtocolData,
+    proposer: PublicKey,
+) -> Option<BTreeSet<TouchedEntity>>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let proposer_account_hash = proposer.to_account_hash();
+    let proposer_account = tracking_copy
+        .read_account(correlation_id, proposer_account_hash)
+        .ok()?;
+    let payment_purse = tracking_copy
+        .get_contract(correlation_id, protocol_data.proof_of_stake())
+        .ok()?
+        .named_keys()
+        .get(POS_PAYMENT_PURSE)
+        .and_then(Key::as_uref)
+        .copied()?;
+
+    let mut shared = BTreeSet::new();
+    shared.insert(TouchedEntity::Account(proposer_account_hash));
+    shared.insert(TouchedEntity::Purse(proposer_account.main_purse().addr()));
+    shared.insert(TouchedEntity::Purse(payment_purse.addr()));
+    Some(shared)
+}
+
+/// Returns the footprint of the given deploy item, resolving accounts' main purses in the state
+/// of `tracking_copy`.
+///
+/// Anything other than a wasm-less transfer paid for by standard payment, and any transfer
+/// touching one of the `shared` entities or a purse which doesn't exist yet, is exclusive.  Custom
+/// payment code can read and write arbitrary keys, so its footprint can't be known up front.
+pub(crate) fn transfer_footprint<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    deploy_item: &Result<DeployItem, ExecutionResult>,
+    shared: &BTreeSet<TouchedEntity>,
+) -> TransferFootprint
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let (account_hash, args) = match deploy_item {
+        Ok(DeployItem {
+            address,
+            payment: ExecutableDeployItem::ModuleBytes { module_bytes, .. },
+            session: ExecutableDeployItem::Transfer { args },
+            ..
+        }) if module_bytes.is_empty() => (*address, args),
+        _ => return TransferFootprint::Exclusive,
+    };
+
+    let mut touched = BTreeSet::new();
+    let touch_account = |tracking_copy: &mut TrackingCopy<R>,
+                         touched: &mut BTreeSet<TouchedEntity>,
+                         account_hash: AccountHash| {
+        touched.insert(TouchedEntity::Account(account_hash));
+        // If the account doesn't exist yet, neither does the main purse a transfer would create
+        // along with it, so other transfers naming that purse are exclusive.
+        if let Ok(account) = tracking_copy.read_account(correlation_id, account_hash) {
+            touched.insert(TouchedEntity::Purse(account.main_purse().addr()));
+        }
+    };
+
+    touch_account(tracking_copy, &mut touched, account_hash);
+
+    if let Some(Ok(source)) = args
+        .get(mint::ARG_SOURCE)
+        .filter(|cl_value| *cl_value.cl_type() == CLType::URef)
+        .map(|cl_value| cl_value.clone().into_t::<URef>())
+    {
+        touched.insert(TouchedEntity::Purse(source.addr()));
+    }
+
+    match args.get(mint::ARG_TARGET) {
+        Some(cl_value) if *cl_value.cl_type() == CLType::URef => {
+            let target = match cl_value.clone().into_t::<URef>() {
+                Ok(target) => target,
+                Err(_) => return TransferFootprint::Exclusive,
+            };
+            // The purse may be created by an earlier transfer in the same block.
+            if tracking_copy
+                .get_purse_balance_key(correlation_id, target.into())
+                .is_err()
+            {
+                return TransferFootprint::Exclusive;
+            }
+            touched.insert(TouchedEntity::Purse(target.addr()));
+        }
+        Some(cl_value) if *cl_value.cl_type() == CLType::ByteArray(32) => {
+            match cl_value.clone().into_t::<AccountHash>() {
+                Ok(target) => touch_account(tracking_copy, &mut touched, target),
+                Err(_) => return TransferFootprint::Exclusive,
+            }
+        }
+        Some(cl_value) if *cl_value.cl_type() == CLType::Key => {
+            match cl_value
+                .clone()
+                .into_t::<Key>()
+                .map(|key| key.into_account())
+            {
+                Ok(Some(target)) => touch_account(tracking_copy, &mut touched, target),
+                _ => return TransferFootprint::Exclusive,
+            }
+        }
+        // The transfer fails without touching anything other than the source account's main
+        // purse.
+        _ => (),
+    }
+
+    if !touched.is_disjoint(shared) {
+        return TransferFootprint::Exclusive;
+    }
+    TransferFootprint::Touches(touched)
+}
+
+/// Partitions transfers into waves, returning the indices of the transfers in each wave in
+/// ascending order.
+///
+/// No two transfers in a wave conflict, and of any two conflicting transfers, the earlier one is
+/// in an earlier wave.  Executing the waves in order, and committing the merged effects of each
+/// wave before executing the next one, is therefore equivalent to executing the transfers one by
+/// one.
+pub(crate) fn schedule(footprints: &[TransferFootprint]) -> Vec<Vec<usize>> {
+    let mut waves: Vec<Vec<usize>> = Vec::new();
+    // The wave after the last one containing a transfer touching the given entity.
+    let mut next_wave_touching: BTreeMap<TouchedEntity, usize> = BTreeMap::new();
+    // The wave after the last one containing an exclusive transfer.
+    let mut barrier = 0;
+
+    for (index, footprint) in footprints.iter().enumerate() {
+        let wave = match footprint {
+            TransferFootprint::Exclusive => {
+                let wave = waves.len();
+                barrier = wave + 1;
+                wave
+            }
+            TransferFootprint::Touches(touched) => {
+                let wave = touched
+                    .iter()
+                    .filter_map(|entity| next_wave_touching.get(entity))
+                    .copied()
+                    .fold(barrier, usize::max);
+                for entity in touched {
+                    next_wave_touching.insert(*entity, wave + 1);
+                }
+                wave
+            }
+        };
+        if wave == waves.len() {
+            waves.push(Vec::new());
+        }
+        waves[wave].push(index);
+    }
+
+    waves
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use casper_types::account::AccountHash;
+
+    use super::{schedule, TouchedEntity, TransferFootprint};
+
+    fn touches(accounts: &[u8]) -> TransferFootprint {
+        let touched: BTreeSet<_> = accounts
+            .iter()
+            .map(|account| TouchedEntity::Account(AccountHash::new([*account; 32])))
+            .collect();
+        TransferFootprint::Touches(touched)
+    }
+
+    #[test]
+    fn should_schedule_disjoint_transfers_in_one_wave() {
+        let footprints = vec![touches(&[1, 2]), touches(&[3, 4]), touches(&[5])];
+        assert_eq!(schedule(&footprints), vec![vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn should_schedule_conflicting_transfers_in_order() {
+        let footprints = vec![
+            touches(&[1, 2]),
+            touches(&[2, 3]),
+            touches(&[4]),
+            touches(&[3, 5]),
+            touches(&[1]),
+        ];
+        assert_eq!(schedule(&footprints), vec![vec![0, 2], vec![1, 4], vec![3]]);
+    }
+
+    #[test]
+    fn should_schedule_exclusive_transfers_alone() {
+        let footprints = vec![
+            touches(&[1]),
+            touches(&[2]),
+            TransferFootprint::Exclusive,
+            touches(&[3]),
+            touches(&[4]),
+        ];
+        assert_eq!(schedule(&footprints), vec![vec![0, 1], vec![2], vec![3, 4]]);
+    }
+
+    #[test]
+    fn should_schedule_nothing() {
+        assert!(schedule(&[]).is_empty());
+    }
+}
@@ -4,13 +4,25 @@ use num_rational::Ratio;
 use thiserror::Error;
 
 use casper_types::{
-    bytesrepr,
-    system::{AUCTION, MINT, PROOF_OF_STAKE, STANDARD_PAYMENT},
-    ContractHash, Key, ProtocolVersion,
+    bytesrepr::{self, ToBytes},
+    contracts::NamedKeys,
+    system::{
+        auction::{
+            Bid, EraId, UnbondingPurse, UnbondingPurses, BIDS_KEY, METHOD_REDELEGATE,
+            REDELEGATION_DELAY_KEY, UNBONDING_PURSES_KEY,
+        },
+        AUCTION, MINT, PROOF_OF_STAKE, STANDARD_PAYMENT,
+    },
+    AccessRights, CLTyped, CLValue, Contract, ContractHash, Key, Phase, ProtocolVersion, PublicKey,
+    URef, U512,
 };
 
 use crate::{
-    core::{engine_state::execution_effect::ExecutionEffect, tracking_copy::TrackingCopy},
+    core::{
+        engine_state::{execution_effect::ExecutionEffect, genesis},
+        execution::AddressGenerator,
+        tracking_copy::TrackingCopy,
+    },
     shared::{
         newtypes::{Blake2bHash, CorrelationId},
         stored_value::StoredValue,
@@ -87,6 +99,10 @@ pub struct UpgradeConfig {
     new_locked_funds_period_millis: Option<u64>,
     new_round_seigniorage_rate: Option<Ratio<u64>>,
     new_unbonding_delay: Option<u64>,
+    new_redelegation_delay: Option<u64>,
+    new_minimum_delegation_amount: Option<u64>,
+    new_max_delegators_per_validator: Option<u32>,
+    new_slashing_rate: Option<Ratio<u64>>,
     global_state_update: BTreeMap<Key, StoredValue>,
 }
 
@@ -104,6 +120,10 @@ impl UpgradeConfig {
         new_locked_funds_period_millis: Option<u64>,
         new_round_seigniorage_rate: Option<Ratio<u64>>,
         new_unbonding_delay: Option<u64>,
+        new_redelegation_delay: Option<u64>,
+        new_minimum_delegation_amount: Option<u64>,
+        new_max_delegators_per_validator: Option<u32>,
+        new_slashing_rate: Option<Ratio<u64>>,
         global_state_update: BTreeMap<Key, StoredValue>,
     ) -> Self {
         UpgradeConfig {
@@ -118,6 +138,10 @@ impl UpgradeConfig {
             new_locked_funds_period_millis,
             new_round_seigniorage_rate,
             new_unbonding_delay,
+            new_redelegation_delay,
+            new_minimum_delegation_amount,
+            new_max_delegators_per_validator,
+            new_slashing_rate,
             global_state_update,
         }
     }
@@ -166,6 +190,22 @@ impl UpgradeConfig {
         self.new_unbonding_delay
     }
 
+    pub fn new_redelegation_delay(&self) -> Option<u64> {
+        self.new_redelegation_delay
+    }
+
+    pub fn new_minimum_delegation_amount(&self) -> Option<u64> {
+        self.new_minimum_delegation_amount
+    }
+
+    pub fn new_max_delegators_per_validator(&self) -> Option<u32> {
+        self.new_max_delegators_per_validator
+    }
+
+    pub fn new_slashing_rate(&self) -> Option<Ratio<u64>> {
+        self.new_slashing_rate
+    }
+
     pub fn global_state_update(&self) -> &BTreeMap<Key, StoredValue> {
         &self.global_state_update
     }
@@ -185,6 +225,12 @@ pub enum ProtocolUpgradeError {
     UnableToRetrieveSystemContractPackage(String),
     #[error("Failed to disable previous version of system contract: {0}")]
     FailedToDisablePreviousVersion(String),
+    #[error("Failed to migrate auction bids: {0}")]
+    FailedToMigrateAuctionBids(String),
+    #[error("Failed to migrate purse balances: {0}")]
+    FailedToMigratePurseBalances(String),
+    #[error("Failed to set auction parameter: {0}")]
+    FailedToSetAuctionParameter(String),
 }
 
 pub(crate) struct SystemUpgrader<S>
@@ -194,6 +240,7 @@ where
     new_protocol_version: ProtocolVersion,
     protocol_data: ProtocolData,
     tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+    uref_address_generator: RefCell<AddressGenerator>,
 }
 
 impl<S> SystemUpgrader<S>
@@ -201,14 +248,20 @@ where
     S: StateProvider,
 {
     pub(crate) fn new(
+        pre_state_hash: Blake2bHash,
         new_protocol_version: ProtocolVersion,
         protocol_data: ProtocolData,
         tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
     ) -> Self {
+        let uref_address_generator = RefCell::new(AddressGenerator::new(
+            pre_state_hash.as_ref(),
+            Phase::System,
+        ));
         SystemUpgrader {
             new_protocol_version,
             protocol_data,
             tracking_copy,
+            uref_address_generator,
         }
     }
 
@@ -233,6 +286,237 @@ where
         Ok(())
     }
 
+    /// Moves bids and unbonding purses out of the auction contract's legacy `BIDS_KEY` and
+    /// `UNBONDING_PURSES_KEY` maps into per-validator `Key::Bid` and `Key::Withdraw` entries.
+    ///
+    /// Does nothing if the auction contract has already been migrated.
+    pub(crate) fn migrate_auction_bids(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> Result<(), ProtocolUpgradeError> {
+        let auction_key = Key::Hash(self.protocol_data.auction().value());
+        let mut auction_contract = self.read_auction_contract(correlation_id)?;
+
+        let bids_key = auction_contract.named_keys().get(BIDS_KEY).copied();
+        let unbonding_purses_key = auction_contract
+            .named_keys()
+            .get(UNBONDING_PURSES_KEY)
+            .copied();
+
+        if bids_key.is_none() && unbonding_purses_key.is_none() {
+            return Ok(());
+        }
+
+        if let Some(bids_key) = bids_key {
+            let cl_value = self.read_legacy_cl_value(
+                correlation_id,
+                bids_key,
+                ProtocolUpgradeError::FailedToMigrateAuctionBids,
+            )?;
+            // The legacy value is a `BTreeMap<PublicKey, Bid>` where `Bid` did not hold the
+            // validator's public key.  As `Bid` now serializes the public key as its first field,
+            // the bytes of that map are exactly those of a `Vec<Bid>`.
+            let bids: Vec<Bid> =
+                bytesrepr::deserialize(cl_value.inner_bytes().clone()).map_err(|error| {
+                    ProtocolUpgradeError::FailedToMigrateAuctionBids(format!("{:?}", error))
+                })?;
+            for bid in bids {
+                let account_hash = bid.validator_public_key().to_account_hash();
+                self.tracking_copy
+                    .borrow_mut()
+                    .write(Key::Bid(account_hash), StoredValue::Bid(Box::new(bid)));
+            }
+            self.tracking_copy
+                .borrow_mut()
+                .write(bids_key, legacy_cl_value(Vec::<Bid>::new())?);
+            auction_contract.remove_named_key(BIDS_KEY);
+        }
+
+        if let Some(unbonding_purses_key) = unbonding_purses_key {
+            let cl_value = self.read_legacy_cl_value(
+                correlation_id,
+                unbonding_purses_key,
+                ProtocolUpgradeError::FailedToMigrateAuctionBids,
+            )?;
+            // Legacy unbonding purses lack the trailing `new_validator` field, so they are read
+            // as tuples of their remaining fields, which serialize to the same bytes.
+            let unbonding_purses: BTreeMap<PublicKey, Vec<LegacyUnbondingPurse>> =
+                bytesrepr::deserialize(cl_value.inner_bytes().clone()).map_err(|error| {
+                    ProtocolUpgradeError::FailedToMigrateAuctionBids(format!("{:?}", error))
+                })?;
+            for (validator_public_key, legacy_unbonding_list) in unbonding_purses {
+                let unbonding_list = legacy_unbonding_list
+                    .into_iter()
+                    .map(unbonding_purse_from_legacy)
+                    .collect();
+                let account_hash = validator_public_key.to_account_hash();
+                self.tracking_copy.borrow_mut().write(
+                    Key::Withdraw(account_hash),
+                    StoredValue::Withdraw(unbonding_list),
+                );
+            }
+            self.tracking_copy.borrow_mut().write(
+                unbonding_purses_key,
+                legacy_cl_value(UnbondingPurses::new())?,
+            );
+            auction_contract.remove_named_key(UNBONDING_PURSES_KEY);
+        }
+
+        self.tracking_copy
+            .borrow_mut()
+            .write(auction_key, StoredValue::Contract(auction_contract));
+
+        Ok(())
+    }
+
+    /// Moves purse balances out of the mint contract's named keys into `Key::Balance` entries.
+    ///
+    /// The mint used to hold each purse's balance under a `URef` stored in its named keys, named
+    /// after the purse's `URef`.  Does nothing if the mint contract has already been migrated.
+    pub(crate) fn migrate_purse_balances(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> Result<(), ProtocolUpgradeError> {
+        let mint_key = Key::Hash(self.protocol_data.mint().value());
+        let mut mint_contract =
+            self.read_system_contract(correlation_id, self.protocol_data.mint(), MINT)?;
+
+        let legacy_balances: Vec<(String, URef, Key)> = mint_contract
+            .named_keys()
+            .iter()
+            .filter_map(|(name, balance_key)| {
+                URef::from_formatted_str(name)
+                    .ok()
+                    .map(|purse_uref| (name.clone(), purse_uref, *balance_key))
+            })
+            .collect();
+
+        if legacy_balances.is_empty() {
+            return Ok(());
+        }
+
+        for (name, purse_uref, balance_key) in legacy_balances {
+            let balance = self.read_legacy_cl_value(
+                correlation_id,
+                balance_key,
+                ProtocolUpgradeError::FailedToMigratePurseBalances,
+            )?;
+            self.tracking_copy.borrow_mut().write(
+                Key::Balance(purse_uref.addr()),
+                StoredValue::CLValue(balance),
+            );
+            mint_contract.remove_named_key(&name);
+        }
+
+        self.tracking_copy
+            .borrow_mut()
+            .write(mint_key, StoredValue::Contract(mint_contract));
+
+        Ok(())
+    }
+
+    /// Sets the auction contract's redelegation delay, adding the `redelegate` entry point first if
+    /// the contract predates redelegation.
+    pub(crate) fn set_redelegation_delay(
+        &self,
+        correlation_id: CorrelationId,
+        redelegation_delay: u64,
+    ) -> Result<(), ProtocolUpgradeError> {
+        let auction_key = Key::Hash(self.protocol_data.auction().value());
+        let mut auction_contract = self.read_auction_contract(correlation_id)?;
+
+        if !auction_contract.has_entry_point(METHOD_REDELEGATE) {
+            auction_contract.add_entry_point::<String>(genesis::redelegate_entry_point());
+            self.tracking_copy
+                .borrow_mut()
+                .write(auction_key, StoredValue::Contract(auction_contract));
+        }
+
+        self.set_auction_parameter(correlation_id, REDELEGATION_DELAY_KEY, redelegation_delay)
+    }
+
+    /// Writes `value` under the auction contract's named key `name`, creating the named key first
+    /// if the contract was installed before the parameter existed.
+    pub(crate) fn set_auction_parameter<T: CLTyped + ToBytes>(
+        &self,
+        correlation_id: CorrelationId,
+        name: &str,
+        value: T,
+    ) -> Result<(), ProtocolUpgradeError> {
+        let auction_key = Key::Hash(self.protocol_data.auction().value());
+        let mut auction_contract = self.read_auction_contract(correlation_id)?;
+
+        let parameter_key = match auction_contract.named_keys().get(name).copied() {
+            Some(key) => key,
+            None => {
+                let uref = self
+                    .uref_address_generator
+                    .borrow_mut()
+                    .new_uref(AccessRights::READ_ADD_WRITE);
+                let mut named_keys = NamedKeys::new();
+                named_keys.insert(name.to_string(), uref.into());
+                auction_contract.named_keys_append(&mut named_keys);
+                self.tracking_copy
+                    .borrow_mut()
+                    .write(auction_key, StoredValue::Contract(auction_contract));
+                uref.into()
+            }
+        };
+
+        let value = CLValue::from_t(value)
+            .map(StoredValue::CLValue)
+            .map_err(|error| {
+                ProtocolUpgradeError::FailedToSetAuctionParameter(format!("{}: {:?}", name, error))
+            })?;
+        self.tracking_copy.borrow_mut().write(parameter_key, value);
+
+        Ok(())
+    }
+
+    fn read_auction_contract(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> Result<Contract, ProtocolUpgradeError> {
+        self.read_system_contract(correlation_id, self.protocol_data.auction(), AUCTION)
+    }
+
+    fn read_system_contract(
+        &self,
+        correlation_id: CorrelationId,
+        contract_hash: ContractHash,
+        contract_name: &str,
+    ) -> Result<Contract, ProtocolUpgradeError> {
+        let contract_key = Key::Hash(contract_hash.value());
+        match self
+            .tracking_copy
+            .borrow_mut()
+            .read(correlation_id, &contract_key)
+        {
+            Ok(Some(StoredValue::Contract(contract))) => Ok(contract),
+            _ => Err(ProtocolUpgradeError::UnableToRetrieveSystemContract(
+                contract_name.to_string(),
+            )),
+        }
+    }
+
+    fn read_legacy_cl_value(
+        &self,
+        correlation_id: CorrelationId,
+        key: Key,
+        migration_error: fn(String) -> ProtocolUpgradeError,
+    ) -> Result<CLValue, ProtocolUpgradeError> {
+        match self.tracking_copy.borrow_mut().read(correlation_id, &key) {
+            Ok(Some(StoredValue::CLValue(cl_value))) => Ok(cl_value),
+            Ok(Some(other)) => Err(migration_error(format!(
+                "expected CLValue under {}, found {}",
+                key,
+                other.type_name()
+            ))),
+            Ok(None) => Err(migration_error(format!("nothing stored under {}", key))),
+            Err(_) => Err(migration_error(format!("unable to read {}", key))),
+        }
+    }
+
     fn store_contract(
         &self,
         correlation_id: CorrelationId,
@@ -301,3 +585,26 @@ where
         Ok(())
     }
 }
+
+/// The fields of an `UnbondingPurse` as stored before redelegation was introduced.
+type LegacyUnbondingPurse = (URef, PublicKey, PublicKey, (EraId, U512));
+
+fn unbonding_purse_from_legacy(legacy_unbonding_purse: LegacyUnbondingPurse) -> UnbondingPurse {
+    let (bonding_purse, validator_public_key, unbonder_public_key, (era_of_creation, amount)) =
+        legacy_unbonding_purse;
+    UnbondingPurse::new(
+        bonding_purse,
+        validator_public_key,
+        unbonder_public_key,
+        era_of_creation,
+        amount,
+        None,
+    )
+}
+
+/// Wraps the emptied contents of a legacy auction map in a `StoredValue`.
+fn legacy_cl_value<T: CLTyped + ToBytes>(value: T) -> Result<StoredValue, ProtocolUpgradeError> {
+    CLValue::from_t(value)
+        .map(StoredValue::CLValue)
+        .map_err(|error| ProtocolUpgradeError::FailedToMigrateAuctionBids(format!("{:?}", error)))
+}
@@ -0,0 +1,76 @@
+use casper_types::{account::AccountHash, CLValue, ProtocolVersion};
+
+use crate::{
+    core::engine_state::executable_deploy_item::ExecutableDeployItem,
+    shared::{gas::Gas, newtypes::Blake2bHash},
+};
+
+#[derive(Debug)]
+pub enum ViewResult {
+    RootNotFound,
+    Success { value: CLValue, cost: Gas },
+}
+
+/// A request to call an entry point of a stored contract in a read-only context, i.e. without a
+/// deploy and without committing any effects.
+///
+/// The `target` must be either `ExecutableDeployItem::StoredContractByHash` or
+/// `ExecutableDeployItem::StoredVersionedContractByHash`.  The call may consume at most
+/// `gas_limit`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewRequest {
+    state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    block_time: u64,
+    caller: AccountHash,
+    gas_limit: Gas,
+    target: ExecutableDeployItem,
+}
+
+impl ViewRequest {
+    pub fn new(
+        state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        block_time: u64,
+        caller: AccountHash,
+        gas_limit: Gas,
+        target: ExecutableDeployItem,
+    ) -> Self {
+        ViewRequest {
+            state_hash,
+            protocol_version,
+            block_time,
+            caller,
+            gas_limit,
+            target,
+        }
+    }
+
+    pub fn state_hash(&self) -> Blake2bHash {
+        self.state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn block_time(&self) -> u64 {
+        self.block_time
+    }
+
+    pub fn caller(&self) -> AccountHash {
+        self.caller
+    }
+
+    pub fn gas_limit(&self) -> Gas {
+        self.gas_limit
+    }
+
+    pub fn target(&self) -> &ExecutableDeployItem {
+        &self.target
+    }
+
+    pub fn take_target(self) -> ExecutableDeployItem {
+        self.target
+    }
+}
@@ -0,0 +1,213 @@
+use std::sync::{Arc, Mutex};
+
+use linked_hash_map::LinkedHashMap;
+use parity_wasm::elements::Module;
+
+use casper_types::{ContractWasmHash, ProtocolVersion};
+
+/// The default maximum number of modules held by a `WasmModuleCache`.
+pub const DEFAULT_MAX_CACHED_WASM_MODULES: usize = 256;
+
+/// Statistics of a `WasmModuleCache`.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct WasmModuleCacheStats {
+    /// The number of lookups which found the module in the cache.
+    pub hits: u64,
+    /// The number of lookups which had to deserialize the module.
+    pub misses: u64,
+    /// The number of modules currently cached.
+    pub entries: usize,
+    /// The total size in bytes of the serialized forms of the modules currently cached.
+    pub size_bytes: usize,
+}
+
+#[derive(Debug)]
+struct CacheState {
+    modules: LinkedHashMap<(ContractWasmHash, ProtocolVersion), (Module, usize)>,
+    max_entries: usize,
+    stats: WasmModuleCacheStats,
+}
+
+/// A bounded cache of deserialized stored contract modules, keyed by contract Wasm hash and the
+/// protocol version they're executed under.  The least recently used module is evicted first.
+#[derive(Clone, Debug)]
+pub struct WasmModuleCache(Arc<Mutex<CacheState>>);
+
+impl WasmModuleCache {
+    /// Creates an empty cache holding at most `max_entries` modules.  If `max_entries` is zero,
+    /// nothing is cached.
+    pub fn new(max_entries: usize) -> Self {
+        WasmModuleCache(Arc::new(Mutex::new(CacheState {
+            modules: LinkedHashMap::new(),
+            max_entries,
+            stats: WasmModuleCacheStats::default(),
+        })))
+    }
+
+    /// Returns a clone of the module cached under `contract_wasm_hash` and `protocol_version`.
+    ///
+    /// If the cache doesn't have the module, it is created by calling `deserialize` and inserted.
+    /// `size_bytes` should be the size of the serialized module.
+    pub fn get_or_insert_with<E>(
+        &self,
+        contract_wasm_hash: ContractWasmHash,
+        protocol_version: ProtocolVersion,
+        size_bytes: usize,
+        deserialize: impl FnOnce() -> Result<Module, E>,
+    ) -> Result<Module, E> {
+        let key = (contract_wasm_hash, protocol_version);
+        {
+            let mut guarded_state = self.0.lock().unwrap();
+            let state = &mut *guarded_state;
+            if let Some((module, _)) = state.modules.get_refresh(&key) {
+                state.stats.hits += 1;
+                return Ok(module.clone());
+            }
+            state.stats.misses += 1;
+        }
+
+        // Don't hold the lock while deserializing, so other lookups aren't blocked.
+        let module = deserialize()?;
+
+        let mut guarded_state = self.0.lock().unwrap();
+        let state = &mut *guarded_state;
+        if state.max_entries == 0 {
+            return Ok(module);
+        }
+        if let Some((_, replaced_size)) = state.modules.insert(key, (module.clone(), size_bytes)) {
+            state.stats.size_bytes -= replaced_size;
+        }
+        state.stats.size_bytes += size_bytes;
+        while state.modules.len() > state.max_entries {
+            if let Some((_, (_, evicted_size))) = state.modules.pop_front() {
+                state.stats.size_bytes -= evicted_size;
+            }
+        }
+        state.stats.entries = state.modules.len();
+
+        Ok(module)
+    }
+
+    /// Removes all modules from the cache.  The hit and miss counts are retained.
+    pub fn clear(&self) {
+        let mut guarded_state = self.0.lock().unwrap();
+        guarded_state.modules.clear();
+        guarded_state.stats.entries = 0;
+        guarded_state.stats.size_bytes = 0;
+    }
+
+    /// Returns the current statistics of the cache.
+    pub fn stats(&self) -> WasmModuleCacheStats {
+        self.0.lock().unwrap().stats
+    }
+}
+
+impl Default for WasmModuleCache {
+    fn default() -> Self {
+        WasmModuleCache::new(DEFAULT_MAX_CACHED_WASM_MODULES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parity_wasm::elements::{Module, ModuleNameSubsection, NameSection, Section};
+
+    use casper_types::{ContractWasmHash, ProtocolVersion};
+
+    use super::{WasmModuleCache, WasmModuleCacheStats};
+
+    fn named_module(name: &str) -> Module {
+        let section = NameSection::new(Some(ModuleNameSubsection::new(name)), None, None);
+        let sections = vec![Section::Name(section)];
+        Module::new(sections)
+    }
+
+    fn get(cache: &WasmModuleCache, hash: u8, protocol_version: ProtocolVersion) -> Module {
+        cache
+            .get_or_insert_with(
+                ContractWasmHash::new([hash; 32]),
+                protocol_version,
+                10,
+                || Ok::<_, ()>(named_module(&hash.to_string())),
+            )
+            .expect("should get module")
+    }
+
+    #[test]
+    fn should_only_deserialize_once() {
+        let cache = WasmModuleCache::new(2);
+        let module = get(&cache, 1, ProtocolVersion::V1_0_0);
+
+        let cached_module = cache
+            .get_or_insert_with(
+                ContractWasmHash::new([1; 32]),
+                ProtocolVersion::V1_0_0,
+                10,
+                || -> Result<Module, ()> { panic!("should not deserialize cached module") },
+            )
+            .unwrap();
+
+        assert_eq!(cached_module, module);
+        assert_eq!(
+            cache.stats(),
+            WasmModuleCacheStats {
+                hits: 1,
+                misses: 1,
+                entries: 1,
+                size_bytes: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn should_key_by_protocol_version() {
+        let cache = WasmModuleCache::new(2);
+        get(&cache, 1, ProtocolVersion::V1_0_0);
+        get(&cache, 1, ProtocolVersion::from_parts(1, 1, 0));
+
+        let stats = cache.stats();
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.entries, 2);
+    }
+
+    #[test]
+    fn should_evict_least_recently_used() {
+        let cache = WasmModuleCache::new(2);
+        get(&cache, 1, ProtocolVersion::V1_0_0);
+        get(&cache, 2, ProtocolVersion::V1_0_0);
+        // Use module 1 so that module 2 is evicted when module 3 is inserted.
+        get(&cache, 1, ProtocolVersion::V1_0_0);
+        get(&cache, 3, ProtocolVersion::V1_0_0);
+        assert_eq!(cache.stats().entries, 2);
+        assert_eq!(cache.stats().size_bytes, 20);
+
+        get(&cache, 1, ProtocolVersion::V1_0_0);
+        assert_eq!(cache.stats().hits, 2);
+        get(&cache, 2, ProtocolVersion::V1_0_0);
+        assert_eq!(cache.stats().misses, 4);
+    }
+
+    #[test]
+    fn should_not_cache_with_zero_max_entries() {
+        let cache = WasmModuleCache::new(0);
+        get(&cache, 1, ProtocolVersion::V1_0_0);
+        get(&cache, 1, ProtocolVersion::V1_0_0);
+
+        let stats = cache.stats();
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.entries, 0);
+        assert_eq!(stats.size_bytes, 0);
+    }
+
+    #[test]
+    fn should_clear() {
+        let cache = WasmModuleCache::new(2);
+        get(&cache, 1, ProtocolVersion::V1_0_0);
+        cache.clear();
+        assert_eq!(cache.stats().entries, 0);
+        assert_eq!(cache.stats().size_bytes, 0);
+
+        get(&cache, 1, ProtocolVersion::V1_0_0);
+        assert_eq!(cache.stats().misses, 2);
+    }
+}
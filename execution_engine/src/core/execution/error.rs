@@ -94,6 +94,8 @@ pub enum Error {
     UnexpectedStoredValueVariant,
     #[error("A locked contract cannot be upgraded")]
     LockedContract(ContractPackageHash),
+    #[error("Attempt to write to global state in a read-only context")]
+    ReadOnlyContext,
 }
 
 impl From<wasm_prep::PreprocessingError> for Error {
@@ -1,4 +1,4 @@
-use std::{cell::RefCell, collections::BTreeSet, rc::Rc};
+use std::{cell::RefCell, collections::BTreeSet, iter::FromIterator, rc::Rc};
 
 use parity_wasm::elements::Module;
 use tracing::warn;
@@ -17,9 +17,10 @@ use crate::{
     core::{
         engine_state::{
             execution_effect::ExecutionEffect, execution_result::ExecutionResult,
-            system_contract_cache::SystemContractCache, EngineConfig,
+            system_contract_cache::SystemContractCache, wasm_module_cache::WasmModuleCache,
+            EngineConfig,
         },
-        execution::{address_generator::AddressGenerator, Error},
+        execution::{address_generator::AddressGenerator, Error, ExecutionTracer},
         runtime::{extract_access_rights_from_keys, instance_and_memory, Runtime},
         runtime_context::{self, RuntimeContext},
         tracking_copy::TrackingCopy,
@@ -73,12 +74,24 @@ macro_rules! on_fail_charge {
 
 pub struct Executor {
     config: EngineConfig,
+    wasm_module_cache: WasmModuleCache,
+    tracer: Option<ExecutionTracer>,
 }
 
 #[allow(clippy::too_many_arguments)]
 impl Executor {
-    pub fn new(config: EngineConfig) -> Self {
-        Executor { config }
+    pub fn new(config: EngineConfig, wasm_module_cache: WasmModuleCache) -> Self {
+        Executor {
+            config,
+            wasm_module_cache,
+            tracer: None,
+        }
+    }
+
+    /// Makes the executor record a trace of the Wasm code it executes into `tracer`.
+    pub fn with_tracer(mut self, tracer: ExecutionTracer) -> Self {
+        self.tracer = Some(tracer);
+        self
     }
 
     pub fn config(&self) -> EngineConfig {
@@ -166,7 +179,15 @@ impl Executor {
             transfers,
         );
 
-        let mut runtime = Runtime::new(self.config, system_contract_cache, memory, module, context);
+        let mut runtime = Runtime::new(
+            self.config,
+            system_contract_cache,
+            self.wasm_module_cache.clone(),
+            memory,
+            module,
+            context,
+        );
+        runtime.set_tracer(self.tracer.clone());
 
         let accounts_access_rights = {
             let keys: Vec<Key> = account.named_keys().values().cloned().collect();
@@ -252,8 +273,11 @@ impl Executor {
                 }
             }
         }
+        runtime.trace_enter_frame(entry_point_name);
+        let result = instance.invoke_export(entry_point_name, &[], &mut runtime);
+        runtime.trace_exit_frame(&result);
         on_fail_charge!(
-            instance.invoke_export(entry_point_name, &[], &mut runtime),
+            result,
             runtime.context().gas_counter(),
             effects_snapshot,
             runtime.context().transfers().to_owned()
@@ -568,6 +592,115 @@ impl Executor {
         Ok(ret)
     }
 
+    /// Calls an entry point of a stored contract in a read-only context.
+    ///
+    /// Returns the value passed to `runtime::ret()` along with the gas consumed, or the unit type
+    /// `()` if the entry point returned without calling it.
+    pub fn exec_view<R>(
+        &self,
+        module: Module,
+        entry_point: EntryPoint,
+        args: RuntimeArgs,
+        base_key: Key,
+        account: &Account,
+        named_keys: &mut NamedKeys,
+        blocktime: BlockTime,
+        gas_limit: Gas,
+        protocol_version: ProtocolVersion,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        protocol_data: ProtocolData,
+        system_contract_cache: SystemContractCache,
+        contract_package: &ContractPackage,
+    ) -> Result<(CLValue, Gas), Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<Error>,
+    {
+        // Nothing is written in a read-only context, so the addresses generated from this are
+        // never persisted.
+        let deploy_hash = DeployHash::new([0u8; 32]);
+        let phase = Phase::Session;
+
+        let (instance, memory) = instance_and_memory(
+            module.clone(),
+            protocol_version,
+            protocol_data.wasm_config(),
+        )?;
+
+        let access_rights = {
+            let keys: Vec<Key> = named_keys.values().cloned().collect();
+            extract_access_rights_from_keys(keys)
+        };
+
+        let new_address_generator = || {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
+            Rc::new(RefCell::new(generator))
+        };
+
+        let mut context = RuntimeContext::new(
+            tracking_copy,
+            entry_point.entry_point_type(),
+            named_keys,
+            access_rights,
+            args,
+            BTreeSet::from_iter(vec![account.account_hash()]),
+            &account,
+            base_key,
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            Gas::default(),
+            new_address_generator(),
+            new_address_generator(),
+            new_address_generator(),
+            protocol_version,
+            correlation_id,
+            phase,
+            protocol_data,
+            Vec::default(),
+        );
+        context.set_read_only(true);
+
+        let mut runtime = Runtime::new(
+            self.config,
+            system_contract_cache,
+            self.wasm_module_cache.clone(),
+            memory,
+            module,
+            context,
+        );
+
+        let accounts_access_rights = {
+            let keys: Vec<Key> = account.named_keys().values().cloned().collect();
+            extract_access_rights_from_keys(keys)
+        };
+
+        runtime_context::validate_entry_point_access_with(
+            &contract_package,
+            entry_point.access(),
+            |uref| runtime_context::uref_has_access_rights(uref, &accounts_access_rights),
+        )?;
+
+        let value = match instance.invoke_export(entry_point.name(), &[], &mut runtime) {
+            // As in `exec_wasm_direct`, an entry point which doesn't call `runtime::ret()` is
+            // treated as returning the unit type `()`.
+            Ok(_) => runtime.take_host_buffer().unwrap_or(CLValue::from_t(())?),
+            Err(error) => match error
+                .as_host_error()
+                .and_then(|host_error| host_error.downcast_ref::<Error>())
+            {
+                Some(Error::Ret(_)) => runtime
+                    .take_host_buffer()
+                    .ok_or(Error::ExpectedReturnValue)?,
+                Some(error) => return Err(error.clone()),
+                None => return Err(Error::Interpreter(error.into())),
+            },
+        };
+
+        Ok((value, runtime.context().gas_counter()))
+    }
+
     pub fn create_runtime<'a, R>(
         &self,
         module: Module,
@@ -636,6 +769,7 @@ impl Executor {
         let runtime = Runtime::new(
             self.config,
             system_contract_cache,
+            self.wasm_module_cache.clone(),
             memory,
             module,
             runtime_context,
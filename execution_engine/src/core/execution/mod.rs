@@ -4,9 +4,11 @@ mod error;
 mod executor;
 #[cfg(test)]
 mod tests;
+mod trace;
 
 pub use self::{
     address_generator::{AddressGenerator, AddressGeneratorBuilder},
     error::Error,
     executor::{DirectSystemContractCall, Executor},
+    trace::{CallFrame, ExecutionTrace, ExecutionTracer, FrameOutcome, HostCallTrace, TraceEvent},
 };
@@ -1,8 +1,10 @@
+use std::collections::BTreeMap;
+
 use tracing::warn;
 
-use casper_types::{Key, U512};
+use casper_types::{account::AccountHash, Key, U512};
 
-use super::Error;
+use super::{Error, ExecutionTracer, FrameOutcome, TraceEvent};
 use crate::{
     core::engine_state::{
         execution_effect::ExecutionEffect, execution_result::ExecutionResult, op::Op,
@@ -79,3 +81,50 @@ fn on_fail_charge_with_action() {
         }
     }
 }
+
+#[test]
+fn execution_tracer_nests_frames_in_host_calls() {
+    let gas = |amount: u64| Gas::new(U512::from(amount));
+    let account_key = Key::Account(AccountHash::new([1; 32]));
+    let contract_key = Key::Hash([2; 32]);
+
+    let tracer = ExecutionTracer::new();
+    tracer.enter_frame(account_key, "call", gas(0));
+    tracer.record_opcodes(gas(3));
+    tracer.record_opcodes(gas(4));
+    tracer.begin_host_call(gas(7));
+    tracer.enter_frame(contract_key, "entry_point", gas(10));
+    tracer.record_opcodes(gas(5));
+    tracer.exit_frame(FrameOutcome::Reverted { code: 1 }, gas(15));
+    let mut args = BTreeMap::new();
+    args.insert("entry_point_name_size", "11".to_string());
+    tracer.end_host_call("host_function_call_contract", &args, gas(20));
+    tracer.exit_frame(FrameOutcome::Returned, gas(20));
+
+    let trace = tracer.take_trace();
+    assert_eq!(trace.frames.len(), 1);
+    let frame = &trace.frames[0];
+    assert_eq!(frame.base_key, account_key.to_formatted_string());
+    assert_eq!(frame.opcode_gas, U512::from(7));
+    assert_eq!(frame.gas, U512::from(20));
+    assert_eq!(frame.outcome, FrameOutcome::Returned);
+    assert_eq!(frame.events.len(), 2);
+    assert_eq!(frame.events[0], TraceEvent::Opcodes { gas: 7.into() });
+
+    let host_call = match &frame.events[1] {
+        TraceEvent::HostCall(host_call) => host_call,
+        event => panic!("unexpected event: {:?}", event),
+    };
+    assert_eq!(host_call.name, "host_function_call_contract");
+    assert_eq!(host_call.gas, U512::from(13));
+    assert_eq!(host_call.args["entry_point_name_size"], "11");
+    assert_eq!(host_call.calls.len(), 1);
+    assert_eq!(host_call.calls[0].entry_point, "entry_point");
+    assert_eq!(host_call.calls[0].gas, U512::from(5));
+    assert_eq!(
+        host_call.calls[0].outcome,
+        FrameOutcome::Reverted { code: 1 }
+    );
+
+    assert!(tracer.take_trace().frames.is_empty());
+}
@@ -0,0 +1,206 @@
+//! Optional tracing of Wasm execution, recording the host functions called and the gas charged.
+
+use std::{cell::RefCell, collections::BTreeMap, mem, rc::Rc};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{Key, U512};
+
+use crate::shared::gas::Gas;
+
+/// The trace of a deploy's execution.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExecutionTrace {
+    /// The top-level frames, i.e. the execution of the payment and session code, in order.
+    ///
+    /// Native system contract calls made directly by the deploy (e.g. standard payment) aren't
+    /// traced.
+    pub frames: Vec<CallFrame>,
+}
+
+/// The execution of a single entry point.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CallFrame {
+    /// The formatted key of the account or contract in whose context the code ran.
+    pub base_key: String,
+    /// The name of the entry point called.
+    pub entry_point: String,
+    /// What happened during execution, in order.
+    pub events: Vec<TraceEvent>,
+    /// The gas charged for executing Wasm opcodes, excluding nested calls.
+    pub opcode_gas: U512,
+    /// The gas charged in total, including nested calls.
+    pub gas: U512,
+    /// How the execution ended.
+    pub outcome: FrameOutcome,
+}
+
+/// Something which happened during the execution of a `CallFrame`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum TraceEvent {
+    /// A run of Wasm opcodes executed between two host function calls.
+    Opcodes {
+        /// The gas charged for the opcodes.
+        gas: U512,
+    },
+    /// A call of a host function.
+    HostCall(HostCallTrace),
+}
+
+/// A call of a host function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HostCallTrace {
+    /// The name of the host function.
+    pub name: String,
+    /// A summary of the arguments, e.g. the sizes of values passed in.
+    pub args: BTreeMap<String, String>,
+    /// The gas charged for the call, including any nested calls.
+    pub gas: U512,
+    /// The entry points called by the host function, e.g. by `call_contract`.
+    pub calls: Vec<CallFrame>,
+}
+
+/// How the execution of a `CallFrame` ended.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum FrameOutcome {
+    /// The entry point returned, with or without calling `ret`.
+    Returned,
+    /// The entry point called `revert`.
+    Reverted {
+        /// The code passed to `revert`.
+        code: u32,
+    },
+    /// The execution failed, e.g. by running out of gas.
+    Failed {
+        /// The error which caused the failure.
+        error: String,
+    },
+}
+
+enum OpenItem {
+    Frame {
+        frame: CallFrame,
+        gas_at_entry: Gas,
+    },
+    HostCall {
+        calls: Vec<CallFrame>,
+        gas_at_entry: Gas,
+    },
+}
+
+#[derive(Default)]
+struct TracerState {
+    open: Vec<OpenItem>,
+    trace: ExecutionTrace,
+}
+
+/// A shared handle collecting an `ExecutionTrace`.
+///
+/// Cloning the handle doesn't copy the trace, so a clone can be passed to each `Runtime` involved
+/// in executing a deploy.
+#[derive(Clone, Default)]
+pub struct ExecutionTracer(Rc<RefCell<TracerState>>);
+
+impl ExecutionTracer {
+    /// Creates a new tracer with an empty trace.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the trace collected so far and resets the tracer.
+    pub fn take_trace(&self) -> ExecutionTrace {
+        let mut state = self.0.borrow_mut();
+        state.open.clear();
+        mem::take(&mut state.trace)
+    }
+
+    pub(crate) fn enter_frame(&self, base_key: Key, entry_point: &str, gas_counter: Gas) {
+        let frame = CallFrame {
+            base_key: base_key.to_formatted_string(),
+            entry_point: entry_point.to_string(),
+            events: Vec::new(),
+            opcode_gas: U512::zero(),
+            gas: U512::zero(),
+            outcome: FrameOutcome::Returned,
+        };
+        self.0.borrow_mut().open.push(OpenItem::Frame {
+            frame,
+            gas_at_entry: gas_counter,
+        });
+    }
+
+    pub(crate) fn exit_frame(&self, outcome: FrameOutcome, gas_counter: Gas) {
+        let mut state = self.0.borrow_mut();
+        let state = &mut *state;
+        let frame = match state.open.pop() {
+            Some(OpenItem::Frame {
+                mut frame,
+                gas_at_entry,
+            }) => {
+                frame.gas = (gas_counter - gas_at_entry).value();
+                frame.outcome = outcome;
+                frame
+            }
+            _ => return,
+        };
+        match state.open.last_mut() {
+            Some(OpenItem::HostCall { calls, .. }) => calls.push(frame),
+            _ => state.trace.frames.push(frame),
+        }
+    }
+
+    pub(crate) fn begin_host_call(&self, gas_counter: Gas) {
+        self.0.borrow_mut().open.push(OpenItem::HostCall {
+            calls: Vec::new(),
+            gas_at_entry: gas_counter,
+        });
+    }
+
+    pub(crate) fn end_host_call(
+        &self,
+        name: &str,
+        args: &BTreeMap<&'static str, String>,
+        gas_counter: Gas,
+    ) {
+        let mut state = self.0.borrow_mut();
+        let host_call = match state.open.pop() {
+            Some(OpenItem::HostCall {
+                calls,
+                gas_at_entry,
+            }) => HostCallTrace {
+                name: name.to_string(),
+                args: args
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.clone()))
+                    .collect(),
+                gas: (gas_counter - gas_at_entry).value(),
+                calls,
+            },
+            _ => return,
+        };
+        if let Some(OpenItem::Frame { frame, .. }) = state.open.last_mut() {
+            frame.events.push(TraceEvent::HostCall(host_call));
+        }
+    }
+
+    /// Records gas charged for executing opcodes, merging it into the preceding event if that was
+    /// also opcode execution.
+    pub(crate) fn record_opcodes(&self, amount: Gas) {
+        let mut state = self.0.borrow_mut();
+        if let Some(OpenItem::Frame { frame, .. }) = state.open.last_mut() {
+            frame.opcode_gas += amount.value();
+            match frame.events.last_mut() {
+                Some(TraceEvent::Opcodes { gas }) => *gas += amount.value(),
+                _ => frame.events.push(TraceEvent::Opcodes {
+                    gas: amount.value(),
+                }),
+            }
+        }
+    }
+}
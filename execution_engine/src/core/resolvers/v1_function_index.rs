@@ -50,6 +50,13 @@ pub enum FunctionIndex {
     Blake2b,
     RecordTransfer,
     RecordEraInfo,
+    EmitMessage,
+    VerifySignature,
+    Sha256,
+    Keccak256,
+    NewDictionary,
+    DictionaryGet,
+    DictionaryPut,
 }
 
 impl Into<usize> for FunctionIndex {
@@ -1,12 +1,14 @@
+use std::collections::BTreeSet;
+
 use casper_types::{
     account,
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::auction::{
-        AccountProvider, Auction, EraInfo, Error, MintProvider, RuntimeProvider, StorageProvider,
-        SystemProvider,
+        AccountProvider, Auction, Bid, EraInfo, Error, MintProvider, RuntimeProvider,
+        StorageProvider, SystemProvider, UnbondingPurse,
     },
-    CLTyped, CLValue, Key, TransferredTo, URef, BLAKE2B_DIGEST_LENGTH, U512,
+    CLTyped, CLValue, Key, KeyTag, TransferredTo, URef, BLAKE2B_DIGEST_LENGTH, U512,
 };
 
 use super::Runtime;
@@ -52,6 +54,76 @@ where
             .metered_write_gs(uref.into(), StoredValue::CLValue(cl_value))
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
     }
+
+    fn get_keys(&mut self, key_tag: &KeyTag) -> Result<BTreeSet<Key>, Error> {
+        self.context
+            .get_keys(key_tag)
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
+    fn read_bid(&mut self, account_hash: &AccountHash) -> Result<Option<Bid>, Error> {
+        match self.context.read_gs(&Key::Bid(*account_hash)) {
+            Ok(Some(StoredValue::Bid(bid))) => Ok(Some(*bid)),
+            Ok(Some(_)) => Err(Error::Storage),
+            Ok(None) => Ok(None),
+            Err(execution::Error::BytesRepr(_)) => Err(Error::Serialization),
+            Err(execution::Error::GasLimit) => Err(Error::GasLimit),
+            Err(_) => Err(Error::Storage),
+        }
+    }
+
+    fn write_bid(&mut self, account_hash: AccountHash, bid: Bid) -> Result<(), Error> {
+        self.context
+            .metered_write_gs_unsafe(Key::Bid(account_hash), StoredValue::Bid(Box::new(bid)))
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
+    fn prune_bid(&mut self, account_hash: AccountHash) -> Result<(), Error> {
+        self.context
+            .prune_gs_unsafe(Key::Bid(account_hash))
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
+    fn read_withdraw(&mut self, account_hash: &AccountHash) -> Result<Vec<UnbondingPurse>, Error> {
+        match self.context.read_gs(&Key::Withdraw(*account_hash)) {
+            Ok(Some(StoredValue::Withdraw(unbonding_purses))) => Ok(unbonding_purses),
+            Ok(Some(_)) => Err(Error::Storage),
+            Ok(None) => Ok(Vec::new()),
+            Err(execution::Error::BytesRepr(_)) => Err(Error::Serialization),
+            Err(execution::Error::GasLimit) => Err(Error::GasLimit),
+            Err(_) => Err(Error::Storage),
+        }
+    }
+
+    fn write_withdraw(
+        &mut self,
+        account_hash: AccountHash,
+        unbonding_purses: Vec<UnbondingPurse>,
+    ) -> Result<(), Error> {
+        self.context
+            .metered_write_gs_unsafe(
+                Key::Withdraw(account_hash),
+                StoredValue::Withdraw(unbonding_purses),
+            )
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
+    fn prune_withdraw(&mut self, account_hash: AccountHash) -> Result<(), Error> {
+        self.context
+            .prune_gs_unsafe(Key::Withdraw(account_hash))
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
+    fn read_era_info(&mut self, era_id: u64) -> Result<Option<EraInfo>, Error> {
+        match self.context.read_gs(&Key::EraInfo(era_id)) {
+            Ok(Some(StoredValue::EraInfo(era_info))) => Ok(Some(era_info)),
+            Ok(Some(_)) => Err(Error::Storage),
+            Ok(None) => Ok(None),
+            Err(execution::Error::BytesRepr(_)) => Err(Error::Serialization),
+            Err(execution::Error::GasLimit) => Err(Error::GasLimit),
+            Err(_) => Err(Error::Storage),
+        }
+    }
 }
 
 impl<'a, R> SystemProvider for Runtime<'a, R>
@@ -164,6 +236,17 @@ where
         self.mint_reduce_total_supply(mint_contract, amount)
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::MintReward))
     }
+
+    fn burn(&mut self, purse: URef, amount: U512) -> Result<(), Error> {
+        let balance = MintProvider::balance(self, purse)?.ok_or(Error::GetBalance)?;
+        let remaining_balance = balance.checked_sub(amount).ok_or(Error::InvalidAmount)?;
+        let cl_value = CLValue::from_t(remaining_balance).map_err(|_| Error::CLValue)?;
+        self.context
+            .write_balance(purse, cl_value)
+            .map_err(|exec_error| {
+                <Option<Error>>::from(exec_error).unwrap_or(Error::MintReduceTotalSupply)
+            })
+    }
 }
 
 impl<'a, R> AccountProvider for Runtime<'a, R>
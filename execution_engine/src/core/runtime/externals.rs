@@ -1,5 +1,7 @@
 use std::{collections::BTreeSet, convert::TryFrom};
 
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
 use wasmi::{Externals, RuntimeArgs, RuntimeValue, Trap};
 
 use casper_types::{
@@ -32,6 +34,41 @@ where
         let func = FunctionIndex::try_from(index).expect("unknown function index");
         let mut scoped_instrumenter = ScopedInstrumenter::new(func);
 
+        let tracer = match self.tracer.clone() {
+            Some(tracer) => tracer,
+            None => return self.invoke_host_function(func, args, &mut scoped_instrumenter),
+        };
+
+        // Calls to the gas function are made by the injected metering code rather than by the
+        // contract, so they're recorded as the cost of executing opcodes.
+        let host_function = scoped_instrumenter.host_function_name();
+        let gas_before = self.gas_counter();
+        if host_function.is_some() {
+            tracer.begin_host_call(gas_before);
+        }
+        let result = self.invoke_host_function(func, args, &mut scoped_instrumenter);
+        let gas_after = self.gas_counter();
+        match host_function {
+            Some(host_function) => {
+                tracer.end_host_call(host_function, scoped_instrumenter.properties(), gas_after)
+            }
+            None => tracer.record_opcodes(gas_after - gas_before),
+        }
+        result
+    }
+}
+
+impl<'a, R> Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<Error>,
+{
+    fn invoke_host_function(
+        &mut self,
+        func: FunctionIndex,
+        args: RuntimeArgs,
+        scoped_instrumenter: &mut ScopedInstrumenter,
+    ) -> Result<Option<RuntimeValue>, Trap> {
         let host_function_costs = self
             .protocol_data()
             .wasm_config()
@@ -59,11 +96,8 @@ where
                     &host_function_costs.load_named_keys,
                     [total_keys_ptr, result_size_ptr],
                 )?;
-                let ret = self.load_named_keys(
-                    total_keys_ptr,
-                    result_size_ptr,
-                    &mut scoped_instrumenter,
-                )?;
+                let ret =
+                    self.load_named_keys(total_keys_ptr, result_size_ptr, scoped_instrumenter)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
@@ -116,7 +150,7 @@ where
                 let (value_ptr, value_size) = Args::parse(args)?;
                 self.charge_host_function_call(&host_function_costs.ret, [value_ptr, value_size])?;
                 scoped_instrumenter.add_property("value_size", value_size);
-                Err(self.ret(value_ptr, value_size as usize, &mut scoped_instrumenter))
+                Err(self.ret(value_ptr, value_size as usize, scoped_instrumenter))
             }
 
             FunctionIndex::GetKeyFuncIndex => {
@@ -720,7 +754,7 @@ where
                     &entry_point_name,
                     args_bytes,
                     result_size_ptr,
-                    &mut scoped_instrumenter,
+                    scoped_instrumenter,
                 )?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -781,7 +815,7 @@ where
                     entry_point_name,
                     args_bytes,
                     result_size_ptr,
-                    &mut scoped_instrumenter,
+                    scoped_instrumenter,
                 )?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -968,6 +1002,139 @@ where
                 self.record_era_info(era_id, era_info)?;
                 Ok(Some(RuntimeValue::I32(0)))
             }
+
+            FunctionIndex::EmitMessage => {
+                // args(0) = pointer to the topic name in wasm memory
+                // args(1) = size of the topic name in wasm memory
+                // args(2) = pointer to the message payload in wasm memory
+                // args(3) = size of the message payload in wasm memory
+                let (topic_ptr, topic_size, payload_ptr, payload_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.emit_message,
+                    [topic_ptr, topic_size, payload_ptr, payload_size],
+                )?;
+                scoped_instrumenter.add_property("topic_size", topic_size.to_string());
+                scoped_instrumenter.add_property("payload_size", payload_size.to_string());
+                let ret = self.emit_message(topic_ptr, topic_size, payload_ptr, payload_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::VerifySignature => {
+                // args(0) = pointer to the serialized public key in wasm memory
+                // args(1) = size of the serialized public key in wasm memory
+                // args(2) = pointer to the signed message in wasm memory
+                // args(3) = size of the signed message in wasm memory
+                // args(4) = pointer to the serialized signature in wasm memory
+                // args(5) = size of the serialized signature in wasm memory
+                let (
+                    public_key_ptr,
+                    public_key_size,
+                    message_ptr,
+                    message_size,
+                    signature_ptr,
+                    signature_size,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.verify_signature,
+                    [
+                        public_key_ptr,
+                        public_key_size,
+                        message_ptr,
+                        message_size,
+                        signature_ptr,
+                        signature_size,
+                    ],
+                )?;
+                scoped_instrumenter.add_property("message_size", message_size.to_string());
+                let ret = self.verify_signature(
+                    public_key_ptr,
+                    public_key_size,
+                    message_ptr,
+                    message_size,
+                    signature_ptr,
+                    signature_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::Sha256 => {
+                let (in_ptr, in_size, out_ptr, out_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.sha256,
+                    [in_ptr, in_size, out_ptr, out_size],
+                )?;
+                scoped_instrumenter.add_property("in_size", in_size.to_string());
+                scoped_instrumenter.add_property("out_size", out_size.to_string());
+                let input: Vec<u8> = self.bytes_from_mem(in_ptr, in_size as usize)?;
+                let digest = Sha256::digest(&input);
+                let ret = self.write_digest(&digest, out_ptr, out_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::Keccak256 => {
+                let (in_ptr, in_size, out_ptr, out_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.keccak256,
+                    [in_ptr, in_size, out_ptr, out_size],
+                )?;
+                scoped_instrumenter.add_property("in_size", in_size.to_string());
+                scoped_instrumenter.add_property("out_size", out_size.to_string());
+                let input: Vec<u8> = self.bytes_from_mem(in_ptr, in_size as usize)?;
+                let digest = Keccak256::digest(&input);
+                let ret = self.write_digest(&digest, out_ptr, out_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::NewDictionary => {
+                // args(0) = pointer to output size (output param)
+                let output_size_ptr = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.new_dictionary,
+                    [output_size_ptr],
+                )?;
+                let ret = self.new_dictionary(output_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::DictionaryGet => {
+                // args(0) = pointer to the serialized seed uref in wasm memory
+                // args(1) = size of the serialized seed uref in wasm memory
+                // args(2) = pointer to the dictionary item key in wasm memory
+                // args(3) = size of the dictionary item key in wasm memory
+                // args(4) = pointer to output size (output param)
+                let (uref_ptr, uref_size, key_ptr, key_size, output_size_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.dictionary_get,
+                    [uref_ptr, uref_size, key_ptr, key_size, output_size_ptr],
+                )?;
+                scoped_instrumenter.add_property("key_size", key_size.to_string());
+                let ret =
+                    self.dictionary_get(uref_ptr, uref_size, key_ptr, key_size, output_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::DictionaryPut => {
+                // args(0) = pointer to the serialized seed uref in wasm memory
+                // args(1) = size of the serialized seed uref in wasm memory
+                // args(2) = pointer to the dictionary item key in wasm memory
+                // args(3) = size of the dictionary item key in wasm memory
+                // args(4) = pointer to the serialized value in wasm memory
+                // args(5) = size of the serialized value in wasm memory
+                let (uref_ptr, uref_size, key_ptr, key_size, value_ptr, value_size) =
+                    Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.dictionary_put,
+                    [
+                        uref_ptr, uref_size, key_ptr, key_size, value_ptr, value_size,
+                    ],
+                )?;
+                scoped_instrumenter.add_property("key_size", key_size.to_string());
+                scoped_instrumenter.add_property("value_size", value_size.to_string());
+                let ret = self.dictionary_put(
+                    uref_ptr, uref_size, key_ptr, key_size, value_ptr, value_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
         }
     }
 }
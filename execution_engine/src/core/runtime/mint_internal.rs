@@ -55,17 +55,17 @@ where
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::NewURef))
     }
 
-    fn write_balance_entry(&mut self, purse_key: URef, balance_uref: URef) -> Result<(), Error> {
-        let cl_value = CLValue::from_t(Key::URef(balance_uref)).map_err(|_| Error::CLValue)?;
+    fn write_balance(&mut self, purse_uref: URef, balance: U512) -> Result<(), Error> {
+        let cl_value = CLValue::from_t(balance).map_err(|_| Error::CLValue)?;
         self.context
-            .write_purse_uref(purse_key, cl_value)
+            .write_balance(purse_uref, cl_value)
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::WriteLocal))
     }
 
-    fn read_balance_entry(&mut self, purse_key: &URef) -> Result<Option<Key>, Error> {
+    fn read_balance(&mut self, purse_uref: &URef) -> Result<Option<U512>, Error> {
         let maybe_value = self
             .context
-            .read_purse_uref(purse_key)
+            .read_balance(purse_uref)
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))?;
         match maybe_value {
             Some(value) => {
@@ -76,6 +76,13 @@ where
         }
     }
 
+    fn add_balance(&mut self, purse_uref: URef, amount: U512) -> Result<(), Error> {
+        let cl_value = CLValue::from_t(amount).map_err(|_| Error::CLValue)?;
+        self.context
+            .add_balance(purse_uref, cl_value)
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
     fn read<T: CLTyped + FromBytes>(&mut self, uref: URef) -> Result<Option<T>, Error> {
         let maybe_value = self
             .context
@@ -15,7 +15,7 @@ use std::{
 
 use itertools::Itertools;
 use parity_wasm::elements::Module;
-use wasmi::{ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, Trap, TrapKind};
+use wasmi::{ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, RuntimeValue, Trap, TrapKind};
 
 use casper_types::{
     account::{AccountHash, ActionType, Weight},
@@ -24,6 +24,7 @@ use casper_types::{
         self, Contract, ContractPackage, ContractPackageStatus, ContractVersion, ContractVersions,
         DisabledVersions, EntryPoint, EntryPointAccess, EntryPoints, Group, Groups, NamedKeys,
     },
+    crypto,
     system::{
         self,
         auction::{self, Auction, EraId, EraInfo},
@@ -34,13 +35,17 @@ use casper_types::{
     },
     AccessRights, ApiError, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash,
     ContractVersionKey, ContractWasm, DeployHash, EntryPointType, Key, Phase, ProtocolVersion,
-    PublicKey, RuntimeArgs, Transfer, TransferResult, TransferredTo, URef, U128, U256, U512,
+    PublicKey, RuntimeArgs, Signature, Transfer, TransferResult, TransferredTo, URef,
+    DICTIONARY_ITEM_KEY_MAX_LENGTH, U128, U256, U512,
 };
 
 use crate::{
     core::{
-        engine_state::{system_contract_cache::SystemContractCache, EngineConfig},
-        execution::{self, Error},
+        engine_state::{
+            system_contract_cache::SystemContractCache, wasm_module_cache::WasmModuleCache,
+            EngineConfig,
+        },
+        execution::{self, Error, ExecutionTracer, FrameOutcome},
         resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
         runtime::scoped_instrumenter::ScopedInstrumenter,
         runtime_context::{self, RuntimeContext},
@@ -58,11 +63,13 @@ use crate::{
 
 pub struct Runtime<'a, R> {
     system_contract_cache: SystemContractCache,
+    wasm_module_cache: WasmModuleCache,
     config: EngineConfig,
     memory: MemoryRef,
     module: Module,
     host_buffer: Option<CLValue>,
     context: RuntimeContext<'a, R>,
+    tracer: Option<ExecutionTracer>,
 }
 
 pub fn instance_and_memory(
@@ -95,6 +102,10 @@ pub fn key_to_tuple(key: Key) -> Option<([u8; 32], AccessRights)> {
         Key::Transfer(_) => None,
         Key::DeployInfo(_) => None,
         Key::EraInfo(_) => None,
+        Key::Bid(_) => None,
+        Key::Withdraw(_) => None,
+        Key::Dictionary(_) => None,
+        Key::Balance(_) => None,
     }
 }
 
@@ -965,6 +976,7 @@ where
     pub fn new(
         config: EngineConfig,
         system_contract_cache: SystemContractCache,
+        wasm_module_cache: WasmModuleCache,
         memory: MemoryRef,
         module: Module,
         context: RuntimeContext<'a, R>,
@@ -972,13 +984,61 @@ where
         Runtime {
             config,
             system_contract_cache,
+            wasm_module_cache,
             memory,
             module,
             host_buffer: None,
             context,
+            tracer: None,
+        }
+    }
+
+    /// Sets the tracer recording the host functions called and the gas charged, including by any
+    /// contracts called from this runtime.
+    pub(crate) fn set_tracer(&mut self, tracer: Option<ExecutionTracer>) {
+        self.tracer = tracer;
+    }
+
+    /// Records the start of executing `entry_point_name` in the trace, if tracing.
+    pub(crate) fn trace_enter_frame(&self, entry_point_name: &str) {
+        if let Some(tracer) = &self.tracer {
+            tracer.enter_frame(
+                self.context.base_key(),
+                entry_point_name,
+                self.gas_counter(),
+            );
         }
     }
 
+    /// Records the end of executing the current entry point in the trace, if tracing.
+    pub(crate) fn trace_exit_frame(&self, result: &Result<Option<RuntimeValue>, wasmi::Error>) {
+        let tracer = match &self.tracer {
+            Some(tracer) => tracer,
+            None => return,
+        };
+        let outcome = match result {
+            Ok(_) => FrameOutcome::Returned,
+            Err(error) => {
+                let downcasted_error = error
+                    .as_host_error()
+                    .and_then(|host_error| host_error.downcast_ref::<Error>());
+                match downcasted_error {
+                    Some(Error::Ret(_)) => FrameOutcome::Returned,
+                    Some(Error::Revert(api_error)) => FrameOutcome::Reverted {
+                        code: (*api_error).into(),
+                    },
+                    Some(error) => FrameOutcome::Failed {
+                        error: error.to_string(),
+                    },
+                    None => FrameOutcome::Failed {
+                        error: error.to_string(),
+                    },
+                }
+            }
+        };
+        tracer.exit_frame(outcome, self.gas_counter());
+    }
+
     pub fn memory(&self) -> &MemoryRef {
         &self.memory
     }
@@ -1322,7 +1382,7 @@ where
         let protocol_data = self.context.protocol_data();
         let transfers = self.context.transfers().to_owned();
 
-        let mint_context = RuntimeContext::new(
+        let mut mint_context = RuntimeContext::new(
             self.context.state(),
             EntryPointType::Contract,
             named_keys,
@@ -1344,10 +1404,12 @@ where
             *protocol_data,
             transfers,
         );
+        mint_context.set_read_only(self.context.is_read_only());
 
         let mut mint_runtime = Runtime::new(
             self.config,
             SystemContractCache::clone(&self.system_contract_cache),
+            self.wasm_module_cache.clone(),
             self.memory.clone(),
             self.module.clone(),
             mint_context,
@@ -1467,7 +1529,7 @@ where
         let protocol_data = self.context.protocol_data();
         let transfers = self.context.transfers().to_owned();
 
-        let runtime_context = RuntimeContext::new(
+        let mut runtime_context = RuntimeContext::new(
             self.context.state(),
             EntryPointType::Contract,
             named_keys,
@@ -1489,10 +1551,12 @@ where
             *protocol_data,
             transfers,
         );
+        runtime_context.set_read_only(self.context.is_read_only());
 
         let mut runtime = Runtime::new(
             self.config,
             SystemContractCache::clone(&self.system_contract_cache),
+            self.wasm_module_cache.clone(),
             self.memory.clone(),
             self.module.clone(),
             runtime_context,
@@ -1594,7 +1658,7 @@ where
         let protocol_data = self.context.protocol_data();
         let transfers = self.context.transfers().to_owned();
 
-        let runtime_context = RuntimeContext::new(
+        let mut runtime_context = RuntimeContext::new(
             self.context.state(),
             EntryPointType::Contract,
             named_keys,
@@ -1616,10 +1680,12 @@ where
             *protocol_data,
             transfers,
         );
+        runtime_context.set_read_only(self.context.is_read_only());
 
         let mut runtime = Runtime::new(
             self.config,
             SystemContractCache::clone(&self.system_contract_cache),
+            self.wasm_module_cache.clone(),
             self.memory.clone(),
             self.module.clone(),
             runtime_context,
@@ -1704,6 +1770,22 @@ where
                 CLValue::from_t(result).map_err(Self::reverter)
             })(),
 
+            auction::METHOD_REDELEGATE => (|| {
+                runtime.charge_system_contract_call(auction_costs.redelegate)?;
+
+                let delegator = Self::get_named_argument(&runtime_args, auction::ARG_DELEGATOR)?;
+                let validator = Self::get_named_argument(&runtime_args, auction::ARG_VALIDATOR)?;
+                let amount = Self::get_named_argument(&runtime_args, auction::ARG_AMOUNT)?;
+                let new_validator =
+                    Self::get_named_argument(&runtime_args, auction::ARG_NEW_VALIDATOR)?;
+
+                let result = runtime
+                    .redelegate(delegator, validator, amount, new_validator)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
             auction::METHOD_RUN_AUCTION => (|| {
                 runtime.charge_system_contract_call(auction_costs.run_auction)?;
 
@@ -2011,7 +2093,12 @@ where
             };
             match maybe_module {
                 Some(module) => module,
-                None => parity_wasm::deserialize_buffer(contract_wasm.bytes())?,
+                None => self.wasm_module_cache.get_or_insert_with(
+                    contract.contract_wasm_hash(),
+                    protocol_version,
+                    contract_wasm.bytes().len(),
+                    || parity_wasm::deserialize_buffer(contract_wasm.bytes()),
+                )?,
             }
         };
 
@@ -2033,11 +2120,13 @@ where
 
         let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
+        let wasm_module_cache = self.wasm_module_cache.clone();
+
         let config = self.config;
 
         let host_buffer = None;
 
-        let context = RuntimeContext::new(
+        let mut context = RuntimeContext::new(
             self.context.state(),
             entry_point.entry_point_type(),
             &mut named_keys,
@@ -2059,17 +2148,22 @@ where
             *self.context.protocol_data(),
             self.context.transfers().to_owned(),
         );
+        context.set_read_only(self.context.is_read_only());
 
         let mut runtime = Runtime {
             system_contract_cache,
+            wasm_module_cache,
             config,
             memory,
             module,
             host_buffer,
             context,
+            tracer: self.tracer.clone(),
         };
 
+        runtime.trace_enter_frame(entry_point_name);
         let result = instance.invoke_export(entry_point_name, &[], &mut runtime);
+        runtime.trace_exit_frame(&result);
 
         // The `runtime`'s context was initialized with our counter from before the call and any gas
         // charged by the sub-call was added to its counter - so let's copy the correct value of the
@@ -2532,7 +2626,7 @@ where
             transfers.push(transfer_addr);
         }
         self.context
-            .write_transfer(Key::Transfer(transfer_addr), transfer);
+            .write_transfer(Key::Transfer(transfer_addr), transfer)?;
         Ok(())
     }
 
@@ -2546,7 +2640,8 @@ where
             return Ok(());
         }
 
-        self.context.write_era_info(Key::EraInfo(era_id), era_info);
+        self.context
+            .write_era_info(Key::EraInfo(era_id), era_info)?;
 
         Ok(())
     }
@@ -2981,25 +3076,13 @@ where
     }
 
     fn get_balance(&mut self, purse: URef) -> Result<Option<U512>, Error> {
-        let balance_uref_key = match self.context.read_purse_uref(&purse)? {
+        match self.context.read_balance(&purse)? {
             Some(cl_value) => {
-                let key: Key = cl_value.into_t()?;
-                if key.as_uref().is_none() {
-                    return Err(Error::KeyIsNotAURef(key));
-                }
-                key
-            }
-            None => return Ok(None),
-        };
-        let ret = match self.context.read_gs_direct(&balance_uref_key)? {
-            Some(StoredValue::CLValue(cl_value)) => {
                 let balance: U512 = cl_value.into_t()?;
-                Some(balance)
+                Ok(Some(balance))
             }
-            Some(_) => return Err(Error::UnexpectedStoredValueVariant),
-            None => None,
-        };
-        Ok(ret)
+            None => Ok(None),
+        }
     }
 
     fn get_balance_host_buffer(
@@ -3121,6 +3204,174 @@ where
         Ok(Ok(()))
     }
 
+    /// Records a message with the given topic and payload in the execution effects of the current
+    /// deploy.  The topic must be a non-empty UTF-8 string.
+    fn emit_message(
+        &mut self,
+        topic_ptr: u32,
+        topic_size: u32,
+        payload_ptr: u32,
+        payload_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let topic_bytes = self.bytes_from_mem(topic_ptr, topic_size as usize)?;
+        let topic = match String::from_utf8(topic_bytes) {
+            Ok(topic) if !topic.is_empty() => topic,
+            _ => return Ok(Err(ApiError::InvalidArgument)),
+        };
+        let payload = self.bytes_from_mem(payload_ptr, payload_size as usize)?;
+        self.context.emit_message(topic, payload)?;
+        Ok(Ok(()))
+    }
+
+    /// Verifies the given signature of the given message against the given public key.  Returns
+    /// `ApiError::InvalidSignature` if the signature is not valid.
+    fn verify_signature(
+        &mut self,
+        public_key_ptr: u32,
+        public_key_size: u32,
+        message_ptr: u32,
+        message_size: u32,
+        signature_ptr: u32,
+        signature_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let public_key_bytes = self.bytes_from_mem(public_key_ptr, public_key_size as usize)?;
+        let public_key: PublicKey = match bytesrepr::deserialize(public_key_bytes) {
+            Ok(public_key) => public_key,
+            Err(error) => return Ok(Err(error.into())),
+        };
+        let signature_bytes = self.bytes_from_mem(signature_ptr, signature_size as usize)?;
+        let signature: Signature = match bytesrepr::deserialize(signature_bytes) {
+            Ok(signature) => signature,
+            Err(error) => return Ok(Err(error.into())),
+        };
+        let message = self.bytes_from_mem(message_ptr, message_size as usize)?;
+
+        if crypto::verify(&message, &signature, &public_key).is_err() {
+            return Ok(Err(ApiError::InvalidSignature));
+        }
+        Ok(Ok(()))
+    }
+
+    /// Writes the given digest to wasm memory.  Returns `ApiError::BufferTooSmall` if the output
+    /// buffer's size doesn't match the digest's length.
+    fn write_digest(
+        &mut self,
+        digest: &[u8],
+        out_ptr: u32,
+        out_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if digest.len() != out_size as usize {
+            return Ok(Err(ApiError::BufferTooSmall));
+        }
+        self.memory
+            .set(out_ptr, digest)
+            .map_err(|error| Error::Interpreter(error.into()))?;
+        Ok(Ok(()))
+    }
+
+    /// Creates a new dictionary, identified by a new seed `URef`, and writes the seed `URef` to
+    /// the host buffer.
+    fn new_dictionary(&mut self, output_size_ptr: u32) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let seed_uref = self.context.new_unit_uref()?;
+        let value = CLValue::from_t(seed_uref).map_err(Error::CLValue)?;
+        let value_size = value.inner_bytes().len() as u32;
+        if let Err(error) = self.write_host_buffer(value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Reads the dictionary item key (defined as `key_ptr` and `key_size` tuple) from Wasm memory.
+    /// The key must be a UTF-8 string of at most `DICTIONARY_ITEM_KEY_MAX_LENGTH` bytes.
+    fn dictionary_item_key_from_mem(
+        &self,
+        key_ptr: u32,
+        key_size: u32,
+    ) -> Result<Result<String, ApiError>, Trap> {
+        if key_size as usize > DICTIONARY_ITEM_KEY_MAX_LENGTH {
+            return Ok(Err(ApiError::DictionaryItemKeyExceedsLength));
+        }
+        let key_bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
+        match String::from_utf8(key_bytes) {
+            Ok(dictionary_item_key) => Ok(Ok(dictionary_item_key)),
+            Err(_) => Ok(Err(ApiError::InvalidArgument)),
+        }
+    }
+
+    /// Reads the item with the given key from the dictionary identified by the given seed `URef`
+    /// and writes it to the host buffer.
+    fn dictionary_get(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        key_ptr: u32,
+        key_size: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let seed_uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let dictionary_item_key = match self.dictionary_item_key_from_mem(key_ptr, key_size)? {
+            Ok(dictionary_item_key) => dictionary_item_key,
+            Err(error) => return Ok(Err(error)),
+        };
+        let cl_value = match self
+            .context
+            .dictionary_get(seed_uref, &dictionary_item_key)?
+        {
+            Some(cl_value) => cl_value,
+            None => return Ok(Err(ApiError::ValueNotFound)),
+        };
+
+        let value_size = cl_value.inner_bytes().len() as u32;
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Writes the given value under the given key to the dictionary identified by the given seed
+    /// `URef`.
+    fn dictionary_put(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        key_ptr: u32,
+        key_size: u32,
+        value_ptr: u32,
+        value_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let seed_uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let dictionary_item_key = match self.dictionary_item_key_from_mem(key_ptr, key_size)? {
+            Ok(dictionary_item_key) => dictionary_item_key,
+            Err(error) => return Ok(Err(error)),
+        };
+        let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
+        self.context
+            .dictionary_put(seed_uref, &dictionary_item_key, cl_value)?;
+        Ok(Ok(()))
+    }
+
     #[cfg(feature = "test-support")]
     fn print(&mut self, text_ptr: u32, text_size: u32) -> Result<(), Trap> {
         let text = self.string_from_mem(text_ptr, text_size)?;
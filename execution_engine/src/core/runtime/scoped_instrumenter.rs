@@ -77,19 +77,11 @@ impl ScopedInstrumenter {
         self.pause_state.complete();
     }
 
-    fn duration(&self) -> Duration {
-        self.start
-            .elapsed()
-            .checked_sub(self.pause_state.duration())
-            .unwrap_or_default()
-    }
-}
-
-impl Drop for ScopedInstrumenter {
-    fn drop(&mut self) {
-        let duration = self.duration();
+    /// Returns the name under which the host function's metrics are logged, or `None` for the
+    /// gas function, which isn't logged.
+    pub(super) fn host_function_name(&self) -> Option<&'static str> {
         let host_function = match self.function_index {
-            FunctionIndex::GasFuncIndex => return,
+            FunctionIndex::GasFuncIndex => return None,
             FunctionIndex::WriteFuncIndex => "host_function_write",
             FunctionIndex::ReadFuncIndex => "host_function_read_value",
             FunctionIndex::AddFuncIndex => "host_function_add",
@@ -143,6 +135,35 @@ impl Drop for ScopedInstrumenter {
             FunctionIndex::Blake2b => "host_blake2b",
             FunctionIndex::RecordTransfer => "host_record_transfer",
             FunctionIndex::RecordEraInfo => "host_record_era_info",
+            FunctionIndex::EmitMessage => "host_emit_message",
+            FunctionIndex::VerifySignature => "host_verify_signature",
+            FunctionIndex::Sha256 => "host_sha256",
+            FunctionIndex::Keccak256 => "host_keccak256",
+            FunctionIndex::NewDictionary => "host_new_dictionary",
+            FunctionIndex::DictionaryGet => "host_dictionary_get",
+            FunctionIndex::DictionaryPut => "host_dictionary_put",
+        };
+        Some(host_function)
+    }
+
+    pub(super) fn properties(&self) -> &BTreeMap<&'static str, String> {
+        &self.properties
+    }
+
+    fn duration(&self) -> Duration {
+        self.start
+            .elapsed()
+            .checked_sub(self.pause_state.duration())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for ScopedInstrumenter {
+    fn drop(&mut self) {
+        let duration = self.duration();
+        let host_function = match self.host_function_name() {
+            Some(host_function) => host_function,
+            None => return,
         };
 
         let mut properties = mem::take(&mut self.properties);
@@ -14,15 +14,18 @@ use casper_types::{
     bytesrepr,
     bytesrepr::ToBytes,
     contracts::NamedKeys,
-    system::auction::EraInfo,
+    system::auction::{Bid, EraInfo, UnbondingPurse},
     AccessRights, BlockTime, CLType, CLValue, Contract, ContractPackage, ContractPackageHash,
-    DeployHash, DeployInfo, EntryPointAccess, EntryPointType, Key, Phase, ProtocolVersion,
+    DeployHash, DeployInfo, EntryPointAccess, EntryPointType, Key, KeyTag, Phase, ProtocolVersion,
     RuntimeArgs, Transfer, TransferAddr, URef, KEY_HASH_LENGTH,
 };
 
 use crate::{
     core::{
-        engine_state::{execution_effect::ExecutionEffect, SYSTEM_ACCOUNT_ADDR},
+        engine_state::{
+            execution_effect::{ExecutionEffect, Message},
+            SYSTEM_ACCOUNT_ADDR,
+        },
         execution::{AddressGenerator, Error},
         tracking_copy::{AddResult, TrackingCopy},
         Address,
@@ -105,6 +108,8 @@ pub struct RuntimeContext<'a, R> {
     protocol_data: ProtocolData,
     entry_point_type: EntryPointType,
     transfers: Vec<TransferAddr>,
+    // Whether writes to global state are rejected
+    read_only: bool,
 }
 
 impl<'a, R> RuntimeContext<'a, R>
@@ -156,9 +161,21 @@ where
             phase,
             protocol_data,
             transfers,
+            read_only: false,
         }
     }
 
+    /// Returns whether this context is read-only, i.e. whether any write to global state fails
+    /// with [`Error::ReadOnlyContext`].
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Sets whether this context is read-only.
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
     pub fn authorization_keys(&self) -> &BTreeSet<AccountHash> {
         &self.authorization_keys
     }
@@ -248,6 +265,28 @@ where
                 // Users cannot remove era infos from global state
                 Ok(())
             }
+            bid_key @ Key::Bid(_) => {
+                let _bid: Bid = self.read_gs_typed(&bid_key)?;
+                self.named_keys.remove(name);
+                // Users cannot remove bids from global state
+                Ok(())
+            }
+            withdraw_key @ Key::Withdraw(_) => {
+                let _unbonding_purses: Vec<UnbondingPurse> = self.read_gs_typed(&withdraw_key)?;
+                self.named_keys.remove(name);
+                // Users cannot remove unbonding purses from global state
+                Ok(())
+            }
+            Key::Dictionary(_) => {
+                self.named_keys.remove(name);
+                // Dictionary items can only be accessed through their dictionary's seed URef
+                Ok(())
+            }
+            Key::Balance(_) => {
+                self.named_keys.remove(name);
+                // Purse balances can only be accessed through the mint
+                Ok(())
+            }
         }
     }
 
@@ -362,11 +401,11 @@ where
         Ok(())
     }
 
-    pub fn read_purse_uref(&mut self, purse_uref: &URef) -> Result<Option<CLValue>, Error> {
+    pub fn read_balance(&mut self, purse_uref: &URef) -> Result<Option<CLValue>, Error> {
         match self
             .tracking_copy
             .borrow_mut()
-            .read(self.correlation_id, &Key::Hash(purse_uref.addr()))
+            .read(self.correlation_id, &Key::Balance(purse_uref.addr()))
             .map_err(Into::into)?
         {
             Some(stored_value) => Ok(Some(stored_value.try_into().map_err(Error::TypeMismatch)?)),
@@ -374,8 +413,15 @@ where
         }
     }
 
-    pub fn write_purse_uref(&mut self, purse_uref: URef, cl_value: CLValue) -> Result<(), Error> {
-        self.metered_write_gs_unsafe(Key::Hash(purse_uref.addr()), cl_value)
+    pub fn write_balance(&mut self, purse_uref: URef, cl_value: CLValue) -> Result<(), Error> {
+        self.metered_write_gs_unsafe(Key::Balance(purse_uref.addr()), cl_value)
+    }
+
+    pub fn add_balance(&mut self, purse_uref: URef, cl_value: CLValue) -> Result<(), Error> {
+        self.metered_add_gs_unsafe(
+            Key::Balance(purse_uref.addr()),
+            StoredValue::CLValue(cl_value),
+        )
     }
 
     pub fn read_gs(&mut self, key: &Key) -> Result<Option<StoredValue>, Error> {
@@ -418,6 +464,27 @@ where
         })
     }
 
+    /// Returns all keys of the given `key_tag` in global state, charging for the bytes of the keys
+    /// enumerated.
+    pub fn get_keys(&mut self, key_tag: &KeyTag) -> Result<BTreeSet<Key>, Error> {
+        let keys = self
+            .tracking_copy
+            .borrow()
+            .get_keys(self.correlation_id, key_tag)?;
+
+        // Unlike storage, enumeration is charged to system contracts too, as its cost grows with
+        // the number of keys of the given tag.
+        let bytes_count = keys.iter().map(Key::serialized_length).sum();
+        let gas_cost = self
+            .protocol_data()
+            .wasm_config()
+            .storage_costs()
+            .calculate_gas_cost(bytes_count);
+        self.charge_gas(gas_cost)?;
+
+        Ok(keys)
+    }
+
     pub fn read_account(&mut self, key: &Key) -> Result<Option<StoredValue>, Error> {
         if let Key::Account(_) = key {
             self.validate_key(key)?;
@@ -441,26 +508,74 @@ where
         }
     }
 
-    pub fn write_transfer(&mut self, key: Key, value: Transfer) {
+    pub fn write_transfer(&mut self, key: Key, value: Transfer) -> Result<(), Error> {
         if let Key::Transfer(_) = key {
+            self.validate_not_read_only()?;
             self.tracking_copy
                 .borrow_mut()
                 .write(key, StoredValue::Transfer(value));
+            Ok(())
         } else {
             panic!("Do not use this function for writing non-transfer keys")
         }
     }
 
-    pub fn write_era_info(&mut self, key: Key, value: EraInfo) {
+    pub fn write_era_info(&mut self, key: Key, value: EraInfo) -> Result<(), Error> {
         if let Key::EraInfo(_) = key {
+            self.validate_not_read_only()?;
             self.tracking_copy
                 .borrow_mut()
                 .write(key, StoredValue::EraInfo(value));
+            Ok(())
         } else {
             panic!("Do not use this function for writing non-era-info keys")
         }
     }
 
+    /// Records a message emitted in the context of this contract or account.
+    pub fn emit_message(&mut self, topic: String, payload: Vec<u8>) -> Result<(), Error> {
+        self.validate_not_read_only()?;
+        let message = Message::new(self.base_key, topic, payload);
+        self.tracking_copy.borrow_mut().emit_message(message);
+        Ok(())
+    }
+
+    /// Reads the item with the given key from the dictionary identified by `seed_uref`.
+    ///
+    /// The seed `URef` must be known to the current context and be readable.
+    pub fn dictionary_get(
+        &mut self,
+        seed_uref: URef,
+        dictionary_item_key: &str,
+    ) -> Result<Option<CLValue>, Error> {
+        self.validate_readable(&Key::URef(seed_uref))?;
+        self.validate_uref(&seed_uref)?;
+
+        let dictionary_key = Key::dictionary(seed_uref, dictionary_item_key.as_bytes());
+        match self.read_gs_direct(&dictionary_key)? {
+            Some(stored_value) => Ok(Some(stored_value.try_into().map_err(Error::TypeMismatch)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Writes the item with the given key to the dictionary identified by `seed_uref`.
+    ///
+    /// The seed `URef` must be known to the current context and be writeable.
+    pub fn dictionary_put(
+        &mut self,
+        seed_uref: URef,
+        dictionary_item_key: &str,
+        cl_value: CLValue,
+    ) -> Result<(), Error> {
+        self.validate_writeable(&Key::URef(seed_uref))?;
+        self.validate_uref(&seed_uref)?;
+
+        let stored_value = StoredValue::CLValue(cl_value);
+        self.validate_value(&stored_value)?;
+        let dictionary_key = Key::dictionary(seed_uref, dictionary_item_key.as_bytes());
+        self.metered_write_gs_unsafe(dictionary_key, stored_value)
+    }
+
     pub fn store_function(
         &mut self,
         contract: StoredValue,
@@ -563,6 +678,8 @@ where
             StoredValue::Transfer(_) => Ok(()),
             StoredValue::DeployInfo(_) => Ok(()),
             StoredValue::EraInfo(_) => Ok(()),
+            StoredValue::Bid(_) => Ok(()),
+            StoredValue::Withdraw(_) => Ok(()),
         }
     }
 
@@ -610,6 +727,14 @@ where
         Ok(keys)
     }
 
+    fn validate_not_read_only(&self) -> Result<(), Error> {
+        if self.read_only {
+            Err(Error::ReadOnlyContext)
+        } else {
+            Ok(())
+        }
+    }
+
     fn validate_readable(&self, key: &Key) -> Result<(), Error> {
         if self.is_readable(&key) {
             Ok(())
@@ -649,6 +774,12 @@ where
             Key::Transfer(_) => true,
             Key::DeployInfo(_) => true,
             Key::EraInfo(_) => true,
+            Key::Bid(_) => true,
+            Key::Withdraw(_) => true,
+            // Dictionary items are only readable through their dictionary's seed URef.
+            Key::Dictionary(_) => false,
+            // Purse balances are only readable through the mint.
+            Key::Balance(_) => false,
         }
     }
 
@@ -660,6 +791,10 @@ where
             Key::Transfer(_) => false,
             Key::DeployInfo(_) => false,
             Key::EraInfo(_) => false,
+            Key::Bid(_) => false,
+            Key::Withdraw(_) => false,
+            Key::Dictionary(_) => false,
+            Key::Balance(_) => false,
         }
     }
 
@@ -671,6 +806,10 @@ where
             Key::Transfer(_) => false,
             Key::DeployInfo(_) => false,
             Key::EraInfo(_) => false,
+            Key::Bid(_) => false,
+            Key::Withdraw(_) => false,
+            Key::Dictionary(_) => false,
+            Key::Balance(_) => false,
         }
     }
 
@@ -745,6 +884,7 @@ where
         K: Into<Key>,
         V: Into<StoredValue>,
     {
+        self.validate_not_read_only()?;
         let stored_value = value.into();
 
         // Charge for amount as measured by serialized length
@@ -757,6 +897,16 @@ where
         Ok(())
     }
 
+    /// Removes `key` and its value from global state.
+    pub(crate) fn prune_gs_unsafe<K>(&mut self, key: K) -> Result<(), Error>
+    where
+        K: Into<Key>,
+    {
+        self.validate_not_read_only()?;
+        self.tracking_copy.borrow_mut().prune(key.into());
+        Ok(())
+    }
+
     pub fn metered_write_gs<T>(&mut self, key: Key, value: T) -> Result<(), Error>
     where
         T: Into<StoredValue>,
@@ -770,6 +920,7 @@ where
     }
 
     fn metered_add_gs_unsafe(&mut self, key: Key, value: StoredValue) -> Result<(), Error> {
+        self.validate_not_read_only()?;
         let value_bytes_count = value.serialized_length();
         self.charge_gas_storage(value_bytes_count)?;
 
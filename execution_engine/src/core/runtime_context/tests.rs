@@ -14,6 +14,7 @@ use casper_types::{
     },
     bytesrepr::ToBytes,
     contracts::NamedKeys,
+    system::auction::EraInfo,
     AccessRights, BlockTime, CLValue, Contract, DeployHash, EntryPointType, EntryPoints, Key,
     Phase, ProtocolVersion, RuntimeArgs, URef, KEY_HASH_LENGTH, U512,
 };
@@ -510,6 +511,26 @@ fn uref_key_writeable_invalid() {
     assert_invalid_access(query_result, AccessRights::WRITE);
 }
 
+#[test]
+fn read_only_context_rejects_writes() {
+    let mut rng = AddressGenerator::new(&DEPLOY_HASH, PHASE);
+    let uref_key = create_uref(&mut rng, AccessRights::READ_ADD_WRITE);
+    let access_rights = extract_access_rights_from_keys(vec![uref_key]);
+    let query_result = test(access_rights, |mut rc| {
+        rc.set_read_only(true);
+        let write_result = rc.metered_write_gs(uref_key, CLValue::from_t(1_i32).unwrap());
+        assert!(matches!(write_result, Err(Error::ReadOnlyContext)));
+        let add_result = rc.metered_add_gs(uref_key, CLValue::from_t(1_i32).unwrap());
+        assert!(matches!(add_result, Err(Error::ReadOnlyContext)));
+        let era_info_result = rc.write_era_info(Key::EraInfo(0), EraInfo::new());
+        assert!(matches!(era_info_result, Err(Error::ReadOnlyContext)));
+        let message_result = rc.emit_message("topic".to_string(), Vec::new());
+        assert!(matches!(message_result, Err(Error::ReadOnlyContext)));
+        rc.read_gs(&uref_key)
+    });
+    assert!(query_result.expect("reading should succeed").is_none());
+}
+
 #[test]
 fn uref_key_addable_valid() {
     let mut rng = AddressGenerator::new(&DEPLOY_HASH, PHASE);
@@ -763,15 +784,15 @@ fn can_roundtrip_key_value_pairs() {
             .as_uref()
             .cloned()
             .unwrap();
-        let test_value = CLValue::from_t("test_value".to_string()).unwrap();
+        let test_value = CLValue::from_t(U512::from(42)).unwrap();
 
         runtime_context
-            .write_purse_uref(test_uref.to_owned(), test_value.clone())
-            .expect("should write_ls");
+            .write_balance(test_uref.to_owned(), test_value.clone())
+            .expect("should write balance");
 
         let result = runtime_context
-            .read_purse_uref(&test_uref)
-            .expect("should read_ls");
+            .read_balance(&test_uref)
+            .expect("should read balance");
 
         Ok(result == Some(test_value))
     };
@@ -47,13 +47,6 @@ pub trait TrackingCopyExt<R> {
         balance_key: Key,
     ) -> Result<Motes, Self::Error>;
 
-    /// Gets the purse balance key for a given purse id and provides a Merkle proof
-    fn get_purse_balance_key_with_proof(
-        &self,
-        correlation_id: CorrelationId,
-        purse_key: Key,
-    ) -> Result<(Key, TrieMerkleProof<Key, StoredValue>), Self::Error>;
-
     /// Gets the balance at a given balance key and provides a Merkle proof
     fn get_purse_balance_with_proof(
         &self,
@@ -133,16 +126,12 @@ where
         purse_key: Key,
     ) -> Result<Key, Self::Error> {
         let balance_key: Key = purse_key
-            .uref_to_hash()
+            .into_uref()
+            .map(|purse_uref| Key::Balance(purse_uref.addr()))
             .ok_or(execution::Error::KeyIsNotAURef(purse_key))?;
-        let stored_value: StoredValue = self
-            .read(correlation_id, &balance_key)
+        self.read(correlation_id, &balance_key)
             .map_err(Into::into)?
             .ok_or(execution::Error::KeyNotFound(purse_key))?;
-        let cl_value: CLValue = stored_value
-            .try_into()
-            .map_err(execution::Error::TypeMismatch)?;
-        let balance_key: Key = cl_value.into_t()?;
         Ok(balance_key)
     }
 
@@ -162,27 +151,6 @@ where
         Ok(balance)
     }
 
-    fn get_purse_balance_key_with_proof(
-        &self,
-        correlation_id: CorrelationId,
-        purse_key: Key,
-    ) -> Result<(Key, TrieMerkleProof<Key, StoredValue>), Self::Error> {
-        let balance_key: Key = purse_key
-            .uref_to_hash()
-            .ok_or(execution::Error::KeyIsNotAURef(purse_key))?;
-        let proof: TrieMerkleProof<Key, StoredValue> = self
-            .read_with_proof(correlation_id, &balance_key) // Key::Hash, so no need to normalize
-            .map_err(Into::into)?
-            .ok_or(execution::Error::KeyNotFound(purse_key))?;
-        let stored_value_ref: &StoredValue = proof.value();
-        let cl_value: CLValue = stored_value_ref
-            .to_owned()
-            .try_into()
-            .map_err(execution::Error::TypeMismatch)?;
-        let balance_key: Key = cl_value.into_t()?;
-        Ok((balance_key, proof))
-    }
-
     fn get_purse_balance_with_proof(
         &self,
         correlation_id: CorrelationId,
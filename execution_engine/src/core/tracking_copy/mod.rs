@@ -5,7 +5,7 @@ pub(self) mod meter;
 mod tests;
 
 use std::{
-    collections::{HashMap, HashSet, VecDeque},
+    collections::{BTreeSet, HashMap, HashSet, VecDeque},
     convert::{From, TryInto},
     iter,
 };
@@ -13,12 +13,18 @@ use std::{
 use linked_hash_map::LinkedHashMap;
 use thiserror::Error;
 
-use casper_types::{bytesrepr, CLType, CLValue, CLValueError, Key, U512};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    CLType, CLValue, CLValueError, Key, KeyTag, U512,
+};
 
 pub use self::ext::TrackingCopyExt;
 use self::meter::{heap_meter::HeapSize, Meter};
 use crate::{
-    core::engine_state::{execution_effect::ExecutionEffect, op::Op},
+    core::engine_state::{
+        execution_effect::{ExecutionEffect, Message},
+        op::Op,
+    },
     shared::{
         additive_map::AdditiveMap,
         newtypes::{Blake2bHash, CorrelationId},
@@ -105,6 +111,7 @@ pub struct TrackingCopyCache<M> {
     current_cache_size: usize,
     reads_cached: LinkedHashMap<Key, StoredValue>,
     muts_cached: HashMap<Key, StoredValue>,
+    prunes_cached: HashSet<Key>,
     meter: M,
 }
 
@@ -119,6 +126,7 @@ impl<M: Meter<Key, StoredValue>> TrackingCopyCache<M> {
             current_cache_size: 0,
             reads_cached: LinkedHashMap::new(),
             muts_cached: HashMap::new(),
+            prunes_cached: HashSet::new(),
             meter,
         }
     }
@@ -141,9 +149,21 @@ impl<M: Meter<Key, StoredValue>> TrackingCopyCache<M> {
 
     /// Inserts `key` and `value` pair to Write/Add cache.
     pub fn insert_write(&mut self, key: Key, value: StoredValue) {
+        self.prunes_cached.remove(&key);
         self.muts_cached.insert(key, value);
     }
 
+    /// Marks `key` as pruned, discarding any pending write to it.
+    pub fn insert_prune(&mut self, key: Key) {
+        self.muts_cached.remove(&key);
+        self.prunes_cached.insert(key);
+    }
+
+    /// Returns `true` if `key` has been pruned.
+    pub fn is_pruned(&self, key: &Key) -> bool {
+        self.prunes_cached.contains(key)
+    }
+
     /// Gets value from `key` in the cache.
     pub fn get(&mut self, key: &Key) -> Option<&StoredValue> {
         if let Some(value) = self.muts_cached.get(&key) {
@@ -159,6 +179,7 @@ pub struct TrackingCopy<R> {
     cache: TrackingCopyCache<HeapSize>,
     ops: AdditiveMap<Key, Op>,
     fns: AdditiveMap<Key, Transform>,
+    messages: Vec<Message>,
 }
 
 #[derive(Debug)]
@@ -192,6 +213,7 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
              * limit? */
             ops: AdditiveMap::new(),
             fns: AdditiveMap::new(),
+            messages: Vec::new(),
         }
     }
 
@@ -220,6 +242,9 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         correlation_id: CorrelationId,
         key: &Key,
     ) -> Result<Option<StoredValue>, R::Error> {
+        if self.cache.is_pruned(key) {
+            return Ok(None);
+        }
         if let Some(value) = self.cache.get(key) {
             return Ok(Some(value.to_owned()));
         }
@@ -253,6 +278,37 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         self.fns.insert_add(normalized_key, Transform::Write(value));
     }
 
+    /// Removes `key` from global state when the effects of this `TrackingCopy` are committed.
+    pub fn prune(&mut self, key: Key) {
+        let normalized_key = key.normalize();
+        self.cache.insert_prune(normalized_key);
+        self.ops.insert_add(normalized_key, Op::Write);
+        self.fns.insert_add(normalized_key, Transform::Prune);
+    }
+
+    /// Returns all keys of the given `key_tag` present in global state, including those written
+    /// to this `TrackingCopy` but not yet committed, and excluding those pruned from it.
+    pub fn get_keys(
+        &self,
+        correlation_id: CorrelationId,
+        key_tag: &KeyTag,
+    ) -> Result<BTreeSet<Key>, R::Error> {
+        let mut keys: BTreeSet<Key> = self
+            .reader
+            .keys_with_prefix(correlation_id, &[*key_tag as u8])?
+            .into_iter()
+            .filter(|key| !self.cache.is_pruned(key))
+            .collect();
+        keys.extend(
+            self.cache
+                .muts_cached
+                .keys()
+                .filter(|key| key.tag() == *key_tag)
+                .copied(),
+        );
+        Ok(keys)
+    }
+
     /// Ok(None) represents missing key to which we want to "add" some value.
     /// Ok(Some(unit)) represents successful operation.
     /// Err(error) is reserved for unexpected errors when accessing global
@@ -330,8 +386,15 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         }
     }
 
+    /// Records a message emitted by a contract.  Like writes and adds, messages only become part of
+    /// the effect of this `TrackingCopy`, not of the one it was forked from.
+    pub fn emit_message(&mut self, message: Message) {
+        self.messages.push(message);
+    }
+
     pub fn effect(&self) -> ExecutionEffect {
         ExecutionEffect::new(self.ops.clone(), self.fns.clone())
+            .with_messages(self.messages.clone())
     }
 
     /// Calling `query()` avoids calling into `self.cache`, so this will not return any values
@@ -427,6 +490,12 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
                 StoredValue::EraInfo(_) => {
                     return Ok(query.into_not_found_result(&"EraInfo value found."));
                 }
+                StoredValue::Bid(_) => {
+                    return Ok(query.into_not_found_result(&"Bid value found."));
+                }
+                StoredValue::Withdraw(_) => {
+                    return Ok(query.into_not_found_result(&"UnbondingPurses value found."));
+                }
             }
         }
     }
@@ -445,6 +514,9 @@ impl<R: StateReader<Key, StoredValue>> StateReader<Key, StoredValue> for &Tracki
         correlation_id: CorrelationId,
         key: &Key,
     ) -> Result<Option<StoredValue>, Self::Error> {
+        if self.cache.is_pruned(key) {
+            return Ok(None);
+        }
         if let Some(value) = self.cache.muts_cached.get(key) {
             return Ok(Some(value.to_owned()));
         }
@@ -462,6 +534,58 @@ impl<R: StateReader<Key, StoredValue>> StateReader<Key, StoredValue> for &Tracki
     ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
         self.reader.read_with_proof(correlation_id, key)
     }
+
+    fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<Vec<Key>, Self::Error> {
+        let mut keys = self.reader.keys_with_prefix(correlation_id, prefix)?;
+        keys.retain(|key| !self.cache.is_pruned(key));
+        for key in self.cache.muts_cached.keys() {
+            let is_match = key
+                .to_bytes()
+                .map(|bytes| bytes.starts_with(prefix))
+                .unwrap_or(false);
+            if is_match && !keys.contains(key) {
+                keys.push(*key);
+            }
+        }
+        Ok(keys)
+    }
+
+    fn keys_with_prefix_after(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        cursor: Option<&Key>,
+        limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        // Fetch enough extra keys to make up for any which have been pruned.
+        let fetch_limit = limit.saturating_add(self.cache.prunes_cached.len());
+        let mut keys =
+            self.reader
+                .keys_with_prefix_after(correlation_id, prefix, cursor, fetch_limit)?;
+        keys.retain(|key| !self.cache.is_pruned(key));
+        let cursor_bytes = cursor.and_then(|cursor| cursor.to_bytes().ok());
+        for key in self.cache.muts_cached.keys() {
+            let is_match = key
+                .to_bytes()
+                .map(|bytes| {
+                    bytes.starts_with(prefix)
+                        && cursor_bytes
+                            .as_ref()
+                            .map_or(true, |cursor_bytes| &bytes > cursor_bytes)
+                })
+                .unwrap_or(false);
+            if is_match && !keys.contains(key) {
+                keys.push(*key);
+            }
+        }
+        keys.sort_by_cached_key(|key| key.to_bytes().unwrap_or_default());
+        keys.truncate(limit);
+        Ok(keys)
+    }
 }
 
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -564,32 +688,16 @@ pub fn validate_query_proof(
 
 pub fn validate_balance_proof(
     hash: &Blake2bHash,
-    purse_proof: &TrieMerkleProof<Key, StoredValue>,
     balance_proof: &TrieMerkleProof<Key, StoredValue>,
     expected_purse_key: Key,
     expected_motes: &U512,
 ) -> Result<(), ValidationError> {
     let expected_balance_key = expected_purse_key
-        .uref_to_hash()
+        .into_uref()
+        .map(|purse_uref| Key::Balance(purse_uref.addr()))
         .ok_or_else(|| ValidationError::KeyIsNotAURef(expected_purse_key.to_owned()))?;
 
-    if purse_proof.key() != &expected_balance_key {
-        return Err(ValidationError::UnexpectedKey);
-    }
-
-    if hash != &purse_proof.compute_state_hash()? {
-        return Err(ValidationError::InvalidProofHash);
-    }
-
-    let purse_proof_stored_value = purse_proof.value().to_owned();
-
-    let purse_balance_clvalue: CLValue = purse_proof_stored_value
-        .try_into()
-        .map_err(|_| ValidationError::ValueToCLValueConversion)?;
-
-    let purse_balance_key: Key = purse_balance_clvalue.into_t()?;
-
-    if balance_proof.key() != &purse_balance_key.normalize() {
+    if balance_proof.key() != &expected_balance_key {
         return Err(ValidationError::UnexpectedKey);
     }
 
@@ -71,6 +71,24 @@ impl StateReader<Key, StoredValue> for CountingDb {
     ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
         Ok(None)
     }
+
+    fn keys_with_prefix(
+        &self,
+        _correlation_id: CorrelationId,
+        _prefix: &[u8],
+    ) -> Result<Vec<Key>, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    fn keys_with_prefix_after(
+        &self,
+        _correlation_id: CorrelationId,
+        _prefix: &[u8],
+        _cursor: Option<&Key>,
+        _limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        Ok(Vec::new())
+    }
 }
 
 #[test]
@@ -156,6 +174,33 @@ fn tracking_copy_write() {
     assert_eq!(tc.ops.get(&k), Some(&Op::Write));
 }
 
+#[test]
+fn tracking_copy_prune() {
+    let correlation_id = CorrelationId::new();
+    let counter = Rc::new(Cell::new(0));
+    let db = CountingDb::new(Rc::clone(&counter));
+    let mut tc = TrackingCopy::new(db);
+    let k = Key::Hash([0u8; 32]);
+
+    let one = StoredValue::CLValue(CLValue::from_t(1_i32).unwrap());
+
+    // pruning a written value replaces the write
+    tc.write(k, one.clone());
+    tc.prune(k);
+    assert_eq!(tc.fns.len(), 1);
+    assert_eq!(tc.fns.get(&k), Some(&Transform::Prune));
+    assert_eq!(tc.ops.get(&k), Some(&Op::Write));
+
+    // a pruned key can't be read, even though the DB still holds it
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), None);
+    assert_eq!(counter.get(), 0);
+
+    // writing again should bring it back
+    tc.write(k, one.clone());
+    assert_eq!(tc.fns.get(&k), Some(&Transform::Write(one.clone())));
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), Some(one));
+}
+
 #[test]
 fn tracking_copy_add_i32() {
     let correlation_id = CorrelationId::new();
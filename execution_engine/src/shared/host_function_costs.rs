@@ -23,6 +23,20 @@ const DEFAULT_ADD_COST: u32 = 5_800;
 const DEFAULT_CALL_CONTRACT_COST: u32 = 4_500;
 const DEFAULT_CALL_CONTRACT_ARGS_SIZE_WEIGHT: u32 = 420;
 
+const DEFAULT_DICTIONARY_GET_COST: u32 = 5_500;
+const DEFAULT_DICTIONARY_GET_KEY_SIZE_WEIGHT: u32 = 590;
+
+const DEFAULT_DICTIONARY_PUT_COST: u32 = 9_500;
+const DEFAULT_DICTIONARY_PUT_KEY_SIZE_WEIGHT: u32 = 1_800;
+const DEFAULT_DICTIONARY_PUT_VALUE_SIZE_WEIGHT: u32 = 520;
+
+const DEFAULT_EMIT_MESSAGE_COST: u32 = 20_000;
+const DEFAULT_EMIT_MESSAGE_TOPIC_SIZE_WEIGHT: u32 = 4_600;
+const DEFAULT_EMIT_MESSAGE_PAYLOAD_SIZE_WEIGHT: u32 = 4_600;
+
+const DEFAULT_VERIFY_SIGNATURE_COST: u32 = 1_000_000;
+const DEFAULT_VERIFY_SIGNATURE_MESSAGE_SIZE_WEIGHT: u32 = 400;
+
 const DEFAULT_CREATE_PURSE_COST: u32 = 170_000;
 const DEFAULT_GET_BALANCE_COST: u32 = 3_800;
 const DEFAULT_GET_BLOCKTIME_COST: u32 = 330;
@@ -38,6 +52,7 @@ const DEFAULT_IS_VALID_UREF_COST: u32 = 760;
 const DEFAULT_LOAD_NAMED_KEYS_COST: u32 = 42_000;
 const DEFAULT_NEW_UREF_COST: u32 = 17_000;
 const DEFAULT_NEW_UREF_VALUE_SIZE_WEIGHT: u32 = 590;
+const DEFAULT_NEW_DICTIONARY_COST: u32 = DEFAULT_NEW_UREF_COST;
 
 const DEFAULT_PRINT_COST: u32 = 20_000;
 const DEFAULT_PRINT_TEXT_SIZE_WEIGHT: u32 = 4_600;
@@ -227,6 +242,13 @@ pub struct HostFunctionCosts {
     pub remove_contract_user_group_urefs: HostFunction<[Cost; 6]>,
     pub print: HostFunction<[Cost; 2]>,
     pub blake2b: HostFunction<[Cost; 4]>,
+    pub emit_message: HostFunction<[Cost; 4]>,
+    pub verify_signature: HostFunction<[Cost; 6]>,
+    pub sha256: HostFunction<[Cost; 4]>,
+    pub keccak256: HostFunction<[Cost; 4]>,
+    pub new_dictionary: HostFunction<[Cost; 1]>,
+    pub dictionary_get: HostFunction<[Cost; 5]>,
+    pub dictionary_put: HostFunction<[Cost; 6]>,
 }
 
 impl Default for HostFunctionCosts {
@@ -344,6 +366,50 @@ impl Default for HostFunctionCosts {
                 [NOT_USED, DEFAULT_PRINT_TEXT_SIZE_WEIGHT],
             ),
             blake2b: HostFunction::default(),
+            emit_message: HostFunction::new(
+                DEFAULT_EMIT_MESSAGE_COST,
+                [
+                    NOT_USED,
+                    DEFAULT_EMIT_MESSAGE_TOPIC_SIZE_WEIGHT,
+                    NOT_USED,
+                    DEFAULT_EMIT_MESSAGE_PAYLOAD_SIZE_WEIGHT,
+                ],
+            ),
+            verify_signature: HostFunction::new(
+                DEFAULT_VERIFY_SIGNATURE_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_VERIFY_SIGNATURE_MESSAGE_SIZE_WEIGHT,
+                    NOT_USED,
+                    NOT_USED,
+                ],
+            ),
+            sha256: HostFunction::default(),
+            keccak256: HostFunction::default(),
+            new_dictionary: HostFunction::fixed(DEFAULT_NEW_DICTIONARY_COST),
+            dictionary_get: HostFunction::new(
+                DEFAULT_DICTIONARY_GET_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_DICTIONARY_GET_KEY_SIZE_WEIGHT,
+                    NOT_USED,
+                ],
+            ),
+            dictionary_put: HostFunction::new(
+                DEFAULT_DICTIONARY_PUT_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_DICTIONARY_PUT_KEY_SIZE_WEIGHT,
+                    NOT_USED,
+                    DEFAULT_DICTIONARY_PUT_VALUE_SIZE_WEIGHT,
+                ],
+            ),
         }
     }
 }
@@ -393,6 +459,13 @@ impl ToBytes for HostFunctionCosts {
         ret.append(&mut self.remove_contract_user_group_urefs.to_bytes()?);
         ret.append(&mut self.print.to_bytes()?);
         ret.append(&mut self.blake2b.to_bytes()?);
+        ret.append(&mut self.emit_message.to_bytes()?);
+        ret.append(&mut self.verify_signature.to_bytes()?);
+        ret.append(&mut self.sha256.to_bytes()?);
+        ret.append(&mut self.keccak256.to_bytes()?);
+        ret.append(&mut self.new_dictionary.to_bytes()?);
+        ret.append(&mut self.dictionary_get.to_bytes()?);
+        ret.append(&mut self.dictionary_put.to_bytes()?);
         Ok(ret)
     }
 
@@ -439,11 +512,25 @@ impl ToBytes for HostFunctionCosts {
             + self.remove_contract_user_group_urefs.serialized_length()
             + self.print.serialized_length()
             + self.blake2b.serialized_length()
+            + self.emit_message.serialized_length()
+            + self.verify_signature.serialized_length()
+            + self.sha256.serialized_length()
+            + self.keccak256.serialized_length()
+            + self.new_dictionary.serialized_length()
+            + self.dictionary_get.serialized_length()
+            + self.dictionary_put.serialized_length()
     }
 }
 
-impl FromBytes for HostFunctionCosts {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+impl HostFunctionCosts {
+    /// Decodes the encoding used before the message, cryptographic and dictionary host functions
+    /// were added, taking their default costs.
+    pub(crate) fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Self::decode(bytes, true)
+    }
+
+    fn decode(bytes: &[u8], legacy: bool) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let defaults = HostFunctionCosts::default();
         let (read_value, rem) = FromBytes::from_bytes(bytes)?;
         let (read_value_local, rem) = FromBytes::from_bytes(rem)?;
         let (write, rem) = FromBytes::from_bytes(rem)?;
@@ -486,6 +573,14 @@ impl FromBytes for HostFunctionCosts {
         let (remove_contract_user_group_urefs, rem) = FromBytes::from_bytes(rem)?;
         let (print, rem) = FromBytes::from_bytes(rem)?;
         let (blake2b, rem) = FromBytes::from_bytes(rem)?;
+        let (emit_message, rem) = from_bytes_unless_legacy(rem, legacy, defaults.emit_message)?;
+        let (verify_signature, rem) =
+            from_bytes_unless_legacy(rem, legacy, defaults.verify_signature)?;
+        let (sha256, rem) = from_bytes_unless_legacy(rem, legacy, defaults.sha256)?;
+        let (keccak256, rem) = from_bytes_unless_legacy(rem, legacy, defaults.keccak256)?;
+        let (new_dictionary, rem) = from_bytes_unless_legacy(rem, legacy, defaults.new_dictionary)?;
+        let (dictionary_get, rem) = from_bytes_unless_legacy(rem, legacy, defaults.dictionary_get)?;
+        let (dictionary_put, rem) = from_bytes_unless_legacy(rem, legacy, defaults.dictionary_put)?;
         Ok((
             HostFunctionCosts {
                 read_value,
@@ -530,12 +625,39 @@ impl FromBytes for HostFunctionCosts {
                 remove_contract_user_group_urefs,
                 print,
                 blake2b,
+                emit_message,
+                verify_signature,
+                sha256,
+                keccak256,
+                new_dictionary,
+                dictionary_get,
+                dictionary_put,
             },
             rem,
         ))
     }
 }
 
+impl FromBytes for HostFunctionCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Self::decode(bytes, false)
+    }
+}
+
+/// Decodes a `T` from `bytes`, or returns `default` without consuming any bytes if decoding the
+/// legacy encoding, which predates `T`'s field.
+fn from_bytes_unless_legacy<T: FromBytes>(
+    bytes: &[u8],
+    legacy: bool,
+    default: T,
+) -> Result<(T, &[u8]), bytesrepr::Error> {
+    if legacy {
+        Ok((default, bytes))
+    } else {
+        T::from_bytes(bytes)
+    }
+}
+
 impl Distribution<HostFunctionCosts> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> HostFunctionCosts {
         HostFunctionCosts {
@@ -581,6 +703,13 @@ impl Distribution<HostFunctionCosts> for Standard {
             remove_contract_user_group_urefs: rng.gen(),
             print: rng.gen(),
             blake2b: rng.gen(),
+            emit_message: rng.gen(),
+            verify_signature: rng.gen(),
+            sha256: rng.gen(),
+            keccak256: rng.gen(),
+            new_dictionary: rng.gen(),
+            dictionary_get: rng.gen(),
+            dictionary_put: rng.gen(),
         }
     }
 }
@@ -639,6 +768,13 @@ pub mod gens {
             remove_contract_user_group_urefs in host_function_cost_arb(),
             print in host_function_cost_arb(),
             blake2b in host_function_cost_arb(),
+            emit_message in host_function_cost_arb(),
+            verify_signature in host_function_cost_arb(),
+            sha256 in host_function_cost_arb(),
+            keccak256 in host_function_cost_arb(),
+            new_dictionary in host_function_cost_arb(),
+            dictionary_get in host_function_cost_arb(),
+            dictionary_put in host_function_cost_arb(),
         ) -> HostFunctionCosts {
             HostFunctionCosts {
                 read_value,
@@ -683,6 +819,13 @@ pub mod gens {
                 remove_contract_user_group_urefs,
                 print,
                 blake2b,
+                emit_message,
+                verify_signature,
+                sha256,
+                keccak256,
+                new_dictionary,
+                dictionary_get,
+                dictionary_put,
             }
         }
     }
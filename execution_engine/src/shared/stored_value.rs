@@ -6,7 +6,7 @@ use serde_bytes::ByteBuf;
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
     contracts::ContractPackage,
-    system::auction::EraInfo,
+    system::auction::{Bid, EraInfo, UnbondingPurse},
     CLValue, Contract, ContractWasm, DeployInfo, Transfer,
 };
 
@@ -22,6 +22,8 @@ enum Tag {
     Transfer = 5,
     DeployInfo = 6,
     EraInfo = 7,
+    Bid = 8,
+    Withdraw = 9,
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
@@ -34,6 +36,8 @@ pub enum StoredValue {
     Transfer(Transfer),
     DeployInfo(DeployInfo),
     EraInfo(EraInfo),
+    Bid(Box<Bid>),
+    Withdraw(Vec<UnbondingPurse>),
 }
 
 impl StoredValue {
@@ -86,6 +90,20 @@ impl StoredValue {
         }
     }
 
+    pub fn as_bid(&self) -> Option<&Bid> {
+        match self {
+            StoredValue::Bid(bid) => Some(bid),
+            _ => None,
+        }
+    }
+
+    pub fn as_withdraw(&self) -> Option<&Vec<UnbondingPurse>> {
+        match self {
+            StoredValue::Withdraw(unbonding_purses) => Some(unbonding_purses),
+            _ => None,
+        }
+    }
+
     pub fn type_name(&self) -> String {
         match self {
             StoredValue::CLValue(cl_value) => format!("{:?}", cl_value.cl_type()),
@@ -96,6 +114,8 @@ impl StoredValue {
             StoredValue::Transfer(_) => "Transfer".to_string(),
             StoredValue::DeployInfo(_) => "DeployInfo".to_string(),
             StoredValue::EraInfo(_) => "EraInfo".to_string(),
+            StoredValue::Bid(_) => "Bid".to_string(),
+            StoredValue::Withdraw(_) => "Withdraw".to_string(),
         }
     }
 }
@@ -232,6 +252,28 @@ impl TryFrom<StoredValue> for EraInfo {
     }
 }
 
+impl TryFrom<StoredValue> for Bid {
+    type Error = TypeMismatch;
+
+    fn try_from(value: StoredValue) -> Result<Self, Self::Error> {
+        match value {
+            StoredValue::Bid(bid) => Ok(*bid),
+            _ => Err(TypeMismatch::new("Bid".to_string(), value.type_name())),
+        }
+    }
+}
+
+impl TryFrom<StoredValue> for Vec<UnbondingPurse> {
+    type Error = TypeMismatch;
+
+    fn try_from(value: StoredValue) -> Result<Self, Self::Error> {
+        match value {
+            StoredValue::Withdraw(unbonding_purses) => Ok(unbonding_purses),
+            _ => Err(TypeMismatch::new("Withdraw".to_string(), value.type_name())),
+        }
+    }
+}
+
 impl ToBytes for StoredValue {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
@@ -248,6 +290,10 @@ impl ToBytes for StoredValue {
             StoredValue::Transfer(transfer) => (Tag::Transfer, transfer.to_bytes()?),
             StoredValue::DeployInfo(deploy_info) => (Tag::DeployInfo, deploy_info.to_bytes()?),
             StoredValue::EraInfo(era_info) => (Tag::EraInfo, era_info.to_bytes()?),
+            StoredValue::Bid(bid) => (Tag::Bid, bid.to_bytes()?),
+            StoredValue::Withdraw(unbonding_purses) => {
+                (Tag::Withdraw, unbonding_purses.to_bytes()?)
+            }
         };
         result.push(tag as u8);
         result.append(&mut serialized_data);
@@ -267,6 +313,8 @@ impl ToBytes for StoredValue {
                 StoredValue::Transfer(transfer) => transfer.serialized_length(),
                 StoredValue::DeployInfo(deploy_info) => deploy_info.serialized_length(),
                 StoredValue::EraInfo(era_info) => era_info.serialized_length(),
+                StoredValue::Bid(bid) => bid.serialized_length(),
+                StoredValue::Withdraw(unbonding_purses) => unbonding_purses.serialized_length(),
             }
     }
 }
@@ -297,6 +345,13 @@ impl FromBytes for StoredValue {
                 .map(|(deploy_info, remainder)| (StoredValue::DeployInfo(deploy_info), remainder)),
             tag if tag == Tag::EraInfo as u8 => EraInfo::from_bytes(remainder)
                 .map(|(deploy_info, remainder)| (StoredValue::EraInfo(deploy_info), remainder)),
+            tag if tag == Tag::Bid as u8 => Bid::from_bytes(remainder)
+                .map(|(bid, remainder)| (StoredValue::Bid(Box::new(bid)), remainder)),
+            tag if tag == Tag::Withdraw as u8 => {
+                Vec::<UnbondingPurse>::from_bytes(remainder).map(|(unbonding_purses, remainder)| {
+                    (StoredValue::Withdraw(unbonding_purses), remainder)
+                })
+            }
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
@@ -117,10 +117,21 @@ impl ToBytes for SystemConfig {
     }
 }
 
-impl FromBytes for SystemConfig {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+impl SystemConfig {
+    /// Decodes the encoding used before entry points were added to [`AuctionCosts`].
+    pub(crate) fn from_legacy_bytes(
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        Self::decode(bytes, true)
+    }
+
+    fn decode(bytes: &[u8], legacy: bool) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
         let (wasmless_transfer_cost, rem) = FromBytes::from_bytes(bytes)?;
-        let (auction_costs, rem) = FromBytes::from_bytes(rem)?;
+        let (auction_costs, rem) = if legacy {
+            AuctionCosts::from_legacy_bytes(rem)?
+        } else {
+            FromBytes::from_bytes(rem)?
+        };
         let (mint_costs, rem) = FromBytes::from_bytes(rem)?;
         let (proof_of_stake_costs, rem) = FromBytes::from_bytes(rem)?;
         let (standard_payment_costs, rem) = FromBytes::from_bytes(rem)?;
@@ -137,6 +148,12 @@ impl FromBytes for SystemConfig {
     }
 }
 
+impl FromBytes for SystemConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        Self::decode(bytes, false)
+    }
+}
+
 #[cfg(any(feature = "gens", test))]
 pub mod gens {
     use proptest::{num, prop_compose};
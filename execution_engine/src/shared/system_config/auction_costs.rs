@@ -16,6 +16,7 @@ pub const DEFAULT_WITHDRAW_DELEGATOR_REWARD_COST: u32 = 10_000;
 pub const DEFAULT_WITHDRAW_VALIDATOR_REWARD_COST: u32 = 10_000;
 pub const DEFAULT_READ_ERA_ID_COST: u32 = 10_000;
 pub const DEFAULT_ACTIVATE_BID_COST: u32 = 10_000;
+pub const DEFAULT_REDELEGATE_COST: u32 = 10_000;
 
 /// Description of costs of calling auction entrypoints.
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
@@ -33,6 +34,7 @@ pub struct AuctionCosts {
     pub withdraw_validator_reward: u32,
     pub read_era_id: u32,
     pub activate_bid: u32,
+    pub redelegate: u32,
 }
 
 impl Default for AuctionCosts {
@@ -51,6 +53,7 @@ impl Default for AuctionCosts {
             withdraw_validator_reward: DEFAULT_WITHDRAW_VALIDATOR_REWARD_COST,
             read_era_id: DEFAULT_READ_ERA_ID_COST,
             activate_bid: DEFAULT_ACTIVATE_BID_COST,
+            redelegate: DEFAULT_REDELEGATE_COST,
         }
     }
 }
@@ -72,6 +75,7 @@ impl ToBytes for AuctionCosts {
         ret.append(&mut self.withdraw_validator_reward.to_bytes()?);
         ret.append(&mut self.read_era_id.to_bytes()?);
         ret.append(&mut self.activate_bid.to_bytes()?);
+        ret.append(&mut self.redelegate.to_bytes()?);
 
         Ok(ret)
     }
@@ -90,11 +94,20 @@ impl ToBytes for AuctionCosts {
             + self.withdraw_validator_reward.serialized_length()
             + self.read_era_id.serialized_length()
             + self.activate_bid.serialized_length()
+            + self.redelegate.serialized_length()
     }
 }
 
-impl FromBytes for AuctionCosts {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+impl AuctionCosts {
+    /// Decodes the encoding used before the `redelegate` entry point was added, taking its default
+    /// cost.
+    pub(crate) fn from_legacy_bytes(
+        bytes: &[u8],
+    ) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        Self::decode(bytes, true)
+    }
+
+    fn decode(bytes: &[u8], legacy: bool) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
         let (get_era_validators, rem) = FromBytes::from_bytes(bytes)?;
         let (read_seigniorage_recipients, rem) = FromBytes::from_bytes(rem)?;
         let (add_bid, rem) = FromBytes::from_bytes(rem)?;
@@ -108,6 +121,11 @@ impl FromBytes for AuctionCosts {
         let (withdraw_validator_reward, rem) = FromBytes::from_bytes(rem)?;
         let (read_era_id, rem) = FromBytes::from_bytes(rem)?;
         let (activate_bid, rem) = FromBytes::from_bytes(rem)?;
+        let (redelegate, rem) = if legacy {
+            (DEFAULT_REDELEGATE_COST, rem)
+        } else {
+            FromBytes::from_bytes(rem)?
+        };
         Ok((
             Self {
                 get_era_validators,
@@ -123,12 +141,19 @@ impl FromBytes for AuctionCosts {
                 withdraw_validator_reward,
                 read_era_id,
                 activate_bid,
+                redelegate,
             },
             rem,
         ))
     }
 }
 
+impl FromBytes for AuctionCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        Self::decode(bytes, false)
+    }
+}
+
 impl Distribution<AuctionCosts> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> AuctionCosts {
         AuctionCosts {
@@ -145,6 +170,7 @@ impl Distribution<AuctionCosts> for Standard {
             withdraw_validator_reward: rng.gen(),
             read_era_id: rng.gen(),
             activate_bid: rng.gen(),
+            redelegate: rng.gen(),
         }
     }
 }
@@ -170,6 +196,7 @@ pub mod gens {
             withdraw_validator_reward in num::u32::ANY,
             read_era_id in num::u32::ANY,
             activate_bid in num::u32::ANY,
+            redelegate in num::u32::ANY,
         ) -> AuctionCosts {
             AuctionCosts {
                 get_era_validators,
@@ -185,6 +212,7 @@ pub mod gens {
                 withdraw_validator_reward,
                 read_era_id,
                 activate_bid,
+                redelegate,
             }
         }
     }
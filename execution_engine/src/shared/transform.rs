@@ -70,6 +70,8 @@ pub enum Transform {
     AddUInt512(U512),
     AddKeys(NamedKeys),
     Failure(Error),
+    /// Removes the key and its value from global state.
+    Prune,
 }
 
 macro_rules! from_try_from_impl {
@@ -194,8 +196,23 @@ impl Transform {
                     let found = "EraInfo".to_string();
                     Err(TypeMismatch::new(expected, found).into())
                 }
+                StoredValue::Bid(_) => {
+                    let expected = "Contract or Account".to_string();
+                    let found = "Bid".to_string();
+                    Err(TypeMismatch::new(expected, found).into())
+                }
+                StoredValue::Withdraw(_) => {
+                    let expected = "Contract or Account".to_string();
+                    let found = "Withdraw".to_string();
+                    Err(TypeMismatch::new(expected, found).into())
+                }
             },
             Transform::Failure(error) => Err(error),
+            Transform::Prune => {
+                let expected = "a transform resulting in a value".to_string();
+                let found = "Prune".to_string();
+                Err(TypeMismatch::new(expected, found).into())
+            }
         }
     }
 }
@@ -242,6 +259,10 @@ impl Add for Transform {
             (a @ Transform::Failure(_), _) => a,
             (_, b @ Transform::Failure(_)) => b,
             (_, b @ Transform::Write(_)) => b,
+            (_, b @ Transform::Prune) => b,
+            (Transform::Prune, b) => Transform::Failure(
+                TypeMismatch::new("Write or Prune".to_owned(), format!("{:?}", b)).into(),
+            ),
             (Transform::Write(v), b) => {
                 // second transform changes value being written
                 match b.apply(v) {
@@ -329,6 +350,12 @@ impl From<&Transform> for casper_types::Transform {
             Transform::Write(StoredValue::EraInfo(era_info)) => {
                 casper_types::Transform::WriteEraInfo(era_info.clone())
             }
+            Transform::Write(StoredValue::Bid(bid)) => {
+                casper_types::Transform::WriteBid(bid.clone())
+            }
+            Transform::Write(StoredValue::Withdraw(unbonding_purses)) => {
+                casper_types::Transform::WriteWithdraw(unbonding_purses.clone())
+            }
             Transform::AddInt32(value) => casper_types::Transform::AddInt32(*value),
             Transform::AddUInt64(value) => casper_types::Transform::AddUInt64(*value),
             Transform::AddUInt128(value) => casper_types::Transform::AddUInt128(*value),
@@ -344,6 +371,7 @@ impl From<&Transform> for casper_types::Transform {
                     .collect(),
             ),
             Transform::Failure(error) => casper_types::Transform::Failure(error.to_string()),
+            Transform::Prune => casper_types::Transform::Prune,
         }
     }
 }
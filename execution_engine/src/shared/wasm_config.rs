@@ -89,13 +89,22 @@ impl ToBytes for WasmConfig {
     }
 }
 
-impl FromBytes for WasmConfig {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+impl WasmConfig {
+    /// Decodes the encoding used before host functions were added to [`HostFunctionCosts`].
+    pub(crate) fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Self::decode(bytes, true)
+    }
+
+    fn decode(bytes: &[u8], legacy: bool) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (max_memory, rem) = FromBytes::from_bytes(bytes)?;
         let (max_stack_height, rem) = FromBytes::from_bytes(rem)?;
         let (opcode_costs, rem) = FromBytes::from_bytes(rem)?;
         let (storage_costs, rem) = FromBytes::from_bytes(rem)?;
-        let (host_function_costs, rem) = FromBytes::from_bytes(rem)?;
+        let (host_function_costs, rem) = if legacy {
+            HostFunctionCosts::from_legacy_bytes(rem)?
+        } else {
+            FromBytes::from_bytes(rem)?
+        };
 
         Ok((
             WasmConfig {
@@ -110,6 +119,12 @@ impl FromBytes for WasmConfig {
     }
 }
 
+impl FromBytes for WasmConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Self::decode(bytes, false)
+    }
+}
+
 impl Distribution<WasmConfig> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> WasmConfig {
         WasmConfig {
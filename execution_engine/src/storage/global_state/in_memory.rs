@@ -22,7 +22,8 @@ use crate::storage::{
     trie_store::{
         in_memory::InMemoryTrieStore,
         operations::{
-            self, missing_trie_keys, put_trie, read, read_with_proof, ReadResult, WriteResult,
+            self, keys_with_prefix, keys_with_prefix_after, missing_trie_keys, put_trie, read,
+            read_with_proof, ReadResult, WriteResult,
         },
     },
 };
@@ -166,6 +167,45 @@ impl StateReader<Key, StoredValue> for InMemoryGlobalStateView {
         txn.commit()?;
         Ok(ret)
     }
+
+    fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<Vec<Key>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = keys_with_prefix::<Key, StoredValue, _, _>(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            prefix,
+        )
+        .collect::<Result<Vec<Key>, _>>()?;
+        txn.commit()?;
+        Ok(ret)
+    }
+
+    fn keys_with_prefix_after(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        cursor: Option<&Key>,
+        limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = keys_with_prefix_after::<Key, StoredValue, _, _>(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            prefix,
+            cursor,
+            limit,
+        )?;
+        txn.commit()?;
+        Ok(ret)
+    }
 }
 
 impl StateProvider for InMemoryGlobalState {
@@ -340,6 +380,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn keys_with_prefix_from_a_checkout_return_expected_keys() {
+        let correlation_id = CorrelationId::new();
+        let mut pairs: Vec<(Key, StoredValue)> = create_test_pairs()
+            .iter()
+            .cloned()
+            .map(|TestPair { key, value }| (key, value))
+            .collect();
+        pairs.push((
+            Key::Hash([1u8; 32]),
+            StoredValue::CLValue(CLValue::from_t(3_i32).unwrap()),
+        ));
+        let (state, root_hash) = InMemoryGlobalState::from_pairs(correlation_id, &pairs).unwrap();
+        let checkout = state.checkout(root_hash).unwrap().unwrap();
+
+        let mut account_keys = checkout.keys_with_prefix(correlation_id, &[0]).unwrap();
+        account_keys.sort();
+        let expected_account_keys: Vec<Key> = create_test_pairs()
+            .iter()
+            .map(|test_pair| test_pair.key)
+            .collect();
+        assert_eq!(account_keys, expected_account_keys);
+
+        let hash_keys = checkout.keys_with_prefix(correlation_id, &[1, 1]).unwrap();
+        assert_eq!(hash_keys, vec![Key::Hash([1u8; 32])]);
+
+        let no_keys = checkout.keys_with_prefix(correlation_id, &[1, 2]).unwrap();
+        assert!(no_keys.is_empty());
+
+        let all_keys = checkout.keys_with_prefix(correlation_id, &[]).unwrap();
+        assert_eq!(all_keys.len(), 3);
+    }
+
     #[test]
     fn checkout_fails_if_unknown_hash_is_given() {
         let (state, _) = create_test_state();
@@ -10,7 +10,7 @@ use casper_types::{Key, ProtocolVersion};
 
 use crate::storage::{
     error,
-    global_state::{commit, CommitResult, StateProvider, StateReader},
+    global_state::{commit_in_txn, CommitResult, StateProvider, StateReader},
     protocol_data::ProtocolData,
     protocol_data_store::lmdb::LmdbProtocolDataStore,
     store::Store,
@@ -18,7 +18,11 @@ use crate::storage::{
     trie::{merkle_proof::TrieMerkleProof, operations::create_hashed_empty_trie, Trie},
     trie_store::{
         lmdb::LmdbTrieStore,
-        operations::{missing_trie_keys, put_trie, read, read_with_proof, ReadResult},
+        operations::{
+            keys_with_prefix, keys_with_prefix_after, missing_trie_keys, put_trie, read,
+            read_with_proof, ReadResult,
+        },
+        pruning::LmdbTriePruner,
     },
 };
 
@@ -27,6 +31,7 @@ pub struct LmdbGlobalState {
     pub trie_store: Arc<LmdbTrieStore>,
     pub protocol_data_store: Arc<LmdbProtocolDataStore>,
     pub empty_root_hash: Blake2bHash,
+    pruner: Option<Arc<LmdbTriePruner>>,
 }
 
 /// Represents a "view" of global state at a particular root hash.
@@ -71,8 +76,18 @@ impl LmdbGlobalState {
             trie_store,
             protocol_data_store,
             empty_root_hash,
+            pruner: None,
         }
     }
+
+    /// Attaches a pruner, so that tries written while it's pruning the trie store are retained.
+    ///
+    /// The pruner must be attached before anything is written to a global state which is pruned
+    /// while in use, or while a pruning run is being resumed.
+    pub fn with_pruner(mut self, pruner: Arc<LmdbTriePruner>) -> Self {
+        self.pruner = Some(pruner);
+        self
+    }
 }
 
 impl StateReader<Key, StoredValue> for LmdbGlobalStateView {
@@ -125,6 +140,45 @@ impl StateReader<Key, StoredValue> for LmdbGlobalStateView {
         txn.commit()?;
         Ok(ret)
     }
+
+    fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<Vec<Key>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = keys_with_prefix::<Key, StoredValue, _, _>(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            prefix,
+        )
+        .collect::<Result<Vec<Key>, _>>()?;
+        txn.commit()?;
+        Ok(ret)
+    }
+
+    fn keys_with_prefix_after(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        cursor: Option<&Key>,
+        limit: usize,
+    ) -> Result<Vec<Key>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = keys_with_prefix_after::<Key, StoredValue, _, _>(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            prefix,
+            cursor,
+            limit,
+        )?;
+        txn.commit()?;
+        Ok(ret)
+    }
 }
 
 impl StateProvider for LmdbGlobalState {
@@ -150,13 +204,20 @@ impl StateProvider for LmdbGlobalState {
         prestate_hash: Blake2bHash,
         effects: AdditiveMap<Key, Transform>,
     ) -> Result<CommitResult, Self::Error> {
-        let commit_result = commit::<LmdbEnvironment, LmdbTrieStore, _, Self::Error>(
-            &self.environment,
+        let mut txn = self.environment.create_read_write_txn()?;
+        let commit_result = commit_in_txn::<_, LmdbTrieStore, _, Self::Error>(
+            &mut txn,
             &self.trie_store,
             correlation_id,
             prestate_hash,
             effects,
         )?;
+        if let CommitResult::Success { state_root } = commit_result {
+            if let Some(pruner) = &self.pruner {
+                pruner.retain_written(&mut txn, state_root)?;
+            }
+            txn.commit()?;
+        }
         Ok(commit_result)
     }
 
@@ -209,6 +270,9 @@ impl StateProvider for LmdbGlobalState {
             LmdbTrieStore,
             Self::Error,
         >(correlation_id, &mut txn, &self.trie_store, trie)?;
+        if let Some(pruner) = &self.pruner {
+            pruner.retain_written(&mut txn, trie_hash)?;
+        }
         txn.commit()?;
         Ok(trie_hash)
     }
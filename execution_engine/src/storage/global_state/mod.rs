@@ -14,10 +14,10 @@ use casper_types::{bytesrepr, Key, ProtocolVersion};
 
 use crate::storage::{
     protocol_data::ProtocolData,
-    transaction_source::{Transaction, TransactionSource},
+    transaction_source::{Readable, Transaction, TransactionSource, Writable},
     trie::{merkle_proof::TrieMerkleProof, Trie},
     trie_store::{
-        operations::{read, write, ReadResult, WriteResult},
+        operations::{delete, read, write, DeleteResult, ReadResult, WriteResult},
         TrieStore,
     },
 };
@@ -36,6 +36,23 @@ pub trait StateReader<K, V> {
         correlation_id: CorrelationId,
         key: &K,
     ) -> Result<Option<TrieMerkleProof<K, V>>, Self::Error>;
+
+    /// Returns the keys in the trie matching `prefix`.
+    fn keys_with_prefix(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<Vec<K>, Self::Error>;
+
+    /// Returns at most `limit` keys in the trie matching `prefix` whose serialized form sorts
+    /// strictly after that of `cursor`, in ascending order of their serialized form.
+    fn keys_with_prefix_after(
+        &self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+        cursor: Option<&K>,
+        limit: usize,
+    ) -> Result<Vec<K>, Self::Error>;
 }
 
 #[derive(Debug)]
@@ -140,16 +157,51 @@ where
     H: BuildHasher,
 {
     let mut txn = environment.create_read_write_txn()?;
+    let commit_result =
+        commit_in_txn::<_, _, _, E>(&mut txn, store, correlation_id, prestate_hash, effects)?;
+    if let CommitResult::Success { .. } = commit_result {
+        txn.commit()?;
+    }
+    Ok(commit_result)
+}
+
+/// Applies `effects` to the state at `prestate_hash` within `txn`, without committing it.
+pub(crate) fn commit_in_txn<T, S, H, E>(
+    txn: &mut T,
+    store: &S,
+    correlation_id: CorrelationId,
+    prestate_hash: Blake2bHash,
+    effects: AdditiveMap<Key, Transform, H>,
+) -> Result<CommitResult, E>
+where
+    T: Readable<Handle = S::Handle> + Writable<Handle = S::Handle>,
+    S: TrieStore<Key, StoredValue>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<bytesrepr::Error>,
+    H: BuildHasher,
+{
     let mut state_root = prestate_hash;
 
-    let maybe_root: Option<Trie<Key, StoredValue>> = store.get(&txn, &state_root)?;
+    let maybe_root: Option<Trie<Key, StoredValue>> = store.get(txn, &state_root)?;
 
     if maybe_root.is_none() {
         return Ok(CommitResult::RootNotFound);
     };
 
     for (key, transform) in effects.into_iter() {
-        let read_result = read::<_, _, _, _, E>(correlation_id, &txn, store, &state_root, &key)?;
+        if let Transform::Prune = transform {
+            // A key pruned after being written within the same effects may not exist yet.
+            match delete::<_, _, _, _, E>(correlation_id, txn, store, &state_root, &key)? {
+                DeleteResult::Deleted(root_hash) => {
+                    state_root = root_hash;
+                }
+                DeleteResult::DoesNotExist => (),
+                _x @ DeleteResult::RootNotFound => panic!(stringify!(_x)),
+            }
+            continue;
+        }
+
+        let read_result = read::<_, _, _, _, E>(correlation_id, txn, store, &state_root, &key)?;
 
         let value = match (read_result, transform) {
             (ReadResult::NotFound, Transform::Write(new_value)) => new_value,
@@ -164,7 +216,7 @@ where
         };
 
         let write_result =
-            write::<_, _, _, _, E>(correlation_id, &mut txn, store, &state_root, &key, &value)?;
+            write::<_, _, _, _, E>(correlation_id, txn, store, &state_root, &key, &value)?;
 
         match write_result {
             WriteResult::Written(root_hash) => {
@@ -175,7 +227,5 @@ where
         }
     }
 
-    txn.commit()?;
-
     Ok(CommitResult::Success { state_root })
 }
@@ -171,10 +171,18 @@ impl ToBytes for ProtocolData {
     }
 }
 
-impl FromBytes for ProtocolData {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (wasm_config, rem) = WasmConfig::from_bytes(bytes)?;
-        let (system_config, rem) = FromBytes::from_bytes(rem)?;
+impl ProtocolData {
+    fn decode(bytes: &[u8], legacy: bool) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (wasm_config, rem) = if legacy {
+            WasmConfig::from_legacy_bytes(bytes)?
+        } else {
+            WasmConfig::from_bytes(bytes)?
+        };
+        let (system_config, rem) = if legacy {
+            SystemConfig::from_legacy_bytes(rem)?
+        } else {
+            SystemConfig::from_bytes(rem)?
+        };
         let (mint, rem) = HashAddr::from_bytes(rem)?;
         let (proof_of_stake, rem) = HashAddr::from_bytes(rem)?;
         let (standard_payment, rem) = HashAddr::from_bytes(rem)?;
@@ -194,6 +202,17 @@ impl FromBytes for ProtocolData {
     }
 }
 
+impl FromBytes for ProtocolData {
+    /// Decodes either the current encoding or the legacy one written by nodes predating the
+    /// recently added host functions and auction entry points, whose costs are then defaulted.
+    ///
+    /// Every field has a fixed size, and the legacy encoding is strictly shorter, so trying to
+    /// decode it as the current encoding fails rather than yielding a wrong value.
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Self::decode(bytes, false).or_else(|_| Self::decode(bytes, true))
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod gens {
     use proptest::prop_compose;
@@ -230,11 +249,90 @@ pub(crate) mod gens {
 mod tests {
     use proptest::proptest;
 
-    use crate::shared::{system_config::SystemConfig, wasm_config::WasmConfig};
-    use casper_types::{bytesrepr, ContractHash};
+    use crate::shared::{
+        host_function_costs::HostFunctionCosts,
+        system_config::{auction_costs::DEFAULT_REDELEGATE_COST, SystemConfig},
+        wasm_config::WasmConfig,
+    };
+    use casper_types::{
+        bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+        ContractHash,
+    };
 
     use super::{gens, ProtocolData};
 
+    /// Encodes `protocol_data` the way nodes did before the message, cryptographic and dictionary
+    /// host functions and the `redelegate` auction entry point were added, i.e. without their
+    /// costs, which were appended to the end of `HostFunctionCosts` and `AuctionCosts`.
+    fn legacy_bytes(protocol_data: &ProtocolData) -> Vec<u8> {
+        let host_function_costs = protocol_data.wasm_config().take_host_function_costs();
+        let added_host_functions_length = host_function_costs.emit_message.serialized_length()
+            + host_function_costs.verify_signature.serialized_length()
+            + host_function_costs.sha256.serialized_length()
+            + host_function_costs.keccak256.serialized_length()
+            + host_function_costs.new_dictionary.serialized_length()
+            + host_function_costs.dictionary_get.serialized_length()
+            + host_function_costs.dictionary_put.serialized_length();
+        let wasm_config_bytes = protocol_data.wasm_config().to_bytes().unwrap();
+        let mut bytes =
+            wasm_config_bytes[..wasm_config_bytes.len() - added_host_functions_length].to_vec();
+
+        let system_config = protocol_data.system_config();
+        bytes.extend(system_config.wasmless_transfer_cost().to_bytes().unwrap());
+        let auction_costs_bytes = system_config.auction_costs().to_bytes().unwrap();
+        bytes.extend(&auction_costs_bytes[..auction_costs_bytes.len() - U32_SERIALIZED_LENGTH]);
+        bytes.extend(system_config.mint_costs().to_bytes().unwrap());
+        bytes.extend(system_config.proof_of_stake_costs().to_bytes().unwrap());
+        bytes.extend(system_config.standard_payment_costs().to_bytes().unwrap());
+
+        bytes.extend(protocol_data.mint().to_bytes().unwrap());
+        bytes.extend(protocol_data.proof_of_stake().to_bytes().unwrap());
+        bytes.extend(protocol_data.standard_payment().to_bytes().unwrap());
+        bytes.extend(protocol_data.auction().to_bytes().unwrap());
+        bytes
+    }
+
+    /// Returns `protocol_data` with the costs missing from the legacy encoding set to defaults.
+    fn with_default_added_costs(protocol_data: &ProtocolData) -> ProtocolData {
+        let wasm_config = protocol_data.wasm_config();
+        let defaults = HostFunctionCosts::default();
+        let mut host_function_costs = wasm_config.take_host_function_costs();
+        host_function_costs.emit_message = defaults.emit_message;
+        host_function_costs.verify_signature = defaults.verify_signature;
+        host_function_costs.sha256 = defaults.sha256;
+        host_function_costs.keccak256 = defaults.keccak256;
+        host_function_costs.new_dictionary = defaults.new_dictionary;
+        host_function_costs.dictionary_get = defaults.dictionary_get;
+        host_function_costs.dictionary_put = defaults.dictionary_put;
+        let wasm_config = WasmConfig::new(
+            wasm_config.max_memory,
+            wasm_config.max_stack_height,
+            wasm_config.opcode_costs(),
+            wasm_config.storage_costs(),
+            host_function_costs,
+        );
+
+        let system_config = protocol_data.system_config();
+        let mut auction_costs = *system_config.auction_costs();
+        auction_costs.redelegate = DEFAULT_REDELEGATE_COST;
+        let system_config = SystemConfig::new(
+            system_config.wasmless_transfer_cost(),
+            auction_costs,
+            *system_config.mint_costs(),
+            *system_config.proof_of_stake_costs(),
+            *system_config.standard_payment_costs(),
+        );
+
+        ProtocolData::new(
+            wasm_config,
+            system_config,
+            protocol_data.mint(),
+            protocol_data.proof_of_stake(),
+            protocol_data.standard_payment(),
+            protocol_data.auction(),
+        )
+    }
+
     #[test]
     fn should_return_all_system_contracts() {
         let mint_reference = [1u8; 32].into();
@@ -308,5 +406,13 @@ mod tests {
         ) {
             bytesrepr::test_serialization_roundtrip(&protocol_data);
         }
+
+        #[test]
+        fn should_deserialize_legacy_encoding(protocol_data in gens::protocol_data_arb()) {
+            let bytes = legacy_bytes(&protocol_data);
+            let (decoded, rem) = ProtocolData::from_bytes(&bytes).unwrap();
+            assert!(rem.is_empty());
+            assert_eq!(decoded, with_default_added_costs(&protocol_data));
+        }
     }
 }
@@ -725,6 +725,106 @@ where
     }
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub enum DeleteResult {
+    Deleted(Blake2bHash),
+    DoesNotExist,
+    RootNotFound,
+}
+
+/// Removes the leaf with the given key, restoring the trie to the shape it would have had if the
+/// key had never been written.
+pub fn delete<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &mut T,
+    store: &S,
+    root: &Blake2bHash,
+    key: &K,
+) -> Result<DeleteResult, E>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle> + Writable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<bytesrepr::Error>,
+{
+    let current_root = match store.get(txn, root)? {
+        None => return Ok(DeleteResult::RootNotFound),
+        Some(current_root) => current_root,
+    };
+    let path: Vec<u8> = key.to_bytes()?;
+    let TrieScan { tip, mut parents } =
+        scan::<K, V, T, S, E>(correlation_id, txn, store, &path, &current_root)?;
+    match tip {
+        Trie::Leaf {
+            key: ref leaf_key, ..
+        } if leaf_key == key => (),
+        _ => return Ok(DeleteResult::DoesNotExist),
+    }
+
+    // The parent of a leaf is always a node.
+    let (leaf_index, mut pointer_block) = match parents.pop() {
+        Some((leaf_index, Trie::Node { pointer_block })) => (leaf_index, pointer_block),
+        Some(_) => panic!("the parent of a leaf should be a node"),
+        None => panic!("a leaf should never be the root"),
+    };
+    pointer_block[leaf_index.into()] = None;
+    let remaining: Vec<(u8, Pointer)> = pointer_block.to_indexed_pointers().collect();
+
+    let new_elements: Vec<(Blake2bHash, Trie<K, V>)> = if parents.is_empty() || remaining.len() > 1
+    {
+        // The root may have any number of children, and any other node with at least two children
+        // left keeps its shape.
+        rehash(Trie::Node { pointer_block }, parents)?
+    } else {
+        // Any other node left with a single child is collapsed into its parent, merging with the
+        // extension above it, if there is one.
+        let (child_index, child_pointer) = remaining[0];
+        let mut affix: Vec<u8> = Vec::new();
+        if matches!(parents.last(), Some((_, Trie::Extension { .. }))) {
+            if let Some((
+                _,
+                Trie::Extension {
+                    affix: parent_affix,
+                    ..
+                },
+            )) = parents.pop()
+            {
+                affix.extend(parent_affix.iter());
+            }
+        }
+        let new_tip = match (child_pointer, store.get(txn, child_pointer.hash())?) {
+            // A lone leaf hangs directly from the closest node above it.
+            (Pointer::LeafPointer(_), Some(leaf @ Trie::Leaf { .. })) => leaf,
+            (Pointer::NodePointer(child_hash), Some(Trie::Node { .. })) => {
+                affix.push(child_index);
+                Trie::extension(affix, Pointer::NodePointer(child_hash))
+            }
+            (
+                Pointer::NodePointer(_),
+                Some(Trie::Extension {
+                    affix: child_affix,
+                    pointer,
+                }),
+            ) => {
+                affix.push(child_index);
+                affix.extend(child_affix.iter());
+                Trie::extension(affix, pointer)
+            }
+            (pointer, _) => panic!("unexpected child trie at {:?}", pointer),
+        };
+        rehash(new_tip, parents)?
+    };
+
+    let mut root_hash = root.to_owned();
+    for (hash, element) in new_elements.iter() {
+        store.put(txn, hash, element)?;
+        root_hash = *hash;
+    }
+    Ok(DeleteResult::Deleted(root_hash))
+}
+
 pub fn put_trie<K, V, T, S, E>(
     _correlation_id: CorrelationId,
     txn: &mut T,
@@ -751,7 +851,6 @@ enum KeysIteratorState<K, V, S: TrieStore<K, V>> {
     /// Iterate normally
     Ok,
     /// Return the error and stop iterating
-    #[allow(dead_code)] // Return variant alone is used in testing.
     ReturnError(S::Error),
     /// Already failed, only return None
     Failed,
@@ -914,7 +1013,6 @@ where
 /// Returns the iterator over the keys in the subtrie matching `prefix`.
 ///
 /// The root should be the apex of the trie.
-#[cfg(test)]
 pub fn keys_with_prefix<'a, 'b, K, V, T, S>(
     _correlation_id: CorrelationId,
     txn: &'b T,
@@ -950,3 +1048,164 @@ where
         state: init_state,
     }
 }
+
+/// Returns the iterator over the keys whose serialized form is greater than or equal to `start`,
+/// in ascending order of their serialized form.
+///
+/// Only the path from the root towards `start` is loaded up front; subtries entirely preceding
+/// `start` are skipped without being read from the store.
+fn keys_from<'a, 'b, K, V, T, S>(
+    txn: &'b T,
+    store: &'a S,
+    root: &Blake2bHash,
+    start: &[u8],
+) -> KeysIterator<'a, 'b, K, V, T, S>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error> + From<bytesrepr::Error>,
+{
+    let mut visited = Vec::new();
+    let mut state = KeysIteratorState::Ok;
+    let mut maybe_current = store.get(txn, root);
+    let mut path: Vec<u8> = Vec::new();
+
+    loop {
+        let current = match maybe_current {
+            Ok(Some(trie)) => trie,
+            Ok(None) => break,
+            Err(error) => {
+                state = KeysIteratorState::ReturnError(error);
+                break;
+            }
+        };
+        let depth = path.len();
+        match current {
+            Trie::Leaf { ref key, .. } => {
+                match key.to_bytes() {
+                    Ok(key_bytes) if key_bytes.as_slice() >= start => {
+                        visited.push(VisitedTrieNode {
+                            trie: current,
+                            maybe_index: None,
+                            path,
+                        })
+                    }
+                    Ok(_) => (),
+                    Err(error) => state = KeysIteratorState::ReturnError(error.into()),
+                }
+                break;
+            }
+            Trie::Node { ref pointer_block } => {
+                let index = match start.get(depth) {
+                    Some(index) => *index as usize,
+                    None => {
+                        // `start` ends here, so every key below this node follows it.
+                        visited.push(VisitedTrieNode {
+                            trie: current,
+                            maybe_index: None,
+                            path,
+                        });
+                        break;
+                    }
+                };
+                let maybe_pointer = pointer_block[index];
+                // The subtries to the right of the seek path are visited after the one on it.
+                visited.push(VisitedTrieNode {
+                    trie: current,
+                    maybe_index: Some(index + 1),
+                    path: path.clone(),
+                });
+                match maybe_pointer {
+                    Some(pointer) => {
+                        maybe_current = store.get(txn, pointer.hash());
+                        path.push(index as u8);
+                    }
+                    None => break,
+                }
+            }
+            Trie::Extension { ref affix, pointer } => {
+                let remaining = &start[cmp::min(depth, start.len())..];
+                let compared_len = cmp::min(affix.len(), remaining.len());
+                match affix[..compared_len].cmp(&remaining[..compared_len]) {
+                    cmp::Ordering::Less => break,
+                    cmp::Ordering::Equal if compared_len == affix.len() => {
+                        path.extend(affix.iter());
+                        maybe_current = store.get(txn, pointer.hash());
+                    }
+                    // Either the affix sorts after `start`, or `start` ends within the affix: in
+                    // both cases every key below this extension follows `start`.
+                    cmp::Ordering::Equal | cmp::Ordering::Greater => {
+                        visited.push(VisitedTrieNode {
+                            trie: current,
+                            maybe_index: None,
+                            path,
+                        });
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    KeysIterator {
+        initial_descend: VecDeque::new(),
+        visited,
+        store,
+        txn,
+        state,
+    }
+}
+
+/// Returns at most `limit` keys in the subtrie matching `prefix` whose serialized form sorts
+/// strictly after that of `cursor`, in ascending order of their serialized form.
+///
+/// The trie is walked lazily from the cursor and the walk stops as soon as `limit` keys have been
+/// collected or the keys no longer match `prefix`.
+///
+/// The root should be the apex of the trie.
+pub fn keys_with_prefix_after<K, V, T, S>(
+    _correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    prefix: &[u8],
+    cursor: Option<&K>,
+    limit: usize,
+) -> Result<Vec<K>, S::Error>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error> + From<bytesrepr::Error>,
+{
+    let mut keys = Vec::new();
+    if limit == 0 {
+        return Ok(keys);
+    }
+
+    let cursor_bytes = cursor.map(ToBytes::to_bytes).transpose()?;
+    let start = match cursor_bytes {
+        Some(ref cursor_bytes) if cursor_bytes.as_slice() > prefix => cursor_bytes.as_slice(),
+        _ => prefix,
+    };
+
+    for maybe_key in keys_from::<K, V, T, S>(txn, store, root, start) {
+        let key = maybe_key?;
+        let key_bytes = key.to_bytes()?;
+        if !key_bytes.starts_with(prefix) {
+            // Keys are visited in ascending order, so no further key can match the prefix.
+            break;
+        }
+        if Some(&key_bytes) == cursor_bytes.as_ref() {
+            continue;
+        }
+        keys.push(key);
+        if keys.len() == limit {
+            break;
+        }
+    }
+    Ok(keys)
+}
@@ -0,0 +1,175 @@
+use super::*;
+use crate::storage::trie_store::operations::DeleteResult;
+
+fn write_leaves_to_root<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root_hash: &Blake2bHash,
+    leaves: &[TestTrie],
+) -> Result<Blake2bHash, E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<bytesrepr::Error>,
+{
+    let results =
+        write_leaves::<_, _, _, _, E>(correlation_id, environment, store, root_hash, leaves)?;
+    let new_root_hash = results
+        .iter()
+        .rev()
+        .find_map(|result| match result {
+            WriteResult::Written(hash) => Some(*hash),
+            _ => None,
+        })
+        .unwrap_or(*root_hash);
+    Ok(new_root_hash)
+}
+
+fn delete_leaf<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root_hash: &Blake2bHash,
+    key: &TestKey,
+) -> Result<DeleteResult, E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<bytesrepr::Error>,
+{
+    let mut txn = environment.create_read_write_txn()?;
+    let result = operations::delete::<TestKey, TestValue, _, _, E>(
+        correlation_id,
+        &mut txn,
+        store,
+        root_hash,
+        key,
+    )?;
+    txn.commit()?;
+    Ok(result)
+}
+
+fn deletes_from_n_leaf_trie_had_expected_results<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    empty_root_hash: &Blake2bHash,
+    leaves: &[TestTrie],
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<bytesrepr::Error>,
+{
+    let root_hash = write_leaves_to_root::<_, _, E>(
+        correlation_id,
+        environment,
+        store,
+        empty_root_hash,
+        leaves,
+    )?;
+
+    // Deleting any one leaf results in the same trie as never having written it.
+    for (index, leaf) in leaves.iter().enumerate() {
+        let key = leaf.key().expect("should be a leaf");
+        let expected_root_hash = {
+            let mut remaining_leaves = leaves.to_vec();
+            remaining_leaves.remove(index);
+            write_leaves_to_root::<_, _, E>(
+                correlation_id,
+                environment,
+                store,
+                empty_root_hash,
+                &remaining_leaves,
+            )?
+        };
+        let new_root_hash =
+            match delete_leaf::<_, _, E>(correlation_id, environment, store, &root_hash, key)? {
+                DeleteResult::Deleted(hash) => hash,
+                other => panic!("unexpected delete result: {:?}", other),
+            };
+        assert_eq!(new_root_hash, expected_root_hash);
+        assert_eq!(
+            delete_leaf::<_, _, E>(correlation_id, environment, store, &new_root_hash, key)?,
+            DeleteResult::DoesNotExist
+        );
+    }
+
+    // Deleting every leaf results in the empty trie.
+    let mut current_root_hash = root_hash;
+    for leaf in leaves.iter() {
+        let key = leaf.key().expect("should be a leaf");
+        current_root_hash = match delete_leaf::<_, _, E>(
+            correlation_id,
+            environment,
+            store,
+            &current_root_hash,
+            key,
+        )? {
+            DeleteResult::Deleted(hash) => hash,
+            other => panic!("unexpected delete result: {:?}", other),
+        };
+    }
+    assert_eq!(current_root_hash, *empty_root_hash);
+    Ok(())
+}
+
+#[test]
+fn lmdb_deletes_from_n_leaf_trie_had_expected_results() {
+    for num_leaves in 1..=TEST_LEAVES_LENGTH {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+        let context = LmdbTestContext::new(&tries).unwrap();
+
+        deletes_from_n_leaf_trie_had_expected_results::<_, _, error::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            &TEST_LEAVES[..num_leaves],
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_deletes_from_n_leaf_trie_had_expected_results() {
+    for num_leaves in 1..=TEST_LEAVES_LENGTH {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+        let context = InMemoryTestContext::new(&tries).unwrap();
+
+        deletes_from_n_leaf_trie_had_expected_results::<_, _, in_memory::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            &TEST_LEAVES[..num_leaves],
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_delete_from_missing_root_had_expected_result() {
+    let correlation_id = CorrelationId::new();
+    let (_, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+    let context = InMemoryTestContext::new(&tries).unwrap();
+    let missing_root_hash = Blake2bHash::new(&[1u8; 32]);
+    let test_leaves = TEST_LEAVES;
+    let key = test_leaves[0].key().unwrap();
+
+    let result = delete_leaf::<_, _, in_memory::Error>(
+        correlation_id,
+        &context.environment,
+        &context.store,
+        &missing_root_hash,
+        key,
+    )
+    .unwrap();
+    assert_eq!(result, DeleteResult::RootNotFound);
+}
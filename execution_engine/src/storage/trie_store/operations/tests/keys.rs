@@ -307,3 +307,86 @@ mod keys_with_prefix_iterator {
         test_prefix(&[0, 0, 0, 0, 0, 0, 1]); // 1 leaf
     }
 }
+
+mod keys_with_prefix_after {
+    use crate::shared::newtypes::CorrelationId;
+
+    use crate::storage::{
+        transaction_source::{Transaction, TransactionSource},
+        trie::Trie,
+        trie_store::operations::{
+            self,
+            tests::{create_6_leaf_trie, InMemoryTestContext, TestKey, TestValue, TEST_LEAVES},
+        },
+    };
+
+    fn expected_keys(prefix: &[u8], cursor: Option<&TestKey>, limit: usize) -> Vec<TestKey> {
+        let mut tmp = TEST_LEAVES
+            .iter()
+            .filter_map(Trie::key)
+            .filter(|key| key.0.starts_with(prefix))
+            .filter(|key| cursor.map_or(true, |cursor| key.0 > cursor.0))
+            .cloned()
+            .collect::<Vec<TestKey>>();
+        tmp.sort();
+        tmp.truncate(limit);
+        tmp
+    }
+
+    fn test_prefix(prefix: &[u8]) {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = create_6_leaf_trie().expect("should create a trie");
+        let context = InMemoryTestContext::new(&tries).expect("should create a new context");
+        let txn = context
+            .environment
+            .create_read_txn()
+            .expect("should create a read txn");
+
+        let mut cursors: Vec<Option<TestKey>> = TEST_LEAVES
+            .iter()
+            .filter_map(Trie::key)
+            .cloned()
+            .map(Some)
+            .collect();
+        cursors.push(None);
+        cursors.push(Some(TestKey([0; 7])));
+        cursors.push(Some(TestKey([255; 7])));
+
+        for cursor in &cursors {
+            for limit in 0..=TEST_LEAVES.len() {
+                let actual = operations::keys_with_prefix_after::<TestKey, TestValue, _, _>(
+                    correlation_id,
+                    &txn,
+                    &context.store,
+                    &root_hash,
+                    prefix,
+                    cursor.as_ref(),
+                    limit,
+                )
+                .expect("should read keys");
+                assert_eq!(
+                    actual,
+                    expected_keys(prefix, cursor.as_ref(), limit),
+                    "prefix {:?}, cursor {:?}, limit {}",
+                    prefix,
+                    cursor,
+                    limit
+                );
+            }
+        }
+        txn.commit().expect("should commit");
+    }
+
+    #[test]
+    fn test_prefixes() {
+        test_prefix(&[]);
+        test_prefix(&[0]);
+        test_prefix(&[0, 1]);
+        test_prefix(&[0, 0]);
+        test_prefix(&[0, 0, 2]);
+        test_prefix(&[0, 0, 0, 0]);
+        test_prefix(&[0, 0, 0, 0, 0, 0]);
+        test_prefix(&[0, 0, 0, 0, 0, 0, 1]);
+        test_prefix(&[1]);
+    }
+}
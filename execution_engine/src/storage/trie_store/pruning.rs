@@ -0,0 +1,678 @@
+//! Pruning of trie nodes which aren't reachable from a retained set of state roots.
+//!
+//! Pruning is a mark-and-sweep garbage collection of an [`LmdbTrieStore`].  Marking walks the tries
+//! of the retained roots, recording every reachable node in the pruner's own database.  Sweeping
+//! then deletes every unmarked node from the trie store.  Both phases proceed in batches, each
+//! committed in a single transaction along with the progress of the run, so a run interrupted by a
+//! crash resumes where its last committed batch left off.
+//!
+//! In offline mode, [`LmdbTriePruner::prune`] runs to completion while nothing else uses the trie
+//! store.  In online mode, [`LmdbTriePruner::start`] begins a run and [`LmdbTriePruner::step`]
+//! advances it by a batch at a time, so that pruning can be interleaved with commits.  The pruner
+//! must then be attached to the global state via `LmdbGlobalState::with_pruner`, so that every trie
+//! written during the run is retained too.  Views of roots which weren't retained must not be read
+//! once the run has finished.
+
+use std::sync::Arc;
+
+use lmdb::{Cursor, Database, DatabaseFlags, RwTransaction, Transaction, WriteFlags};
+use tracing::{debug, info};
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    Key,
+};
+
+use crate::{
+    shared::{newtypes::Blake2bHash, stored_value::StoredValue},
+    storage::{
+        error,
+        store::Store,
+        transaction_source::{lmdb::LmdbEnvironment, TransactionSource},
+        trie::{Pointer, Trie},
+        trie_store::{self, lmdb::LmdbTrieStore},
+    },
+};
+
+/// The default maximum number of trie nodes processed by a single pruning step.
+pub const DEFAULT_PRUNING_BATCH_SIZE: usize = 10_000;
+
+// The keys of the state of a run in the pruner's database.  Marks are keyed by the 32-byte hashes
+// of trie nodes, so these can't collide with them.
+const PHASE_KEY: &[u8] = b"phase";
+const PENDING_KEY: &[u8] = b"pending";
+const CURSOR_KEY: &[u8] = b"cursor";
+const COUNTERS_KEY: &[u8] = b"counters";
+
+// The value of a mark.
+const MARK: [u8; 0] = [];
+
+const MARKING_TAG: u8 = 1;
+const SWEEPING_TAG: u8 = 2;
+
+/// The phase of a pruning run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PruningPhase {
+    /// No run is in progress.
+    Idle,
+    /// Trie nodes reachable from the retained roots are being marked.
+    Marking,
+    /// Unmarked trie nodes are being deleted.
+    Sweeping,
+}
+
+impl Default for PruningPhase {
+    fn default() -> Self {
+        PruningPhase::Idle
+    }
+}
+
+/// The progress of a pruning run.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct PruningProgress {
+    /// The phase of the run.
+    pub phase: PruningPhase,
+    /// The number of trie nodes marked as reachable from a retained root.
+    pub nodes_marked: u64,
+    /// The number of trie nodes waiting to be marked.
+    pub nodes_pending: u64,
+    /// The number of trie nodes checked by the sweep.
+    pub nodes_swept: u64,
+    /// The number of trie nodes deleted.
+    pub nodes_deleted: u64,
+}
+
+/// The persisted state of a pruning run.
+#[derive(Default)]
+struct RunState {
+    phase: PruningPhase,
+    /// The hashes of trie nodes still to be marked.
+    pending: Vec<Blake2bHash>,
+    /// The key of the trie node last checked by the sweep.
+    cursor: Option<Vec<u8>>,
+    nodes_marked: u64,
+    nodes_swept: u64,
+    nodes_deleted: u64,
+}
+
+impl RunState {
+    fn read<T: Transaction>(txn: &T, db: Database) -> Result<Self, error::Error> {
+        let phase = match get(txn, db, PHASE_KEY)? {
+            None => return Ok(RunState::default()),
+            Some(&[MARKING_TAG]) => PruningPhase::Marking,
+            Some(&[SWEEPING_TAG]) => PruningPhase::Sweeping,
+            Some(_) => return Err(bytesrepr::Error::Formatting.into()),
+        };
+        let pending = match get(txn, db, PENDING_KEY)? {
+            Some(bytes) => bytesrepr::deserialize(bytes.to_vec())?,
+            None => Vec::new(),
+        };
+        let cursor = get(txn, db, CURSOR_KEY)?.map(<[u8]>::to_vec);
+        let (nodes_marked, nodes_swept, nodes_deleted) = match get(txn, db, COUNTERS_KEY)? {
+            Some(bytes) => bytesrepr::deserialize(bytes.to_vec())?,
+            None => (0, 0, 0),
+        };
+        Ok(RunState {
+            phase,
+            pending,
+            cursor,
+            nodes_marked,
+            nodes_swept,
+            nodes_deleted,
+        })
+    }
+
+    /// Writes the state to the pruner's database.  Once the run is finished, this removes all its
+    /// marks.
+    fn write(&self, txn: &mut RwTransaction, db: Database) -> Result<(), error::Error> {
+        let tag = match self.phase {
+            PruningPhase::Idle => return txn.clear_db(db).map_err(Into::into),
+            PruningPhase::Marking => MARKING_TAG,
+            PruningPhase::Sweeping => SWEEPING_TAG,
+        };
+        txn.put(db, &PHASE_KEY, &[tag], WriteFlags::empty())?;
+        txn.put(
+            db,
+            &PENDING_KEY,
+            &self.pending.to_bytes()?,
+            WriteFlags::empty(),
+        )?;
+        if let Some(cursor) = &self.cursor {
+            txn.put(db, &CURSOR_KEY, cursor, WriteFlags::empty())?;
+        }
+        let counters = (self.nodes_marked, self.nodes_swept, self.nodes_deleted);
+        txn.put(
+            db,
+            &COUNTERS_KEY,
+            &counters.to_bytes()?,
+            WriteFlags::empty(),
+        )?;
+        Ok(())
+    }
+
+    fn progress(&self) -> PruningProgress {
+        PruningProgress {
+            phase: self.phase,
+            nodes_marked: self.nodes_marked,
+            nodes_pending: self.pending.len() as u64,
+            nodes_swept: self.nodes_swept,
+            nodes_deleted: self.nodes_deleted,
+        }
+    }
+}
+
+fn get<'txn, T: Transaction>(
+    txn: &'txn T,
+    db: Database,
+    key: &[u8],
+) -> Result<Option<&'txn [u8]>, error::Error> {
+    match txn.get(db, &key) {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(lmdb::Error::NotFound) => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Prunes an LMDB-backed trie store, deleting all trie nodes not reachable from a set of retained
+/// state roots.
+///
+/// The progress of a run is kept in a database of its own in the same environment as the trie
+/// store.
+#[derive(Debug)]
+pub struct LmdbTriePruner {
+    environment: Arc<LmdbEnvironment>,
+    trie_store: Arc<LmdbTrieStore>,
+    db: Database,
+}
+
+impl LmdbTriePruner {
+    pub fn new(
+        environment: Arc<LmdbEnvironment>,
+        trie_store: Arc<LmdbTrieStore>,
+        maybe_name: Option<&str>,
+        flags: DatabaseFlags,
+    ) -> Result<Self, error::Error> {
+        let name = Self::name(maybe_name);
+        let db = environment.env().create_db(Some(&name), flags)?;
+        Ok(LmdbTriePruner {
+            environment,
+            trie_store,
+            db,
+        })
+    }
+
+    pub fn open(
+        environment: Arc<LmdbEnvironment>,
+        trie_store: Arc<LmdbTrieStore>,
+        maybe_name: Option<&str>,
+    ) -> Result<Self, error::Error> {
+        let name = Self::name(maybe_name);
+        let db = environment.env().open_db(Some(&name))?;
+        Ok(LmdbTriePruner {
+            environment,
+            trie_store,
+            db,
+        })
+    }
+
+    fn name(maybe_name: Option<&str>) -> String {
+        maybe_name
+            .map(|name| format!("{}_PRUNING-{}", trie_store::NAME, name))
+            .unwrap_or_else(|| format!("{}_PRUNING", trie_store::NAME))
+    }
+
+    /// Returns the progress of the current run.
+    pub fn progress(&self) -> Result<PruningProgress, error::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let progress = RunState::read(&txn, self.db)?.progress();
+        txn.commit()?;
+        Ok(progress)
+    }
+
+    /// Starts a run retaining the tries of `retained_roots`.
+    ///
+    /// If a run is already in progress, `retained_roots` are retained by it in addition to the
+    /// roots it was started with.  If no run is in progress and `retained_roots` is empty, this
+    /// does nothing, rather than starting a run which would delete every trie node.
+    pub fn start(&self, retained_roots: &[Blake2bHash]) -> Result<(), error::Error> {
+        let mut txn = self.environment.create_read_write_txn()?;
+        let mut state = RunState::read(&txn, self.db)?;
+        if state.phase == PruningPhase::Idle {
+            if retained_roots.is_empty() {
+                return Ok(());
+            }
+            info!(
+                retained_roots = retained_roots.len(),
+                "starting to prune trie store"
+            );
+            state.phase = PruningPhase::Marking;
+        }
+        for root in retained_roots {
+            self.retain(&mut txn, &mut state, *root)?;
+        }
+        state.write(&mut txn, self.db)?;
+        txn.commit()?;
+        Ok(())
+    }
+
+    /// Advances the current run, processing at most `batch_size` trie nodes in a single
+    /// transaction.
+    ///
+    /// Returns the progress of the run after the step, with a phase of `PruningPhase::Idle` once
+    /// the run has finished.
+    pub fn step(&self, batch_size: usize) -> Result<PruningProgress, error::Error> {
+        let mut txn = self.environment.create_read_write_txn()?;
+        let mut state = RunState::read(&txn, self.db)?;
+        match state.phase {
+            PruningPhase::Idle => return Ok(state.progress()),
+            PruningPhase::Marking => {
+                self.mark(&mut txn, &mut state, batch_size)?;
+                if state.pending.is_empty() {
+                    info!(
+                        nodes_marked = state.nodes_marked,
+                        "finished marking trie nodes"
+                    );
+                    state.phase = PruningPhase::Sweeping;
+                }
+            }
+            PruningPhase::Sweeping => {
+                self.sweep(&mut txn, &mut state, batch_size)?;
+                if state.phase == PruningPhase::Idle {
+                    info!(
+                        nodes_swept = state.nodes_swept,
+                        nodes_deleted = state.nodes_deleted,
+                        "finished pruning trie store"
+                    );
+                }
+            }
+        }
+        state.write(&mut txn, self.db)?;
+        txn.commit()?;
+        let progress = state.progress();
+        debug!(?progress, "pruned trie store");
+        Ok(progress)
+    }
+
+    /// Prunes the trie store offline, retaining the tries of `retained_roots`, and returns the
+    /// final progress of the run.
+    ///
+    /// If a run was interrupted, it is resumed and `retained_roots` are retained in addition to
+    /// the roots it was started with.  Nothing else may write to the trie store meanwhile.
+    pub fn prune(
+        &self,
+        retained_roots: &[Blake2bHash],
+        batch_size: usize,
+    ) -> Result<PruningProgress, error::Error> {
+        self.start(retained_roots)?;
+        loop {
+            let progress = self.step(batch_size)?;
+            if progress.phase == PruningPhase::Idle {
+                return Ok(progress);
+            }
+        }
+    }
+
+    /// Retains the trie at `trie_key`, which has just been written in `txn`, if a run is in
+    /// progress.
+    pub(crate) fn retain_written(
+        &self,
+        txn: &mut RwTransaction,
+        trie_key: Blake2bHash,
+    ) -> Result<(), error::Error> {
+        let mut state = RunState::read(&*txn, self.db)?;
+        if state.phase == PruningPhase::Idle {
+            return Ok(());
+        }
+        self.retain(txn, &mut state, trie_key)?;
+        state.write(txn, self.db)
+    }
+
+    fn retain(
+        &self,
+        txn: &mut RwTransaction,
+        state: &mut RunState,
+        trie_key: Blake2bHash,
+    ) -> Result<(), error::Error> {
+        state.pending.push(trie_key);
+        // The sweep relies on every descendant of a marked node being marked, so while sweeping,
+        // the trie must be marked right away.
+        if state.phase == PruningPhase::Sweeping {
+            self.mark(txn, state, usize::MAX)?;
+        }
+        Ok(())
+    }
+
+    /// Marks pending trie nodes and their descendants, visiting at most `batch_size` nodes.
+    ///
+    /// A node is marked before its descendants, which are added to the pending nodes in the same
+    /// transaction, so a marked node's descendants are always either marked or pending.
+    fn mark(
+        &self,
+        txn: &mut RwTransaction,
+        state: &mut RunState,
+        batch_size: usize,
+    ) -> Result<(), error::Error> {
+        let mut visited = 0;
+        while visited < batch_size {
+            let trie_key = match state.pending.pop() {
+                Some(trie_key) => trie_key,
+                None => break,
+            };
+            visited += 1;
+            if self.is_marked(txn, &trie_key)? {
+                continue;
+            }
+            let maybe_trie: Option<Trie<Key, StoredValue>> =
+                self.trie_store.get(&*txn, &trie_key)?;
+            match maybe_trie {
+                // A node which is still being synchronized is retained once it's written.
+                None => continue,
+                Some(Trie::Leaf { .. }) => (),
+                Some(Trie::Node { pointer_block }) => {
+                    for (_, pointer) in pointer_block.to_indexed_pointers() {
+                        match pointer {
+                            // Leaves have no descendants, so avoid reading them.
+                            Pointer::LeafPointer(leaf_key) => {
+                                if !self.is_marked(txn, &leaf_key)? {
+                                    self.set_mark(txn, state, &leaf_key)?;
+                                }
+                            }
+                            Pointer::NodePointer(node_key) => state.pending.push(node_key),
+                        }
+                    }
+                }
+                Some(Trie::Extension { pointer, .. }) => state.pending.push(pointer.into_hash()),
+            }
+            self.set_mark(txn, state, &trie_key)?;
+        }
+        Ok(())
+    }
+
+    /// Deletes unmarked trie nodes, checking at most `batch_size` nodes.  Finishes the run once
+    /// all nodes have been checked.
+    fn sweep(
+        &self,
+        txn: &mut RwTransaction,
+        state: &mut RunState,
+        batch_size: usize,
+    ) -> Result<(), error::Error> {
+        let trie_db = self.trie_db();
+        let last_key = state.cursor.take();
+        let mut unmarked_keys = Vec::new();
+        let mut finished = true;
+        {
+            let mut cursor = txn.open_ro_cursor(trie_db)?;
+            let iter = match &last_key {
+                Some(last_key) => cursor.iter_from(last_key),
+                None => cursor.iter_start(),
+            };
+            let mut swept = 0;
+            for (key, _) in iter {
+                // `iter_from` starts at the last checked key itself.
+                if last_key.as_deref() == Some(key) {
+                    continue;
+                }
+                if swept == batch_size {
+                    finished = false;
+                    break;
+                }
+                swept += 1;
+                if get(&*txn, self.db, key)?.is_none() {
+                    unmarked_keys.push(key.to_vec());
+                }
+                state.cursor = Some(key.to_vec());
+            }
+            state.nodes_swept += swept as u64;
+        }
+        if state.cursor.is_none() {
+            state.cursor = last_key;
+        }
+        for key in &unmarked_keys {
+            txn.del(trie_db, key, None)?;
+        }
+        state.nodes_deleted += unmarked_keys.len() as u64;
+        if finished {
+            state.phase = PruningPhase::Idle;
+        }
+        Ok(())
+    }
+
+    fn is_marked(&self, txn: &RwTransaction, trie_key: &Blake2bHash) -> Result<bool, error::Error> {
+        Ok(get(txn, self.db, &trie_key.to_bytes()?)?.is_some())
+    }
+
+    fn set_mark(
+        &self,
+        txn: &mut RwTransaction,
+        state: &mut RunState,
+        trie_key: &Blake2bHash,
+    ) -> Result<(), error::Error> {
+        txn.put(self.db, &trie_key.to_bytes()?, &MARK, WriteFlags::empty())?;
+        state.nodes_marked += 1;
+        Ok(())
+    }
+
+    fn trie_db(&self) -> Database {
+        Store::<Blake2bHash, Trie<Key, StoredValue>>::handle(self.trie_store.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use lmdb::DatabaseFlags;
+    use tempfile::{tempdir, TempDir};
+
+    use casper_types::{account::AccountHash, CLValue, Key};
+
+    use super::{LmdbTriePruner, PruningPhase};
+    use crate::{
+        shared::{
+            additive_map::AdditiveMap,
+            newtypes::{Blake2bHash, CorrelationId},
+            stored_value::StoredValue,
+            transform::Transform,
+        },
+        storage::{
+            global_state::{lmdb::LmdbGlobalState, CommitResult, StateProvider, StateReader},
+            protocol_data_store::lmdb::LmdbProtocolDataStore,
+            transaction_source::lmdb::LmdbEnvironment,
+            trie_store::lmdb::LmdbTrieStore,
+            DEFAULT_TEST_MAX_DB_SIZE, DEFAULT_TEST_MAX_READERS,
+        },
+    };
+
+    const BATCH_SIZE: usize = 2;
+
+    struct TestState {
+        _temp_dir: TempDir,
+        environment: Arc<LmdbEnvironment>,
+        trie_store: Arc<LmdbTrieStore>,
+        pruner: Arc<LmdbTriePruner>,
+        global_state: LmdbGlobalState,
+    }
+
+    fn create_test_state() -> TestState {
+        let temp_dir = tempdir().unwrap();
+        let environment = Arc::new(
+            LmdbEnvironment::new(
+                &temp_dir.path().to_path_buf(),
+                DEFAULT_TEST_MAX_DB_SIZE,
+                DEFAULT_TEST_MAX_READERS,
+            )
+            .unwrap(),
+        );
+        let trie_store =
+            Arc::new(LmdbTrieStore::new(&environment, None, DatabaseFlags::empty()).unwrap());
+        let protocol_data_store = Arc::new(
+            LmdbProtocolDataStore::new(&environment, None, DatabaseFlags::empty()).unwrap(),
+        );
+        let pruner = Arc::new(
+            LmdbTriePruner::new(
+                Arc::clone(&environment),
+                Arc::clone(&trie_store),
+                None,
+                DatabaseFlags::empty(),
+            )
+            .unwrap(),
+        );
+        let global_state = LmdbGlobalState::empty(
+            Arc::clone(&environment),
+            Arc::clone(&trie_store),
+            protocol_data_store,
+        )
+        .unwrap()
+        .with_pruner(Arc::clone(&pruner));
+        TestState {
+            _temp_dir: temp_dir,
+            environment,
+            trie_store,
+            pruner,
+            global_state,
+        }
+    }
+
+    fn account_key(index: u8) -> Key {
+        Key::Account(AccountHash::new([index; 32]))
+    }
+
+    fn value(value: i32) -> StoredValue {
+        StoredValue::CLValue(CLValue::from_t(value).unwrap())
+    }
+
+    /// Commits writes of `new_value` under the keys of accounts `indices` on top of
+    /// `prestate_hash`.
+    fn commit(
+        global_state: &LmdbGlobalState,
+        prestate_hash: Blake2bHash,
+        indices: &[u8],
+        new_value: i32,
+    ) -> Blake2bHash {
+        let mut effects = AdditiveMap::new();
+        for index in indices {
+            effects.insert(account_key(*index), Transform::Write(value(new_value)));
+        }
+        match global_state
+            .commit(CorrelationId::new(), prestate_hash, effects)
+            .unwrap()
+        {
+            CommitResult::Success { state_root } => state_root,
+            result => panic!("commit failed: {}", result),
+        }
+    }
+
+    fn read(global_state: &LmdbGlobalState, root: Blake2bHash, index: u8) -> Option<StoredValue> {
+        global_state
+            .checkout(root)
+            .unwrap()
+            .expect("should have root")
+            .read(CorrelationId::new(), &account_key(index))
+            .unwrap()
+    }
+
+    fn assert_intact(global_state: &LmdbGlobalState, root: Blake2bHash) {
+        let missing_trie_keys = global_state
+            .missing_trie_keys(CorrelationId::new(), root)
+            .unwrap();
+        assert!(missing_trie_keys.is_empty(), "trie should be intact");
+    }
+
+    #[test]
+    fn should_delete_tries_of_unretained_roots() {
+        let test_state = create_test_state();
+        let global_state = &test_state.global_state;
+        let root_1 = commit(global_state, global_state.empty_root(), &[1, 2, 3], 1);
+        let root_2 = commit(global_state, root_1, &[2], 2);
+        let root_3 = commit(global_state, root_2, &[3, 4], 3);
+
+        let progress = test_state
+            .pruner
+            .prune(&[root_1, root_3], BATCH_SIZE)
+            .unwrap();
+
+        assert_eq!(progress.phase, PruningPhase::Idle);
+        assert!(progress.nodes_deleted > 0);
+        assert!(global_state.checkout(root_2).unwrap().is_none());
+        assert!(global_state
+            .checkout(global_state.empty_root())
+            .unwrap()
+            .is_none());
+        assert_intact(global_state, root_1);
+        assert_intact(global_state, root_3);
+        assert_eq!(read(global_state, root_1, 2), Some(value(1)));
+        assert_eq!(read(global_state, root_3, 2), Some(value(2)));
+        assert_eq!(read(global_state, root_3, 4), Some(value(3)));
+        assert_eq!(test_state.pruner.progress().unwrap(), Default::default());
+    }
+
+    #[test]
+    fn should_not_start_without_retained_roots() {
+        let test_state = create_test_state();
+        let global_state = &test_state.global_state;
+        let root = commit(global_state, global_state.empty_root(), &[1], 1);
+
+        let progress = test_state.pruner.prune(&[], BATCH_SIZE).unwrap();
+
+        assert_eq!(progress, Default::default());
+        assert_intact(global_state, root);
+    }
+
+    #[test]
+    fn should_resume_interrupted_run() {
+        let test_state = create_test_state();
+        let global_state = &test_state.global_state;
+        let root_1 = commit(global_state, global_state.empty_root(), &[1, 2, 3], 1);
+        let root_2 = commit(global_state, root_1, &[1, 2, 3], 2);
+
+        test_state.pruner.start(&[root_2]).unwrap();
+        test_state.pruner.step(1).unwrap();
+        let progress = test_state.pruner.progress().unwrap();
+        assert_eq!(progress.phase, PruningPhase::Marking);
+        assert!(progress.nodes_marked > 0);
+        assert!(progress.nodes_pending > 0);
+
+        // Reopen the pruner as after a restart, and resume the run.
+        let pruner = LmdbTriePruner::open(
+            Arc::clone(&test_state.environment),
+            Arc::clone(&test_state.trie_store),
+            None,
+        )
+        .unwrap();
+        let progress = pruner.prune(&[], BATCH_SIZE).unwrap();
+
+        assert_eq!(progress.phase, PruningPhase::Idle);
+        assert!(global_state.checkout(root_1).unwrap().is_none());
+        assert_intact(global_state, root_2);
+    }
+
+    #[test]
+    fn should_retain_roots_committed_while_pruning() {
+        let test_state = create_test_state();
+        let global_state = &test_state.global_state;
+        let pruner = &test_state.pruner;
+        let root_1 = commit(global_state, global_state.empty_root(), &[1, 2, 3, 4], 1);
+        let root_2 = commit(global_state, root_1, &[1], 2);
+
+        pruner.start(&[root_2]).unwrap();
+        pruner.step(1).unwrap();
+        assert_eq!(pruner.progress().unwrap().phase, PruningPhase::Marking);
+        let root_3 = commit(global_state, root_2, &[2], 3);
+
+        while pruner.progress().unwrap().phase == PruningPhase::Marking {
+            pruner.step(BATCH_SIZE).unwrap();
+        }
+        // Write a trie which only existed under the unretained root again.
+        let root_4 = commit(global_state, root_3, &[1], 1);
+
+        let progress = pruner.prune(&[], BATCH_SIZE).unwrap();
+
+        assert_eq!(progress.phase, PruningPhase::Idle);
+        for root in &[root_2, root_3, root_4] {
+            assert_intact(global_state, *root);
+        }
+        assert_eq!(read(global_state, root_3, 2), Some(value(3)));
+        assert_eq!(read(global_state, root_4, 1), Some(value(1)));
+        assert_eq!(read(global_state, root_4, 4), Some(value(1)));
+    }
+}
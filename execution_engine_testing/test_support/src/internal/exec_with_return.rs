@@ -134,12 +134,14 @@ where
     let wasm_config = *DEFAULT_WASM_CONFIG;
 
     let preprocessor = Preprocessor::new(wasm_config);
+    let wasm_module_cache = builder.get_engine_state().wasm_module_cache().clone();
     let parity_module = deploy_item
         .get_deploy_metadata(
             tracking_copy,
             &account,
             correlation_id,
             &preprocessor,
+            &wasm_module_cache,
             &protocol_version,
             &protocol_data,
             phase,
@@ -152,7 +154,14 @@ where
         runtime::instance_and_memory(module.clone(), protocol_version, &wasm_config)
             .expect("should be able to make wasm instance from module");
 
-    let mut runtime = Runtime::new(config, Default::default(), memory, module, context);
+    let mut runtime = Runtime::new(
+        config,
+        Default::default(),
+        wasm_module_cache,
+        memory,
+        module,
+        context,
+    );
 
     match instance.invoke_export(entry_point_name, &[], &mut runtime) {
         Ok(_) => None,
@@ -39,6 +39,14 @@ pub const DEFAULT_AUCTION_DELAY: u64 = 3;
 pub const DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS: u64 = 90 * 24 * 60 * 60 * 1000;
 /// Default number of eras that need to pass to be able to withdraw unbonded funds.
 pub const DEFAULT_UNBONDING_DELAY: u64 = 14;
+/// Default number of eras that need to pass before redelegated funds are bonded again.
+pub const DEFAULT_REDELEGATION_DELAY: u64 = 7;
+/// Default minimum amount of motes a delegator has to keep delegated to a validator.
+pub const DEFAULT_MINIMUM_DELEGATION_AMOUNT: u64 = 0;
+/// Default maximum number of delegators a single validator accepts.
+pub const DEFAULT_MAX_DELEGATORS_PER_VALIDATOR: u32 = u32::MAX;
+/// Default fraction of the stake taken from a slashed validator and its delegators.
+pub const DEFAULT_SLASHING_RATE: Ratio<u64> = Ratio::new_raw(1, 1);
 
 /// Default round seigniorage rate represented as a fractional number.
 ///
@@ -105,6 +113,10 @@ pub static DEFAULT_EXEC_CONFIG: Lazy<ExecConfig> = Lazy::new(|| {
         DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
         DEFAULT_ROUND_SEIGNIORAGE_RATE,
         DEFAULT_UNBONDING_DELAY,
+        DEFAULT_REDELEGATION_DELAY,
+        DEFAULT_MINIMUM_DELEGATION_AMOUNT,
+        DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
+        DEFAULT_SLASHING_RATE,
         DEFAULT_GENESIS_TIMESTAMP_MILLIS,
     )
 });
@@ -24,6 +24,10 @@ pub struct UpgradeRequestBuilder {
     new_locked_funds_period_millis: Option<u64>,
     new_round_seigniorage_rate: Option<Ratio<u64>>,
     new_unbonding_delay: Option<u64>,
+    new_redelegation_delay: Option<u64>,
+    new_minimum_delegation_amount: Option<u64>,
+    new_max_delegators_per_validator: Option<u32>,
+    new_slashing_rate: Option<Ratio<u64>>,
     global_state_update: BTreeMap<Key, StoredValue>,
 }
 
@@ -79,6 +83,29 @@ impl UpgradeRequestBuilder {
         self
     }
 
+    pub fn with_new_redelegation_delay(mut self, redelegation_delay: u64) -> Self {
+        self.new_redelegation_delay = Some(redelegation_delay);
+        self
+    }
+
+    pub fn with_new_minimum_delegation_amount(mut self, minimum_delegation_amount: u64) -> Self {
+        self.new_minimum_delegation_amount = Some(minimum_delegation_amount);
+        self
+    }
+
+    pub fn with_new_max_delegators_per_validator(
+        mut self,
+        max_delegators_per_validator: u32,
+    ) -> Self {
+        self.new_max_delegators_per_validator = Some(max_delegators_per_validator);
+        self
+    }
+
+    pub fn with_new_slashing_rate(mut self, slashing_rate: Ratio<u64>) -> Self {
+        self.new_slashing_rate = Some(slashing_rate);
+        self
+    }
+
     pub fn with_new_system_config(mut self, new_system_config: SystemConfig) -> Self {
         self.new_system_config = Some(new_system_config);
         self
@@ -110,6 +137,10 @@ impl UpgradeRequestBuilder {
             self.new_locked_funds_period_millis,
             self.new_round_seigniorage_rate,
             self.new_unbonding_delay,
+            self.new_redelegation_delay,
+            self.new_minimum_delegation_amount,
+            self.new_max_delegators_per_validator,
+            self.new_slashing_rate,
             self.global_state_update,
         )
     }
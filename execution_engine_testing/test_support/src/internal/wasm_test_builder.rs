@@ -19,12 +19,14 @@ use casper_execution_engine::{
             era_validators::GetEraValidatorsRequest,
             execute_request::ExecuteRequest,
             execution_result::ExecutionResult,
+            keys_with_prefix::{KeysWithPrefixRequest, KeysWithPrefixResult},
             run_genesis_request::RunGenesisRequest,
             step::{StepRequest, StepResult},
+            transfer_scheduler::ExecuteTransfersResult,
             BalanceResult, EngineConfig, EngineState, GenesisResult, QueryRequest, QueryResult,
-            UpgradeConfig, UpgradeResult, SYSTEM_ACCOUNT_ADDR,
+            UpgradeConfig, UpgradeResult, ViewRequest, ViewResult, SYSTEM_ACCOUNT_ADDR,
         },
-        execution,
+        execution::{self, ExecutionTrace},
     },
     shared::{
         account::Account,
@@ -52,13 +54,15 @@ use casper_types::{
     runtime_args,
     system::{
         auction::{
-            EraId, EraValidators, ValidatorWeights, ARG_ERA_END_TIMESTAMP_MILLIS,
-            ARG_EVICTED_VALIDATORS, AUCTION_DELAY_KEY, ERA_ID_KEY, METHOD_RUN_AUCTION,
+            Bids, EraId, EraValidators, UnbondingPurses, ValidatorWeights,
+            ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS, AUCTION_DELAY_KEY, ERA_ID_KEY,
+            METHOD_RUN_AUCTION,
         },
         mint::TOTAL_SUPPLY_KEY,
     },
     CLTyped, CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash, ContractWasm,
-    DeployHash, DeployInfo, Key, PublicKey, RuntimeArgs, Transfer, TransferAddr, URef, U512,
+    DeployHash, DeployInfo, Key, KeyTag, PublicKey, RuntimeArgs, Transfer, TransferAddr, URef,
+    U512,
 };
 
 use crate::internal::{
@@ -426,6 +430,11 @@ where
         panic! {query_result};
     }
 
+    pub fn run_view(&self, view_request: ViewRequest) -> Result<ViewResult, engine_state::Error> {
+        self.engine_state
+            .run_view(CorrelationId::new(), view_request)
+    }
+
     pub fn total_supply(&self, maybe_post_state: Option<Blake2bHash>) -> U512 {
         let mint_key: Key = self
             .mint_contract_hash
@@ -474,6 +483,64 @@ where
         self
     }
 
+    /// Runs `exec_request` in the same way as [`exec`](Self::exec), returning a trace of the host
+    /// functions called and the gas charged while executing each deploy's Wasm code.
+    pub fn exec_with_trace(&mut self, mut exec_request: ExecuteRequest) -> Vec<ExecutionTrace> {
+        exec_request.parent_state_hash = self
+            .post_state_hash
+            .clone()
+            .expect("expected post_state_hash");
+        let results_and_traces = self
+            .engine_state
+            .run_execute_traced(CorrelationId::new(), exec_request)
+            .expect("should run traced execution");
+
+        let mut exec_results = Vec::with_capacity(results_and_traces.len());
+        let mut traces = Vec::with_capacity(results_and_traces.len());
+        for (exec_result, trace) in results_and_traces {
+            self.transforms
+                .push(exec_result.effect().transforms.clone());
+            exec_results.push(Rc::new(exec_result));
+            traces.push(trace);
+        }
+        self.exec_results.push(exec_results);
+        traces
+    }
+
+    /// Runs the wasm-less transfers in `exec_request` as a block would, each against the state left
+    /// by the previous ones, and commits their effects.
+    pub fn exec_transfers(&mut self, mut exec_request: ExecuteRequest) -> &mut Self
+    where
+        S: Sync,
+    {
+        exec_request.parent_state_hash = self.post_state_hash.expect("expected post_state_hash");
+        let result = self
+            .engine_state
+            .run_execute_transfers(CorrelationId::new(), exec_request)
+            .expect("should run transfers");
+
+        if let ExecuteTransfersResult::Success {
+            post_state_hash,
+            execution_results,
+        } = result
+        {
+            self.transforms.extend(
+                execution_results
+                    .iter()
+                    .map(|res| res.effect().transforms.clone()),
+            );
+            self.exec_results
+                .push(execution_results.into_iter().map(Rc::new).collect());
+            self.post_state_hash = Some(post_state_hash);
+            self
+        } else {
+            panic!(
+                "Expected successful transfers result, but instead got error: {:?}",
+                result,
+            )
+        }
+    }
+
     /// Commit effects of previous exec call on the latest post-state hash.
     pub fn commit(&mut self) -> &mut Self {
         let prestate_hash = self
@@ -711,16 +778,9 @@ where
     }
 
     pub fn get_purse_balance(&self, purse: URef) -> U512 {
-        let purse_addr = purse.addr();
-        let balance_mapping_key = Key::Hash(purse_addr);
-
-        let base_key = self
-            .query(None, balance_mapping_key, &[])
-            .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
-            .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
-            .expect("should find balance uref");
+        let balance_key = Key::Balance(purse.addr());
 
-        self.query(None, base_key, &[])
+        self.query(None, balance_key, &[])
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
             .expect("should parse balance into a U512")
@@ -886,4 +946,49 @@ where
         let auction_contract = self.get_auction_contract_hash();
         self.get_value(auction_contract, AUCTION_DELAY_KEY)
     }
+
+    /// Returns all stored values under keys of the given `key_tag`.
+    pub fn get_values_with_key_tag(&self, key_tag: KeyTag) -> Vec<(Key, StoredValue)> {
+        let correlation_id = CorrelationId::new();
+        let request = KeysWithPrefixRequest::new(
+            self.get_post_state_hash(),
+            vec![key_tag as u8],
+            None,
+            usize::MAX,
+        );
+        match self
+            .engine_state
+            .get_keys_with_prefix(correlation_id, request)
+            .expect("should get keys with prefix")
+        {
+            KeysWithPrefixResult::Success {
+                keys_and_values, ..
+            } => keys_and_values,
+            KeysWithPrefixResult::RootNotFound => panic!("should have post state hash"),
+        }
+    }
+
+    /// Returns the current bids, keyed by validator.
+    pub fn get_bids(&self) -> Bids {
+        self.get_values_with_key_tag(KeyTag::Bid)
+            .into_iter()
+            .filter_map(|(_key, value)| value.as_bid().cloned())
+            .filter(|bid| !bid.is_empty())
+            .map(|bid| (*bid.validator_public_key(), bid))
+            .collect()
+    }
+
+    /// Returns the current unbonding purses, keyed by validator.
+    pub fn get_withdraws(&self) -> UnbondingPurses {
+        let mut withdraws = UnbondingPurses::new();
+        for (_key, value) in self.get_values_with_key_tag(KeyTag::Withdraw) {
+            let unbonding_purses = match value.as_withdraw() {
+                Some(unbonding_purses) if !unbonding_purses.is_empty() => unbonding_purses,
+                _ => continue,
+            };
+            let validator_public_key = *unbonding_purses[0].validator_public_key();
+            withdraws.insert(validator_public_key, unbonding_purses.clone());
+        }
+        withdraws
+    }
 }
@@ -9,9 +9,11 @@ use clap::{crate_version, App};
 use casper_engine_test_support::internal::{
     DeployItemBuilder, ExecuteRequestBuilder, LmdbWasmTestBuilder, ARG_AMOUNT, DEFAULT_ACCOUNTS,
     DEFAULT_ACCOUNT_ADDR, DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_CONFIG_HASH,
-    DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PAYMENT,
-    DEFAULT_PROTOCOL_VERSION, DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG,
-    DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
+    DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
+    DEFAULT_MAX_DELEGATORS_PER_VALIDATOR, DEFAULT_MINIMUM_DELEGATION_AMOUNT, DEFAULT_PAYMENT,
+    DEFAULT_PROTOCOL_VERSION, DEFAULT_REDELEGATION_DELAY, DEFAULT_ROUND_SEIGNIORAGE_RATE,
+    DEFAULT_SLASHING_RATE, DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS,
+    DEFAULT_WASM_CONFIG,
 };
 use casper_engine_tests::profiling;
 use casper_execution_engine::core::engine_state::{
@@ -76,6 +78,10 @@ fn main() {
         DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
         DEFAULT_ROUND_SEIGNIORAGE_RATE,
         DEFAULT_UNBONDING_DELAY,
+        DEFAULT_REDELEGATION_DELAY,
+        DEFAULT_MINIMUM_DELEGATION_AMOUNT,
+        DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
+        DEFAULT_SLASHING_RATE,
         DEFAULT_GENESIS_TIMESTAMP_MILLIS,
     );
     let run_genesis_request = RunGenesisRequest::new(
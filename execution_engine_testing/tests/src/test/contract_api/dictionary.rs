@@ -0,0 +1,106 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, Key, RuntimeArgs, URef, DICTIONARY_ITEM_KEY_MAX_LENGTH};
+
+const DICTIONARY_WASM: &str = "dictionary.wasm";
+const DICTIONARY_NAME: &str = "dictionary";
+const ARG_ITEM_KEY: &str = "item_key";
+const ARG_VALUE: &str = "value";
+const ARG_READ_ONLY: &str = "read_only";
+
+fn put_item(builder: &mut InMemoryWasmTestBuilder, item_key: &str, value: u64, read_only: bool) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        DICTIONARY_WASM,
+        runtime_args! {
+            ARG_ITEM_KEY => item_key.to_string(),
+            ARG_VALUE => value,
+            ARG_READ_ONLY => read_only,
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).commit();
+}
+
+fn get_seed_uref(builder: &InMemoryWasmTestBuilder) -> URef {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    *account
+        .named_keys()
+        .get(DICTIONARY_NAME)
+        .expect("should have dictionary")
+        .as_uref()
+        .expect("should be uref")
+}
+
+fn get_item(builder: &InMemoryWasmTestBuilder, item_key: &str) -> u64 {
+    let seed_uref = get_seed_uref(builder);
+    builder
+        .query(None, Key::dictionary(seed_uref, item_key.as_bytes()), &[])
+        .expect("should query")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should convert")
+}
+
+#[ignore]
+#[test]
+fn should_put_and_get_dictionary_items() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    let named_keys_before = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .len();
+
+    put_item(&mut builder, "alice", 1, false);
+    builder.expect_success();
+    put_item(&mut builder, "bob", 2, false);
+    builder.expect_success();
+    put_item(&mut builder, "alice", 3, false);
+    builder.expect_success();
+
+    assert_eq!(get_item(&builder, "alice"), 3);
+    assert_eq!(get_item(&builder, "bob"), 2);
+
+    // Only the seed URef is added to the account's named keys, regardless of the number of items.
+    let named_keys_after = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .len();
+    assert_eq!(named_keys_after, named_keys_before + 1);
+}
+
+#[ignore]
+#[test]
+fn should_not_put_dictionary_item_through_read_only_seed_uref() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    put_item(&mut builder, "alice", 1, false);
+    builder.expect_success();
+
+    put_item(&mut builder, "alice", 2, true);
+    assert!(builder.is_error());
+    assert_eq!(get_item(&builder, "alice"), 1);
+}
+
+#[ignore]
+#[test]
+fn should_reject_too_long_dictionary_item_key() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let item_key = "a".repeat(DICTIONARY_ITEM_KEY_MAX_LENGTH + 1);
+    put_item(&mut builder, &item_key, 1, false);
+    assert!(builder.is_error());
+}
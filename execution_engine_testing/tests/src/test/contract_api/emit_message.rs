@@ -0,0 +1,71 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::execution_effect::Message;
+use casper_types::{runtime_args, Key, RuntimeArgs};
+
+const EMIT_MESSAGE_WASM: &str = "emit_message.wasm";
+const ARG_TOPIC: &str = "topic";
+const ARG_PAYLOAD: &str = "payload";
+const ARG_REVERT: &str = "revert";
+
+const TOPIC: &str = "greetings";
+
+fn emit_message(topic: &str, payload: Vec<u8>, revert: bool) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        EMIT_MESSAGE_WASM,
+        runtime_args! {
+            ARG_TOPIC => topic.to_string(),
+            ARG_PAYLOAD => payload,
+            ARG_REVERT => revert,
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+fn get_messages(builder: &InMemoryWasmTestBuilder) -> Vec<Message> {
+    let exec_results = builder
+        .get_exec_result(0)
+        .expect("there should be a response");
+    exec_results[0].effect().messages.clone()
+}
+
+#[ignore]
+#[test]
+fn should_record_emitted_message() {
+    let payload = b"hello, world".to_vec();
+    let builder = emit_message(TOPIC, payload.clone(), false);
+    assert!(!builder.is_error());
+
+    let expected = Message::new(
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        TOPIC.to_string(),
+        payload,
+    );
+    assert_eq!(get_messages(&builder), vec![expected]);
+}
+
+#[ignore]
+#[test]
+fn should_discard_messages_of_failed_session() {
+    let builder = emit_message(TOPIC, b"hello, world".to_vec(), true);
+    assert!(builder.is_error());
+    assert!(get_messages(&builder).is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_reject_empty_topic() {
+    let builder = emit_message("", b"hello, world".to_vec(), false);
+    assert!(builder.is_error());
+    assert!(get_messages(&builder).is_empty());
+}
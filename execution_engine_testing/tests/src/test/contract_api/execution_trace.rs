@@ -0,0 +1,139 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::execution::{
+    CallFrame, ExecutionTrace, FrameOutcome, HostCallTrace, TraceEvent,
+};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U512};
+
+const CONTRACT_GET_CALLER_SUBCALL: &str = "get_caller_subcall.wasm";
+const CONTRACT_REVERT: &str = "revert.wasm";
+const ENTRY_POINT_CALL: &str = "call";
+const ENTRY_POINT_GET_CALLER_EXT: &str = "get_caller_ext";
+const HOST_FUNCTION_CALL_CONTRACT: &str = "host_function_call_contract";
+const HOST_FUNCTION_RET: &str = "host_function_ret";
+const HOST_FUNCTION_REVERT: &str = "host_function_revert";
+const ARG_ACCOUNT: &str = "account";
+
+fn host_calls(frame: &CallFrame) -> Vec<&HostCallTrace> {
+    frame
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            TraceEvent::HostCall(host_call) => Some(host_call),
+            TraceEvent::Opcodes { .. } => None,
+        })
+        .collect()
+}
+
+fn only_frame(mut traces: Vec<ExecutionTrace>) -> CallFrame {
+    assert_eq!(traces.len(), 1, "should have one trace per deploy");
+    let mut frames = traces.remove(0).frames;
+    // Standard payment is native, so only the session code is traced.
+    assert_eq!(frames.len(), 1, "should only trace session code");
+    frames.remove(0)
+}
+
+#[ignore]
+#[test]
+fn should_trace_nested_contract_calls() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_CALLER_SUBCALL,
+        runtime_args! { ARG_ACCOUNT => *DEFAULT_ACCOUNT_ADDR },
+    )
+    .build();
+    let traces = builder.exec_with_trace(exec_request);
+    builder.expect_success().commit();
+
+    let session = only_frame(traces);
+    assert_eq!(
+        session.base_key,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR).to_formatted_string()
+    );
+    assert_eq!(session.entry_point, ENTRY_POINT_CALL);
+    assert_eq!(session.outcome, FrameOutcome::Returned);
+    assert!(session.opcode_gas > 0.into(), "should charge for opcodes");
+    assert!(session.gas > session.opcode_gas);
+
+    let call_contract = host_calls(&session)
+        .into_iter()
+        .find(|host_call| host_call.name == HOST_FUNCTION_CALL_CONTRACT)
+        .expect("should trace call_contract");
+    assert_eq!(call_contract.calls.len(), 1);
+
+    let subcall = &call_contract.calls[0];
+    assert_eq!(subcall.entry_point, ENTRY_POINT_GET_CALLER_EXT);
+    assert_eq!(subcall.outcome, FrameOutcome::Returned);
+    assert!(call_contract.gas >= subcall.gas);
+    assert_eq!(
+        host_calls(subcall)
+            .last()
+            .map(|host_call| host_call.name.as_str()),
+        Some(HOST_FUNCTION_RET)
+    );
+
+    // The gas of the events in a frame adds up to the gas charged for the frame.
+    let events_gas = session
+        .events
+        .iter()
+        .fold(U512::zero(), |total, event| match event {
+            TraceEvent::Opcodes { gas } => total + *gas,
+            TraceEvent::HostCall(host_call) => total + host_call.gas,
+        });
+    assert_eq!(events_gas, session.gas);
+}
+
+#[ignore]
+#[test]
+fn should_trace_revert_point() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_REVERT, RuntimeArgs::new())
+            .build();
+    let traces = builder.exec_with_trace(exec_request);
+    assert!(builder.is_error());
+
+    let session = only_frame(traces);
+    assert_eq!(
+        session.outcome,
+        FrameOutcome::Reverted {
+            code: ApiError::User(100).into()
+        }
+    );
+    assert_eq!(
+        host_calls(&session)
+            .last()
+            .map(|host_call| host_call.name.as_str()),
+        Some(HOST_FUNCTION_REVERT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_change_execution_result_when_tracing() {
+    let exec_request = || {
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_GET_CALLER_SUBCALL,
+            runtime_args! { ARG_ACCOUNT => *DEFAULT_ACCOUNT_ADDR },
+        )
+        .build()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    builder.exec(exec_request());
+    let untraced_cost = builder.last_exec_gas_cost();
+    builder.exec_with_trace(exec_request());
+    let traced_cost = builder.last_exec_gas_cost();
+
+    assert_eq!(untraced_cost, traced_cost);
+}
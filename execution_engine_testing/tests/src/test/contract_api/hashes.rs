@@ -0,0 +1,60 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, RuntimeArgs};
+
+const HASHES_WASM: &str = "hashes.wasm";
+const ARG_BYTES: &str = "bytes";
+const SHA256_RESULT: &str = "sha256_result";
+const KECCAK256_RESULT: &str = "keccak256_result";
+
+const INPUT: &[u8] = b"abc";
+const EXPECTED_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+const EXPECTED_KECCAK256: &str = "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45";
+
+fn get_digest(builder: &InMemoryWasmTestBuilder, name: &str) -> [u8; 32] {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let uref = account.named_keys().get(name).expect("should have value");
+
+    builder
+        .query(None, *uref, &[])
+        .expect("should query")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should convert")
+}
+
+#[ignore]
+#[test]
+fn should_hash_with_sha256_and_keccak256() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        HASHES_WASM,
+        runtime_args! {
+            ARG_BYTES => INPUT.to_vec()
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+
+    assert_eq!(
+        base16::encode_lower(&get_digest(&builder, SHA256_RESULT)),
+        EXPECTED_SHA256
+    );
+    assert_eq!(
+        base16::encode_lower(&get_digest(&builder, KECCAK256_RESULT)),
+        EXPECTED_KECCAK256
+    );
+}
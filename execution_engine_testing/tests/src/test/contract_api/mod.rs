@@ -1,10 +1,15 @@
 mod account;
 mod blake2b;
+mod contract_view;
 mod create_purse;
+mod dictionary;
+mod emit_message;
+mod execution_trace;
 mod get_arg;
 mod get_blocktime;
 mod get_caller;
 mod get_phase;
+mod hashes;
 mod list_named_keys;
 mod main_purse;
 mod mint_purse;
@@ -15,3 +20,4 @@ mod transfer_purse_to_account;
 mod transfer_purse_to_purse;
 mod transfer_stored;
 mod transfer_u512_stored;
+mod verify_signature;
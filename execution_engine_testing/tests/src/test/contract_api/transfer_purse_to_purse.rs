@@ -76,25 +76,11 @@ fn should_run_purse_to_purse_transfer() {
     let purse_secondary_key = default_account.named_keys()["purse:secondary"];
     let _purse_main_key = default_account.named_keys()["purse:main"];
 
-    // Lookup key used to find the actual purse uref
-    // TODO: This should be more consistent
-    let purse_secondary_lookup_key = purse_secondary_key
-        .as_uref()
-        .unwrap()
-        .remove_access_rights()
-        .to_formatted_string();
-
-    let mint_contract_hash = builder.get_mint_contract_hash();
-    let mint_contract = builder
-        .get_contract(mint_contract_hash)
-        .expect("should have mint contract");
-
-    // Find `purse:secondary`.
-    let purse_secondary_uref = mint_contract.named_keys()[&purse_secondary_lookup_key];
-    let purse_secondary_key: Key = purse_secondary_uref.normalize();
+    // Find the balance of `purse:secondary`.
+    let purse_secondary_balance_key = Key::Balance(purse_secondary_key.as_uref().unwrap().addr());
     let purse_secondary_balance = CLValue::try_from(
         builder
-            .query(None, purse_secondary_key, &[])
+            .query(None, purse_secondary_balance_key, &[])
             .expect("should have main purse balance"),
     )
     .expect("should be a CLValue")
@@ -169,25 +155,11 @@ fn should_run_purse_to_purse_transfer_with_error() {
     let purse_secondary_key = default_account.named_keys()["purse:secondary"];
     let _purse_main_key = default_account.named_keys()["purse:main"];
 
-    // Lookup key used to find the actual purse uref
-    // TODO: This should be more consistent
-    let purse_secondary_lookup_key = purse_secondary_key
-        .as_uref()
-        .unwrap()
-        .remove_access_rights()
-        .to_formatted_string();
-
-    let mint_contract_uref = builder.get_mint_contract_hash();
-    let mint_contract = builder
-        .get_contract(mint_contract_uref)
-        .expect("should have mint contract");
-
-    // Find `purse:secondary` for a balance
-    let purse_secondary_uref = mint_contract.named_keys()[&purse_secondary_lookup_key];
-    let purse_secondary_key: Key = purse_secondary_uref.normalize();
+    // Find the balance of `purse:secondary`.
+    let purse_secondary_balance_key = Key::Balance(purse_secondary_key.as_uref().unwrap().addr());
     let purse_secondary_balance = CLValue::try_from(
         builder
-            .query(None, purse_secondary_key, &[])
+            .query(None, purse_secondary_balance_key, &[])
             .expect("should have main purse balance"),
     )
     .expect("should be a CLValue")
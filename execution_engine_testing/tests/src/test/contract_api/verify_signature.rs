@@ -0,0 +1,99 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{bytesrepr::ToBytes, runtime_args, PublicKey, RuntimeArgs, Signature};
+
+const VERIFY_SIGNATURE_WASM: &str = "verify_signature.wasm";
+const ARG_PUBLIC_KEY: &str = "public_key";
+const ARG_MESSAGE: &str = "message";
+const ARG_SIGNATURE: &str = "signature";
+const SIGNATURE_VALID: &str = "signature_valid";
+
+// Test vector 1 from RFC 8032, section 7.1: the signature of an empty message.
+const ED25519_PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+const ED25519_SIGNATURE: &str = concat!(
+    "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155",
+    "5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+);
+// The public key of test vector 2 from RFC 8032, section 7.1.
+const OTHER_ED25519_PUBLIC_KEY: &str =
+    "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+
+fn public_key(hex: &str) -> PublicKey {
+    let mut bytes = [0; PublicKey::ED25519_LENGTH];
+    bytes.copy_from_slice(&base16::decode(hex).unwrap());
+    PublicKey::ed25519(bytes).unwrap()
+}
+
+fn signature() -> Signature {
+    let mut bytes = [0; Signature::ED25519_LENGTH];
+    bytes.copy_from_slice(&base16::decode(ED25519_SIGNATURE).unwrap());
+    Signature::ed25519(bytes).unwrap()
+}
+
+fn verify_signature(public_key: PublicKey, message: Vec<u8>, signature: Signature) -> bool {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        VERIFY_SIGNATURE_WASM,
+        runtime_args! {
+            ARG_PUBLIC_KEY => public_key,
+            ARG_MESSAGE => message,
+            ARG_SIGNATURE => signature.to_bytes().unwrap(),
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let uref = account
+        .named_keys()
+        .get(SIGNATURE_VALID)
+        .expect("should have value");
+    builder
+        .query(None, *uref, &[])
+        .expect("should query")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should convert")
+}
+
+#[ignore]
+#[test]
+fn should_verify_valid_signature() {
+    assert!(verify_signature(
+        public_key(ED25519_PUBLIC_KEY),
+        vec![],
+        signature()
+    ));
+}
+
+#[ignore]
+#[test]
+fn should_reject_signature_of_other_message() {
+    assert!(!verify_signature(
+        public_key(ED25519_PUBLIC_KEY),
+        vec![1],
+        signature()
+    ));
+}
+
+#[ignore]
+#[test]
+fn should_reject_signature_by_other_key() {
+    assert!(!verify_signature(
+        public_key(OTHER_ED25519_PUBLIC_KEY),
+        vec![],
+        signature()
+    ));
+}
@@ -59,11 +59,10 @@ fn get_balance_should_work() {
 
     let state_root_hash = builder.get_post_state_hash();
 
-    let (purse_proof, balance_proof) = alice_balance_result.proofs().expect("should have proofs");
+    let balance_proof = alice_balance_result.proof().expect("should have proof");
 
     assert!(core::validate_balance_proof(
         &state_root_hash,
-        &purse_proof,
         &balance_proof,
         alice_main_purse.into(),
         &alice_balance,
@@ -74,7 +73,6 @@ fn get_balance_should_work() {
     assert_eq!(
         core::validate_balance_proof(
             &state_root_hash,
-            &purse_proof,
             &balance_proof,
             bogus_key.to_owned(),
             &alice_balance,
@@ -84,13 +82,7 @@ fn get_balance_should_work() {
 
     let bogus_uref: Key = Key::URef(URef::new([3u8; 32], AccessRights::READ_ADD_WRITE));
     assert_eq!(
-        core::validate_balance_proof(
-            &state_root_hash,
-            &purse_proof,
-            &balance_proof,
-            bogus_uref,
-            &alice_balance,
-        ),
+        core::validate_balance_proof(&state_root_hash, &balance_proof, bogus_uref, &alice_balance,),
         Err(ValidationError::UnexpectedKey)
     );
 
@@ -98,7 +90,6 @@ fn get_balance_should_work() {
     assert_eq!(
         core::validate_balance_proof(
             &bogus_hash,
-            &purse_proof,
             &balance_proof,
             alice_main_purse.into(),
             &alice_balance,
@@ -106,11 +97,18 @@ fn get_balance_should_work() {
         Err(ValidationError::InvalidProofHash)
     );
 
+    let default_account_main_purse = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account")
+        .main_purse();
+    let default_account_balance_proof = builder
+        .get_purse_balance_result(default_account_main_purse)
+        .proof()
+        .expect("should have proof");
     assert_eq!(
         core::validate_balance_proof(
             &state_root_hash,
-            &purse_proof,
-            &purse_proof,
+            &default_account_balance_proof,
             alice_main_purse.into(),
             &alice_balance,
         ),
@@ -121,7 +119,6 @@ fn get_balance_should_work() {
     assert_eq!(
         core::validate_balance_proof(
             &state_root_hash,
-            &purse_proof,
             &balance_proof,
             alice_main_purse.into(),
             &bogus_motes,
@@ -153,15 +150,20 @@ fn get_balance_should_work() {
 
     let state_root_hash = builder.get_post_state_hash();
 
-    let (_purse_proof_new, balance_proof_new) = alice_balance_result_new
-        .proofs()
-        .expect("should have proofs");
+    let balance_proof_new = alice_balance_result_new.proof().expect("should have proof");
+
+    assert!(core::validate_balance_proof(
+        &state_root_hash,
+        &balance_proof_new,
+        alice_main_purse.into(),
+        &alice_balance,
+    )
+    .is_ok());
 
     assert_eq!(
         core::validate_balance_proof(
             &state_root_hash,
-            &purse_proof,
-            &balance_proof_new,
+            &balance_proof,
             alice_main_purse.into(),
             &alice_balance,
         ),
@@ -0,0 +1,163 @@
+use casper_engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_PROPOSER_ADDR, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::engine_state::deploy_item::DeployItem;
+use casper_types::{account::AccountHash, runtime_args, system::mint, RuntimeArgs, U512};
+
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const ACCOUNT_2_ADDR: AccountHash = AccountHash::new([2u8; 32]);
+const ACCOUNT_3_ADDR: AccountHash = AccountHash::new([3u8; 32]);
+const ACCOUNT_4_ADDR: AccountHash = AccountHash::new([4u8; 32]);
+const ACCOUNT_5_ADDR: AccountHash = AccountHash::new([5u8; 32]);
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    for account_hash in &[ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ACCOUNT_3_ADDR] {
+        let exec_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+            runtime_args! {
+                mint::ARG_TARGET => *account_hash,
+                mint::ARG_AMOUNT => *DEFAULT_PAYMENT,
+                mint::ARG_ID => <Option<u64>>::None
+            },
+        )
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    builder
+}
+
+fn transfer(index: u8, source: AccountHash, target: AccountHash, amount: U512) -> DeployItem {
+    DeployItemBuilder::new()
+        .with_address(source)
+        .with_empty_payment_bytes(RuntimeArgs::new())
+        .with_transfer_args(runtime_args! {
+            mint::ARG_TARGET => target,
+            mint::ARG_AMOUNT => amount,
+            mint::ARG_ID => <Option<u64>>::None
+        })
+        .with_authorization_keys(&[source])
+        .with_deploy_hash([index; 32])
+        .build()
+}
+
+fn transfer_with_custom_payment(
+    index: u8,
+    source: AccountHash,
+    target: AccountHash,
+    amount: U512,
+) -> DeployItem {
+    DeployItemBuilder::new()
+        .with_address(source)
+        .with_payment_code(CONTRACT_DO_NOTHING, RuntimeArgs::new())
+        .with_transfer_args(runtime_args! {
+            mint::ARG_TARGET => target,
+            mint::ARG_AMOUNT => amount,
+            mint::ARG_ID => <Option<u64>>::None
+        })
+        .with_authorization_keys(&[source])
+        .with_deploy_hash([index; 32])
+        .build()
+}
+
+/// Executes and commits `transfers` one at a time, and all at once via `exec_transfers`, checking
+/// both yield the same results and post state hash.
+fn assert_same_as_sequential_execution(transfers: Vec<DeployItem>) {
+    let mut sequential_builder = setup();
+    let mut parallel_builder = sequential_builder.clone();
+
+    for deploy_item in transfers.iter().cloned() {
+        let exec_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+        sequential_builder.exec(exec_request).commit();
+    }
+    let exec_results = sequential_builder.get_exec_results();
+    let sequential_results = exec_results[exec_results.len() - transfers.len()..]
+        .iter()
+        .map(|results| results[0].clone())
+        .collect::<Vec<_>>();
+
+    let exec_request = transfers
+        .into_iter()
+        .fold(ExecuteRequestBuilder::new(), |exec_request, deploy_item| {
+            exec_request.push_deploy(deploy_item)
+        })
+        .build();
+    parallel_builder.exec_transfers(exec_request);
+    let parallel_results = parallel_builder
+        .get_exec_results()
+        .last()
+        .expect("should have results")
+        .clone();
+
+    assert_eq!(parallel_results.len(), sequential_results.len());
+    for (parallel_result, sequential_result) in parallel_results.iter().zip(&sequential_results) {
+        assert_eq!(parallel_result.is_success(), sequential_result.is_success());
+        assert_eq!(parallel_result.cost(), sequential_result.cost());
+    }
+    assert_eq!(
+        parallel_builder.get_post_state_hash(),
+        sequential_builder.get_post_state_hash()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_execute_independent_transfers_as_sequential_execution() {
+    let amount = U512::from(1_000);
+    assert_same_as_sequential_execution(vec![
+        transfer(1, ACCOUNT_1_ADDR, ACCOUNT_4_ADDR, amount),
+        transfer(2, ACCOUNT_2_ADDR, ACCOUNT_5_ADDR, amount),
+        transfer(3, ACCOUNT_3_ADDR, *DEFAULT_ACCOUNT_ADDR, amount),
+    ]);
+}
+
+#[ignore]
+#[test]
+fn should_execute_conflicting_transfers_as_sequential_execution() {
+    // Account 1 can only afford the second transfer after receiving the first one.
+    let balance = *DEFAULT_PAYMENT;
+    assert_same_as_sequential_execution(vec![
+        transfer(1, ACCOUNT_2_ADDR, ACCOUNT_1_ADDR, U512::from(1_000_000)),
+        transfer(2, ACCOUNT_1_ADDR, ACCOUNT_4_ADDR, balance - 1),
+        transfer(3, ACCOUNT_3_ADDR, ACCOUNT_5_ADDR, U512::from(1_000)),
+        transfer(4, ACCOUNT_4_ADDR, ACCOUNT_3_ADDR, U512::from(1_000)),
+        transfer(5, ACCOUNT_5_ADDR, ACCOUNT_2_ADDR, U512::from(1_000)),
+        transfer(6, ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, balance),
+    ]);
+}
+
+#[ignore]
+#[test]
+fn should_execute_transfers_involving_proposer_as_sequential_execution() {
+    let amount = U512::from(1_000);
+    assert_same_as_sequential_execution(vec![
+        transfer(1, ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, amount),
+        transfer(2, *DEFAULT_PROPOSER_ADDR, ACCOUNT_3_ADDR, amount),
+        transfer(3, ACCOUNT_2_ADDR, *DEFAULT_PROPOSER_ADDR, amount),
+        transfer(4, ACCOUNT_3_ADDR, ACCOUNT_4_ADDR, amount),
+    ]);
+}
+
+#[ignore]
+#[test]
+fn should_execute_transfers_with_custom_payment_as_sequential_execution() {
+    let amount = U512::from(1_000);
+    assert_same_as_sequential_execution(vec![
+        transfer(1, ACCOUNT_1_ADDR, ACCOUNT_4_ADDR, amount),
+        transfer_with_custom_payment(2, ACCOUNT_2_ADDR, ACCOUNT_5_ADDR, amount),
+        transfer(3, ACCOUNT_3_ADDR, ACCOUNT_2_ADDR, amount),
+        transfer_with_custom_payment(4, ACCOUNT_1_ADDR, ACCOUNT_3_ADDR, amount),
+        transfer(5, ACCOUNT_2_ADDR, ACCOUNT_5_ADDR, amount),
+    ]);
+}
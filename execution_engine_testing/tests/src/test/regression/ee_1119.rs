@@ -15,7 +15,7 @@ use casper_types::{
     system::{
         auction::{
             Bids, UnbondingPurses, ARG_DELEGATOR, ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEYS,
-            BIDS_KEY, METHOD_SLASH, UNBONDING_PURSES_KEY,
+            METHOD_SLASH,
         },
         mint::TOTAL_SUPPLY_KEY,
     },
@@ -45,7 +45,7 @@ const VESTING_WEEKS: u64 = 14;
 
 #[ignore]
 #[test]
-fn should_run_ee_1119_dont_slash_delegated_validators() {
+fn should_run_ee_1119_slash_delegated_validators() {
     let accounts = {
         let validator_1 = GenesisAccount::new(
             *VALIDATOR_1,
@@ -100,7 +100,7 @@ fn should_run_ee_1119_dont_slash_delegated_validators() {
         .expect_success()
         .commit();
 
-    let bids: Bids = builder.get_value(auction, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
     let validator_1_bid = bids.get(&VALIDATOR_1).expect("should have bid");
     let bid_purse = validator_1_bid.bonding_purse();
     assert_eq!(
@@ -108,7 +108,7 @@ fn should_run_ee_1119_dont_slash_delegated_validators() {
         U512::from(VALIDATOR_1_STAKE),
     );
 
-    let unbond_purses: UnbondingPurses = builder.get_value(auction, UNBONDING_PURSES_KEY);
+    let unbond_purses: UnbondingPurses = builder.get_withdraws();
     assert_eq!(unbond_purses.len(), 0);
 
     //
@@ -156,7 +156,7 @@ fn should_run_ee_1119_dont_slash_delegated_validators() {
 
     builder.exec(withdraw_bid_request).expect_success().commit();
 
-    let unbond_purses: UnbondingPurses = builder.get_value(auction, UNBONDING_PURSES_KEY);
+    let unbond_purses: UnbondingPurses = builder.get_withdraws();
     assert_eq!(unbond_purses.len(), 1);
 
     let unbond_list = unbond_purses
@@ -200,18 +200,18 @@ fn should_run_ee_1119_dont_slash_delegated_validators() {
 
     builder.exec(slash_request_1).expect_success().commit();
 
-    let unbond_purses_noop: UnbondingPurses = builder.get_value(auction, UNBONDING_PURSES_KEY);
+    let unbond_purses_noop: UnbondingPurses = builder.get_withdraws();
     assert_eq!(
         unbond_purses, unbond_purses_noop,
         "slashing default validator should be noop because no unbonding was done"
     );
 
-    let bids: Bids = builder.get_value(auction, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
     assert!(!bids.is_empty());
     assert!(bids.contains_key(&VALIDATOR_1)); // still bid upon
 
     //
-    // Slash - only `withdraw_bid` amount is slashed
+    // Slash - the remaining stake, the delegation and both unbonding purses are slashed
     //
     let total_supply_before_slashing: U512 =
         builder.get_value(builder.get_mint_contract_hash(), TOTAL_SUPPLY_KEY);
@@ -228,7 +228,7 @@ fn should_run_ee_1119_dont_slash_delegated_validators() {
 
     builder.exec(slash_request_2).expect_success().commit();
 
-    let unbond_purses: UnbondingPurses = builder.get_value(auction, UNBONDING_PURSES_KEY);
+    let unbond_purses: UnbondingPurses = builder.get_withdraws();
     assert_eq!(unbond_purses.len(), 0);
 
     assert!(
@@ -241,13 +241,13 @@ fn should_run_ee_1119_dont_slash_delegated_validators() {
         "should not be a part of unbond list because delegator was slashed"
     );
 
-    let bids: Bids = builder.get_value(auction, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
     assert!(!bids.contains_key(&VALIDATOR_1)); // still bid upon
 
     let total_supply_after_slashing: U512 =
         builder.get_value(builder.get_mint_contract_hash(), TOTAL_SUPPLY_KEY);
     assert_eq!(
         total_supply_before_slashing - total_supply_after_slashing,
-        U512::from(VALIDATOR_1_STAKE + UNDELEGATE_AMOUNT_1),
+        U512::from(VALIDATOR_1_STAKE + DELEGATE_AMOUNT_1),
     );
 }
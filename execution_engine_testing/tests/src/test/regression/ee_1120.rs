@@ -11,8 +11,8 @@ use casper_types::{
     account::{AccountHash, ACCOUNT_HASH_LENGTH},
     runtime_args,
     system::auction::{
-        Bids, UnbondingPurses, ARG_DELEGATOR, ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEYS, BIDS_KEY,
-        METHOD_SLASH, UNBONDING_PURSES_KEY,
+        Bids, UnbondingPurses, ARG_DELEGATOR, ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEYS,
+        METHOD_SLASH,
     },
     PublicKey, RuntimeArgs, SecretKey, U512,
 };
@@ -151,13 +151,13 @@ fn should_run_ee_1120_slash_delegators() {
         .commit();
 
     // Ensure that initial bid entries exist for validator 1 and validator 2
-    let initial_bids: Bids = builder.get_value(auction, BIDS_KEY);
+    let initial_bids: Bids = builder.get_bids();
     assert_eq!(
         initial_bids.keys().copied().collect::<BTreeSet<_>>(),
         BTreeSet::from_iter(vec![*VALIDATOR_2, *VALIDATOR_1])
     );
 
-    let initial_unbond_purses: UnbondingPurses = builder.get_value(auction, UNBONDING_PURSES_KEY);
+    let initial_unbond_purses: UnbondingPurses = builder.get_withdraws();
     assert_eq!(initial_unbond_purses.len(), 0);
 
     // DELEGATOR_1 partially unbonds from VALIDATOR_1
@@ -202,7 +202,7 @@ fn should_run_ee_1120_slash_delegators() {
 
     // Check unbonding purses before slashing
 
-    let unbond_purses_before: UnbondingPurses = builder.get_value(auction, UNBONDING_PURSES_KEY);
+    let unbond_purses_before: UnbondingPurses = builder.get_withdraws();
     assert_eq!(unbond_purses_before.len(), 2);
 
     let validator_1_unbond_list_before = unbond_purses_before
@@ -260,7 +260,7 @@ fn should_run_ee_1120_slash_delegators() {
 
     // Check bids before slashing
 
-    let bids_before: Bids = builder.get_value(auction, BIDS_KEY);
+    let bids_before: Bids = builder.get_bids();
     assert_eq!(
         bids_before.keys().collect::<Vec<_>>(),
         initial_bids.keys().collect::<Vec<_>>()
@@ -279,7 +279,7 @@ fn should_run_ee_1120_slash_delegators() {
     builder.exec(slash_request_1).expect_success().commit();
 
     // Compare bids after slashing validator 2
-    let bids_after: Bids = builder.get_value(auction, BIDS_KEY);
+    let bids_after: Bids = builder.get_bids();
     assert_ne!(bids_before, bids_after);
     assert_eq!(bids_after.len(), 1);
     assert!(!bids_after.contains_key(&VALIDATOR_2));
@@ -295,7 +295,7 @@ fn should_run_ee_1120_slash_delegators() {
         .delegators()
         .contains_key(&DELEGATOR_1));
 
-    let unbond_purses_after: UnbondingPurses = builder.get_value(auction, UNBONDING_PURSES_KEY);
+    let unbond_purses_after: UnbondingPurses = builder.get_withdraws();
     assert_ne!(unbond_purses_before, unbond_purses_after);
 
     let validator_1_unbond_list_after = unbond_purses_after
@@ -333,8 +333,8 @@ fn should_run_ee_1120_slash_delegators() {
 
     builder.exec(slash_request_2).expect_success().commit();
 
-    let bids_after: Bids = builder.get_value(auction, BIDS_KEY);
+    let bids_after: Bids = builder.get_bids();
     assert!(bids_after.is_empty());
-    let unbond_purses_after: UnbondingPurses = builder.get_value(auction, UNBONDING_PURSES_KEY);
+    let unbond_purses_after: UnbondingPurses = builder.get_withdraws();
     assert!(unbond_purses_after.is_empty());
 }
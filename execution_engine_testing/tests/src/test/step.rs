@@ -14,8 +14,7 @@ use casper_types::{
     account::AccountHash,
     system::{
         auction::{
-            Bids, SeigniorageRecipientsSnapshot, BIDS_KEY, BLOCK_REWARD,
-            SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY,
+            Bids, SeigniorageRecipientsSnapshot, BLOCK_REWARD, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY,
         },
         mint::TOTAL_SUPPLY_KEY,
     },
@@ -93,14 +92,14 @@ fn should_step() {
     let before_auction_seigniorage: SeigniorageRecipientsSnapshot =
         builder.get_value(auction_hash, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY);
 
-    let bids_before_slashing: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids_before_slashing: Bids = builder.get_bids();
     assert!(
         bids_before_slashing.contains_key(&ACCOUNT_1_PK),
         "should have entry in the genesis bids table {:?}",
         bids_before_slashing
     );
 
-    let bids_before_slashing: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids_before_slashing: Bids = builder.get_bids();
     assert!(
         bids_before_slashing.contains_key(&ACCOUNT_1_PK),
         "should have entry in bids table before slashing {:?}",
@@ -109,14 +108,14 @@ fn should_step() {
 
     builder.step(step_request);
 
-    let bids_after_slashing: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids_after_slashing: Bids = builder.get_bids();
     assert!(
         !bids_after_slashing.contains_key(&ACCOUNT_1_PK),
         "should not have entry in bids table after slashing {:?}",
         bids_after_slashing
     );
 
-    let bids_after_slashing: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids_after_slashing: Bids = builder.get_bids();
     assert_ne!(
         bids_before_slashing, bids_after_slashing,
         "bids table should be different before and after slashing"
@@ -7,15 +7,21 @@ use once_cell::sync::Lazy;
 use casper_engine_test_support::{
     internal::{
         utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
-        DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
-        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_UNBONDING_DELAY,
-        TIMESTAMP_MILLIS_INCREMENT,
+        DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PROTOCOL_VERSION, DEFAULT_REDELEGATION_DELAY,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_SLASHING_RATE,
+        DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS,
+        DEFAULT_WASM_CONFIG, TIMESTAMP_MILLIS_INCREMENT,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE, MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
 use casper_execution_engine::{
     core::{
-        engine_state::{self, genesis::GenesisAccount},
+        engine_state::{
+            self,
+            genesis::{ExecConfig, GenesisAccount},
+            run_genesis_request::RunGenesisRequest,
+        },
         execution,
     },
     shared::motes::Motes,
@@ -26,12 +32,12 @@ use casper_types::{
     api_error::ApiError,
     runtime_args,
     system::auction::{
-        self, Bids, DelegationRate, EraId, EraValidators, SeigniorageRecipients, UnbondingPurses,
-        ValidatorWeights, ARG_AMOUNT, ARG_DELEGATION_RATE, ARG_DELEGATOR, ARG_PUBLIC_KEY,
-        ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEY, BIDS_KEY, ERA_ID_KEY, INITIAL_ERA_ID,
-        METHOD_ACTIVATE_BID, UNBONDING_PURSES_KEY,
+        Bids, DelegationRate, EraId, EraValidators, Error as AuctionError, SeigniorageRecipients,
+        UnbondingPurses, ValidatorWeights, ARG_AMOUNT, ARG_DELEGATION_RATE, ARG_DELEGATOR,
+        ARG_NEW_VALIDATOR, ARG_PUBLIC_KEY, ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEY, ERA_ID_KEY,
+        INITIAL_ERA_ID, METHOD_ACTIVATE_BID,
     },
-    PublicKey, RuntimeArgs, SecretKey, U512,
+    Key, PublicKey, RuntimeArgs, SecretKey, U512,
 };
 
 const ARG_ENTRY_POINT: &str = "entry_point";
@@ -43,6 +49,7 @@ const CONTRACT_ADD_BID: &str = "add_bid.wasm";
 const CONTRACT_WITHDRAW_BID: &str = "withdraw_bid.wasm";
 const CONTRACT_DELEGATE: &str = "delegate.wasm";
 const CONTRACT_UNDELEGATE: &str = "undelegate.wasm";
+const CONTRACT_REDELEGATE: &str = "redelegate.wasm";
 
 const TRANSFER_AMOUNT: u64 = MINIMUM_ACCOUNT_CREATION_BALANCE + 1000;
 const SYSTEM_ADDR: AccountHash = AccountHash::new([0u8; 32]);
@@ -59,6 +66,9 @@ const ARG_READ_SEIGNIORAGE_RECIPIENTS: &str = "read_seigniorage_recipients";
 const DELEGATE_AMOUNT_1: u64 = 125_000;
 const DELEGATE_AMOUNT_2: u64 = 15_000;
 const UNDELEGATE_AMOUNT_1: u64 = 35_000;
+const REDELEGATE_AMOUNT_1: u64 = 35_000;
+const MINIMUM_DELEGATION_AMOUNT: u64 = DELEGATE_AMOUNT_2;
+const MAX_DELEGATORS_PER_VALIDATOR: u32 = 1;
 
 const SYSTEM_TRANSFER_AMOUNT: u64 = MINIMUM_ACCOUNT_CREATION_BALANCE;
 
@@ -148,7 +158,7 @@ fn should_run_add_bid() {
     builder.exec(exec_request_1).commit().expect_success();
 
     let auction_hash = builder.get_auction_contract_hash();
-    let bids: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
 
     assert_eq!(bids.len(), 1);
 
@@ -173,7 +183,7 @@ fn should_run_add_bid() {
 
     builder.exec(exec_request_2).commit().expect_success();
 
-    let bids: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
 
     assert_eq!(bids.len(), 1);
 
@@ -196,7 +206,7 @@ fn should_run_add_bid() {
     .build();
     builder.exec(exec_request_3).commit().expect_success();
 
-    let bids: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
 
     assert_eq!(bids.len(), 1);
 
@@ -279,7 +289,7 @@ fn should_run_delegate_and_undelegate() {
 
     let auction_hash = builder.get_auction_contract_hash();
 
-    let bids: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
     assert_eq!(bids.len(), 1);
     let active_bid = bids.get(&NON_FOUNDER_VALIDATOR_1_PK).unwrap();
     assert_eq!(
@@ -309,7 +319,7 @@ fn should_run_delegate_and_undelegate() {
 
     builder.exec(exec_request_1).commit().expect_success();
 
-    let bids: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
     assert_eq!(bids.len(), 1);
     let delegators = bids[&NON_FOUNDER_VALIDATOR_1_PK].delegators();
     assert_eq!(delegators.len(), 1);
@@ -330,7 +340,7 @@ fn should_run_delegate_and_undelegate() {
 
     builder.exec(exec_request_2).commit().expect_success();
 
-    let bids: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
     assert_eq!(bids.len(), 1);
     let delegators = bids[&NON_FOUNDER_VALIDATOR_1_PK].delegators();
     assert_eq!(delegators.len(), 1);
@@ -352,7 +362,7 @@ fn should_run_delegate_and_undelegate() {
     .build();
     builder.exec(exec_request_3).commit().expect_success();
 
-    let bids: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
     assert_eq!(bids.len(), 1);
     let delegators = bids[&NON_FOUNDER_VALIDATOR_1_PK].delegators();
     assert_eq!(delegators.len(), 1);
@@ -362,7 +372,7 @@ fn should_run_delegate_and_undelegate() {
         U512::from(DELEGATE_AMOUNT_1 + DELEGATE_AMOUNT_2 - UNDELEGATE_AMOUNT_1)
     );
 
-    let unbonding_purses: UnbondingPurses = builder.get_value(auction_hash, UNBONDING_PURSES_KEY);
+    let unbonding_purses: UnbondingPurses = builder.get_withdraws();
     assert_eq!(unbonding_purses.len(), 1);
 
     let unbond_list = unbonding_purses
@@ -438,7 +448,7 @@ fn should_calculate_era_validators() {
     .build();
 
     let auction_hash = builder.get_auction_contract_hash();
-    let bids: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
     assert_eq!(bids.len(), 2, "founding validators {:?}", bids);
 
     // Verify first era validators
@@ -577,8 +587,7 @@ fn should_get_first_seigniorage_recipients() {
     )
     .build();
 
-    let auction_hash = builder.get_auction_contract_hash();
-    let bids: Bids = builder.get_value(auction_hash, BIDS_KEY);
+    let bids: Bids = builder.get_bids();
     assert_eq!(bids.len(), 2);
 
     let founding_validator_1 = bids.get(&ACCOUNT_1_PK).expect("should have account 1 pk");
@@ -777,7 +786,7 @@ fn should_release_founder_stake() {
 
     // Check bid and its vesting schedule
     {
-        let bids: Bids = builder.get_value(auction, BIDS_KEY);
+        let bids: Bids = builder.get_bids();
         assert_eq!(bids.len(), 1);
 
         let entry = bids.get(&ACCOUNT_1_PK).unwrap();
@@ -801,7 +810,7 @@ fn should_release_founder_stake() {
 
     // Check bid and its vesting schedule
     {
-        let bids: Bids = builder.get_value(auction, BIDS_KEY);
+        let bids: Bids = builder.get_bids();
         assert_eq!(bids.len(), 1);
 
         let entry = bids.get(&ACCOUNT_1_PK).unwrap();
@@ -1427,8 +1436,6 @@ fn should_undelegate_delegators_when_validator_unbonds() {
     let mut builder = InMemoryWasmTestBuilder::default();
 
     builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
-    let auction = builder.get_auction_contract_hash();
-
     for request in post_genesis_requests {
         builder.exec(request).commit().expect_success();
     }
@@ -1438,8 +1445,7 @@ fn should_undelegate_delegators_when_validator_unbonds() {
         timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
     }
 
-    let bids_before: Bids =
-        builder.get_value(builder.get_auction_contract_hash(), auction::BIDS_KEY);
+    let bids_before: Bids = builder.get_bids();
     let validator_1_bid = bids_before
         .get(&*VALIDATOR_1)
         .expect("should have validator 1 bid");
@@ -1453,8 +1459,7 @@ fn should_undelegate_delegators_when_validator_unbonds() {
     );
 
     // Validator partially unbonds and only one entry is present
-    let unbonding_purses_before: UnbondingPurses =
-        builder.get_value(auction, auction::UNBONDING_PURSES_KEY);
+    let unbonding_purses_before: UnbondingPurses = builder.get_withdraws();
     assert_eq!(unbonding_purses_before[&*VALIDATOR_1].len(), 1);
     assert_eq!(
         unbonding_purses_before[&*VALIDATOR_1][0].unbonder_public_key(),
@@ -1476,13 +1481,12 @@ fn should_undelegate_delegators_when_validator_unbonds() {
         .commit()
         .expect_success();
 
-    let bids_after: Bids = builder.get_value(auction, auction::BIDS_KEY);
+    let bids_after: Bids = builder.get_bids();
     assert!(
         bids_after.get(&VALIDATOR_1).is_none(),
         "does not have validator 1 bid and delegator bids are removed as well"
     );
-    let unbonding_purses_after: UnbondingPurses =
-        builder.get_value(auction, auction::UNBONDING_PURSES_KEY);
+    let unbonding_purses_after: UnbondingPurses = builder.get_withdraws();
     assert_ne!(unbonding_purses_after, unbonding_purses_before);
 
     let validator_1_unbonding_purse = unbonding_purses_after
@@ -1574,6 +1578,14 @@ fn should_undelegate_delegators_when_validator_unbonds() {
         delegator_2_balance_before + U512::from(DELEGATOR_2_STAKE),
         delegator_2_balance_after
     );
+
+    // The empty bid and the drained unbonding list have been removed from global state
+    assert!(builder
+        .query(None, Key::Bid(*VALIDATOR_1_ADDR), &[])
+        .is_err());
+    assert!(builder
+        .query(None, Key::Withdraw(*VALIDATOR_1_ADDR), &[])
+        .is_err());
 }
 
 #[ignore]
@@ -1668,8 +1680,6 @@ fn should_undelegate_delegators_when_validator_fully_unbonds() {
     let mut builder = InMemoryWasmTestBuilder::default();
 
     builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
-    let auction = builder.get_auction_contract_hash();
-
     for request in post_genesis_requests {
         builder.exec(request).commit().expect_success();
     }
@@ -1690,13 +1700,12 @@ fn should_undelegate_delegators_when_validator_fully_unbonds() {
         .commit()
         .expect_success();
 
-    let bids_after: Bids = builder.get_value(auction, auction::BIDS_KEY);
+    let bids_after: Bids = builder.get_bids();
     assert!(
         bids_after.get(&VALIDATOR_1).is_none(),
         "does not have validator 1 bid and delegator bids are removed as well"
     );
-    let unbonding_purses_before: UnbondingPurses =
-        builder.get_value(auction, auction::UNBONDING_PURSES_KEY);
+    let unbonding_purses_before: UnbondingPurses = builder.get_withdraws();
 
     let validator_1_unbonding_purse = unbonding_purses_before
         .get(&VALIDATOR_1)
@@ -1934,3 +1943,376 @@ fn should_handle_evictions() {
         ])
     );
 }
+
+/// Sets up two non-founder validators, with `BID_ACCOUNT_1` delegating to the first one, and has
+/// the delegator redelegate `REDELEGATE_AMOUNT_1` to the second one.
+///
+/// Returns the builder along with the timestamp of the next auction.
+fn setup_redelegation() -> (InMemoryWasmTestBuilder, u64) {
+    let system_fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => SYSTEM_ADDR,
+            ARG_AMOUNT => U512::from(SYSTEM_TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let validator_1_fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *NON_FOUNDER_VALIDATOR_1_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let validator_2_fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *NON_FOUNDER_VALIDATOR_2_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let delegator_1_fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *BID_ACCOUNT_1_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let validator_1_add_bid_request = ExecuteRequestBuilder::standard(
+        *NON_FOUNDER_VALIDATOR_1_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *NON_FOUNDER_VALIDATOR_1_PK,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+
+    let validator_2_add_bid_request = ExecuteRequestBuilder::standard(
+        *NON_FOUNDER_VALIDATOR_2_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *NON_FOUNDER_VALIDATOR_2_PK,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_2),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_2,
+        },
+    )
+    .build();
+
+    let delegator_1_validator_1_delegate_request = ExecuteRequestBuilder::standard(
+        *BID_ACCOUNT_1_ADDR,
+        CONTRACT_DELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DELEGATE_AMOUNT_1),
+            ARG_VALIDATOR => *NON_FOUNDER_VALIDATOR_1_PK,
+            ARG_DELEGATOR => *BID_ACCOUNT_1_PK,
+        },
+    )
+    .build();
+
+    let post_genesis_requests = vec![
+        system_fund_request,
+        delegator_1_fund_request,
+        validator_1_fund_request,
+        validator_2_fund_request,
+        validator_1_add_bid_request,
+        validator_2_add_bid_request,
+        delegator_1_validator_1_delegate_request,
+    ];
+
+    let mut timestamp_millis =
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    for request in post_genesis_requests {
+        builder.exec(request).commit().expect_success();
+    }
+
+    for _ in 0..5 {
+        builder.run_auction(timestamp_millis, Vec::new());
+        timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+    }
+
+    let delegator_1_redelegate_request = ExecuteRequestBuilder::standard(
+        *BID_ACCOUNT_1_ADDR,
+        CONTRACT_REDELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(REDELEGATE_AMOUNT_1),
+            ARG_VALIDATOR => *NON_FOUNDER_VALIDATOR_1_PK,
+            ARG_DELEGATOR => *BID_ACCOUNT_1_PK,
+            ARG_NEW_VALIDATOR => *NON_FOUNDER_VALIDATOR_2_PK,
+        },
+    )
+    .build();
+
+    builder
+        .exec(delegator_1_redelegate_request)
+        .commit()
+        .expect_success();
+
+    (builder, timestamp_millis)
+}
+
+#[ignore]
+#[test]
+fn redelegated_funds_should_be_delegated_to_new_validator() {
+    let (mut builder, mut timestamp_millis) = setup_redelegation();
+
+    let delegator_1_main_purse = builder
+        .get_account(*BID_ACCOUNT_1_ADDR)
+        .expect("should have delegator account")
+        .main_purse();
+
+    let bids: Bids = builder.get_bids();
+    let delegators = bids[&NON_FOUNDER_VALIDATOR_1_PK].delegators();
+    assert_eq!(
+        *delegators[&BID_ACCOUNT_1_PK].staked_amount(),
+        U512::from(DELEGATE_AMOUNT_1 - REDELEGATE_AMOUNT_1)
+    );
+
+    let withdraws: UnbondingPurses = builder.get_withdraws();
+    let unbonding_list = &withdraws[&NON_FOUNDER_VALIDATOR_1_PK];
+    assert_eq!(unbonding_list.len(), 1);
+    assert_eq!(
+        unbonding_list[0].new_validator(),
+        Some(&*NON_FOUNDER_VALIDATOR_2_PK)
+    );
+
+    let delegator_1_purse_balance_before = builder.get_purse_balance(delegator_1_main_purse);
+
+    for _ in 0..=DEFAULT_REDELEGATION_DELAY {
+        let bids: Bids = builder.get_bids();
+        assert!(!bids[&NON_FOUNDER_VALIDATOR_2_PK]
+            .delegators()
+            .contains_key(&BID_ACCOUNT_1_PK));
+
+        builder.run_auction(timestamp_millis, Vec::new());
+        timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+    }
+
+    let bids: Bids = builder.get_bids();
+    let delegators = bids[&NON_FOUNDER_VALIDATOR_2_PK].delegators();
+    let redelegated = &delegators[&BID_ACCOUNT_1_PK];
+    assert_eq!(
+        *redelegated.staked_amount(),
+        U512::from(REDELEGATE_AMOUNT_1)
+    );
+    assert_eq!(
+        builder.get_purse_balance(*redelegated.bonding_purse()),
+        U512::from(REDELEGATE_AMOUNT_1)
+    );
+
+    assert!(!builder
+        .get_withdraws()
+        .contains_key(&NON_FOUNDER_VALIDATOR_1_PK));
+
+    // Redelegated funds never pass through the delegator's main purse
+    assert_eq!(
+        builder.get_purse_balance(delegator_1_main_purse),
+        delegator_1_purse_balance_before
+    );
+}
+
+#[ignore]
+#[test]
+fn redelegation_should_survive_eviction_of_new_validator_in_same_era() {
+    let (mut builder, mut timestamp_millis) = setup_redelegation();
+
+    for _ in 0..DEFAULT_REDELEGATION_DELAY {
+        builder.run_auction(timestamp_millis, Vec::new());
+        timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+    }
+
+    // The redelegation matures in the same auction which evicts the new validator.
+    builder.run_auction(timestamp_millis, vec![*NON_FOUNDER_VALIDATOR_2_PK]);
+
+    let bids: Bids = builder.get_bids();
+    let new_validator_bid = &bids[&NON_FOUNDER_VALIDATOR_2_PK];
+    assert!(new_validator_bid.inactive());
+    let redelegated = &new_validator_bid.delegators()[&BID_ACCOUNT_1_PK];
+    assert_eq!(
+        *redelegated.staked_amount(),
+        U512::from(REDELEGATE_AMOUNT_1)
+    );
+    assert_eq!(
+        builder.get_purse_balance(*redelegated.bonding_purse()),
+        U512::from(REDELEGATE_AMOUNT_1)
+    );
+    assert!(!builder
+        .get_withdraws()
+        .contains_key(&NON_FOUNDER_VALIDATOR_1_PK));
+}
+
+#[ignore]
+#[test]
+fn should_enforce_delegation_limits() {
+    let assert_auction_error = |builder: &InMemoryWasmTestBuilder, expected: AuctionError| {
+        let error = {
+            let response = builder
+                .get_exec_results()
+                .last()
+                .expect("should have last exec result");
+            let exec_response = response.last().expect("should have response");
+            exec_response.as_error().expect("should have error")
+        };
+        assert_matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(code)))
+            if *code == expected as u8
+        );
+    };
+
+    let delegate_request = |delegator_addr: AccountHash, delegator: PublicKey, amount: u64| {
+        ExecuteRequestBuilder::standard(
+            delegator_addr,
+            CONTRACT_DELEGATE,
+            runtime_args! {
+                ARG_AMOUNT => U512::from(amount),
+                ARG_VALIDATOR => *NON_FOUNDER_VALIDATOR_1_PK,
+                ARG_DELEGATOR => delegator,
+            },
+        )
+        .build()
+    };
+
+    let undelegate_request = |amount: u64| {
+        ExecuteRequestBuilder::standard(
+            *BID_ACCOUNT_1_ADDR,
+            CONTRACT_UNDELEGATE,
+            runtime_args! {
+                ARG_AMOUNT => U512::from(amount),
+                ARG_VALIDATOR => *NON_FOUNDER_VALIDATOR_1_PK,
+                ARG_DELEGATOR => *BID_ACCOUNT_1_PK,
+            },
+        )
+        .build()
+    };
+
+    let exec_config = ExecConfig::new(
+        DEFAULT_ACCOUNTS.clone(),
+        *DEFAULT_WASM_CONFIG,
+        *DEFAULT_SYSTEM_CONFIG,
+        DEFAULT_VALIDATOR_SLOTS,
+        DEFAULT_AUCTION_DELAY,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE,
+        DEFAULT_UNBONDING_DELAY,
+        DEFAULT_REDELEGATION_DELAY,
+        MINIMUM_DELEGATION_AMOUNT,
+        MAX_DELEGATORS_PER_VALIDATOR,
+        DEFAULT_SLASHING_RATE,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    );
+    let run_genesis_request = RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    );
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&run_genesis_request);
+
+    let fund_requests = [
+        *NON_FOUNDER_VALIDATOR_1_ADDR,
+        *BID_ACCOUNT_1_ADDR,
+        *BID_ACCOUNT_2_ADDR,
+    ]
+    .iter()
+    .map(|target| {
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_TO_ACCOUNT,
+            runtime_args! {
+                ARG_TARGET => *target,
+                ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+            },
+        )
+        .build()
+    })
+    .collect::<Vec<_>>();
+
+    for request in fund_requests {
+        builder.exec(request).commit().expect_success();
+    }
+
+    let validator_1_add_bid_request = ExecuteRequestBuilder::standard(
+        *NON_FOUNDER_VALIDATOR_1_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *NON_FOUNDER_VALIDATOR_1_PK,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+
+    builder
+        .exec(validator_1_add_bid_request)
+        .commit()
+        .expect_success();
+
+    // Delegating less than the minimum is rejected.
+    builder
+        .exec(delegate_request(
+            *BID_ACCOUNT_1_ADDR,
+            *BID_ACCOUNT_1_PK,
+            MINIMUM_DELEGATION_AMOUNT - 1,
+        ))
+        .commit();
+    assert_auction_error(&builder, AuctionError::DelegationAmountTooSmall);
+
+    builder
+        .exec(delegate_request(
+            *BID_ACCOUNT_1_ADDR,
+            *BID_ACCOUNT_1_PK,
+            DELEGATE_AMOUNT_1,
+        ))
+        .commit()
+        .expect_success();
+
+    // The validator is already at its maximum number of delegators.
+    builder
+        .exec(delegate_request(
+            *BID_ACCOUNT_2_ADDR,
+            *BID_ACCOUNT_2_PK,
+            DELEGATE_AMOUNT_1,
+        ))
+        .commit();
+    assert_auction_error(&builder, AuctionError::ExceededDelegatorSizeLimit);
+
+    // Undelegating must not leave less than the minimum behind.
+    builder
+        .exec(undelegate_request(
+            DELEGATE_AMOUNT_1 - MINIMUM_DELEGATION_AMOUNT + 1,
+        ))
+        .commit();
+    assert_auction_error(&builder, AuctionError::DelegationAmountTooSmall);
+
+    builder
+        .exec(undelegate_request(DELEGATE_AMOUNT_1))
+        .commit()
+        .expect_success();
+
+    let bids: Bids = builder.get_bids();
+    assert!(bids[&NON_FOUNDER_VALIDATOR_1_PK]
+        .delegators()
+        .get(&BID_ACCOUNT_1_PK)
+        .map(|delegator| delegator.staked_amount().is_zero())
+        .unwrap_or(true));
+}
@@ -0,0 +1,214 @@
+use num_rational::Ratio;
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_AUCTION_DELAY,
+        DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
+        DEFAULT_MINIMUM_DELEGATION_AMOUNT, DEFAULT_PROTOCOL_VERSION, DEFAULT_REDELEGATION_DELAY,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY,
+        DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_execution_engine::{
+    core::engine_state::{
+        genesis::{ExecConfig, GenesisAccount},
+        run_genesis_request::RunGenesisRequest,
+    },
+    shared::motes::Motes,
+};
+use casper_types::{
+    account::{AccountHash, ACCOUNT_HASH_LENGTH},
+    runtime_args,
+    system::{
+        auction::{
+            Bids, SlashInfo, UnbondingPurses, ARG_DELEGATOR, ARG_VALIDATOR,
+            ARG_VALIDATOR_PUBLIC_KEYS, METHOD_SLASH,
+        },
+        mint::TOTAL_SUPPLY_KEY,
+    },
+    Key, PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_DELEGATE: &str = "delegate.wasm";
+const CONTRACT_UNDELEGATE: &str = "undelegate.wasm";
+
+const ARG_AMOUNT: &str = "amount";
+
+const TRANSFER_AMOUNT: u64 = MINIMUM_ACCOUNT_CREATION_BALANCE;
+
+const VALIDATOR_1_STAKE: u64 = 400_000;
+const DELEGATE_AMOUNT_1: u64 = 100_000;
+const UNDELEGATE_AMOUNT_1: u64 = 20_000;
+
+const SLASHING_RATE: Ratio<u64> = Ratio::new_raw(1, 4);
+
+static SYSTEM_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::new([0u8; ACCOUNT_HASH_LENGTH]));
+static VALIDATOR_1: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([3; SecretKey::ED25519_LENGTH]).into());
+static VALIDATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*VALIDATOR_1));
+static DELEGATOR_1: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([5; SecretKey::ED25519_LENGTH]).into());
+static DELEGATOR_1_ADDR: Lazy<AccountHash> = Lazy::new(|| AccountHash::from(&*DELEGATOR_1));
+
+#[ignore]
+#[test]
+fn should_slash_a_fraction_of_validator_and_delegator_stakes() {
+    let accounts = {
+        let validator_1 = GenesisAccount::new(
+            *VALIDATOR_1,
+            *VALIDATOR_1_ADDR,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(VALIDATOR_1_STAKE.into()),
+        );
+
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(validator_1);
+        tmp
+    };
+    let exec_config = ExecConfig::new(
+        accounts,
+        *DEFAULT_WASM_CONFIG,
+        *DEFAULT_SYSTEM_CONFIG,
+        DEFAULT_VALIDATOR_SLOTS,
+        DEFAULT_AUCTION_DELAY,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE,
+        DEFAULT_UNBONDING_DELAY,
+        DEFAULT_REDELEGATION_DELAY,
+        DEFAULT_MINIMUM_DELEGATION_AMOUNT,
+        DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
+        SLASHING_RATE,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    );
+    let run_genesis_request = RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    );
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request);
+
+    for target in &[*SYSTEM_ADDR, *DELEGATOR_1_ADDR] {
+        let transfer_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_TO_ACCOUNT,
+            runtime_args! {
+                "target" => *target,
+                "amount" => U512::from(TRANSFER_AMOUNT)
+            },
+        )
+        .build();
+
+        builder.exec(transfer_request).expect_success().commit();
+    }
+
+    let delegate_request = ExecuteRequestBuilder::standard(
+        *DELEGATOR_1_ADDR,
+        CONTRACT_DELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DELEGATE_AMOUNT_1),
+            ARG_VALIDATOR => *VALIDATOR_1,
+            ARG_DELEGATOR => *DELEGATOR_1,
+        },
+    )
+    .build();
+
+    builder.exec(delegate_request).expect_success().commit();
+
+    let undelegate_request = ExecuteRequestBuilder::standard(
+        *DELEGATOR_1_ADDR,
+        CONTRACT_UNDELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(UNDELEGATE_AMOUNT_1),
+            ARG_VALIDATOR => *VALIDATOR_1,
+            ARG_DELEGATOR => *DELEGATOR_1,
+        },
+    )
+    .build();
+
+    builder.exec(undelegate_request).expect_success().commit();
+
+    let total_supply_before_slashing: U512 =
+        builder.get_value(builder.get_mint_contract_hash(), TOTAL_SUPPLY_KEY);
+
+    let slash_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *SYSTEM_ADDR,
+        builder.get_auction_contract_hash(),
+        METHOD_SLASH,
+        runtime_args! {
+            ARG_VALIDATOR_PUBLIC_KEYS => vec![*VALIDATOR_1]
+        },
+    )
+    .build();
+
+    builder.exec(slash_request).expect_success().commit();
+
+    let validator_slash = U512::from(VALIDATOR_1_STAKE / 4);
+    let delegator_stake_slash = U512::from((DELEGATE_AMOUNT_1 - UNDELEGATE_AMOUNT_1) / 4);
+    let delegator_unbond_slash = U512::from(UNDELEGATE_AMOUNT_1 / 4);
+
+    // The slashed validator keeps the rest of its stake, but is taken out of the auction.
+    let bids: Bids = builder.get_bids();
+    let validator_1_bid = bids.get(&VALIDATOR_1).expect("should have bid");
+    assert!(validator_1_bid.inactive());
+    assert_eq!(
+        *validator_1_bid.staked_amount(),
+        U512::from(VALIDATOR_1_STAKE) - validator_slash
+    );
+    assert_eq!(
+        *validator_1_bid.delegators()[&DELEGATOR_1].staked_amount(),
+        U512::from(DELEGATE_AMOUNT_1 - UNDELEGATE_AMOUNT_1) - delegator_stake_slash
+    );
+
+    let unbond_purses: UnbondingPurses = builder.get_withdraws();
+    let validator_1_unbond_list = &unbond_purses[&VALIDATOR_1];
+    assert_eq!(validator_1_unbond_list.len(), 1);
+    assert_eq!(
+        *validator_1_unbond_list[0].amount(),
+        U512::from(UNDELEGATE_AMOUNT_1) - delegator_unbond_slash
+    );
+
+    let total_supply_after_slashing: U512 =
+        builder.get_value(builder.get_mint_contract_hash(), TOTAL_SUPPLY_KEY);
+    assert_eq!(
+        total_supply_before_slashing - total_supply_after_slashing,
+        validator_slash + delegator_stake_slash + delegator_unbond_slash
+    );
+
+    // The slashed motes are burned, so the bonding purses hold exactly what is still staked or
+    // unbonding.
+    assert_eq!(
+        builder.get_purse_balance(*validator_1_bid.bonding_purse()),
+        *validator_1_bid.staked_amount()
+    );
+    let delegator_1 = &validator_1_bid.delegators()[&DELEGATOR_1];
+    assert_eq!(
+        builder.get_purse_balance(*delegator_1.bonding_purse()),
+        *delegator_1.staked_amount() + *validator_1_unbond_list[0].amount()
+    );
+
+    let era = builder.get_era();
+    let era_info = builder
+        .query(None, Key::EraInfo(era), &[])
+        .expect("should have era info")
+        .as_era_info()
+        .cloned()
+        .expect("should be era info");
+
+    assert_eq!(
+        era_info.slashes(),
+        &vec![
+            SlashInfo::validator(*VALIDATOR_1, validator_slash),
+            SlashInfo::delegator(
+                *DELEGATOR_1,
+                *VALIDATOR_1,
+                delegator_stake_slash + delegator_unbond_slash
+            ),
+        ]
+    );
+}
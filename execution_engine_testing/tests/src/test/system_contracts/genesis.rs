@@ -3,7 +3,9 @@ use once_cell::sync::Lazy;
 use casper_engine_test_support::{
     internal::{
         InMemoryWasmTestBuilder, DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
-        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
+        DEFAULT_MINIMUM_DELEGATION_AMOUNT, DEFAULT_REDELEGATION_DELAY,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SLASHING_RATE, DEFAULT_SYSTEM_CONFIG,
         DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
     },
     AccountHash,
@@ -66,6 +68,10 @@ fn should_run_genesis() {
     let locked_funds_period = DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
     let round_seigniorage_rate = DEFAULT_ROUND_SEIGNIORAGE_RATE;
     let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let redelegation_delay = DEFAULT_REDELEGATION_DELAY;
+    let minimum_delegation_amount = DEFAULT_MINIMUM_DELEGATION_AMOUNT;
+    let max_delegators_per_validator = DEFAULT_MAX_DELEGATORS_PER_VALIDATOR;
+    let slashing_rate = DEFAULT_SLASHING_RATE;
     let genesis_timestamp = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
 
     let exec_config = ExecConfig::new(
@@ -77,6 +83,10 @@ fn should_run_genesis() {
         locked_funds_period,
         round_seigniorage_rate,
         unbonding_delay,
+        redelegation_delay,
+        minimum_delegation_amount,
+        max_delegators_per_validator,
+        slashing_rate,
         genesis_timestamp,
     );
     let run_genesis_request =
@@ -135,6 +145,10 @@ fn should_track_total_token_supply_in_mint() {
     let locked_funds_period = DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
     let round_seigniorage_rate = DEFAULT_ROUND_SEIGNIORAGE_RATE;
     let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let redelegation_delay = DEFAULT_REDELEGATION_DELAY;
+    let minimum_delegation_amount = DEFAULT_MINIMUM_DELEGATION_AMOUNT;
+    let max_delegators_per_validator = DEFAULT_MAX_DELEGATORS_PER_VALIDATOR;
+    let slashing_rate = DEFAULT_SLASHING_RATE;
     let genesis_tiemstamp = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
     let ee_config = ExecConfig::new(
         accounts.clone(),
@@ -145,6 +159,10 @@ fn should_track_total_token_supply_in_mint() {
         locked_funds_period,
         round_seigniorage_rate,
         unbonding_delay,
+        redelegation_delay,
+        minimum_delegation_amount,
+        max_delegators_per_validator,
+        slashing_rate,
         genesis_tiemstamp,
     );
     let run_genesis_request =
@@ -1,7 +1,8 @@
 use std::collections::BTreeMap;
 
 use casper_engine_test_support::internal::{
-    InMemoryWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    InMemoryWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
+    DEFAULT_MINIMUM_DELEGATION_AMOUNT, DEFAULT_REDELEGATION_DELAY, DEFAULT_RUN_GENESIS_REQUEST,
     DEFAULT_UNBONDING_DELAY, DEFAULT_WASM_CONFIG,
 };
 
@@ -23,13 +24,19 @@ use casper_execution_engine::{
     },
 };
 use casper_types::{
+    bytesrepr::ToBytes,
+    contracts::NamedKeys,
     system::{
         auction::{
-            AUCTION_DELAY_KEY, LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
+            Bid, Bids, UnbondingPurse, UnbondingPurses, AUCTION_DELAY_KEY, BIDS_KEY,
+            LOCKED_FUNDS_PERIOD_KEY, MAX_DELEGATORS_PER_VALIDATOR_KEY, METHOD_REDELEGATE,
+            MINIMUM_DELEGATION_AMOUNT_KEY, REDELEGATION_DELAY_KEY, UNBONDING_DELAY_KEY,
+            UNBONDING_PURSES_KEY, VALIDATOR_SLOTS_KEY,
         },
         mint::ROUND_SEIGNIORAGE_RATE_KEY,
     },
-    CLValue, ProtocolVersion, U512,
+    AccessRights, CLTyped, CLValue, Contract, EntryPoint, EntryPoints, Key, ProtocolVersion,
+    PublicKey, SecretKey, URef, U512,
 };
 use num_rational::Ratio;
 
@@ -571,6 +578,190 @@ fn should_upgrade_only_unbonding_delay() {
     );
 }
 
+#[ignore]
+#[test]
+fn should_add_redelegation_to_legacy_auction_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let legacy_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 2);
+
+    // Recreate an auction contract installed before redelegation was introduced.
+    let auction_hash = builder.get_auction_contract_hash();
+    let auction_contract = builder
+        .get_contract(auction_hash)
+        .expect("auction should exist");
+    let mut legacy_named_keys = auction_contract.named_keys().clone();
+    legacy_named_keys.remove(REDELEGATION_DELAY_KEY);
+    let legacy_entry_points: Vec<EntryPoint> = auction_contract
+        .entry_points()
+        .clone()
+        .take_entry_points()
+        .into_iter()
+        .filter(|entry_point| entry_point.name() != METHOD_REDELEGATE)
+        .collect();
+    let legacy_auction_contract = Contract::new(
+        auction_contract.contract_package_hash(),
+        auction_contract.contract_wasm_hash(),
+        legacy_named_keys,
+        EntryPoints::from(legacy_entry_points),
+        auction_contract.protocol_version(),
+    );
+
+    let mut update_map = BTreeMap::new();
+    update_map.insert(
+        Key::from(auction_hash),
+        StoredValue::from(legacy_auction_contract),
+    );
+
+    let mut legacy_upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(legacy_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_global_state_update(update_map)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut legacy_upgrade_request)
+        .expect_upgrade_success();
+
+    let new_redelegation_delay = DEFAULT_REDELEGATION_DELAY + 5;
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(legacy_protocol_version)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_redelegation_delay(new_redelegation_delay)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    let auction_contract = builder
+        .get_contract(auction_hash)
+        .expect("auction should exist");
+    assert!(auction_contract.has_entry_point(METHOD_REDELEGATE));
+
+    let redelegation_delay_key = auction_contract.named_keys()[REDELEGATION_DELAY_KEY];
+    let after_redelegation_delay: u64 = builder
+        .query(None, redelegation_delay_key, &[])
+        .expect("should have redelegation delay")
+        .as_cl_value()
+        .expect("should be a CLValue")
+        .clone()
+        .into_t()
+        .expect("should be u64");
+
+    assert_eq!(new_redelegation_delay, after_redelegation_delay);
+}
+
+#[ignore]
+#[test]
+fn should_add_delegation_limits_to_legacy_auction_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let legacy_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 2);
+
+    // Recreate an auction contract installed before delegation limits were introduced.
+    let auction_hash = builder.get_auction_contract_hash();
+    let auction_contract = builder
+        .get_contract(auction_hash)
+        .expect("auction should exist");
+    let mut legacy_named_keys = auction_contract.named_keys().clone();
+    legacy_named_keys.remove(MINIMUM_DELEGATION_AMOUNT_KEY);
+    legacy_named_keys.remove(MAX_DELEGATORS_PER_VALIDATOR_KEY);
+    let legacy_auction_contract = Contract::new(
+        auction_contract.contract_package_hash(),
+        auction_contract.contract_wasm_hash(),
+        legacy_named_keys,
+        auction_contract.entry_points().clone(),
+        auction_contract.protocol_version(),
+    );
+
+    let mut update_map = BTreeMap::new();
+    update_map.insert(
+        Key::from(auction_hash),
+        StoredValue::from(legacy_auction_contract),
+    );
+
+    let mut legacy_upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(legacy_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_global_state_update(update_map)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut legacy_upgrade_request)
+        .expect_upgrade_success();
+
+    let new_minimum_delegation_amount = DEFAULT_MINIMUM_DELEGATION_AMOUNT + 1_000;
+    let new_max_delegators_per_validator = DEFAULT_MAX_DELEGATORS_PER_VALIDATOR / 2;
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(legacy_protocol_version)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_minimum_delegation_amount(new_minimum_delegation_amount)
+        .with_new_max_delegators_per_validator(new_max_delegators_per_validator)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    let auction_contract = builder
+        .get_contract(auction_hash)
+        .expect("auction should exist");
+
+    let minimum_delegation_amount_key =
+        auction_contract.named_keys()[MINIMUM_DELEGATION_AMOUNT_KEY];
+    let after_minimum_delegation_amount: u64 = builder
+        .query(None, minimum_delegation_amount_key, &[])
+        .expect("should have minimum delegation amount")
+        .as_cl_value()
+        .expect("should be a CLValue")
+        .clone()
+        .into_t()
+        .expect("should be u64");
+
+    let max_delegators_per_validator_key =
+        auction_contract.named_keys()[MAX_DELEGATORS_PER_VALIDATOR_KEY];
+    let after_max_delegators_per_validator: u32 = builder
+        .query(None, max_delegators_per_validator_key, &[])
+        .expect("should have max delegators per validator")
+        .as_cl_value()
+        .expect("should be a CLValue")
+        .clone()
+        .into_t()
+        .expect("should be u32");
+
+    assert_ne!(
+        minimum_delegation_amount_key,
+        max_delegators_per_validator_key
+    );
+    assert_eq!(
+        new_minimum_delegation_amount,
+        after_minimum_delegation_amount
+    );
+    assert_eq!(
+        new_max_delegators_per_validator,
+        after_max_delegators_per_validator
+    );
+}
+
 #[ignore]
 #[test]
 fn should_apply_global_state_upgrade() {
@@ -634,3 +825,192 @@ fn should_apply_global_state_upgrade() {
         "Should have modified locked funds period"
     );
 }
+
+#[ignore]
+#[test]
+fn should_migrate_legacy_auction_bids_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let legacy_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 2);
+
+    let validator_public_key: PublicKey =
+        SecretKey::ed25519([201; SecretKey::ED25519_LENGTH]).into();
+    let bonding_purse = URef::new([202; 32], AccessRights::READ_ADD_WRITE);
+    let bid = Bid::unlocked(validator_public_key, bonding_purse, U512::from(1_000), 10);
+    let unbonding_purse = UnbondingPurse::new(
+        bonding_purse,
+        validator_public_key,
+        validator_public_key,
+        0,
+        U512::from(500),
+        None,
+    );
+
+    // Recreate the layout used before bids were stored under their own keys.  A legacy
+    // `BTreeMap<PublicKey, Bid>` serializes to the same bytes as a `Vec` of the current `Bid`s,
+    // and a legacy `UnbondingPurse` to the same bytes as a tuple of its fields.
+    let legacy_bids = CLValue::from_components(
+        Bids::cl_type(),
+        vec![bid.clone()].to_bytes().expect("should serialize bids"),
+    );
+    let mut legacy_unbonding_purses = BTreeMap::new();
+    legacy_unbonding_purses.insert(
+        validator_public_key,
+        vec![(
+            bonding_purse,
+            validator_public_key,
+            validator_public_key,
+            (0u64, U512::from(500)),
+        )],
+    );
+    let legacy_unbonding_purses = CLValue::from_components(
+        UnbondingPurses::cl_type(),
+        legacy_unbonding_purses
+            .to_bytes()
+            .expect("should serialize unbonding purses"),
+    );
+
+    let bids_uref = URef::new([203; 32], AccessRights::READ_ADD_WRITE);
+    let unbonding_purses_uref = URef::new([204; 32], AccessRights::READ_ADD_WRITE);
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let mut auction_contract = builder
+        .get_contract(auction_hash)
+        .expect("auction should exist");
+    let mut legacy_named_keys = NamedKeys::new();
+    legacy_named_keys.insert(BIDS_KEY.to_string(), bids_uref.into());
+    legacy_named_keys.insert(
+        UNBONDING_PURSES_KEY.to_string(),
+        unbonding_purses_uref.into(),
+    );
+    auction_contract.named_keys_append(&mut legacy_named_keys);
+
+    let mut update_map = BTreeMap::new();
+    update_map.insert(Key::from(bids_uref), StoredValue::from(legacy_bids));
+    update_map.insert(
+        Key::from(unbonding_purses_uref),
+        StoredValue::from(legacy_unbonding_purses),
+    );
+    update_map.insert(Key::from(auction_hash), StoredValue::from(auction_contract));
+
+    let mut legacy_upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(legacy_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_global_state_update(update_map)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut legacy_upgrade_request)
+        .expect_upgrade_success();
+
+    assert!(!builder.get_bids().contains_key(&validator_public_key));
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(legacy_protocol_version)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    let bids = builder.get_bids();
+    assert_eq!(bids.get(&validator_public_key), Some(&bid));
+
+    let withdraws = builder.get_withdraws();
+    assert_eq!(
+        withdraws.get(&validator_public_key),
+        Some(&vec![unbonding_purse])
+    );
+
+    let named_keys = builder
+        .get_contract(auction_hash)
+        .expect("auction should exist")
+        .named_keys()
+        .clone();
+    assert!(!named_keys.contains_key(BIDS_KEY));
+    assert!(!named_keys.contains_key(UNBONDING_PURSES_KEY));
+}
+
+#[ignore]
+#[test]
+fn should_migrate_legacy_purse_balances_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let legacy_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 2);
+
+    let mint_hash = builder.get_mint_contract_hash();
+    let mut mint_contract = builder.get_contract(mint_hash).expect("mint should exist");
+
+    // Purses created at genesis keep their balances out of the mint's named keys.
+    assert!(mint_contract
+        .named_keys()
+        .keys()
+        .all(|name| URef::from_formatted_str(name).is_err()));
+
+    // Recreate the layout used before balances were stored under their own keys, where the mint
+    // held each balance under a `URef` named after the purse.
+    let purse_uref = URef::new([205; 32], AccessRights::READ_ADD_WRITE);
+    let balance_uref = URef::new([206; 32], AccessRights::READ_ADD_WRITE);
+    let balance = U512::from(1_000);
+
+    let purse_name = purse_uref.remove_access_rights().to_formatted_string();
+    let mut legacy_named_keys = NamedKeys::new();
+    legacy_named_keys.insert(purse_name.clone(), balance_uref.into());
+    mint_contract.named_keys_append(&mut legacy_named_keys);
+
+    let mut update_map = BTreeMap::new();
+    update_map.insert(
+        Key::from(balance_uref),
+        StoredValue::from(CLValue::from_t(balance).expect("should create a CLValue")),
+    );
+    update_map.insert(Key::from(mint_hash), StoredValue::from(mint_contract));
+
+    let mut legacy_upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(legacy_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_global_state_update(update_map)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut legacy_upgrade_request)
+        .expect_upgrade_success();
+
+    assert!(builder
+        .query(None, Key::Balance(purse_uref.addr()), &[])
+        .is_err());
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(legacy_protocol_version)
+        .with_new_protocol_version(new_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    assert_eq!(builder.get_purse_balance(purse_uref), balance);
+
+    let named_keys = builder
+        .get_contract(mint_hash)
+        .expect("mint should exist")
+        .named_keys()
+        .clone();
+    assert!(!named_keys.contains_key(&purse_name));
+}
@@ -19,6 +19,7 @@ use tracing::{error, info, trace};
 
 use crate::config;
 use casper_node::{
+    crypto::hash::Digest,
     logging,
     reactor::{initializer, joiner, validator, ReactorExit, Runner},
     setup_signal_hooks,
@@ -74,6 +75,43 @@ pub enum Cli {
         #[structopt(long)]
         new_config: PathBuf,
     },
+    /// Export the global state under a state root hash to a snapshot file.
+    ///
+    /// The node should be stopped while exporting.
+    ExportGlobalState {
+        /// Path to configuration file.
+        config: PathBuf,
+        /// Hex-encoded state root hash of the global state to export.
+        #[structopt(long, parse(try_from_str = Digest::from_hex))]
+        state_root_hash: Digest,
+        /// Path of the snapshot file to write.
+        #[structopt(long)]
+        output: PathBuf,
+    },
+    /// Import the global state from a snapshot file.
+    ///
+    /// The snapshot must be of the given state root hash, which should be that of a trusted block.
+    /// The node should be stopped while importing.
+    ImportGlobalState {
+        /// Path to configuration file.
+        config: PathBuf,
+        /// Hex-encoded state root hash of the snapshot.
+        #[structopt(long, parse(try_from_str = Digest::from_hex))]
+        state_root_hash: Digest,
+        /// Path of the snapshot file to read.
+        #[structopt(long)]
+        input: PathBuf,
+    },
+    /// Prune the global state, deleting the trie nodes which are only reachable from the state
+    /// root hashes of blocks outside the retention window.
+    ///
+    /// The global state of the most recent blocks and of all switch blocks is retained, as
+    /// configured by `contract_runtime.pruning_retained_blocks`.  The node should be stopped while
+    /// pruning.
+    PruneGlobalState {
+        /// Path to configuration file.
+        config: PathBuf,
+    },
 }
 
 #[derive(Debug)]
@@ -245,6 +283,34 @@ impl Cli {
                 info!(version = %env!("CARGO_PKG_VERSION"), "migrating data");
                 casper_node::migrate_data(WithDir::new(old_root, old_config), new_config)?;
             }
+            Cli::ExportGlobalState {
+                config,
+                state_root_hash,
+                output,
+            } => {
+                let config = Self::init(&config, vec![])?;
+                info!(%state_root_hash, "exporting global state");
+                casper_node::export_global_state(config, state_root_hash, &output)?;
+            }
+            Cli::ImportGlobalState {
+                config,
+                state_root_hash,
+                input,
+            } => {
+                let config = Self::init(&config, vec![])?;
+                info!(%state_root_hash, "importing global state");
+                casper_node::import_global_state(config, state_root_hash, &input)?;
+            }
+            Cli::PruneGlobalState { config } => {
+                let config = Self::init(&config, vec![])?;
+                info!("pruning global state");
+                let progress = casper_node::prune_global_state(config)?;
+                info!(
+                    nodes_marked = progress.nodes_marked,
+                    nodes_deleted = progress.nodes_deleted,
+                    "pruned global state"
+                );
+            }
         }
 
         Ok(())
@@ -19,8 +19,10 @@ use casper_execution_engine::{
     core::engine_state::{
         deploy_item::DeployItem,
         execute_request::ExecuteRequest,
+        execution_effect::ExecutionEffect,
         execution_result::{ExecutionResult as EngineExecutionResult, ExecutionResults},
         step::{EvictItem, RewardItem, SlashItem, StepRequest, StepResult},
+        transfer_scheduler::ExecuteTransfersResult,
     },
     storage::global_state::CommitResult,
 };
@@ -267,6 +269,9 @@ impl BlockExecutor {
                     .event(|result| Event::RunStepResult { state, result });
             }
         };
+        if next_deploy.session().is_transfer() {
+            return self.execute_transfers(effect_builder, state, next_deploy);
+        }
         let deploy_hash = *next_deploy.id();
         let deploy_header = next_deploy.header().clone();
         let deploy_item = DeployItem::from(next_deploy);
@@ -294,6 +299,51 @@ impl BlockExecutor {
             })
     }
 
+    /// Executes `first_transfer` along with the transfers immediately following it in
+    /// `state.remaining_deploys`.
+    ///
+    /// The contract runtime commits the effects of the transfers itself, executing those which
+    /// don't conflict with each other in parallel.
+    fn execute_transfers<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        mut state: Box<State>,
+        first_transfer: Deploy,
+    ) -> Effects<Event> {
+        let mut transfers = vec![first_transfer];
+        while state
+            .remaining_deploys
+            .front()
+            .map_or(false, |deploy| deploy.session().is_transfer())
+        {
+            transfers.extend(state.remaining_deploys.pop_front());
+        }
+
+        let deploy_hashes_and_headers = transfers
+            .iter()
+            .map(|transfer| (*transfer.id(), transfer.header().clone()))
+            .collect();
+        let deploy_items = transfers
+            .into_iter()
+            .map(|transfer| Ok(DeployItem::from(transfer)))
+            .collect();
+        let execute_request = ExecuteRequest::new(
+            state.state_root_hash.into(),
+            state.finalized_block.timestamp().millis(),
+            deploy_items,
+            self.protocol_version,
+            state.finalized_block.proposer(),
+        );
+
+        effect_builder
+            .request_execute_transfers(execute_request)
+            .event(move |result| Event::TransfersExecutionResult {
+                state,
+                deploy_hashes_and_headers,
+                result,
+            })
+    }
+
     fn handle_get_deploys_result<REv: ReactorEventT>(
         &mut self,
         effect_builder: EffectBuilder<REv>,
@@ -378,31 +428,8 @@ impl BlockExecutor {
             .into_iter()
             .exactly_one()
             .expect("should only be one exec result");
-        let execution_result = ExecutionResult::from(&ee_execution_result);
-        let _ = state
-            .execution_results
-            .insert(deploy_hash, (deploy_header, execution_result));
-
-        let execution_effect = match ee_execution_result {
-            EngineExecutionResult::Success { effect, cost, .. } => {
-                // We do want to see the deploy hash and cost in the logs.
-                // We don't need to see the effects in the logs.
-                debug!(?deploy_hash, %cost, "execution succeeded");
-                effect
-            }
-            EngineExecutionResult::Failure {
-                error,
-                effect,
-                cost,
-                ..
-            } => {
-                // Failure to execute a contract is a user error, not a system error.
-                // We do want to see the deploy hash, error, and cost in the logs.
-                // We don't need to see the effects in the logs.
-                debug!(?deploy_hash, ?error, %cost, "execution failure");
-                effect
-            }
-        };
+        let execution_effect =
+            record_execution_result(&mut state, deploy_hash, deploy_header, ee_execution_result);
         effect_builder
             .request_commit(state.state_root_hash, execution_effect.transforms)
             .event(|commit_result| Event::CommitExecutionEffects {
@@ -559,19 +586,67 @@ impl<REv: ReactorEventT> Component<REv> for BlockExecutor {
                 }
             }
 
+            Event::TransfersExecutionResult {
+                mut state,
+                deploy_hashes_and_headers,
+                result,
+            } => {
+                trace!(?state, ?result, "transfers execution result");
+                match result {
+                    Ok(ExecuteTransfersResult::Success {
+                        post_state_hash,
+                        execution_results,
+                    }) => {
+                        debug!(?post_state_hash, "transfers committed");
+                        assert_eq!(
+                            deploy_hashes_and_headers.len(),
+                            execution_results.len(),
+                            "should have one exec result per transfer"
+                        );
+                        for ((deploy_hash, deploy_header), ee_execution_result) in
+                            deploy_hashes_and_headers.into_iter().zip(execution_results)
+                        {
+                            // The effects have already been committed.
+                            let _ = record_execution_result(
+                                &mut state,
+                                deploy_hash,
+                                deploy_header,
+                                ee_execution_result,
+                            );
+                        }
+                        state.state_root_hash = post_state_hash.into();
+                        self.execute_next_deploy_or_create_block(effect_builder, state)
+                    }
+                    _ => {
+                        // As with a failed commit, we'll not be able to execute the next block.
+                        error!(
+                            ?result,
+                            "executing transfers failed - internal contract runtime error"
+                        );
+                        panic!("unable to execute transfers");
+                    }
+                }
+            }
+
             Event::RunStepResult { mut state, result } => {
                 trace!(?result, "run step result");
                 match result {
                     Ok(StepResult::Success {
                         post_state_hash,
                         next_era_validators,
+                        execution_effect,
                     }) => {
+                        let era_id = state.finalized_block.era_id();
+                        let mut effects = effect_builder
+                            .announce_step_success(era_id, (&execution_effect).into())
+                            .ignore();
                         state.state_root_hash = post_state_hash.into();
-                        self.finalize_block_execution(
+                        effects.extend(self.finalize_block_execution(
                             effect_builder,
                             state,
                             Some(next_era_validators),
-                        )
+                        ));
+                        effects
                     }
                     _ => {
                         // When step fails, the auction process is broken and we should panic.
@@ -583,3 +658,37 @@ impl<REv: ReactorEventT> Component<REv> for BlockExecutor {
         }
     }
 }
+
+/// Records the result of executing a deploy in `state`, returning the effects of the execution.
+fn record_execution_result(
+    state: &mut State,
+    deploy_hash: DeployHash,
+    deploy_header: DeployHeader,
+    ee_execution_result: EngineExecutionResult,
+) -> ExecutionEffect {
+    let execution_result = ExecutionResult::from(&ee_execution_result);
+    let _ = state
+        .execution_results
+        .insert(deploy_hash, (deploy_header, execution_result));
+
+    match ee_execution_result {
+        EngineExecutionResult::Success { effect, cost, .. } => {
+            // We do want to see the deploy hash and cost in the logs.
+            // We don't need to see the effects in the logs.
+            debug!(?deploy_hash, %cost, "execution succeeded");
+            effect
+        }
+        EngineExecutionResult::Failure {
+            error,
+            effect,
+            cost,
+            ..
+        } => {
+            // Failure to execute a contract is a user error, not a system error.
+            // We do want to see the deploy hash, error, and cost in the logs.
+            // We don't need to see the effects in the logs.
+            debug!(?deploy_hash, ?error, %cost, "execution failure");
+            effect
+        }
+    }
+}
@@ -8,7 +8,10 @@ use derive_more::From;
 use casper_execution_engine::{
     core::{
         engine_state,
-        engine_state::{step::StepResult, ExecutionResults, RootNotFound},
+        engine_state::{
+            step::StepResult, transfer_scheduler::ExecuteTransfersResult, ExecutionResults,
+            RootNotFound,
+        },
     },
     storage::global_state::CommitResult,
 };
@@ -57,6 +60,15 @@ pub enum Event {
         /// Result of deploy execution.
         result: Result<ExecutionResults, RootNotFound>,
     },
+    /// The result of executing a run of transfers and committing their effects.
+    TransfersExecutionResult {
+        /// State of this request.
+        state: Box<State>,
+        /// The IDs and headers of the transfers executed, in order.
+        deploy_hashes_and_headers: Vec<(DeployHash, DeployHeader)>,
+        /// Result of executing the transfers.
+        result: Result<ExecuteTransfersResult, engine_state::Error>,
+    },
     /// The result of committing a single set of transforms after executing a single deploy.
     CommitExecutionEffects {
         /// State of this request.
@@ -122,6 +134,18 @@ impl Display for Event {
                 state.finalized_block.height(),
                 state.state_root_hash
             ),
+            Event::TransfersExecutionResult {
+                state,
+                deploy_hashes_and_headers,
+                ..
+            } => write!(
+                f,
+                "execution result for {} transfers of finalized block with height {} with \
+                pre-state hash {}",
+                deploy_hashes_and_headers.len(),
+                state.finalized_block.height(),
+                state.state_root_hash
+            ),
             Event::CommitExecutionEffects {
                 state,
                 commit_result: Ok(CommitResult::Success { state_root, .. }),
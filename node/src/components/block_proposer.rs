@@ -2,6 +2,11 @@
 //!
 //! The block proposer stores deploy hashes in memory, tracking their suitability for inclusion into
 //! a new block. Upon request, it returns a list of candidates that can be included.
+//!
+//! It can also be asked for the deploys it currently holds as pending, and for the status of an
+//! individual deploy, allowing users to find out why a deploy has not been included in a block yet.
+//! Only the proto blocks known as of the last proto block proposed by this node are taken into
+//! account, so deploys included in proto blocks are only reported as such by validators.
 
 mod deploy_sets;
 mod event;
@@ -13,6 +18,7 @@ mod tests;
 use std::{
     collections::{HashMap, HashSet},
     convert::Infallible,
+    ops::Bound::{Excluded, Unbounded},
     time::Duration,
 };
 
@@ -23,10 +29,14 @@ use tracing::{debug, error, info, trace, warn};
 use crate::{
     components::Component,
     effect::{
+        announcements::BlockProposerAnnouncement,
         requests::{BlockProposerRequest, ProtoBlockRequest, StateStoreRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects,
     },
-    types::{chainspec::DeployConfig, Chainspec, DeployHash, DeployHeader, ProtoBlock, Timestamp},
+    types::{
+        chainspec::DeployConfig, Chainspec, DeployHash, DeployHeader, DeployStatus, PendingDeploy,
+        PendingDeployKind, PendingDeploysPage, ProtoBlock, Timestamp,
+    },
     NodeRng,
 };
 use casper_execution_engine::shared::gas::Gas;
@@ -123,7 +133,12 @@ impl BlockProposer {
 
 impl<REv> Component<REv> for BlockProposer
 where
-    REv: From<Event> + From<StorageRequest> + From<StateStoreRequest> + Send + 'static,
+    REv: From<Event>
+        + From<StorageRequest>
+        + From<StateStoreRequest>
+        + From<BlockProposerAnnouncement>
+        + Send
+        + 'static,
 {
     type Event = Event;
     type ConstructionError = Infallible;
@@ -159,6 +174,7 @@ where
                     deploy_config: *deploy_config,
                     state_key: state_key.clone(),
                     request_queue: Default::default(),
+                    proto_block_deploys: Default::default(),
                 };
 
                 // Replay postponed events onto new state.
@@ -214,6 +230,12 @@ struct BlockProposerReady {
     state_key: Vec<u8>,
     /// The queue of requests awaiting being handled.
     request_queue: RequestQueue,
+    /// The deploys included in not yet finalized proto blocks, as of the proto block most recently
+    /// proposed by this node: those of its ancestors, and its own.
+    ///
+    /// This is only populated on validators, and doesn't cover proto blocks proposed by others
+    /// after this node's latest proposal.
+    proto_block_deploys: HashSet<DeployHash>,
 }
 
 impl BlockProposerReady {
@@ -223,7 +245,7 @@ impl BlockProposerReady {
         event: Event,
     ) -> Effects<Event>
     where
-        REv: Send + From<StateStoreRequest>,
+        REv: Send + From<StateStoreRequest> + From<BlockProposerAnnouncement>,
     {
         match event {
             Event::Request(BlockProposerRequest::RequestProtoBlock(request)) => {
@@ -250,19 +272,37 @@ impl BlockProposerReady {
                         .ignore()
                 }
             }
+            Event::Request(BlockProposerRequest::GetPendingDeploys {
+                cursor,
+                limit,
+                responder,
+            }) => responder
+                .respond(self.pending_deploys(Timestamp::now(), cursor, limit))
+                .ignore(),
+            Event::Request(BlockProposerRequest::GetDeployStatus {
+                deploy_hash,
+                responder,
+            }) => responder
+                .respond(self.deploy_status(&deploy_hash, Timestamp::now()))
+                .ignore(),
             Event::BufferDeploy { hash, deploy_type } => {
                 self.add_deploy_or_transfer(Timestamp::now(), hash, *deploy_type);
                 Effects::new()
             }
             Event::Prune => {
-                let pruned = self.prune(Timestamp::now());
-                debug!(%pruned, "pruned deploys from buffer");
+                let expired = self.prune(Timestamp::now());
+                debug!(expired = %expired.len(), "pruned deploys from buffer");
 
                 // After pruning, we store a state snapshot.
                 let mut effects = effect_builder
                     .save_state(self.state_key.clone().into(), self.sets.clone())
                     .ignore();
 
+                // Announce the pending deploys which expired before being included in a block.
+                if !expired.is_empty() {
+                    effects.extend(effect_builder.announce_expired_deploys(expired).ignore());
+                }
+
                 // Re-trigger timer after `PRUNE_INTERVAL`.
                 effects.extend(
                     effect_builder
@@ -348,6 +388,7 @@ impl BlockProposerReady {
         I: IntoIterator<Item = DeployHash>,
     {
         for deploy_hash in deploys.into_iter() {
+            let _ = self.proto_block_deploys.remove(&deploy_hash);
             match self.sets.pending.remove(&deploy_hash) {
                 Some(deploy_type) => {
                     self.sets
@@ -487,11 +528,92 @@ impl BlockProposerReady {
             }
         }
 
+        self.proto_block_deploys = past_deploys;
+        self.proto_block_deploys
+            .extend(wasm_deploys.iter().chain(transfers.iter()).copied());
+
         ProtoBlock::new(wasm_deploys, transfers, random_bit)
     }
 
-    /// Prunes expired deploy information from the BlockProposer, returns the total deploys pruned.
-    fn prune(&mut self, current_instant: Timestamp) -> usize {
+    /// Returns a page of at most `limit` deploys pending inclusion in a block, ordered by deploy
+    /// hash and starting after `cursor`.
+    fn pending_deploys(
+        &self,
+        current_instant: Timestamp,
+        cursor: Option<DeployHash>,
+        limit: usize,
+    ) -> PendingDeploysPage {
+        let lower_bound = cursor.map_or(Unbounded, Excluded);
+        let mut pending: Vec<_> = self
+            .sets
+            .pending
+            .range((lower_bound, Unbounded))
+            .filter(|(hash, deploy_type)| {
+                !deploy_type.header().expired(current_instant)
+                    && !self.proto_block_deploys.contains(hash)
+            })
+            .take(limit + 1)
+            .collect();
+        let next_cursor = if pending.len() > limit && limit > 0 {
+            Some(*pending[limit - 1].0)
+        } else {
+            None
+        };
+        pending.truncate(limit);
+
+        let deploys = pending
+            .into_iter()
+            .map(|(hash, deploy_type)| {
+                let header = deploy_type.header();
+                let kind = if deploy_type.is_transfer() {
+                    PendingDeployKind::Transfer
+                } else {
+                    PendingDeployKind::Wasm
+                };
+                let unmet_dependencies = header
+                    .dependencies()
+                    .iter()
+                    .filter(|dep| !self.contains_finalized(dep))
+                    .copied()
+                    .collect();
+                PendingDeploy {
+                    deploy_hash: *hash,
+                    kind,
+                    expires: header.expires(),
+                    gas_price: header.gas_price(),
+                    unmet_dependencies,
+                }
+            })
+            .collect();
+        PendingDeploysPage {
+            deploys,
+            next_cursor,
+        }
+    }
+
+    /// Returns the status of the given deploy as known by the block proposer.
+    ///
+    /// Deploys which were pruned after expiring or being finalized are unknown.  Deploys are only
+    /// reported as `InProtoBlock` if they are included in `proto_block_deploys`.
+    fn deploy_status(&self, deploy_hash: &DeployHash, current_instant: Timestamp) -> DeployStatus {
+        if self.contains_finalized(deploy_hash) {
+            return DeployStatus::Finalized;
+        }
+        if self.proto_block_deploys.contains(deploy_hash) {
+            return DeployStatus::InProtoBlock;
+        }
+        match self.sets.pending.get(deploy_hash) {
+            Some(deploy_type) if deploy_type.header().expired(current_instant) => {
+                DeployStatus::Expired
+            }
+            Some(_) => DeployStatus::Pending,
+            None => DeployStatus::Unknown,
+        }
+    }
+
+    /// Prunes expired deploy information from the BlockProposer, returns the hashes of the pending
+    /// deploys pruned.
+    fn prune(&mut self, current_instant: Timestamp) -> Vec<DeployHash> {
         self.sets.prune(current_instant)
     }
 
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt::{self, Display, Formatter},
 };
 
@@ -12,8 +12,8 @@ use crate::types::{Chainspec, DeployHash, DeployHeader, Timestamp};
 /// Stores the internal state of the BlockProposer.
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
 pub struct BlockProposerDeploySets {
-    /// The collection of deploys pending for inclusion in a block.
-    pub(super) pending: HashMap<DeployHash, DeployType>,
+    /// The collection of deploys pending for inclusion in a block, ordered by deploy hash.
+    pub(super) pending: BTreeMap<DeployHash, DeployType>,
     /// The deploys that have already been included in a finalized block.
     pub(super) finalized_deploys: HashMap<DeployHash, DeployHeader>,
     /// The next block height we expect to be finalized.
@@ -28,7 +28,7 @@ pub struct BlockProposerDeploySets {
 
 impl Default for BlockProposerDeploySets {
     fn default() -> Self {
-        let pending = HashMap::new();
+        let pending = BTreeMap::new();
         let finalized_deploys = Default::default();
         let next_finalized = Default::default();
         let finalization_queue = Default::default();
@@ -74,12 +74,12 @@ pub fn create_storage_key(chainspec: &Chainspec) -> Vec<u8> {
 }
 
 impl BlockProposerDeploySets {
-    /// Prunes expired deploy information from the BlockProposerState, returns the total deploys
-    /// pruned
-    pub(crate) fn prune(&mut self, current_instant: Timestamp) -> usize {
-        let pending = prune_pending_deploys(&mut self.pending, current_instant);
-        let finalized = prune_deploys(&mut self.finalized_deploys, current_instant);
-        pending + finalized
+    /// Prunes expired deploy information from the BlockProposerState, returns the hashes of the
+    /// pending deploys which were pruned
+    pub(crate) fn prune(&mut self, current_instant: Timestamp) -> Vec<DeployHash> {
+        let expired_pending = prune_pending_deploys(&mut self.pending, current_instant);
+        let _ = prune_deploys(&mut self.finalized_deploys, current_instant);
+        expired_pending
     }
 }
 
@@ -95,12 +95,18 @@ pub(super) fn prune_deploys(
 }
 
 /// Prunes expired deploy information from an individual pending deploy collection, returns the
-/// total deploys pruned
+/// hashes of the deploys pruned
 pub(super) fn prune_pending_deploys(
-    deploys: &mut HashMap<DeployHash, DeployType>,
+    deploys: &mut BTreeMap<DeployHash, DeployType>,
     current_instant: Timestamp,
-) -> usize {
-    let initial_len = deploys.len();
-    deploys.retain(|_hash, wrapper| !wrapper.header().expired(current_instant));
-    initial_len - deploys.len()
+) -> Vec<DeployHash> {
+    let expired: Vec<DeployHash> = deploys
+        .iter()
+        .filter(|(_hash, wrapper)| wrapper.header().expired(current_instant))
+        .map(|(hash, _wrapper)| *hash)
+        .collect();
+    for hash in &expired {
+        let _ = deploys.remove(hash);
+    }
+    expired
 }
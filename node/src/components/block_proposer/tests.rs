@@ -97,6 +97,7 @@ fn create_test_proposer() -> BlockProposerReady {
         state_key: b"block-proposer-test".to_vec(),
         request_queue: Default::default(),
         unhandled_finalized: Default::default(),
+        proto_block_deploys: Default::default(),
     }
 }
 
@@ -302,7 +303,7 @@ fn should_successfully_prune() {
 
     // test for retained values
     let pruned = proposer.prune(test_time);
-    assert_eq!(pruned, 0);
+    assert!(pruned.is_empty());
 
     assert_eq!(proposer.sets.pending.len(), 3);
     assert_eq!(proposer.sets.finalized_deploys.len(), 1);
@@ -310,7 +311,10 @@ fn should_successfully_prune() {
 
     // now move the clock to make some things expire
     let pruned = proposer.prune(expired_time);
-    assert_eq!(pruned, 3);
+    // only the pending deploys are reported, deploy1 was already finalized
+    assert_eq!(pruned.len(), 2);
+    assert!(pruned.contains(deploy2.id()));
+    assert!(pruned.contains(deploy3.id()));
 
     assert_eq!(proposer.sets.pending.len(), 1); // deploy4 is still valid
     assert_eq!(proposer.sets.finalized_deploys.len(), 0);
@@ -670,3 +674,129 @@ fn should_return_deploy_dependencies() {
     assert_eq!(deploys2.len(), 1);
     assert!(deploys2.contains(deploy2.id()));
 }
+
+#[test]
+fn should_report_pending_deploys_and_deploy_status() {
+    let creation_time = Timestamp::from(100);
+    let ttl = TimeDiff::from(Duration::from_millis(100));
+    let block_time = Timestamp::from(120);
+    let expired_time = Timestamp::from(201);
+
+    let mut rng = crate::new_rng();
+    let deploy1 = generate_deploy(
+        &mut rng,
+        creation_time,
+        ttl,
+        vec![],
+        default_gas_payment(),
+        DEFAULT_TEST_GAS_PRICE,
+    );
+    // let deploy2 depend on deploy1
+    let deploy2 = generate_deploy(
+        &mut rng,
+        creation_time,
+        ttl,
+        vec![*deploy1.id()],
+        default_gas_payment(),
+        2,
+    );
+    let transfer = generate_transfer(&mut rng, creation_time, ttl, vec![], default_gas_payment());
+    let unknown_hash = DeployHash::random(&mut rng);
+
+    let mut proposer = create_test_proposer();
+    for deploy in &[&deploy1, &deploy2, &transfer] {
+        proposer.add_deploy_or_transfer(creation_time, *deploy.id(), deploy.deploy_type().unwrap());
+    }
+
+    let pending_deploys = proposer.pending_deploys(block_time, None, usize::MAX);
+    assert!(pending_deploys.next_cursor.is_none());
+    let pending_deploys = pending_deploys.deploys;
+    let mut expected_hashes = vec![*deploy1.id(), *deploy2.id(), *transfer.id()];
+    expected_hashes.sort();
+
+    // The pending deploys can be paged through.
+    let first_page = proposer.pending_deploys(block_time, None, 2);
+    assert_eq!(
+        first_page
+            .deploys
+            .iter()
+            .map(|pending| pending.deploy_hash)
+            .collect::<Vec<_>>(),
+        expected_hashes[..2].to_vec()
+    );
+    assert_eq!(first_page.next_cursor, Some(expected_hashes[1]));
+    let second_page = proposer.pending_deploys(block_time, first_page.next_cursor, 2);
+    assert_eq!(second_page.deploys.len(), 1);
+    assert_eq!(second_page.deploys[0].deploy_hash, expected_hashes[2]);
+    assert!(second_page.next_cursor.is_none());
+    assert_eq!(
+        pending_deploys
+            .iter()
+            .map(|pending| pending.deploy_hash)
+            .collect::<Vec<_>>(),
+        expected_hashes
+    );
+    let pending_deploy2 = pending_deploys
+        .iter()
+        .find(|pending| pending.deploy_hash == *deploy2.id())
+        .unwrap();
+    assert_eq!(pending_deploy2.kind, PendingDeployKind::Wasm);
+    assert_eq!(pending_deploy2.expires, creation_time + ttl);
+    assert_eq!(pending_deploy2.gas_price, 2);
+    assert_eq!(pending_deploy2.unmet_dependencies, vec![*deploy1.id()]);
+    assert!(pending_deploys
+        .iter()
+        .any(|pending| pending.deploy_hash == *transfer.id()
+            && pending.kind == PendingDeployKind::Transfer));
+
+    assert_eq!(
+        proposer.deploy_status(deploy2.id(), block_time),
+        DeployStatus::Pending
+    );
+    assert_eq!(
+        proposer.deploy_status(&unknown_hash, block_time),
+        DeployStatus::Unknown
+    );
+
+    // deploy1 and the transfer are proposed, deploy2 has to wait for deploy1.
+    let _ = proposer.propose_proto_block(DeployConfig::default(), block_time, HashSet::new(), true);
+    assert_eq!(
+        proposer.deploy_status(deploy1.id(), block_time),
+        DeployStatus::InProtoBlock
+    );
+    assert_eq!(
+        proposer.deploy_status(transfer.id(), block_time),
+        DeployStatus::InProtoBlock
+    );
+    assert_eq!(
+        proposer.deploy_status(deploy2.id(), block_time),
+        DeployStatus::Pending
+    );
+    let pending_deploys = proposer
+        .pending_deploys(block_time, None, usize::MAX)
+        .deploys;
+    assert_eq!(pending_deploys.len(), 1);
+    assert_eq!(pending_deploys[0].deploy_hash, *deploy2.id());
+
+    // Once deploy1 is finalized, deploy2's dependency is met.
+    proposer.finalized_deploys(vec![*deploy1.id()]);
+    assert_eq!(
+        proposer.deploy_status(deploy1.id(), block_time),
+        DeployStatus::Finalized
+    );
+    assert!(proposer
+        .pending_deploys(block_time, None, usize::MAX)
+        .deploys[0]
+        .unmet_dependencies
+        .is_empty());
+
+    // Pending deploys which expired are no longer listed.
+    assert_eq!(
+        proposer.deploy_status(deploy2.id(), expired_time),
+        DeployStatus::Expired
+    );
+    assert!(proposer
+        .pending_deploys(expired_time, None, usize::MAX)
+        .deploys
+        .is_empty());
+}
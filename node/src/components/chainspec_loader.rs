@@ -475,6 +475,10 @@ impl ChainspecLoader {
             Some(self.chainspec.core_config.locked_funds_period.millis()),
             Some(self.chainspec.core_config.round_seigniorage_rate),
             Some(self.chainspec.core_config.unbonding_delay),
+            Some(self.chainspec.core_config.redelegation_delay),
+            Some(self.chainspec.core_config.minimum_delegation_amount),
+            Some(self.chainspec.core_config.max_delegators_per_validator),
+            Some(self.chainspec.core_config.slashing_rate),
             global_state_update,
         ))
     }
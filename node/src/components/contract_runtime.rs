@@ -8,45 +8,80 @@ pub use types::{EraValidatorsRequest, ValidatorWeightsByEraIdRequest};
 use std::{
     fmt::{self, Debug, Display, Formatter},
     sync::Arc,
-    time::Instant,
+    time::{Duration, Instant},
 };
 
 use datasize::DataSize;
 use derive_more::From;
+use itertools::Itertools;
 use lmdb::DatabaseFlags;
-use prometheus::{self, Histogram, HistogramOpts, Registry};
+use prometheus::{self, Histogram, HistogramOpts, IntGauge, Registry};
 use serde::Serialize;
 use thiserror::Error;
-use tokio::task;
-use tracing::{error, trace};
+use tokio::{sync::Semaphore, task};
+use tracing::{error, info, trace};
 
 use casper_execution_engine::{
     core::engine_state::{
-        genesis::GenesisResult, EngineConfig, EngineState, Error, GetEraValidatorsError,
-        GetEraValidatorsRequest,
+        execution_result::ExecutionResult as EngineExecutionResult, genesis::GenesisResult,
+        EngineConfig, EngineState, Error, GetEraValidatorsError, GetEraValidatorsRequest,
+        WasmModuleCacheStats,
     },
-    shared::newtypes::CorrelationId,
+    shared::newtypes::{Blake2bHash, CorrelationId},
     storage::{
-        error::lmdb::Error as StorageLmdbError, global_state::lmdb::LmdbGlobalState,
+        error::lmdb::Error as StorageLmdbError,
+        global_state::lmdb::LmdbGlobalState,
         protocol_data_store::lmdb::LmdbProtocolDataStore,
-        transaction_source::lmdb::LmdbEnvironment, trie_store::lmdb::LmdbTrieStore,
+        transaction_source::lmdb::LmdbEnvironment,
+        trie_store::{
+            lmdb::LmdbTrieStore,
+            pruning::{LmdbTriePruner, PruningPhase, PruningProgress},
+        },
     },
 };
-use casper_types::{system::auction::ValidatorWeights, ProtocolVersion};
+use casper_types::{system::auction::ValidatorWeights, ExecutionResult, ProtocolVersion};
 
 use crate::{
     components::Component,
-    effect::{requests::ContractRuntimeRequest, EffectBuilder, EffectExt, Effects},
-    types::Chainspec,
+    crypto::hash::Digest,
+    effect::{
+        requests::{ContractRuntimeRequest, StorageRequest},
+        EffectBuilder, EffectExt, Effects,
+    },
+    types::{Chainspec, DeployValidationFailure},
     utils::WithDir,
     NodeRng, StorageConfig,
 };
 
+/// The delay between consecutive steps of a pruning run, leaving the trie store to the execution
+/// of blocks in between.
+const PRUNING_STEP_DELAY: Duration = Duration::from_millis(100);
+
 /// The contract runtime components.
 #[derive(DataSize)]
 pub struct ContractRuntime {
     engine_state: Arc<EngineState<LmdbGlobalState>>,
     metrics: Arc<ContractRuntimeMetrics>,
+    /// Limits the number of concurrent speculative executions, so that they can't starve the
+    /// execution of blocks.
+    #[data_size(skip)]
+    speculative_execution_permits: Arc<Semaphore>,
+    /// Limits the number of concurrent contract views, for the same reason.
+    #[data_size(skip)]
+    contract_view_permits: Arc<Semaphore>,
+    /// Prunes the trie store of the global state.
+    #[data_size(skip)]
+    pruner: Arc<LmdbTriePruner>,
+    /// Whether to prune the global state while running.
+    enable_pruning: bool,
+    /// The number of most recent blocks whose global state is retained by pruning.
+    pruning_retained_blocks: u64,
+    /// The number of blocks between the starts of consecutive pruning runs.
+    pruning_interval: u64,
+    /// The maximum number of trie nodes processed by a single pruning step.
+    pruning_batch_size: usize,
+    /// Whether a pruning run is being driven by this component.
+    pruning_in_progress: bool,
 }
 
 impl Debug for ContractRuntime {
@@ -61,12 +96,39 @@ pub enum Event {
     /// A request made of the contract runtime component.
     #[from]
     Request(ContractRuntimeRequest),
+    /// A block has been added to the linear chain.
+    BlockAdded {
+        /// The height of the block.
+        block_height: u64,
+        /// The state root hash of the block.
+        state_root_hash: Digest,
+    },
+    /// A pruning run has been started or resumed, retaining the global state of the most recent
+    /// blocks and of all switch blocks.
+    PruningStarted(#[serde(skip_serializing)] Result<(), StorageLmdbError>),
+    /// The current pruning run should be advanced by a step.
+    PruneStep,
+    /// A step of the current pruning run has completed.
+    PruneStepped(#[serde(skip_serializing)] Result<PruningProgress, StorageLmdbError>),
 }
 
 impl Display for Event {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Event::Request(request) => write!(f, "{}", request),
+            Event::BlockAdded {
+                block_height,
+                state_root_hash,
+            } => write!(
+                f,
+                "block at height {} with state root hash {} added",
+                block_height, state_root_hash
+            ),
+            Event::PruningStarted(Ok(())) => write!(f, "pruning started"),
+            Event::PruningStarted(Err(error)) => write!(f, "failed to start pruning: {}", error),
+            Event::PruneStep => write!(f, "prune step"),
+            Event::PruneStepped(Ok(progress)) => write!(f, "pruned: {:?}", progress),
+            Event::PruneStepped(Err(error)) => write!(f, "failed to prune: {}", error),
         }
     }
 }
@@ -75,9 +137,13 @@ impl Display for Event {
 #[derive(Debug)]
 pub struct ContractRuntimeMetrics {
     run_execute: Histogram,
+    run_execute_transfers: Histogram,
+    speculative_execute: Histogram,
     apply_effect: Histogram,
     commit_upgrade: Histogram,
     run_query: Histogram,
+    run_view: Histogram,
+    get_keys_with_prefix: Histogram,
     get_balance: Histogram,
     get_validator_weights: Histogram,
     get_era_validators: Histogram,
@@ -85,6 +151,15 @@ pub struct ContractRuntimeMetrics {
     missing_trie_keys: Histogram,
     put_trie: Histogram,
     read_trie: Histogram,
+    wasm_module_cache_hits: IntGauge,
+    wasm_module_cache_misses: IntGauge,
+    wasm_module_cache_entries: IntGauge,
+    wasm_module_cache_size: IntGauge,
+    pruning_phase: IntGauge,
+    pruning_nodes_marked: IntGauge,
+    pruning_nodes_pending: IntGauge,
+    pruning_nodes_swept: IntGauge,
+    pruning_nodes_deleted: IntGauge,
 }
 
 /// Value of upper bound of histogram.
@@ -96,10 +171,21 @@ const EXPONENTIAL_BUCKET_COUNT: usize = 6;
 
 const RUN_EXECUTE_NAME: &str = "contract_runtime_run_execute";
 const RUN_EXECUTE_HELP: &str = "tracking run of engine_state.run_execute in seconds.";
+const RUN_EXECUTE_TRANSFERS_NAME: &str = "contract_runtime_run_execute_transfers";
+const RUN_EXECUTE_TRANSFERS_HELP: &str =
+    "tracking run of engine_state.run_execute_transfers in seconds.";
+const SPECULATIVE_EXECUTE_NAME: &str = "contract_runtime_speculative_execute";
+const SPECULATIVE_EXECUTE_HELP: &str =
+    "tracking speculative run of engine_state.run_execute in seconds.";
 const APPLY_EFFECT_NAME: &str = "contract_runtime_apply_commit";
 const APPLY_EFFECT_HELP: &str = "tracking run of engine_state.apply_effect in seconds.";
 const RUN_QUERY_NAME: &str = "contract_runtime_run_query";
 const RUN_QUERY_HELP: &str = "tracking run of engine_state.run_query in seconds.";
+const RUN_VIEW_NAME: &str = "contract_runtime_run_view";
+const RUN_VIEW_HELP: &str = "tracking run of engine_state.run_view in seconds.";
+const GET_KEYS_WITH_PREFIX_NAME: &str = "contract_runtime_get_keys_with_prefix";
+const GET_KEYS_WITH_PREFIX_HELP: &str =
+    "tracking run of engine_state.get_keys_with_prefix in seconds.";
 const COMMIT_UPGRADE_NAME: &str = "contract_runtime_commit_upgrade";
 const COMMIT_UPGRADE_HELP: &str = "tracking run of engine_state.commit_upgrade in seconds";
 const GET_BALANCE_NAME: &str = "contract_runtime_get_balance";
@@ -119,6 +205,32 @@ const PUT_TRIE_NAME: &str = "contract_runtime_put_trie";
 const PUT_TRIE_HELP: &str = "tracking run of engine_state.put_trie in seconds.";
 const MISSING_TRIE_KEYS_NAME: &str = "contract_runtime_missing_trie_keys";
 const MISSING_TRIE_KEYS_HELP: &str = "tracking run of engine_state.missing_trie_keys in seconds.";
+const WASM_MODULE_CACHE_HITS_NAME: &str = "contract_runtime_wasm_module_cache_hits";
+const WASM_MODULE_CACHE_HITS_HELP: &str =
+    "total number of stored contract modules found in the wasm module cache.";
+const WASM_MODULE_CACHE_MISSES_NAME: &str = "contract_runtime_wasm_module_cache_misses";
+const WASM_MODULE_CACHE_MISSES_HELP: &str =
+    "total number of stored contract modules not found in the wasm module cache.";
+const WASM_MODULE_CACHE_ENTRIES_NAME: &str = "contract_runtime_wasm_module_cache_entries";
+const WASM_MODULE_CACHE_ENTRIES_HELP: &str =
+    "number of stored contract modules held in the wasm module cache.";
+const WASM_MODULE_CACHE_SIZE_NAME: &str = "contract_runtime_wasm_module_cache_size";
+const WASM_MODULE_CACHE_SIZE_HELP: &str =
+    "total serialized size in bytes of the modules held in the wasm module cache.";
+const PRUNING_PHASE_NAME: &str = "contract_runtime_pruning_phase";
+const PRUNING_PHASE_HELP: &str =
+    "phase of the current global state pruning run: 0 if idle, 1 if marking, 2 if sweeping.";
+const PRUNING_NODES_MARKED_NAME: &str = "contract_runtime_pruning_nodes_marked";
+const PRUNING_NODES_MARKED_HELP: &str =
+    "number of trie nodes marked as retained by the current pruning run.";
+const PRUNING_NODES_PENDING_NAME: &str = "contract_runtime_pruning_nodes_pending";
+const PRUNING_NODES_PENDING_HELP: &str =
+    "number of trie nodes waiting to be marked by the current pruning run.";
+const PRUNING_NODES_SWEPT_NAME: &str = "contract_runtime_pruning_nodes_swept";
+const PRUNING_NODES_SWEPT_HELP: &str =
+    "number of trie nodes checked by the sweep of the current pruning run.";
+const PRUNING_NODES_DELETED_NAME: &str = "contract_runtime_pruning_nodes_deleted";
+const PRUNING_NODES_DELETED_HELP: &str = "number of trie nodes deleted by the current pruning run.";
 
 /// Create prometheus Histogram and register.
 fn register_histogram_metric(
@@ -137,17 +249,44 @@ fn register_histogram_metric(
     Ok(histogram)
 }
 
+/// Create prometheus IntGauge and register.
+fn register_int_gauge_metric(
+    registry: &Registry,
+    metric_name: &str,
+    metric_help: &str,
+) -> Result<IntGauge, prometheus::Error> {
+    let int_gauge = IntGauge::new(metric_name, metric_help)?;
+    registry.register(Box::new(int_gauge.clone()))?;
+    Ok(int_gauge)
+}
+
 impl ContractRuntimeMetrics {
     /// Constructor of metrics which creates and registers metrics objects for use.
     fn new(registry: &Registry) -> Result<Self, prometheus::Error> {
         Ok(ContractRuntimeMetrics {
             run_execute: register_histogram_metric(registry, RUN_EXECUTE_NAME, RUN_EXECUTE_HELP)?,
+            run_execute_transfers: register_histogram_metric(
+                registry,
+                RUN_EXECUTE_TRANSFERS_NAME,
+                RUN_EXECUTE_TRANSFERS_HELP,
+            )?,
+            speculative_execute: register_histogram_metric(
+                registry,
+                SPECULATIVE_EXECUTE_NAME,
+                SPECULATIVE_EXECUTE_HELP,
+            )?,
             apply_effect: register_histogram_metric(
                 registry,
                 APPLY_EFFECT_NAME,
                 APPLY_EFFECT_HELP,
             )?,
             run_query: register_histogram_metric(registry, RUN_QUERY_NAME, RUN_QUERY_HELP)?,
+            run_view: register_histogram_metric(registry, RUN_VIEW_NAME, RUN_VIEW_HELP)?,
+            get_keys_with_prefix: register_histogram_metric(
+                registry,
+                GET_KEYS_WITH_PREFIX_NAME,
+                GET_KEYS_WITH_PREFIX_HELP,
+            )?,
             commit_upgrade: register_histogram_metric(
                 registry,
                 COMMIT_UPGRADE_NAME,
@@ -176,24 +315,136 @@ impl ContractRuntimeMetrics {
                 MISSING_TRIE_KEYS_NAME,
                 MISSING_TRIE_KEYS_HELP,
             )?,
+            wasm_module_cache_hits: register_int_gauge_metric(
+                registry,
+                WASM_MODULE_CACHE_HITS_NAME,
+                WASM_MODULE_CACHE_HITS_HELP,
+            )?,
+            wasm_module_cache_misses: register_int_gauge_metric(
+                registry,
+                WASM_MODULE_CACHE_MISSES_NAME,
+                WASM_MODULE_CACHE_MISSES_HELP,
+            )?,
+            wasm_module_cache_entries: register_int_gauge_metric(
+                registry,
+                WASM_MODULE_CACHE_ENTRIES_NAME,
+                WASM_MODULE_CACHE_ENTRIES_HELP,
+            )?,
+            wasm_module_cache_size: register_int_gauge_metric(
+                registry,
+                WASM_MODULE_CACHE_SIZE_NAME,
+                WASM_MODULE_CACHE_SIZE_HELP,
+            )?,
+            pruning_phase: register_int_gauge_metric(
+                registry,
+                PRUNING_PHASE_NAME,
+                PRUNING_PHASE_HELP,
+            )?,
+            pruning_nodes_marked: register_int_gauge_metric(
+                registry,
+                PRUNING_NODES_MARKED_NAME,
+                PRUNING_NODES_MARKED_HELP,
+            )?,
+            pruning_nodes_pending: register_int_gauge_metric(
+                registry,
+                PRUNING_NODES_PENDING_NAME,
+                PRUNING_NODES_PENDING_HELP,
+            )?,
+            pruning_nodes_swept: register_int_gauge_metric(
+                registry,
+                PRUNING_NODES_SWEPT_NAME,
+                PRUNING_NODES_SWEPT_HELP,
+            )?,
+            pruning_nodes_deleted: register_int_gauge_metric(
+                registry,
+                PRUNING_NODES_DELETED_NAME,
+                PRUNING_NODES_DELETED_HELP,
+            )?,
         })
     }
+
+    /// Updates the wasm module cache metrics from the cache's current statistics.
+    fn observe_wasm_module_cache(&self, stats: WasmModuleCacheStats) {
+        self.wasm_module_cache_hits.set(stats.hits as i64);
+        self.wasm_module_cache_misses.set(stats.misses as i64);
+        self.wasm_module_cache_entries.set(stats.entries as i64);
+        self.wasm_module_cache_size.set(stats.size_bytes as i64);
+    }
+
+    /// Updates the pruning metrics from the progress of the current pruning run.
+    fn observe_pruning_progress(&self, progress: PruningProgress) {
+        let phase = match progress.phase {
+            PruningPhase::Idle => 0,
+            PruningPhase::Marking => 1,
+            PruningPhase::Sweeping => 2,
+        };
+        self.pruning_phase.set(phase);
+        self.pruning_nodes_marked.set(progress.nodes_marked as i64);
+        self.pruning_nodes_pending
+            .set(progress.nodes_pending as i64);
+        self.pruning_nodes_swept.set(progress.nodes_swept as i64);
+        self.pruning_nodes_deleted
+            .set(progress.nodes_deleted as i64);
+    }
 }
 
 impl<REv> Component<REv> for ContractRuntime
 where
-    REv: From<Event> + Send,
+    REv: From<Event> + From<StorageRequest> + Send,
 {
     type Event = Event;
     type ConstructionError = ConfigError;
 
     fn handle_event(
         &mut self,
-        _effect_builder: EffectBuilder<REv>,
+        effect_builder: EffectBuilder<REv>,
         _rng: &mut NodeRng,
         event: Self::Event,
     ) -> Effects<Self::Event> {
         match event {
+            Event::BlockAdded {
+                block_height,
+                state_root_hash,
+            } => self.handle_block_added(effect_builder, block_height, state_root_hash),
+            Event::PruningStarted(Ok(())) => {
+                effect_builder.immediately().event(|()| Event::PruneStep)
+            }
+            Event::PruningStarted(Err(error)) => {
+                error!(%error, "failed to start pruning global state");
+                self.pruning_in_progress = false;
+                Effects::new()
+            }
+            Event::PruneStep => {
+                let pruner = Arc::clone(&self.pruner);
+                let batch_size = self.pruning_batch_size;
+                async move {
+                    task::spawn_blocking(move || pruner.step(batch_size))
+                        .await
+                        .expect("should run")
+                }
+                .event(Event::PruneStepped)
+            }
+            Event::PruneStepped(Ok(progress)) => {
+                self.metrics.observe_pruning_progress(progress);
+                if progress.phase == PruningPhase::Idle {
+                    info!(
+                        nodes_deleted = progress.nodes_deleted,
+                        "finished pruning global state"
+                    );
+                    self.pruning_in_progress = false;
+                    Effects::new()
+                } else {
+                    effect_builder
+                        .set_timeout(PRUNING_STEP_DELAY)
+                        .event(|_| Event::PruneStep)
+                }
+            }
+            Event::PruneStepped(Err(error)) => {
+                // The run is resumed with the next block added.
+                error!(%error, "failed to prune global state");
+                self.pruning_in_progress = false;
+                Effects::new()
+            }
             Event::Request(ContractRuntimeRequest::GetProtocolData {
                 protocol_version,
                 responder,
@@ -226,6 +477,7 @@ where
                         let execution_result =
                             engine_state.run_execute(correlation_id, *execute_request);
                         metrics.run_execute.observe(start.elapsed().as_secs_f64());
+                        metrics.observe_wasm_module_cache(engine_state.wasm_module_cache().stats());
                         execution_result
                     })
                     .await
@@ -235,6 +487,104 @@ where
                 }
                 .ignore()
             }
+            Event::Request(ContractRuntimeRequest::ExecuteTransfers {
+                execute_request,
+                responder,
+            }) => {
+                trace!(?execute_request, "execute transfers");
+                let engine_state = Arc::clone(&self.engine_state);
+                let metrics = Arc::clone(&self.metrics);
+                async move {
+                    let correlation_id = CorrelationId::new();
+                    let result = task::spawn_blocking(move || {
+                        let start = Instant::now();
+                        let result =
+                            engine_state.run_execute_transfers(correlation_id, *execute_request);
+                        metrics
+                            .run_execute_transfers
+                            .observe(start.elapsed().as_secs_f64());
+                        result
+                    })
+                    .await
+                    .expect("should run");
+                    trace!(?result, "execute transfers result");
+                    responder.respond(result).await
+                }
+                .ignore()
+            }
+            Event::Request(ContractRuntimeRequest::SpeculativeExecute {
+                execute_request,
+                trace,
+                responder,
+            }) => {
+                trace!(?execute_request, trace, "speculative execute");
+                let engine_state = Arc::clone(&self.engine_state);
+                let metrics = Arc::clone(&self.metrics);
+                let permits = Arc::clone(&self.speculative_execution_permits);
+                async move {
+                    let _permit = match permits.try_acquire() {
+                        Ok(permit) => permit,
+                        Err(_) => {
+                            return responder
+                                .respond(Err(SpeculativeExecutionError::Busy))
+                                .await
+                        }
+                    };
+                    let correlation_id = CorrelationId::new();
+                    let state_root_hash = Digest::from(execute_request.parent_state_hash);
+                    let result = task::spawn_blocking(move || {
+                        let start = Instant::now();
+                        let execution_result = if trace {
+                            engine_state
+                                .run_execute_traced(correlation_id, *execute_request)
+                                .map(|results| {
+                                    results
+                                        .into_iter()
+                                        .map(|(result, trace)| (result, Some(trace)))
+                                        .collect::<Vec<_>>()
+                                })
+                        } else {
+                            engine_state
+                                .run_execute(correlation_id, *execute_request)
+                                .map(|results| {
+                                    results.into_iter().map(|result| (result, None)).collect()
+                                })
+                        };
+                        metrics
+                            .speculative_execute
+                            .observe(start.elapsed().as_secs_f64());
+                        metrics.observe_wasm_module_cache(engine_state.wasm_module_cache().stats());
+                        execution_result
+                    })
+                    .await
+                    .expect("should run");
+                    trace!(?result, "speculative execute result");
+                    let result = match result {
+                        Ok(execution_results) => {
+                            let (execution_result, trace) = execution_results
+                                .into_iter()
+                                .exactly_one()
+                                .expect("should only be one exec result");
+                            // Failing a precondition means the deploy couldn't be executed at all.
+                            let is_precondition_failure =
+                                execution_result.has_precondition_failure();
+                            match execution_result {
+                                EngineExecutionResult::Failure { error, .. }
+                                    if is_precondition_failure =>
+                                {
+                                    Err(SpeculativeExecutionError::EngineState(error))
+                                }
+                                execution_result => {
+                                    Ok((ExecutionResult::from(&execution_result), trace))
+                                }
+                            }
+                        }
+                        Err(_) => Err(SpeculativeExecutionError::RootNotFound(state_root_hash)),
+                    };
+                    responder.respond(result).await
+                }
+                .ignore()
+            }
             Event::Request(ContractRuntimeRequest::Commit {
                 state_root_hash,
                 effects,
@@ -277,6 +627,7 @@ where
                         metrics
                             .commit_upgrade
                             .observe(start.elapsed().as_secs_f64());
+                        metrics.observe_wasm_module_cache(engine_state.wasm_module_cache().stats());
                         result
                     })
                     .await
@@ -308,6 +659,57 @@ where
                 }
                 .ignore()
             }
+            Event::Request(ContractRuntimeRequest::View {
+                view_request,
+                responder,
+            }) => {
+                trace!(?view_request, "view");
+                let engine_state = Arc::clone(&self.engine_state);
+                let metrics = Arc::clone(&self.metrics);
+                let permits = Arc::clone(&self.contract_view_permits);
+                async move {
+                    let _permit = match permits.try_acquire() {
+                        Ok(permit) => permit,
+                        Err(_) => return responder.respond(Err(ContractViewError::Busy)).await,
+                    };
+                    let correlation_id = CorrelationId::new();
+                    let result = task::spawn_blocking(move || {
+                        let start = Instant::now();
+                        let result = engine_state
+                            .run_view(correlation_id, *view_request)
+                            .map_err(ContractViewError::EngineState);
+                        metrics.run_view.observe(start.elapsed().as_secs_f64());
+                        metrics.observe_wasm_module_cache(engine_state.wasm_module_cache().stats());
+                        result
+                    })
+                    .await
+                    .expect("should run");
+                    trace!(?result, "view result");
+                    responder.respond(result).await
+                }
+                .ignore()
+            }
+            Event::Request(ContractRuntimeRequest::GetKeysWithPrefix { request, responder }) => {
+                trace!(?request, "get keys with prefix");
+                let engine_state = Arc::clone(&self.engine_state);
+                let metrics = Arc::clone(&self.metrics);
+                async move {
+                    let correlation_id = CorrelationId::new();
+                    let result = task::spawn_blocking(move || {
+                        let start = Instant::now();
+                        let result = engine_state.get_keys_with_prefix(correlation_id, request);
+                        metrics
+                            .get_keys_with_prefix
+                            .observe(start.elapsed().as_secs_f64());
+                        result
+                    })
+                    .await
+                    .expect("should run");
+                    trace!(?result, "get keys with prefix result");
+                    responder.respond(result).await
+                }
+                .ignore()
+            }
             Event::Request(ContractRuntimeRequest::GetBalance {
                 balance_request,
                 responder,
@@ -527,6 +929,36 @@ where
     }
 }
 
+/// Error returned from a speculative execution.
+#[derive(Debug, Error)]
+pub enum SpeculativeExecutionError {
+    /// The maximum number of speculative executions are already running.
+    #[error("too many speculative executions already running")]
+    Busy,
+    /// The state root hash to execute against was not found.
+    #[error("state root hash {0} not found")]
+    RootNotFound(Digest),
+    /// The deploy failed the checks the deploy acceptor would have subjected it to.
+    #[error("invalid deploy: {0}")]
+    InvalidDeploy(DeployValidationFailure),
+    /// The deploy could not be executed, e.g. because its account doesn't exist or it isn't
+    /// authorized by the account's keys.
+    #[error("failed to execute deploy: {0}")]
+    EngineState(Error),
+}
+
+/// Error returned from a contract view.
+#[derive(Debug, Error)]
+pub enum ContractViewError {
+    /// The maximum number of contract views are already running.
+    #[error("too many contract views already running")]
+    Busy,
+    /// The view failed, e.g. because its target doesn't exist, it attempted to write to global
+    /// state or it exceeded its gas limit.
+    #[error("failed to run contract view: {0}")]
+    EngineState(Error),
+}
+
 /// Error returned from mis-configuring the contract runtime component.
 #[derive(Debug, Error)]
 pub enum ConfigError {
@@ -563,18 +995,94 @@ impl ContractRuntime {
             DatabaseFlags::empty(),
         )?);
 
-        let global_state = LmdbGlobalState::empty(environment, trie_store, protocol_data_store)?;
-        let engine_config = EngineConfig::new();
+        // The pruner is attached even if pruning is disabled, so that an interrupted run of the
+        // offline pruning retains whatever is written before it's resumed.
+        let pruner = Arc::new(LmdbTriePruner::new(
+            Arc::clone(&environment),
+            Arc::clone(&trie_store),
+            None,
+            DatabaseFlags::empty(),
+        )?);
+
+        let global_state = LmdbGlobalState::empty(environment, trie_store, protocol_data_store)?
+            .with_pruner(Arc::clone(&pruner));
+        let engine_config = EngineConfig::new()
+            .with_max_cached_wasm_modules(contract_runtime_config.max_cached_wasm_modules());
 
         let engine_state = Arc::new(EngineState::new(global_state, engine_config));
 
         let metrics = Arc::new(ContractRuntimeMetrics::new(registry)?);
+        metrics.observe_pruning_progress(pruner.progress()?);
+        let speculative_execution_permits = Arc::new(Semaphore::new(
+            contract_runtime_config.max_speculative_executions() as usize,
+        ));
+        let contract_view_permits = Arc::new(Semaphore::new(
+            contract_runtime_config.max_contract_views() as usize,
+        ));
         Ok(ContractRuntime {
             engine_state,
             metrics,
+            speculative_execution_permits,
+            contract_view_permits,
+            pruner,
+            enable_pruning: contract_runtime_config.enable_pruning(),
+            pruning_retained_blocks: contract_runtime_config.pruning_retained_blocks(),
+            pruning_interval: contract_runtime_config.pruning_interval(),
+            pruning_batch_size: contract_runtime_config.pruning_batch_size(),
+            pruning_in_progress: false,
         })
     }
 
+    /// Starts a pruning run every `pruning_interval` blocks, or resumes an interrupted one.
+    ///
+    /// The run is started with the state root hash of the added block before the state root
+    /// hashes to retain are read from storage, so that every trie written in the meantime is
+    /// retained too.  Steps are only taken once all of them have been added to the run.
+    fn handle_block_added<REv>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        block_height: u64,
+        state_root_hash: Digest,
+    ) -> Effects<Event>
+    where
+        REv: From<StorageRequest> + Send,
+    {
+        if !self.enable_pruning || self.pruning_in_progress {
+            return Effects::new();
+        }
+        let is_interrupted = match self.pruner.progress() {
+            Ok(progress) => progress.phase != PruningPhase::Idle,
+            Err(error) => {
+                error!(%error, "failed to read pruning progress");
+                return Effects::new();
+            }
+        };
+        if !is_interrupted && block_height % self.pruning_interval != 0 {
+            return Effects::new();
+        }
+
+        info!(block_height, is_interrupted, "pruning global state");
+        self.pruning_in_progress = true;
+        let pruner = Arc::clone(&self.pruner);
+        let retained_blocks = self.pruning_retained_blocks;
+        async move {
+            let block_pruner = Arc::clone(&pruner);
+            task::spawn_blocking(move || block_pruner.start(&[state_root_hash.into()]))
+                .await
+                .expect("should run")?;
+            let retained_roots: Vec<Blake2bHash> = effect_builder
+                .get_retained_state_root_hashes_from_storage(retained_blocks)
+                .await
+                .into_iter()
+                .map(Blake2bHash::from)
+                .collect();
+            task::spawn_blocking(move || pruner.start(&retained_roots))
+                .await
+                .expect("should run")
+        }
+        .event(Event::PruningStarted)
+    }
+
     /// Commits a genesis using a chainspec
     fn commit_genesis(&self, chainspec: Arc<Chainspec>) -> Result<GenesisResult, Error> {
         let correlation_id = CorrelationId::new();
@@ -1,10 +1,18 @@
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
-use casper_execution_engine::shared::utils;
+use casper_execution_engine::{
+    core::engine_state::wasm_module_cache::DEFAULT_MAX_CACHED_WASM_MODULES, shared::utils,
+    storage::trie_store::pruning::DEFAULT_PRUNING_BATCH_SIZE,
+};
 
 const DEFAULT_MAX_GLOBAL_STATE_SIZE: usize = 805_306_368_000; // 750 GiB
 const DEFAULT_MAX_READERS: u32 = 512;
+const DEFAULT_MAX_SPECULATIVE_EXECUTIONS: u32 = 2;
+const DEFAULT_MAX_CONTRACT_VIEWS: u32 = 4;
+const DEFAULT_ENABLE_PRUNING: bool = false;
+const DEFAULT_PRUNING_RETAINED_BLOCKS: u64 = 1_000;
+const DEFAULT_PRUNING_INTERVAL: u64 = 10_000;
 
 /// Contract runtime configuration.
 #[derive(Clone, Copy, DataSize, Debug, Deserialize, Serialize)]
@@ -21,6 +29,40 @@ pub struct Config {
     ///
     /// Defaults to 512.
     max_readers: Option<u32>,
+    /// The maximum number of speculative executions run concurrently on behalf of the
+    /// `speculative_exec` RPC.
+    ///
+    /// Defaults to 2.
+    max_speculative_executions: Option<u32>,
+    /// The maximum number of contract views run concurrently on behalf of the
+    /// `query_contract_view` RPC.
+    ///
+    /// Defaults to 4.
+    max_contract_views: Option<u32>,
+    /// The maximum number of deserialized stored contract modules kept in memory.  Zero disables
+    /// caching.
+    ///
+    /// Defaults to 256.
+    max_cached_wasm_modules: Option<usize>,
+    /// Whether to prune the global state while the node is running, deleting the trie nodes
+    /// which are only reachable from the state root hashes of blocks outside the retention
+    /// window.
+    ///
+    /// Defaults to false.
+    enable_pruning: Option<bool>,
+    /// The number of most recent blocks whose global state is retained by pruning, in addition to
+    /// that of every switch block.
+    ///
+    /// Defaults to 1,000.
+    pruning_retained_blocks: Option<u64>,
+    /// The number of blocks between the starts of consecutive pruning runs.
+    ///
+    /// Defaults to 10,000.
+    pruning_interval: Option<u64>,
+    /// The maximum number of trie nodes processed by a single pruning step.
+    ///
+    /// Defaults to 10,000.
+    pruning_batch_size: Option<usize>,
 }
 
 impl Config {
@@ -35,6 +77,42 @@ impl Config {
     pub(crate) fn max_readers(&self) -> u32 {
         self.max_readers.unwrap_or(DEFAULT_MAX_READERS)
     }
+
+    pub(crate) fn max_speculative_executions(&self) -> u32 {
+        self.max_speculative_executions
+            .unwrap_or(DEFAULT_MAX_SPECULATIVE_EXECUTIONS)
+    }
+
+    pub(crate) fn max_contract_views(&self) -> u32 {
+        self.max_contract_views
+            .unwrap_or(DEFAULT_MAX_CONTRACT_VIEWS)
+    }
+
+    pub(crate) fn max_cached_wasm_modules(&self) -> usize {
+        self.max_cached_wasm_modules
+            .unwrap_or(DEFAULT_MAX_CACHED_WASM_MODULES)
+    }
+
+    pub(crate) fn enable_pruning(&self) -> bool {
+        self.enable_pruning.unwrap_or(DEFAULT_ENABLE_PRUNING)
+    }
+
+    pub(crate) fn pruning_retained_blocks(&self) -> u64 {
+        self.pruning_retained_blocks
+            .unwrap_or(DEFAULT_PRUNING_RETAINED_BLOCKS)
+    }
+
+    pub(crate) fn pruning_interval(&self) -> u64 {
+        self.pruning_interval
+            .unwrap_or(DEFAULT_PRUNING_INTERVAL)
+            .max(1)
+    }
+
+    pub(crate) fn pruning_batch_size(&self) -> usize {
+        self.pruning_batch_size
+            .unwrap_or(DEFAULT_PRUNING_BATCH_SIZE)
+            .max(1)
+    }
 }
 
 impl Default for Config {
@@ -42,6 +120,13 @@ impl Default for Config {
         Config {
             max_global_state_size: Some(DEFAULT_MAX_GLOBAL_STATE_SIZE),
             max_readers: Some(DEFAULT_MAX_READERS),
+            max_speculative_executions: Some(DEFAULT_MAX_SPECULATIVE_EXECUTIONS),
+            max_contract_views: Some(DEFAULT_MAX_CONTRACT_VIEWS),
+            max_cached_wasm_modules: Some(DEFAULT_MAX_CACHED_WASM_MODULES),
+            enable_pruning: Some(DEFAULT_ENABLE_PRUNING),
+            pruning_retained_blocks: Some(DEFAULT_PRUNING_RETAINED_BLOCKS),
+            pruning_interval: Some(DEFAULT_PRUNING_INTERVAL),
+            pruning_batch_size: Some(DEFAULT_PRUNING_BATCH_SIZE),
         }
     }
 }
@@ -1,7 +1,31 @@
+//! Deploy acceptor component.
+//!
+//! # Admission policies
+//!
+//! Besides validating deploys, the deploy acceptor can be configured to reject deploys received
+//! from clients on policy grounds: accounts can be restricted to an allow list or excluded via a
+//! deny list, the number of deploys pending per account can be limited, and deploys with a gas
+//! price below a minimum or a serialized size above a maximum can be refused.  Each rejection is
+//! reported to the client with a dedicated [`Error`] variant.
+//!
+//! Deploys received from peers are never subject to these policies, since they may already have
+//! been included in a block proposed by another validator.
+//!
+//! To enforce the pending deploy limit, the acceptor tracks the client deploys it has accepted
+//! for each account until they are finalized or expire.  Expired deploys are evicted for all
+//! accounts whenever a block is finalized, so accounts which stop sending deploys don't keep
+//! their entries around.
+
 mod config;
 mod event;
+#[cfg(test)]
+mod tests;
 
-use std::{convert::Infallible, fmt::Debug};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+    fmt::Debug,
+};
 
 use thiserror::Error;
 use tracing::{debug, error, info};
@@ -13,11 +37,14 @@ use crate::{
         requests::{ContractRuntimeRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects,
     },
-    types::{chainspec::DeployConfig, Chainspec, Deploy, DeployValidationFailure, NodeId},
+    types::{
+        chainspec::DeployConfig, Chainspec, Deploy, DeployHash, DeployValidationFailure, NodeId,
+        Timestamp,
+    },
     utils::Source,
     NodeRng,
 };
-use casper_types::Key;
+use casper_types::{bytesrepr::ToBytes, Key, PublicKey};
 
 use crate::effect::Responder;
 pub use config::Config;
@@ -34,6 +61,36 @@ pub enum Error {
     /// A deploy was sent from account with insufficient balance.
     #[error("insufficient balance")]
     InsufficientBalance,
+    /// A deploy was sent from an account which is not on the allow list.
+    #[error("account {0} is not allowed to send deploys to this node")]
+    AccountNotAllowed(PublicKey),
+    /// A deploy was sent from an account which is on the deny list.
+    #[error("account {0} is denied from sending deploys to this node")]
+    AccountDenied(PublicKey),
+    /// A deploy was sent from an account which already has the maximum number of pending deploys.
+    #[error("account {account} already has the maximum of {max_pending} pending deploys")]
+    TooManyPendingDeploys {
+        /// The account which sent the deploy.
+        account: PublicKey,
+        /// The maximum number of pending deploys per account.
+        max_pending: u32,
+    },
+    /// A deploy was sent with a gas price below the configured minimum.
+    #[error("gas price of {gas_price} is below the minimum of {min_gas_price}")]
+    GasPriceTooLow {
+        /// The gas price of the deploy.
+        gas_price: u64,
+        /// The minimum gas price.
+        min_gas_price: u64,
+    },
+    /// A deploy was sent with a serialized size above the configured maximum.
+    #[error("deploy size of {size} bytes exceeds the maximum of {max_deploy_size} bytes")]
+    DeployTooLarge {
+        /// The serialized size of the deploy in bytes.
+        size: usize,
+        /// The maximum serialized size of a deploy in bytes.
+        max_deploy_size: u32,
+    },
 }
 
 /// A helper trait constraining `DeployAcceptor` compatible reactor events.
@@ -65,6 +122,13 @@ pub struct DeployAcceptor {
     chain_name: String,
     deploy_config: DeployConfig,
     verify_accounts: bool,
+    allowed_accounts: HashSet<PublicKey>,
+    denied_accounts: HashSet<PublicKey>,
+    max_pending_deploys_per_account: u32,
+    min_gas_price: u64,
+    max_deploy_size: u32,
+    /// The deploys accepted from clients which are not yet finalized, with their expiry times.
+    pending_client_deploys: HashMap<PublicKey, HashMap<DeployHash, Timestamp>>,
 }
 
 impl DeployAcceptor {
@@ -73,6 +137,12 @@ impl DeployAcceptor {
             chain_name: chainspec.network_config.name.clone(),
             deploy_config: chainspec.deploy_config,
             verify_accounts: config.verify_accounts(),
+            allowed_accounts: config.allowed_accounts().iter().cloned().collect(),
+            denied_accounts: config.denied_accounts().iter().cloned().collect(),
+            max_pending_deploys_per_account: config.max_pending_deploys_per_account(),
+            min_gas_price: config.min_gas_price(),
+            max_deploy_size: config.max_deploy_size(),
+            pending_client_deploys: HashMap::new(),
         }
     }
 
@@ -104,6 +174,21 @@ impl DeployAcceptor {
             return effects;
         }
 
+        if source.from_client() {
+            if let Err(error) = self.check_admission(&deploy, Timestamp::now()) {
+                info!(%error, deploy_hash = %deploy.id(), "rejected deploy from client");
+                if let Some(responder) = maybe_responder {
+                    effects.extend(responder.respond(Err(error)).ignore());
+                }
+                effects.extend(
+                    effect_builder
+                        .announce_invalid_deploy(deploy, source)
+                        .ignore(),
+                );
+                return effects;
+            }
+        }
+
         let account_key = deploy.header().account().to_account_hash().into();
 
         // skip account verification if deploy not received from client or node is configured to
@@ -184,6 +269,9 @@ impl DeployAcceptor {
             }
         }
 
+        if source.from_client() {
+            self.release_pending_deploy(deploy.header().account(), deploy.id());
+        }
         effects.extend(
             effect_builder
                 .announce_invalid_deploy(deploy, source)
@@ -192,6 +280,78 @@ impl DeployAcceptor {
         effects
     }
 
+    /// Checks the deploy against the configured admission policies.
+    ///
+    /// If the deploy is admitted, it is counted as pending for its account until it is finalized,
+    /// expires or fails account verification.
+    fn check_admission(&mut self, deploy: &Deploy, now: Timestamp) -> Result<(), Error> {
+        let account = deploy.header().account();
+        if self.denied_accounts.contains(account) {
+            return Err(Error::AccountDenied(account.clone()));
+        }
+        if !self.allowed_accounts.is_empty() && !self.allowed_accounts.contains(account) {
+            return Err(Error::AccountNotAllowed(account.clone()));
+        }
+
+        let gas_price = deploy.header().gas_price();
+        if gas_price < self.min_gas_price {
+            return Err(Error::GasPriceTooLow {
+                gas_price,
+                min_gas_price: self.min_gas_price,
+            });
+        }
+
+        if self.max_deploy_size != 0 {
+            let size = deploy.serialized_length();
+            if size > self.max_deploy_size as usize {
+                return Err(Error::DeployTooLarge {
+                    size,
+                    max_deploy_size: self.max_deploy_size,
+                });
+            }
+        }
+
+        let pending = self
+            .pending_client_deploys
+            .entry(account.clone())
+            .or_default();
+        pending.retain(|_, expires| *expires >= now);
+        if self.max_pending_deploys_per_account != 0
+            && !pending.contains_key(deploy.id())
+            && pending.len() >= self.max_pending_deploys_per_account as usize
+        {
+            return Err(Error::TooManyPendingDeploys {
+                account: account.clone(),
+                max_pending: self.max_pending_deploys_per_account,
+            });
+        }
+        let _ = pending.insert(*deploy.id(), deploy.header().expires());
+        Ok(())
+    }
+
+    /// Stops counting the given deploy as pending for the account.
+    fn release_pending_deploy(&mut self, account: &PublicKey, deploy_hash: &DeployHash) {
+        if let Some(pending) = self.pending_client_deploys.get_mut(account) {
+            let _ = pending.remove(deploy_hash);
+            if pending.is_empty() {
+                let _ = self.pending_client_deploys.remove(account);
+            }
+        }
+    }
+
+    /// Stops counting the given finalized deploys, and any deploys which have expired as of `now`,
+    /// as pending.
+    fn deploys_finalized(&mut self, deploy_hashes: &[DeployHash], now: Timestamp) {
+        for pending in self.pending_client_deploys.values_mut() {
+            for deploy_hash in deploy_hashes {
+                let _ = pending.remove(deploy_hash);
+            }
+            pending.retain(|_, expires| *expires >= now);
+        }
+        self.pending_client_deploys
+            .retain(|_, pending| !pending.is_empty());
+    }
+
     fn handle_put_to_storage<REv: ReactorEventT>(
         &mut self,
         effect_builder: EffectBuilder<REv>,
@@ -244,6 +404,10 @@ impl<REv: ReactorEventT> Component<REv> for DeployAcceptor {
                 verified,
                 maybe_responder,
             ),
+            Event::DeploysFinalized { deploy_hashes } => {
+                self.deploys_finalized(&deploy_hashes, Timestamp::now());
+                Effects::new()
+            }
         }
     }
 }
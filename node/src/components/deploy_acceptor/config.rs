@@ -1,28 +1,73 @@
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
-/// Configuration options for fetching.
-#[derive(Copy, Clone, DataSize, Debug, Deserialize, Serialize)]
+use casper_types::PublicKey;
+
+/// Configuration options for accepting deploys.
+///
+/// The admission policies (`allowed_accounts`, `denied_accounts`,
+/// `max_pending_deploys_per_account`, `min_gas_price` and `max_deploy_size`) are only applied to
+/// deploys received from clients; deploys received from peers are never rejected on their grounds.
+#[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
+#[serde(default)]
 pub struct Config {
     verify_accounts: bool,
+    allowed_accounts: Vec<PublicKey>,
+    denied_accounts: Vec<PublicKey>,
+    max_pending_deploys_per_account: u32,
+    min_gas_price: u64,
+    max_deploy_size: u32,
 }
 
 impl Config {
     /// Constructor for deploy_acceptor config.
     pub fn new(verify_accounts: bool) -> Self {
-        Config { verify_accounts }
+        Config {
+            verify_accounts,
+            ..Default::default()
+        }
     }
 
     /// Get verify_accounts setting.
     pub(crate) fn verify_accounts(&self) -> bool {
         self.verify_accounts
     }
+
+    /// Get the accounts allowed to send deploys.  If empty, all accounts are allowed.
+    pub(crate) fn allowed_accounts(&self) -> &[PublicKey] {
+        &self.allowed_accounts
+    }
+
+    /// Get the accounts denied from sending deploys.
+    pub(crate) fn denied_accounts(&self) -> &[PublicKey] {
+        &self.denied_accounts
+    }
+
+    /// Get the maximum number of pending deploys per account.  If 0, there is no limit.
+    pub(crate) fn max_pending_deploys_per_account(&self) -> u32 {
+        self.max_pending_deploys_per_account
+    }
+
+    /// Get the minimum gas price.
+    pub(crate) fn min_gas_price(&self) -> u64 {
+        self.min_gas_price
+    }
+
+    /// Get the maximum serialized size of a deploy in bytes.  If 0, there is no limit.
+    pub(crate) fn max_deploy_size(&self) -> u32 {
+        self.max_deploy_size
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Config {
             verify_accounts: true,
+            allowed_accounts: vec![],
+            denied_accounts: vec![],
+            max_pending_deploys_per_account: 0,
+            min_gas_price: 0,
+            max_deploy_size: 0,
         }
     }
 }
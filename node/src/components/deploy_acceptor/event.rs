@@ -6,7 +6,7 @@ use super::Source;
 use crate::{
     components::deploy_acceptor::Error,
     effect::{announcements::RpcServerAnnouncement, Responder},
-    types::{Deploy, NodeId},
+    types::{Deploy, DeployHash, NodeId},
 };
 use casper_types::Key;
 
@@ -33,6 +33,8 @@ pub enum Event {
         verified: Option<bool>,
         maybe_responder: Option<Responder<Result<(), Error>>>,
     },
+    /// The given deploys have been included in a finalized block.
+    DeploysFinalized { deploy_hashes: Vec<DeployHash> },
 }
 
 impl From<RpcServerAnnouncement> for Event {
@@ -75,6 +77,9 @@ impl Display for Event {
                     account_key
                 )
             }
+            Event::DeploysFinalized { deploy_hashes } => {
+                write!(formatter, "{} deploys finalized", deploy_hashes.len())
+            }
         }
     }
 }
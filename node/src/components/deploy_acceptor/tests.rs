@@ -0,0 +1,174 @@
+use rand::Rng;
+
+use casper_types::SecretKey;
+
+use super::*;
+use crate::{crypto::AsymmetricKeyExt, testing::TestRng, types::TimeDiff};
+
+const TTL_MILLIS: u64 = 60_000;
+
+fn create_test_acceptor(rng: &mut TestRng) -> DeployAcceptor {
+    DeployAcceptor::new(Config::new(false), &Chainspec::random(rng))
+}
+
+fn create_deploy(
+    rng: &mut TestRng,
+    secret_key: &SecretKey,
+    timestamp: Timestamp,
+    gas_price: u64,
+) -> Deploy {
+    Deploy::new(
+        timestamp,
+        TimeDiff::from(TTL_MILLIS),
+        gas_price,
+        vec![],
+        "chain".to_string(),
+        rng.gen(),
+        rng.gen(),
+        secret_key,
+        rng,
+    )
+}
+
+#[test]
+fn should_admit_any_deploy_by_default() {
+    let mut rng = crate::new_rng();
+    let mut acceptor = create_test_acceptor(&mut rng);
+    let secret_key = SecretKey::random(&mut rng);
+
+    for _ in 0..10 {
+        let deploy = create_deploy(&mut rng, &secret_key, Timestamp::zero(), 0);
+        assert!(acceptor.check_admission(&deploy, Timestamp::zero()).is_ok());
+    }
+}
+
+#[test]
+fn should_apply_allow_and_deny_lists() {
+    let mut rng = crate::new_rng();
+    let mut acceptor = create_test_acceptor(&mut rng);
+    let allowed_key = SecretKey::random(&mut rng);
+    let denied_key = SecretKey::random(&mut rng);
+    let other_key = SecretKey::random(&mut rng);
+    let _ = acceptor
+        .allowed_accounts
+        .insert(PublicKey::from(&allowed_key));
+    let _ = acceptor
+        .allowed_accounts
+        .insert(PublicKey::from(&denied_key));
+    let _ = acceptor
+        .denied_accounts
+        .insert(PublicKey::from(&denied_key));
+
+    let deploy = create_deploy(&mut rng, &allowed_key, Timestamp::zero(), 1);
+    assert!(acceptor.check_admission(&deploy, Timestamp::zero()).is_ok());
+
+    // The deny list takes precedence over the allow list.
+    let deploy = create_deploy(&mut rng, &denied_key, Timestamp::zero(), 1);
+    match acceptor.check_admission(&deploy, Timestamp::zero()) {
+        Err(Error::AccountDenied(account)) => assert_eq!(account, PublicKey::from(&denied_key)),
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    let deploy = create_deploy(&mut rng, &other_key, Timestamp::zero(), 1);
+    match acceptor.check_admission(&deploy, Timestamp::zero()) {
+        Err(Error::AccountNotAllowed(account)) => assert_eq!(account, PublicKey::from(&other_key)),
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn should_reject_deploys_below_min_gas_price_or_above_max_size() {
+    let mut rng = crate::new_rng();
+    let mut acceptor = create_test_acceptor(&mut rng);
+    let secret_key = SecretKey::random(&mut rng);
+    acceptor.min_gas_price = 10;
+
+    let deploy = create_deploy(&mut rng, &secret_key, Timestamp::zero(), 9);
+    match acceptor.check_admission(&deploy, Timestamp::zero()) {
+        Err(Error::GasPriceTooLow {
+            gas_price: 9,
+            min_gas_price: 10,
+        }) => (),
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    let deploy = create_deploy(&mut rng, &secret_key, Timestamp::zero(), 10);
+    let size = deploy.serialized_length();
+    acceptor.max_deploy_size = size as u32 - 1;
+    match acceptor.check_admission(&deploy, Timestamp::zero()) {
+        Err(Error::DeployTooLarge { size: actual, .. }) => assert_eq!(actual, size),
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    acceptor.max_deploy_size = size as u32;
+    assert!(acceptor.check_admission(&deploy, Timestamp::zero()).is_ok());
+}
+
+#[test]
+fn should_limit_pending_deploys_per_account() {
+    let mut rng = crate::new_rng();
+    let mut acceptor = create_test_acceptor(&mut rng);
+    let secret_key = SecretKey::random(&mut rng);
+    let other_key = SecretKey::random(&mut rng);
+    acceptor.max_pending_deploys_per_account = 2;
+
+    let now = Timestamp::from(1_000);
+    let deploy1 = create_deploy(&mut rng, &secret_key, now, 1);
+    let deploy2 = create_deploy(&mut rng, &secret_key, now + TimeDiff::from(1), 1);
+    let deploy3 = create_deploy(&mut rng, &secret_key, now, 1);
+    assert!(acceptor.check_admission(&deploy1, now).is_ok());
+    assert!(acceptor.check_admission(&deploy2, now).is_ok());
+
+    // Resubmitting an already pending deploy doesn't count against the limit.
+    assert!(acceptor.check_admission(&deploy1, now).is_ok());
+
+    match acceptor.check_admission(&deploy3, now) {
+        Err(Error::TooManyPendingDeploys { max_pending: 2, .. }) => (),
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    // Other accounts are unaffected.
+    let other_deploy = create_deploy(&mut rng, &other_key, now, 1);
+    assert!(acceptor.check_admission(&other_deploy, now).is_ok());
+
+    // Once a deploy is finalized, it frees up a slot.
+    acceptor.deploys_finalized(&[*deploy1.id()], now);
+    assert!(acceptor.check_admission(&deploy3, now).is_ok());
+
+    // Once a deploy expires, it frees up a slot too.
+    let deploy4 = create_deploy(&mut rng, &secret_key, now, 1);
+    let after_expiry = deploy1.header().expires() + TimeDiff::from(1);
+    assert!(acceptor.check_admission(&deploy4, after_expiry).is_ok());
+
+    // Deploys failing account verification are released.
+    acceptor.release_pending_deploy(deploy4.header().account(), deploy4.id());
+    let deploy5 = create_deploy(&mut rng, &secret_key, after_expiry, 1);
+    assert!(acceptor.check_admission(&deploy5, after_expiry).is_ok());
+}
+
+#[test]
+fn should_evict_expired_pending_deploys_of_all_accounts_on_finalization() {
+    let mut rng = crate::new_rng();
+    let mut acceptor = create_test_acceptor(&mut rng);
+    acceptor.max_pending_deploys_per_account = 2;
+
+    let now = Timestamp::from(1_000);
+    let secret_keys: Vec<_> = (0..3).map(|_| SecretKey::random(&mut rng)).collect();
+    for secret_key in &secret_keys {
+        let deploy = create_deploy(&mut rng, secret_key, now, 1);
+        assert!(acceptor.check_admission(&deploy, now).is_ok());
+    }
+    let later = now + TimeDiff::from(TTL_MILLIS / 2);
+    let unexpired_deploy = create_deploy(&mut rng, &secret_keys[0], later, 1);
+    assert!(acceptor.check_admission(&unexpired_deploy, later).is_ok());
+    assert_eq!(acceptor.pending_client_deploys.len(), 3);
+
+    // Finalizing an unrelated block evicts the expired deploys of every account, dropping the
+    // accounts which have no pending deploys left.
+    let after_expiry = now + TimeDiff::from(TTL_MILLIS + 1);
+    acceptor.deploys_finalized(&[], after_expiry);
+    assert_eq!(acceptor.pending_client_deploys.len(), 1);
+    let pending = &acceptor.pending_client_deploys[unexpired_deploy.header().account()];
+    assert_eq!(pending.len(), 1);
+    assert!(pending.contains_key(unexpired_deploy.id()));
+}
@@ -7,28 +7,35 @@
 //!
 //! This module currently provides both halves of what is required for an API server:
 //! a component implementation that interfaces with other components via being plugged into a
-//! reactor, and an external facing http server that manages SSE subscriptions on a single endpoint.
+//! reactor, and an external facing http server that manages SSE subscriptions.
+//!
+//! Subscribers to `/events` receive every event, while `/events/main`, `/events/deploys` and
+//! `/events/sigs` each provide a subset of the events.  The events can be further filtered via the
+//! query string by `account`, `deploy_hash` and `era_id`.
 //!
 //! This component is passive and receives announcements made by other components while never making
 //! a request of other components itself. The handled announcements are serialized to JSON and
 //! pushed to subscribers.
 //!
-//! This component uses a ring buffer for outbound events providing some robustness against
-//! unintended subscriber disconnects, if a disconnected subscriber re-subscribes before the buffer
-//! has advanced past their last received event.
+//! Outbound events are persisted in an on-disk event store alongside the node's storage, and are
+//! assigned IDs which keep increasing across node restarts.  This provides robustness against
+//! subscriber disconnects and node restarts: a subscriber can resume the stream from any event
+//! which is still within the configured retention window.
 //!
 //! For details about the SSE model and a list of supported SSEs, see:
 //! <https://github.com/CasperLabs/ceps/blob/master/text/0009-client-api.md#rpcs>
 
 mod config;
 mod event;
+mod event_store;
 mod http_server;
 mod sse_server;
 
-use std::{convert::Infallible, fmt::Debug};
+use std::{convert::Infallible, fmt::Debug, path::Path};
 
 use datasize::DataSize;
 use semver::Version;
+use thiserror::Error;
 use tokio::sync::mpsc::{self, UnboundedSender};
 
 use super::Component;
@@ -40,8 +47,20 @@ use crate::{
 
 pub use config::Config;
 pub(crate) use event::Event;
+use event_store::EventStore;
 pub use sse_server::SseData;
 
+/// An error starting the event stream server.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Failed to start listening.
+    #[error(transparent)]
+    Listening(#[from] ListeningError),
+    /// Failed to open the event store.
+    #[error("failed to open event store: {0}")]
+    EventStore(#[from] event_store::Error),
+}
+
 /// A helper trait whose bounds represent the requirements for a reactor event that `run_server` can
 /// work with.
 pub trait ReactorEventT: From<Event> + Send {}
@@ -57,13 +76,20 @@ pub(crate) struct EventStreamServer {
 }
 
 impl EventStreamServer {
-    pub(crate) fn new(config: Config, api_version: Version) -> Result<Self, ListeningError> {
+    /// Creates a new event stream server, persisting its events in the directory `storage_path`.
+    pub(crate) fn new(
+        config: Config,
+        storage_path: &Path,
+        api_version: Version,
+    ) -> Result<Self, Error> {
         let (sse_data_sender, sse_data_receiver) = mpsc::unbounded_channel();
+        let event_store = EventStore::open(storage_path, config.max_retained_events)?;
         let builder = utils::start_listening(&config.address)?;
         tokio::spawn(http_server::run(
             config,
             api_version,
             builder,
+            event_store,
             sse_data_receiver,
         ));
 
@@ -98,6 +124,7 @@ where
                 block_hash,
                 block_header: Box::new(*block_header),
             }),
+            Event::DeployAccepted(deploy) => self.broadcast(SseData::DeployAccepted { deploy }),
             Event::DeployProcessed {
                 deploy_hash,
                 deploy_header,
@@ -112,6 +139,9 @@ where
                 block_hash: Box::new(block_hash),
                 execution_result,
             }),
+            Event::DeployExpired(deploy_hash) => {
+                self.broadcast(SseData::DeployExpired { deploy_hash })
+            }
             Event::Fault {
                 era_id,
                 public_key,
@@ -122,6 +152,24 @@ where
                 timestamp,
             }),
             Event::FinalitySignature(fs) => self.broadcast(SseData::FinalitySignature(fs)),
+            Event::Step {
+                era_id,
+                execution_effect,
+            } => self.broadcast(SseData::Step {
+                era_id,
+                execution_effect,
+            }),
+            Event::EraEnded {
+                era_id,
+                block_hash,
+                era_report,
+                next_era_validator_weights,
+            } => self.broadcast(SseData::EraEnded {
+                era_id,
+                block_hash,
+                era_report,
+                next_era_validator_weights,
+            }),
         }
     }
 }
@@ -6,8 +6,8 @@ use serde::{Deserialize, Serialize};
 /// Uses a fixed port per node, but binds on any interface.
 const DEFAULT_ADDRESS: &str = "0.0.0.0:0";
 
-/// Default number of SSEs to buffer.
-const DEFAULT_EVENT_STREAM_BUFFER_LENGTH: u32 = 100;
+/// Default number of SSEs to retain in the event store.
+const DEFAULT_MAX_RETAINED_EVENTS: u32 = 10_000;
 
 /// Default broadcast channel size.
 const DEFAULT_BROADCAST_CHANNEL_SIZE: usize = 100;
@@ -23,8 +23,10 @@ pub struct Config {
     /// Address to bind event stream SSE HTTP server to.
     pub address: String,
 
-    /// Number of SSEs to buffer.
-    pub event_stream_buffer_length: u32,
+    /// Number of most recent SSEs to retain in the event store and make available to clients
+    /// resuming the event stream via `start_from`.
+    #[serde(alias = "event_stream_buffer_length")]
+    pub max_retained_events: u32,
 
     /// The number of events to buffer in the tokio broadcast channel to help slower clients to try
     /// to avoid missing events.  See <https://docs.rs/tokio/0.2.22/tokio/sync/broadcast/index.html#lagging>
@@ -40,7 +42,7 @@ impl Config {
     pub fn new() -> Self {
         Config {
             address: DEFAULT_ADDRESS.to_string(),
-            event_stream_buffer_length: DEFAULT_EVENT_STREAM_BUFFER_LENGTH,
+            max_retained_events: DEFAULT_MAX_RETAINED_EVENTS,
             broadcast_channel_size: DEFAULT_BROADCAST_CHANNEL_SIZE,
             qps_limit: DEFAULT_QPS_LIMIT,
         }
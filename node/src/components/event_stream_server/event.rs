@@ -1,10 +1,16 @@
-use std::fmt::{self, Display, Formatter};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
 
-use casper_types::{ExecutionResult, PublicKey};
+use casper_types::{ExecutionEffect, ExecutionResult, PublicKey, U512};
 
 use crate::{
     components::consensus::EraId,
-    types::{BlockHash, BlockHeader, DeployHash, DeployHeader, FinalitySignature, Timestamp},
+    types::{
+        Block, BlockHash, BlockHeader, Deploy, DeployHash, DeployHeader, EraReport,
+        FinalitySignature, Timestamp,
+    },
 };
 
 #[derive(Debug)]
@@ -13,27 +19,58 @@ pub enum Event {
         block_hash: BlockHash,
         block_header: Box<BlockHeader>,
     },
+    DeployAccepted(Box<Deploy>),
     DeployProcessed {
         deploy_hash: DeployHash,
         deploy_header: Box<DeployHeader>,
         block_hash: BlockHash,
         execution_result: Box<ExecutionResult>,
     },
+    DeployExpired(DeployHash),
     Fault {
         era_id: EraId,
         public_key: PublicKey,
         timestamp: Timestamp,
     },
     FinalitySignature(Box<FinalitySignature>),
+    Step {
+        era_id: EraId,
+        execution_effect: Box<ExecutionEffect>,
+    },
+    EraEnded {
+        era_id: EraId,
+        block_hash: BlockHash,
+        era_report: Box<EraReport>,
+        next_era_validator_weights: BTreeMap<PublicKey, U512>,
+    },
+}
+
+impl Event {
+    /// Returns an `EraEnded` event if the given block is a switch block.
+    pub(crate) fn era_ended(block: &Block) -> Option<Self> {
+        let block_header = block.header();
+        let era_report = block_header.era_end()?;
+        let next_era_validator_weights = block_header.next_era_validator_weights()?;
+        Some(Event::EraEnded {
+            era_id: block_header.era_id(),
+            block_hash: *block.hash(),
+            era_report: Box::new(era_report.clone()),
+            next_era_validator_weights: next_era_validator_weights.clone(),
+        })
+    }
 }
 
 impl Display for Event {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         match self {
             Event::BlockAdded { block_hash, .. } => write!(formatter, "block added {}", block_hash),
+            Event::DeployAccepted(deploy) => write!(formatter, "deploy accepted {}", deploy.id()),
             Event::DeployProcessed { deploy_hash, .. } => {
                 write!(formatter, "deploy processed {}", deploy_hash)
             }
+            Event::DeployExpired(deploy_hash) => {
+                write!(formatter, "deploy expired {}", deploy_hash)
+            }
             Event::Fault {
                 era_id,
                 public_key,
@@ -44,6 +81,10 @@ impl Display for Event {
                 public_key, timestamp, era_id,
             ),
             Event::FinalitySignature(fs) => write!(formatter, "finality signature {}", fs),
+            Event::Step { era_id, .. } => write!(formatter, "step committed for era {}", era_id),
+            Event::EraEnded {
+                era_id, block_hash, ..
+            } => write!(formatter, "era {} ended with block {}", era_id, block_hash),
         }
     }
 }
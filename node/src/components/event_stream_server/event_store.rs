@@ -0,0 +1,534 @@
+//! Persistent store for the events emitted on the event stream.
+//!
+//! Every event is written to a dedicated LMDB database, keyed by its ID encoded as big-endian bytes
+//! so that the database's natural ordering matches the order in which events were emitted. This
+//! allows IDs to keep increasing monotonically across node restarts, and subscribers to resume
+//! from any event which is still retained.
+//!
+//! Only the most recent `max_retained_events` events are kept; older ones are pruned as new events
+//! are stored.
+//!
+//! Stored events are replayed to new subscribers via an [`EventStoreReader`], which reads them in
+//! bounded batches on blocking threads, so that a client resuming from the oldest retained event
+//! neither holds up the event stream server's task nor has all retained events loaded at once.
+//!
+//! Each stored event is prefixed with the version of its encoding. Events written with a different
+//! version, e.g. by a node version whose `SseData` differed, can't be decoded and are dropped when
+//! the store is opened.
+
+use std::{
+    convert::TryInto,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use lmdb::{
+    Cursor, Database, DatabaseFlags, Environment, EnvironmentFlags, Transaction, WriteFlags,
+};
+use thiserror::Error;
+use tokio::{
+    sync::{mpsc, Semaphore},
+    task::{self, JoinError},
+};
+use tracing::{error, info, warn};
+
+use super::sse_server::{Id, ServerSentEvent, SseData};
+
+/// Filename for the LMDB database holding the event stream events.
+const EVENT_STORE_DB_FILENAME: &str = "event_stream.lmdb";
+
+/// Name of the database holding the event stream events.
+const EVENTS_DB_NAME: &str = "events";
+
+/// The version of the encoding of stored events.
+///
+/// Must be incremented whenever the serialized form of `SseData` changes.
+const EVENT_ENCODING_VERSION: u8 = 0;
+
+/// One Gibibyte.
+const GIB: usize = 1024 * 1024 * 1024;
+
+/// Upper bound for the memory map used by the event store.
+const MAX_EVENT_STORE_SIZE: usize = 10 * GIB;
+
+/// The maximum number of concurrent read transactions, i.e. of batches of stored events being read
+/// for new subscribers at the same time.
+const MAX_READERS: u32 = 16;
+
+/// The maximum number of events read in a single transaction when replaying stored events.
+pub(super) const REPLAY_BATCH_SIZE: usize = 100;
+
+/// An event store error.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Failure to create the directory holding the database.
+    #[error("failed to create event store directory `{}`: {}", .0.display(), .1)]
+    CreateDatabaseDirectory(PathBuf, io::Error),
+    /// LMDB error while operating.
+    #[error("event store database error: {0}")]
+    Lmdb(#[from] lmdb::Error),
+    /// Failed to serialize or deserialize an event.
+    #[error("event store serialization error: {0}")]
+    Serialization(#[from] bincode::Error),
+    /// Found a key in the database which is not a valid event ID.
+    #[error("invalid event ID in event store: {0:?}")]
+    InvalidId(Vec<u8>),
+    /// Found an event whose encoding version doesn't match the current one.
+    #[error("event {0} in event store has an unsupported encoding version")]
+    UnsupportedEncodingVersion(Id),
+    /// The blocking task reading stored events failed.
+    #[error("event store read task failed: {0}")]
+    ReadTask(#[from] JoinError),
+}
+
+/// The persistent store of events emitted on the event stream.
+pub(super) struct EventStore {
+    /// Environment holding the LMDB database.
+    env: Arc<Environment>,
+    /// The events database.
+    events_db: Database,
+    /// Permits limiting the number of concurrent read transactions to `MAX_READERS`.
+    read_permits: Arc<Semaphore>,
+    /// The ID which will be assigned to the next stored event.
+    next_id: Id,
+    /// The maximum number of events retained in the store.
+    max_retained_events: u32,
+}
+
+impl EventStore {
+    /// Opens (or creates) the event store in the directory `root`.
+    ///
+    /// Any events which fall outside the retention window are pruned immediately.
+    pub(super) fn open(root: &Path, max_retained_events: u32) -> Result<Self, Error> {
+        if !root.exists() {
+            fs::create_dir_all(root)
+                .map_err(|err| Error::CreateDatabaseDirectory(root.to_path_buf(), err))?;
+        }
+
+        let env = Environment::new()
+            .set_flags(
+                // We manage our own directory.
+                EnvironmentFlags::NO_SUB_DIR
+                // Disable thread local storage, strongly suggested for operation with tokio.
+                    | EnvironmentFlags::NO_TLS,
+            )
+            .set_max_readers(MAX_READERS)
+            .set_max_dbs(1)
+            .set_map_size(MAX_EVENT_STORE_SIZE)
+            .open(&root.join(EVENT_STORE_DB_FILENAME))?;
+        let events_db = env.create_db(Some(EVENTS_DB_NAME), DatabaseFlags::empty())?;
+
+        let mut event_store = EventStore {
+            env: Arc::new(env),
+            events_db,
+            read_permits: Arc::new(Semaphore::new(MAX_READERS as usize)),
+            next_id: 0,
+            max_retained_events,
+        };
+
+        let stored = event_store.stored_ids_and_versions()?;
+        // IDs of events which can't be decoded are never reused, so that clients resuming the
+        // stream don't mistake a new event for one they've already seen.
+        if let Some((latest_id, _)) = stored.last() {
+            event_store.next_id = latest_id + 1;
+        }
+        let oldest_retained_id = event_store.oldest_retained_id();
+        let (expired_ids, incompatible_ids): (Vec<Id>, Vec<Id>) = stored
+            .into_iter()
+            .filter(|(id, version)| {
+                *id < oldest_retained_id || *version != Some(EVENT_ENCODING_VERSION)
+            })
+            .map(|(id, _)| id)
+            .partition(|id| *id < oldest_retained_id);
+        if !expired_ids.is_empty() || !incompatible_ids.is_empty() {
+            let mut txn = event_store.env.begin_rw_txn()?;
+            for id in expired_ids.iter().chain(&incompatible_ids) {
+                txn.del(event_store.events_db, &id.to_be_bytes(), None)?;
+            }
+            txn.commit()?;
+        }
+        if !incompatible_ids.is_empty() {
+            warn!(
+                dropped = incompatible_ids.len(),
+                "dropped stored events with an unsupported encoding version"
+            );
+        }
+
+        info!(
+            next_id = event_store.next_id,
+            pruned = expired_ids.len(),
+            "opened event store"
+        );
+        Ok(event_store)
+    }
+
+    /// Assigns the next ID to the given data and persists the resulting event, pruning the oldest
+    /// event if it has fallen outside the retention window.
+    ///
+    /// A failure to persist the event is logged, but the event is still returned so that it can be
+    /// broadcast to connected clients.
+    pub(super) fn store(&mut self, data: SseData) -> ServerSentEvent {
+        let event = ServerSentEvent {
+            id: Some(self.next_id),
+            data,
+        };
+        self.next_id += 1;
+        if let Err(error) = self.put(&event) {
+            error!(%error, id = ?event.id, "failed to persist event");
+        }
+        event
+    }
+
+    /// Returns a reader of the stored events which can be used outside the event stream server's
+    /// task.
+    pub(super) fn reader(&self) -> EventStoreReader {
+        EventStoreReader {
+            env: Arc::clone(&self.env),
+            events_db: self.events_db,
+            read_permits: Arc::clone(&self.read_permits),
+        }
+    }
+
+    /// Writes the event to the database and removes the event which has just fallen out of the
+    /// retention window, if any.
+    fn put(&self, event: &ServerSentEvent) -> Result<(), Error> {
+        let id = event
+            .id
+            .expect("events emitted on the event stream should have an ID");
+        let mut value = vec![EVENT_ENCODING_VERSION];
+        bincode::serialize_into(&mut value, &event.data)?;
+
+        let mut txn = self.env.begin_rw_txn()?;
+        txn.put(
+            self.events_db,
+            &id.to_be_bytes(),
+            &value,
+            WriteFlags::default(),
+        )?;
+        if let Some(expired_id) = self.oldest_retained_id().checked_sub(1) {
+            match txn.del(self.events_db, &expired_id.to_be_bytes(), None) {
+                Ok(()) | Err(lmdb::Error::NotFound) => (),
+                Err(error) => return Err(error.into()),
+            }
+        }
+        txn.commit()?;
+        Ok(())
+    }
+
+    /// Returns the IDs of all events currently held in the database along with the versions of
+    /// their encodings, in ascending order of IDs.
+    fn stored_ids_and_versions(&self) -> Result<Vec<(Id, Option<u8>)>, Error> {
+        let txn = self.env.begin_ro_txn()?;
+        let mut cursor = txn.open_ro_cursor(self.events_db)?;
+        // Note: `iter_start` has an undocumented panic if called on an empty database. We rely on
+        //       the iterator being at the start when created.
+        cursor
+            .iter()
+            .map(|(raw_key, raw_value)| Ok((decode_id(raw_key)?, raw_value.first().copied())))
+            .collect()
+    }
+
+    /// Returns the ID of the oldest event inside the retention window.
+    fn oldest_retained_id(&self) -> Id {
+        self.next_id
+            .saturating_sub(Id::from(self.max_retained_events))
+    }
+}
+
+/// A handle for reading the events held in the event store.
+#[derive(Clone)]
+pub(super) struct EventStoreReader {
+    env: Arc<Environment>,
+    events_db: Database,
+    read_permits: Arc<Semaphore>,
+}
+
+impl EventStoreReader {
+    /// Sends all retained events with an ID of `start_from` or greater to `sender`, in order.
+    ///
+    /// Events are read in batches of at most `REPLAY_BATCH_SIZE`, and the next batch is only read
+    /// once `sender` has accepted the previous one.  Returns early if the receiver is dropped.
+    pub(super) async fn replay(
+        &self,
+        mut start_from: Id,
+        mut sender: mpsc::Sender<ServerSentEvent>,
+    ) -> Result<(), Error> {
+        loop {
+            let events = self.read_batch(start_from).await?;
+            let is_last_batch = events.len() < REPLAY_BATCH_SIZE;
+            for event in events {
+                if let Some(id) = event.id {
+                    start_from = id + 1;
+                }
+                if sender.send(event).await.is_err() {
+                    return Ok(());
+                }
+            }
+            if is_last_batch {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Reads the next batch of events starting from `start_from` on a blocking thread.
+    async fn read_batch(&self, start_from: Id) -> Result<Vec<ServerSentEvent>, Error> {
+        let _permit = self.read_permits.acquire().await;
+        let reader = self.clone();
+        task::spawn_blocking(move || reader.events_from(start_from, REPLAY_BATCH_SIZE)).await?
+    }
+
+    /// Returns at most `max_count` retained events with an ID of `start_from` or greater, in order.
+    fn events_from(&self, start_from: Id, max_count: usize) -> Result<Vec<ServerSentEvent>, Error> {
+        let txn = self.env.begin_ro_txn()?;
+        let mut cursor = txn.open_ro_cursor(self.events_db)?;
+
+        // Keys are big-endian encoded IDs, so seeking to the encoded `start_from` positions the
+        // cursor at the first event to return.
+        let mut events = vec![];
+        for (raw_key, raw_value) in cursor.iter_from(&start_from.to_be_bytes()).take(max_count) {
+            let id = decode_id(raw_key)?;
+            let data = match raw_value.split_first() {
+                Some((&EVENT_ENCODING_VERSION, encoded_data)) => {
+                    bincode::deserialize(encoded_data)?
+                }
+                _ => return Err(Error::UnsupportedEncodingVersion(id)),
+            };
+            events.push(ServerSentEvent { id: Some(id), data });
+        }
+        Ok(events)
+    }
+}
+
+/// Decodes an event ID from its big-endian database key.
+fn decode_id(raw_key: &[u8]) -> Result<Id, Error> {
+    raw_key
+        .try_into()
+        .map(Id::from_be_bytes)
+        .map_err(|_| Error::InvalidId(raw_key.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use rand::Rng;
+
+    use casper_types::{ExecutionResult, PublicKey, SecretKey, U512};
+
+    use super::*;
+    use crate::{
+        components::consensus::EraId,
+        crypto::AsymmetricKeyExt,
+        testing::TestRng,
+        types::{Block, Deploy, EraReport, Timestamp},
+    };
+
+    fn fault(era_id: u64) -> SseData {
+        SseData::Fault {
+            era_id: EraId(era_id),
+            public_key: PublicKey::System,
+            timestamp: Timestamp::zero(),
+        }
+    }
+
+    /// Returns a random execution result whose effect contains messages.
+    fn execution_result_with_messages(rng: &mut TestRng) -> ExecutionResult {
+        let mut execution_result: ExecutionResult = rng.gen();
+        let effect = match &mut execution_result {
+            ExecutionResult::Failure { effect, .. } | ExecutionResult::Success { effect, .. } => {
+                effect
+            }
+        };
+        effect.messages.push(rng.gen());
+        effect.messages.push(rng.gen());
+        execution_result
+    }
+
+    fn ids(events: &[ServerSentEvent]) -> Vec<Id> {
+        events.iter().map(|event| event.id.unwrap()).collect()
+    }
+
+    fn stored_ids(event_store: &EventStore, start_from: Id) -> Vec<Id> {
+        ids(&event_store
+            .reader()
+            .events_from(start_from, usize::MAX)
+            .unwrap())
+    }
+
+    #[test]
+    fn should_assign_increasing_ids_and_replay_from_any_retained_id() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut event_store = EventStore::open(tempdir.path(), 10).unwrap();
+
+        for era_id in 0..5 {
+            let event = event_store.store(fault(era_id));
+            assert_eq!(event.id, Some(era_id));
+            assert_eq!(event.data, fault(era_id));
+        }
+
+        let events = event_store.reader().events_from(0, usize::MAX).unwrap();
+        assert_eq!(ids(&events), vec![0, 1, 2, 3, 4]);
+        assert_eq!(events[3].data, fault(3));
+
+        assert_eq!(stored_ids(&event_store, 3), vec![3, 4]);
+        assert!(stored_ids(&event_store, 5).is_empty());
+        assert_eq!(
+            ids(&event_store.reader().events_from(1, 2).unwrap()),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn should_only_retain_configured_number_of_events() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut event_store = EventStore::open(tempdir.path(), 3).unwrap();
+
+        for era_id in 0..7 {
+            let _ = event_store.store(fault(era_id));
+        }
+
+        assert_eq!(stored_ids(&event_store, 0), vec![4, 5, 6]);
+    }
+
+    #[test]
+    fn should_continue_ids_and_prune_after_reopening() {
+        let tempdir = tempfile::tempdir().unwrap();
+        {
+            let mut event_store = EventStore::open(tempdir.path(), 10).unwrap();
+            for era_id in 0..6 {
+                let _ = event_store.store(fault(era_id));
+            }
+        }
+
+        // Reopen with a smaller retention window.
+        let mut event_store = EventStore::open(tempdir.path(), 4).unwrap();
+        assert_eq!(stored_ids(&event_store, 0), vec![2, 3, 4, 5]);
+
+        let event = event_store.store(fault(6));
+        assert_eq!(event.id, Some(6));
+        assert_eq!(stored_ids(&event_store, 0), vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn should_drop_events_with_unsupported_encoding_version_on_reopening() {
+        let tempdir = tempfile::tempdir().unwrap();
+        {
+            let mut event_store = EventStore::open(tempdir.path(), 10).unwrap();
+            for era_id in 0..4 {
+                let _ = event_store.store(fault(era_id));
+            }
+
+            // Overwrite event 2 as if written with a different encoding version.
+            let mut value = vec![EVENT_ENCODING_VERSION + 1];
+            bincode::serialize_into(&mut value, &fault(2)).unwrap();
+            let mut txn = event_store.env.begin_rw_txn().unwrap();
+            txn.put(
+                event_store.events_db,
+                &2u64.to_be_bytes(),
+                &value,
+                WriteFlags::default(),
+            )
+            .unwrap();
+            txn.commit().unwrap();
+            assert!(matches!(
+                event_store.reader().events_from(0, usize::MAX),
+                Err(Error::UnsupportedEncodingVersion(2))
+            ));
+        }
+
+        let mut event_store = EventStore::open(tempdir.path(), 10).unwrap();
+        assert_eq!(stored_ids(&event_store, 0), vec![0, 1, 3]);
+        assert_eq!(event_store.store(fault(4)).id, Some(4));
+    }
+
+    #[tokio::test]
+    async fn should_replay_events_in_batches() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let event_count = 2 * REPLAY_BATCH_SIZE as u64 + 5;
+        let mut event_store = EventStore::open(tempdir.path(), event_count as u32).unwrap();
+        for era_id in 0..event_count {
+            let _ = event_store.store(fault(era_id));
+        }
+
+        // A channel smaller than a batch forces the replay to wait for the receiver.
+        let (sender, mut receiver) = mpsc::channel(10);
+        let replay = tokio::spawn({
+            let reader = event_store.reader();
+            async move { reader.replay(3, sender).await }
+        });
+        let mut replayed = vec![];
+        while let Some(event) = receiver.recv().await {
+            replayed.push(event);
+        }
+        replay.await.unwrap().unwrap();
+
+        assert_eq!(ids(&replayed), (3..event_count).collect::<Vec<_>>());
+        assert_eq!(replayed[0].data, fault(3));
+    }
+
+    #[test]
+    fn should_round_trip_persisted_event_payloads() {
+        let mut rng = TestRng::new();
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut event_store = EventStore::open(tempdir.path(), 10).unwrap();
+
+        let block = Block::random(&mut rng);
+        let deploy = Deploy::random(&mut rng);
+        let validator = PublicKey::from(&SecretKey::random(&mut rng));
+        let mut rewards = BTreeMap::new();
+        let _ = rewards.insert(validator, rng.gen::<u64>());
+        let mut next_era_validator_weights = BTreeMap::new();
+        let _ = next_era_validator_weights.insert(validator, U512::from(rng.gen::<u64>()));
+        let step_effect = match execution_result_with_messages(&mut rng) {
+            ExecutionResult::Failure { effect, .. } | ExecutionResult::Success { effect, .. } => {
+                effect
+            }
+        };
+
+        let payloads = vec![
+            SseData::BlockAdded {
+                block_hash: *block.hash(),
+                block_header: Box::new(block.header().clone()),
+            },
+            SseData::DeployAccepted {
+                deploy: Box::new(deploy.clone()),
+            },
+            SseData::DeployProcessed {
+                deploy_hash: Box::new(*deploy.id()),
+                account: *deploy.header().account(),
+                timestamp: deploy.header().timestamp(),
+                ttl: deploy.header().ttl(),
+                dependencies: deploy.header().dependencies().clone(),
+                block_hash: Box::new(*block.hash()),
+                execution_result: Box::new(execution_result_with_messages(&mut rng)),
+            },
+            SseData::Step {
+                era_id: EraId(rng.gen_range(0, 10)),
+                execution_effect: Box::new(step_effect),
+            },
+            SseData::EraEnded {
+                era_id: EraId(rng.gen_range(0, 10)),
+                block_hash: *block.hash(),
+                era_report: Box::new(EraReport {
+                    equivocators: vec![validator],
+                    rewards,
+                    inactive_validators: vec![validator],
+                }),
+                next_era_validator_weights,
+            },
+        ];
+
+        for payload in &payloads {
+            let _ = event_store.store(payload.clone());
+        }
+        let stored: Vec<SseData> = event_store
+            .reader()
+            .events_from(0, usize::MAX)
+            .unwrap()
+            .into_iter()
+            .map(|event| event.data)
+            .collect();
+        assert_eq!(stored, payloads);
+    }
+}
@@ -11,22 +11,23 @@ use tokio::{
     sync::{mpsc, oneshot},
 };
 use tower::builder::ServiceBuilder;
-use tracing::{info, trace};
-use wheelbuf::WheelBuf;
+use tracing::{info, trace, warn};
 
 use super::{
-    sse_server::{self, BroadcastChannelMessage, ServerSentEvent},
+    event_store::{EventStore, EventStoreReader},
+    sse_server::{self, BroadcastChannelMessage, NewSubscriberInfo, ServerSentEvent},
     Config, SseData,
 };
 
 /// Run the HTTP server.
 ///
-/// `data_receiver` will provide the server with local events which should then be sent to all
-/// subscribed clients.
+/// `data_receiver` will provide the server with local events which should then be persisted in the
+/// `event_store` and sent to all subscribed clients.
 pub(super) async fn run(
     config: Config,
     api_version: Version,
     builder: Builder<AddrIncoming>,
+    mut event_store: EventStore,
     mut data_receiver: mpsc::UnboundedReceiver<SseData>,
 ) {
     // Event stream channels and filter.
@@ -54,13 +55,6 @@ pub(super) async fn run(
 
     let server_joiner = tokio::spawn(server_with_shutdown);
 
-    // Initialize the index and buffer for the SSEs.
-    let mut event_index = 0_u32;
-    let mut buffer = WheelBuf::new(vec![
-        ServerSentEvent::initial_event(api_version.clone());
-        config.event_stream_buffer_length as usize
-    ]);
-
     // Start handling received messages from the two channels; info on new client subscribers and
     // incoming events announced by node components.
     let event_stream_fut = async {
@@ -68,37 +62,26 @@ pub(super) async fn run(
             select! {
                 maybe_new_subscriber = new_subscriber_info_receiver.recv() => {
                     if let Some(subscriber) = maybe_new_subscriber {
-                        // First send the client the `ApiVersion` event.  We don't care if this
-                        // errors - the client may have disconnected already.
-                        let _ = subscriber
-                            .initial_events_sender
-                            .send(ServerSentEvent::initial_event(api_version.clone()));
-                        // If the client supplied a "start_from" index, provide the buffered events.
-                        // If they requested more than is buffered, just provide the whole buffer.
-                        if let Some(start_index) = subscriber.start_from {
-                            for event in buffer
-                                .iter()
-                                .skip_while(|event| event.id.unwrap() < start_index)
-                            {
-                                // As per sending `SSE_INITIAL_EVENT`, we don't care if this errors.
-                                let _ = subscriber.initial_events_sender.send(event.clone());
-                            }
-                        }
+                        // Reading the stored events may take a while, so it's done on a separate
+                        // task to avoid holding up the broadcasting of new events.
+                        let _ = tokio::spawn(send_initial_events(
+                            subscriber,
+                            api_version.clone(),
+                            event_store.reader(),
+                        ));
                     }
                 }
 
                 maybe_data = data_receiver.recv() => {
                     match maybe_data {
                         Some(data) => {
-                            // Buffer the data and broadcast it to subscribed clients.
+                            // Persist the data and broadcast it to subscribed clients.
                             trace!("Event stream server received {:?}", data);
-                            let event = ServerSentEvent { id: Some(event_index), data };
-                            buffer.push(event.clone());
+                            let event = event_store.store(data);
                             let message = BroadcastChannelMessage::ServerSentEvent(event);
                             // This can validly fail if there are no connected clients, so don't log
                             // the error.
                             let _ = broadcaster.send(message);
-                            event_index = event_index.wrapping_add(1);
                         }
                         None => {
                             // The data sender has been dropped - exit the loop.
@@ -121,3 +104,31 @@ pub(super) async fn run(
 
     trace!("Event stream server stopped");
 }
+
+/// Sends the `ApiVersion` event to a new subscriber, followed by the stored events it requested.
+async fn send_initial_events(
+    subscriber: NewSubscriberInfo,
+    api_version: Version,
+    event_store_reader: EventStoreReader,
+) {
+    let mut initial_events_sender = subscriber.initial_events_sender;
+    // First send the client the `ApiVersion` event.  We don't care if this errors - the client may
+    // have disconnected already.
+    if initial_events_sender
+        .send(ServerSentEvent::initial_event(api_version))
+        .await
+        .is_err()
+    {
+        return;
+    }
+    // If the client supplied a "start_from" index, provide the stored events.  If they requested
+    // more than is retained, just provide all retained events.
+    if let Some(start_index) = subscriber.start_from {
+        if let Err(error) = event_store_reader
+            .replay(start_index, initial_events_sender)
+            .await
+        {
+            warn!(%error, "failed to read stored events for new subscriber");
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! Types and functions used by the http server to manage the event-stream.
 
+use std::collections::BTreeMap;
+
 use datasize::DataSize;
-use futures::{Stream, StreamExt};
+use futures::{future, Stream, StreamExt};
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use tokio::sync::{
@@ -11,22 +13,36 @@ use tokio::sync::{
 use tracing::{error, info, trace};
 use warp::{
     filters::BoxedFilter,
+    http::StatusCode,
+    reply::Response,
     sse::{self, ServerSentEvent as WarpServerSentEvent},
     Filter, Reply,
 };
 
-use casper_types::{ExecutionResult, PublicKey};
+use casper_types::{AsymmetricType, ExecutionEffect, ExecutionResult, PublicKey, U512};
 
 use crate::{
     components::consensus::EraId,
-    types::{BlockHash, BlockHeader, DeployHash, FinalitySignature, TimeDiff, Timestamp},
+    crypto::hash::Digest,
+    types::{
+        BlockHash, BlockHeader, Deploy, DeployHash, EraReport, FinalitySignature, TimeDiff,
+        Timestamp,
+    },
 };
 
+use super::event_store::REPLAY_BATCH_SIZE;
+
 /// The URL path.
 pub const SSE_API_PATH: &str = "events";
+/// The URL path segment, following `SSE_API_PATH`, of the endpoint for the main events.
+pub const SSE_API_MAIN_PATH: &str = "main";
+/// The URL path segment, following `SSE_API_PATH`, of the endpoint for deploy events.
+pub const SSE_API_DEPLOYS_PATH: &str = "deploys";
+/// The URL path segment, following `SSE_API_PATH`, of the endpoint for finality signatures.
+pub const SSE_API_SIGNATURES_PATH: &str = "sigs";
 
 /// The "id" field of the events sent on the event stream to clients.
-type Id = u32;
+pub(super) type Id = u64;
 
 /// The "data" field of the events sent on the event stream to clients.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
@@ -40,6 +56,8 @@ pub enum SseData {
         block_hash: BlockHash,
         block_header: Box<BlockHeader>,
     },
+    /// The given deploy has been newly accepted by this node.
+    DeployAccepted { deploy: Box<Deploy> },
     /// The given deploy has been executed, committed and forms part of the given block.
     DeployProcessed {
         deploy_hash: Box<DeployHash>,
@@ -51,6 +69,9 @@ pub enum SseData {
         #[data_size(skip)]
         execution_result: Box<ExecutionResult>,
     },
+    /// The given deploy has expired before being included in a block, and has been dropped from
+    /// this node's block proposer.
+    DeployExpired { deploy_hash: DeployHash },
     /// Generic representation of validator's fault in an era.
     Fault {
         era_id: EraId,
@@ -59,6 +80,19 @@ pub enum SseData {
     },
     /// New finality signature received.
     FinalitySignature(Box<FinalitySignature>),
+    /// The step at the end of the given era has been committed.
+    Step {
+        era_id: EraId,
+        #[data_size(skip)]
+        execution_effect: Box<ExecutionEffect>,
+    },
+    /// The given era has ended with the given switch block.
+    EraEnded {
+        era_id: EraId,
+        block_hash: BlockHash,
+        era_report: Box<EraReport>,
+        next_era_validator_weights: BTreeMap<PublicKey, U512>,
+    },
 }
 
 /// The components of a single SSE.
@@ -97,14 +131,149 @@ pub(super) struct NewSubscriberInfo {
     /// The event ID from which the stream should start for this client.
     pub(super) start_from: Option<Id>,
     /// A channel to send the initial events to the client's handler.  This will always send the
-    /// ApiVersion as the first event, and then any buffered events as indicated by `start_from`.
-    pub(super) initial_events_sender: mpsc::UnboundedSender<ServerSentEvent>,
+    /// ApiVersion as the first event, and then any stored events as indicated by `start_from`.
+    ///
+    /// The channel is bounded, so that stored events are only read as fast as the client consumes
+    /// them.
+    pub(super) initial_events_sender: mpsc::Sender<ServerSentEvent>,
 }
 
-/// The endpoint's query string, e.g. `http://localhost:22777/events?start_from=999`
+/// The endpoint's query string, e.g.
+/// `http://localhost:22777/events/main?start_from=999&account=01aa..ff`
 #[derive(Deserialize, Debug)]
 struct Query {
+    /// The event ID from which the stream should start.
     start_from: Option<Id>,
+    /// Only events relating to the account with the given hex-encoded public key are sent.
+    account: Option<String>,
+    /// Only events relating to the deploy with the given hex-encoded hash are sent.
+    deploy_hash: Option<String>,
+    /// Only events relating to the given era are sent.
+    era_id: Option<u64>,
+}
+
+/// The endpoints of the event-stream server, each providing a subset of the events.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Endpoint {
+    /// `/events`: all events.
+    Events,
+    /// `/events/main`: all events other than `DeployAccepted`s and `FinalitySignature`s.
+    Main,
+    /// `/events/deploys`: deploy-related events.
+    Deploys,
+    /// `/events/sigs`: `FinalitySignature` events.
+    Sigs,
+}
+
+impl Endpoint {
+    /// Returns the path segment following `SSE_API_PATH` for this endpoint, if any.
+    fn path(&self) -> Option<&'static str> {
+        match self {
+            Endpoint::Events => None,
+            Endpoint::Main => Some(SSE_API_MAIN_PATH),
+            Endpoint::Deploys => Some(SSE_API_DEPLOYS_PATH),
+            Endpoint::Sigs => Some(SSE_API_SIGNATURES_PATH),
+        }
+    }
+
+    /// Returns whether the given data should be streamed on this endpoint.
+    fn includes(&self, data: &SseData) -> bool {
+        match (self, data) {
+            (_, SseData::ApiVersion(_)) | (Endpoint::Events, _) => true,
+            (Endpoint::Main, SseData::BlockAdded { .. })
+            | (Endpoint::Main, SseData::DeployProcessed { .. })
+            | (Endpoint::Main, SseData::DeployExpired { .. })
+            | (Endpoint::Main, SseData::Fault { .. })
+            | (Endpoint::Main, SseData::Step { .. })
+            | (Endpoint::Main, SseData::EraEnded { .. }) => true,
+            (Endpoint::Deploys, SseData::DeployAccepted { .. })
+            | (Endpoint::Deploys, SseData::DeployProcessed { .. })
+            | (Endpoint::Deploys, SseData::DeployExpired { .. }) => true,
+            (Endpoint::Sigs, SseData::FinalitySignature(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// The filter applied to the events streamed to a single client, derived from the endpoint and
+/// query string of the client's request.
+///
+/// Where a query filter is specified, only events which relate to the requested value are sent;
+/// events which carry no such value are not sent.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(super) struct EventFilter {
+    endpoint: Endpoint,
+    account: Option<PublicKey>,
+    deploy_hash: Option<DeployHash>,
+    era_id: Option<EraId>,
+}
+
+impl EventFilter {
+    /// Constructs a new filter for the given endpoint, parsing the filters from the query.
+    fn new(endpoint: Endpoint, query: &Query) -> Result<Self, String> {
+        let account = query
+            .account
+            .as_ref()
+            .map(|account| {
+                PublicKey::from_hex(account)
+                    .map_err(|error| format!("failed to parse account: {}", error))
+            })
+            .transpose()?;
+        let deploy_hash = query
+            .deploy_hash
+            .as_ref()
+            .map(|deploy_hash| {
+                Digest::from_hex(deploy_hash)
+                    .map(DeployHash::new)
+                    .map_err(|error| format!("failed to parse deploy_hash: {}", error))
+            })
+            .transpose()?;
+        Ok(EventFilter {
+            endpoint,
+            account,
+            deploy_hash,
+            era_id: query.era_id.map(EraId),
+        })
+    }
+
+    /// Returns whether the given data should be sent to the client.
+    pub(super) fn should_send(&self, data: &SseData) -> bool {
+        if !self.endpoint.includes(data) {
+            return false;
+        }
+        let (account, deploy_hash, era_id) = match data {
+            // The API version is always sent, regardless of the query filters.
+            SseData::ApiVersion(_) => return true,
+            SseData::BlockAdded { block_header, .. } => (None, None, Some(block_header.era_id())),
+            SseData::DeployAccepted { deploy } => {
+                (Some(deploy.header().account()), Some(deploy.id()), None)
+            }
+            SseData::DeployProcessed {
+                deploy_hash,
+                account,
+                ..
+            } => (Some(account), Some(&**deploy_hash), None),
+            SseData::DeployExpired { deploy_hash } => (None, Some(deploy_hash), None),
+            SseData::Fault {
+                era_id, public_key, ..
+            } => (Some(public_key), None, Some(*era_id)),
+            SseData::FinalitySignature(fs) => (Some(&fs.public_key), None, Some(fs.era_id)),
+            SseData::Step { era_id, .. } | SseData::EraEnded { era_id, .. } => {
+                (None, None, Some(*era_id))
+            }
+        };
+        matches_filter(&self.account, account)
+            && matches_filter(&self.deploy_hash, deploy_hash)
+            && matches_filter(&self.era_id, era_id.as_ref())
+    }
+}
+
+/// Returns `true` if no filter value is given, or if the event's value equals the filter value.
+fn matches_filter<T: PartialEq>(filter: &Option<T>, value: Option<&T>) -> bool {
+    match filter {
+        None => true,
+        Some(filter_value) => value == Some(filter_value),
+    }
 }
 
 /// Creates the message-passing channels required to run the event-stream server and the warp filter
@@ -118,17 +287,63 @@ pub(super) fn create_channels_and_filter(
 ) {
     // Create a channel to broadcast new events to all subscribed clients' streams.
     let (broadcaster, _) = broadcast::channel(broadcast_channel_size);
-    let cloned_broadcaster = broadcaster.clone();
 
     // Create a channel for `NewSubscriberInfo`s to pass the information required to handle a new
     // client subscription.
     let (new_subscriber_info_sender, new_subscriber_info_receiver) = mpsc::unbounded_channel();
 
-    let filter = warp::get()
-        .and(warp::path(SSE_API_PATH))
+    let filter = endpoint_filter(
+        Endpoint::Events,
+        broadcaster.clone(),
+        new_subscriber_info_sender.clone(),
+    )
+    .or(endpoint_filter(
+        Endpoint::Main,
+        broadcaster.clone(),
+        new_subscriber_info_sender.clone(),
+    ))
+    .unify()
+    .or(endpoint_filter(
+        Endpoint::Deploys,
+        broadcaster.clone(),
+        new_subscriber_info_sender.clone(),
+    ))
+    .unify()
+    .or(endpoint_filter(
+        Endpoint::Sigs,
+        broadcaster.clone(),
+        new_subscriber_info_sender,
+    ))
+    .unify()
+    .boxed();
+
+    (broadcaster, new_subscriber_info_receiver, filter)
+}
+
+/// Creates the warp filter handling subscriptions to a single endpoint.
+fn endpoint_filter(
+    endpoint: Endpoint,
+    broadcaster: broadcast::Sender<BroadcastChannelMessage>,
+    new_subscriber_info_sender: mpsc::UnboundedSender<NewSubscriberInfo>,
+) -> BoxedFilter<(Response,)> {
+    let path = match endpoint.path() {
+        Some(path) => warp::path(SSE_API_PATH).and(warp::path(path)).boxed(),
+        None => warp::path(SSE_API_PATH).boxed(),
+    };
+
+    warp::get()
+        .and(path)
+        .and(warp::path::end())
         .and(warp::query().map(move |query: Query| {
+            let event_filter = match EventFilter::new(endpoint, &query) {
+                Ok(event_filter) => event_filter,
+                Err(error) => {
+                    return warp::reply::with_status(error, StatusCode::BAD_REQUEST).into_response()
+                }
+            };
+
             // Create a channel for the client's handler to receive the stream of initial events.
-            let (initial_events_sender, initial_events_receiver) = mpsc::unbounded_channel();
+            let (initial_events_sender, initial_events_receiver) = mpsc::channel(REPLAY_BATCH_SIZE);
 
             // Supply the server with the sender part of the channel along with the client's
             // requested starting point.
@@ -144,16 +359,16 @@ pub(super) fn create_channels_and_filter(
             }
 
             // Create a channel for the client's handler to receive the stream of ongoing events.
-            let ongoing_events_receiver = cloned_broadcaster.subscribe();
+            let ongoing_events_receiver = broadcaster.subscribe();
 
             sse::reply(sse::keep_alive().stream(stream_to_client(
                 initial_events_receiver,
                 ongoing_events_receiver,
+                event_filter,
             )))
+            .into_response()
         }))
-        .boxed();
-
-    (broadcaster, new_subscriber_info_receiver, filter)
+        .boxed()
 }
 
 /// This takes the two channel receivers and turns them into a stream of SSEs to the subscribed
@@ -167,13 +382,40 @@ pub(super) fn create_channels_and_filter(
 /// either the client disconnects, or the server shuts down (indicated by sending a `Shutdown`
 /// variant via the channel).  This channel will receive all SSEs created from the moment the client
 /// subscribed to the server's event stream.
+///
+/// Since the client subscribes to the ongoing events before the initial events are read from the
+/// event store, an event may be present in both channels.  As event IDs are strictly increasing,
+/// any event with an ID not greater than that of the last event sent is skipped.
+///
+/// Events not passing the client's `event_filter` are skipped.
 fn stream_to_client(
-    initial_events: mpsc::UnboundedReceiver<ServerSentEvent>,
+    initial_events: mpsc::Receiver<ServerSentEvent>,
     ongoing_events: broadcast::Receiver<BroadcastChannelMessage>,
+    event_filter: EventFilter,
 ) -> impl Stream<Item = Result<impl WarpServerSentEvent, RecvError>> + 'static {
+    let mut latest_sent_id: Option<Id> = None;
     initial_events
         .map(|event| Ok(BroadcastChannelMessage::ServerSentEvent(event)))
         .chain(ongoing_events)
+        .filter(move |result| {
+            let should_send = match result {
+                Ok(BroadcastChannelMessage::ServerSentEvent(ServerSentEvent {
+                    id: Some(id),
+                    data,
+                })) => {
+                    if !event_filter.should_send(data) {
+                        false
+                    } else if latest_sent_id.map_or(true, |latest_id| *id > latest_id) {
+                        latest_sent_id = Some(*id);
+                        true
+                    } else {
+                        false
+                    }
+                }
+                _ => true,
+            };
+            future::ready(should_send)
+        })
         .map(|result| {
             trace!(?result);
             match result {
@@ -181,9 +423,13 @@ fn stream_to_client(
                     match (event.id, &event.data) {
                         (None, &SseData::ApiVersion { .. }) => Ok(sse::json(event.data).boxed()),
                         (Some(id), &SseData::BlockAdded { .. })
+                        | (Some(id), &SseData::DeployAccepted { .. })
                         | (Some(id), &SseData::DeployProcessed { .. })
+                        | (Some(id), &SseData::DeployExpired { .. })
                         | (Some(id), &SseData::FinalitySignature(_))
-                        | (Some(id), &SseData::Fault { .. }) => {
+                        | (Some(id), &SseData::Fault { .. })
+                        | (Some(id), &SseData::Step { .. })
+                        | (Some(id), &SseData::EraEnded { .. }) => {
                             Ok((sse::id(id), sse::json(event.data)).boxed())
                         }
                         _ => unreachable!("only ApiVersion may have no event ID"),
@@ -202,3 +448,215 @@ fn stream_to_client(
             }
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use casper_types::SecretKey;
+
+    use super::*;
+    use crate::{
+        crypto::AsymmetricKeyExt,
+        testing::TestRng,
+        types::{Block, Deploy},
+    };
+
+    fn block_added(rng: &mut TestRng) -> SseData {
+        let block = Block::random(rng);
+        SseData::BlockAdded {
+            block_hash: *block.hash(),
+            block_header: Box::new(block.header().clone()),
+        }
+    }
+
+    fn deploy_processed(rng: &mut TestRng) -> SseData {
+        let deploy = Deploy::random(rng);
+        SseData::DeployProcessed {
+            deploy_hash: Box::new(*deploy.id()),
+            account: *deploy.header().account(),
+            timestamp: deploy.header().timestamp(),
+            ttl: deploy.header().ttl(),
+            dependencies: deploy.header().dependencies().clone(),
+            block_hash: Box::new(BlockHash::random(rng)),
+            execution_result: Box::new(rng.gen()),
+        }
+    }
+
+    fn deploy_accepted(rng: &mut TestRng) -> SseData {
+        SseData::DeployAccepted {
+            deploy: Box::new(Deploy::random(rng)),
+        }
+    }
+
+    fn deploy_expired(rng: &mut TestRng) -> SseData {
+        SseData::DeployExpired {
+            deploy_hash: *Deploy::random(rng).id(),
+        }
+    }
+
+    fn step(rng: &mut TestRng) -> SseData {
+        SseData::Step {
+            era_id: EraId(rng.gen_range(0, 10)),
+            execution_effect: Box::new(ExecutionEffect::default()),
+        }
+    }
+
+    fn era_ended(rng: &mut TestRng) -> SseData {
+        let validator = PublicKey::from(&SecretKey::random(rng));
+        let mut next_era_validator_weights = BTreeMap::new();
+        let _ = next_era_validator_weights.insert(validator, U512::from(rng.gen::<u64>()));
+        SseData::EraEnded {
+            era_id: EraId(rng.gen_range(0, 10)),
+            block_hash: BlockHash::random(rng),
+            era_report: Box::new(EraReport {
+                equivocators: vec![],
+                rewards: BTreeMap::new(),
+                inactive_validators: vec![],
+            }),
+            next_era_validator_weights,
+        }
+    }
+
+    fn fault(rng: &mut TestRng) -> SseData {
+        SseData::Fault {
+            era_id: EraId(rng.gen_range(0, 10)),
+            public_key: PublicKey::from(&SecretKey::random(rng)),
+            timestamp: Timestamp::random(rng),
+        }
+    }
+
+    fn finality_signature(rng: &mut TestRng) -> SseData {
+        let secret_key = SecretKey::random(rng);
+        SseData::FinalitySignature(Box::new(FinalitySignature::new(
+            BlockHash::random(rng),
+            EraId(rng.gen_range(0, 10)),
+            &secret_key,
+            PublicKey::from(&secret_key),
+            rng,
+        )))
+    }
+
+    fn no_query() -> Query {
+        Query {
+            start_from: None,
+            account: None,
+            deploy_hash: None,
+            era_id: None,
+        }
+    }
+
+    #[test]
+    fn endpoints_should_stream_their_subset_of_events() {
+        let mut rng = TestRng::new();
+        let api_version = SseData::ApiVersion(Version::new(1, 0, 0));
+        let block_added = block_added(&mut rng);
+        let deploy_accepted = deploy_accepted(&mut rng);
+        let deploy_processed = deploy_processed(&mut rng);
+        let deploy_expired = deploy_expired(&mut rng);
+        let fault = fault(&mut rng);
+        let step = step(&mut rng);
+        let era_ended = era_ended(&mut rng);
+        let finality_signature = finality_signature(&mut rng);
+
+        let check = |endpoint: Endpoint, expected: [bool; 9]| {
+            let filter = EventFilter::new(endpoint, &no_query()).unwrap();
+            let actual = [
+                filter.should_send(&api_version),
+                filter.should_send(&block_added),
+                filter.should_send(&deploy_accepted),
+                filter.should_send(&deploy_processed),
+                filter.should_send(&deploy_expired),
+                filter.should_send(&fault),
+                filter.should_send(&step),
+                filter.should_send(&era_ended),
+                filter.should_send(&finality_signature),
+            ];
+            assert_eq!(actual, expected, "unexpected events for {:?}", endpoint);
+        };
+
+        check(Endpoint::Events, [true; 9]);
+        check(
+            Endpoint::Main,
+            [true, true, false, true, true, true, true, true, false],
+        );
+        check(
+            Endpoint::Deploys,
+            [true, false, true, true, true, false, false, false, false],
+        );
+        check(
+            Endpoint::Sigs,
+            [true, false, false, false, false, false, false, false, true],
+        );
+    }
+
+    #[test]
+    fn query_filters_should_only_pass_matching_events() {
+        let mut rng = TestRng::new();
+        let deploy_processed = deploy_processed(&mut rng);
+        let other_deploy_processed = deploy_processed(&mut rng);
+        let block_added = block_added(&mut rng);
+        let (account, deploy_hash) = match &deploy_processed {
+            SseData::DeployProcessed {
+                account,
+                deploy_hash,
+                ..
+            } => (account.to_hex(), hex::encode(deploy_hash.inner())),
+            _ => unreachable!(),
+        };
+
+        let by_account = EventFilter::new(
+            Endpoint::Events,
+            &Query {
+                account: Some(account),
+                ..no_query()
+            },
+        )
+        .unwrap();
+        assert!(by_account.should_send(&SseData::ApiVersion(Version::new(1, 0, 0))));
+        assert!(by_account.should_send(&deploy_processed));
+        assert!(!by_account.should_send(&other_deploy_processed));
+        assert!(!by_account.should_send(&block_added));
+
+        let by_deploy_hash = EventFilter::new(
+            Endpoint::Deploys,
+            &Query {
+                deploy_hash: Some(deploy_hash),
+                ..no_query()
+            },
+        )
+        .unwrap();
+        assert!(by_deploy_hash.should_send(&deploy_processed));
+        assert!(!by_deploy_hash.should_send(&other_deploy_processed));
+
+        let era_id = match &block_added {
+            SseData::BlockAdded { block_header, .. } => block_header.era_id(),
+            _ => unreachable!(),
+        };
+        let by_era_id = EventFilter::new(
+            Endpoint::Main,
+            &Query {
+                era_id: Some(era_id.0),
+                ..no_query()
+            },
+        )
+        .unwrap();
+        assert!(by_era_id.should_send(&block_added));
+        assert!(!by_era_id.should_send(&deploy_processed));
+    }
+
+    #[test]
+    fn should_reject_invalid_query_filters() {
+        let invalid_account = Query {
+            account: Some("not hex".to_string()),
+            ..no_query()
+        };
+        assert!(EventFilter::new(Endpoint::Main, &invalid_account).is_err());
+
+        let invalid_deploy_hash = Query {
+            deploy_hash: Some("0102".to_string()),
+            ..no_query()
+        };
+        assert!(EventFilter::new(Endpoint::Deploys, &invalid_deploy_hash).is_err());
+    }
+}
@@ -12,6 +12,12 @@ pub(crate) struct NetworkingMetrics {
     pub(crate) queued_messages: IntGauge,
     /// Number of connected peers.
     pub(crate) peers: IntGauge,
+    /// Number of peers disconnected due to participating in a different network.
+    pub(crate) network_name_mismatches: IntCounter,
+    /// Number of peers disconnected due to running an incompatible protocol version.
+    pub(crate) protocol_version_mismatches: IntCounter,
+    /// Number of peers disconnected due to running a different chainspec.
+    pub(crate) chainspec_hash_mismatches: IntCounter,
 
     /// Registry instance.
     registry: Registry,
@@ -33,12 +39,27 @@ impl NetworkingMetrics {
             "number of messages waiting to be sent out",
         )?;
         let peers = IntGauge::new("peers", "Number of connected peers.")?;
+        let network_name_mismatches = IntCounter::new(
+            "net_handshake_network_name_mismatches",
+            "number of peers disconnected due to participating in a different network",
+        )?;
+        let protocol_version_mismatches = IntCounter::new(
+            "net_handshake_protocol_version_mismatches",
+            "number of peers disconnected due to running an incompatible protocol version",
+        )?;
+        let chainspec_hash_mismatches = IntCounter::new(
+            "net_handshake_chainspec_hash_mismatches",
+            "number of peers disconnected due to running a different chainspec",
+        )?;
 
         registry.register(Box::new(broadcast_requests.clone()))?;
         registry.register(Box::new(direct_message_requests.clone()))?;
         registry.register(Box::new(open_connections.clone()))?;
         registry.register(Box::new(queued_messages.clone()))?;
         registry.register(Box::new(peers.clone()))?;
+        registry.register(Box::new(network_name_mismatches.clone()))?;
+        registry.register(Box::new(protocol_version_mismatches.clone()))?;
+        registry.register(Box::new(chainspec_hash_mismatches.clone()))?;
 
         Ok(NetworkingMetrics {
             broadcast_requests,
@@ -46,6 +67,9 @@ impl NetworkingMetrics {
             open_connections,
             queued_messages,
             peers,
+            network_name_mismatches,
+            protocol_version_mismatches,
+            chainspec_hash_mismatches,
             registry: registry.clone(),
         })
     }
@@ -68,5 +92,14 @@ impl Drop for NetworkingMetrics {
         self.registry
             .unregister(Box::new(self.peers.clone()))
             .expect("did not expect deregistering peers to fail");
+        self.registry
+            .unregister(Box::new(self.network_name_mismatches.clone()))
+            .expect("did not expect deregistering network_name_mismatches to fail");
+        self.registry
+            .unregister(Box::new(self.protocol_version_mismatches.clone()))
+            .expect("did not expect deregistering protocol_version_mismatches to fail");
+        self.registry
+            .unregister(Box::new(self.chainspec_hash_mismatches.clone()))
+            .expect("did not expect deregistering chainspec_hash_mismatches to fail");
     }
 }
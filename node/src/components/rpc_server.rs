@@ -26,28 +26,39 @@ use futures::join;
 use semver::Version;
 
 use casper_execution_engine::{
-    core::engine_state::{
-        self, BalanceRequest, BalanceResult, GetEraValidatorsError, QueryRequest, QueryResult,
+    core::{
+        engine_state::{
+            self, deploy_item::DeployItem, execute_request::ExecuteRequest, BalanceRequest,
+            BalanceResult, ExecutableDeployItem, GetEraValidatorsError, KeysWithPrefixRequest,
+            KeysWithPrefixResult, QueryRequest, QueryResult, ViewRequest, ViewResult,
+        },
+        execution::ExecutionTrace,
     },
+    shared::gas::Gas,
     storage::protocol_data::ProtocolData,
 };
-use casper_types::{system::auction::EraValidators, Key, ProtocolVersion, URef};
+use casper_types::{
+    account::AccountHash, system::auction::EraValidators, ExecutionResult, Key, ProtocolVersion,
+    PublicKey, URef, U512,
+};
 
 use self::rpcs::chain::BlockIdentifier;
 
 use super::Component;
 use crate::{
-    components::contract_runtime::EraValidatorsRequest,
+    components::contract_runtime::{
+        ContractViewError, EraValidatorsRequest, SpeculativeExecutionError,
+    },
     crypto::hash::Digest,
     effect::{
         announcements::RpcServerAnnouncement,
         requests::{
-            ChainspecLoaderRequest, ContractRuntimeRequest, LinearChainRequest, MetricsRequest,
-            NetworkInfoRequest, RpcRequest, StorageRequest,
+            BlockProposerRequest, ChainspecLoaderRequest, ContractRuntimeRequest,
+            LinearChainRequest, MetricsRequest, NetworkInfoRequest, RpcRequest, StorageRequest,
         },
         EffectBuilder, EffectExt, Effects, Responder,
     },
-    types::{NodeId, StatusFeed},
+    types::{chainspec::DeployConfig, Chainspec, Deploy, NodeId, StatusFeed, Timestamp},
     utils::{self, ListeningError},
     NodeRng,
 };
@@ -60,6 +71,7 @@ pub trait ReactorEventT:
     From<Event>
     + From<RpcRequest<NodeId>>
     + From<RpcServerAnnouncement>
+    + From<BlockProposerRequest>
     + From<ChainspecLoaderRequest>
     + From<ContractRuntimeRequest>
     + From<LinearChainRequest<NodeId>>
@@ -74,6 +86,7 @@ impl<REv> ReactorEventT for REv where
     REv: From<Event>
         + From<RpcRequest<NodeId>>
         + From<RpcServerAnnouncement>
+        + From<BlockProposerRequest>
         + From<ChainspecLoaderRequest>
         + From<ContractRuntimeRequest>
         + From<LinearChainRequest<NodeId>>
@@ -86,11 +99,19 @@ impl<REv> ReactorEventT for REv where
 }
 
 #[derive(DataSize, Debug)]
-pub(crate) struct RpcServer {}
+pub(crate) struct RpcServer {
+    /// The name of the chain, which deploys to be speculatively executed must specify.
+    chain_name: String,
+    /// The limits deploys to be speculatively executed must comply with.
+    deploy_config: DeployConfig,
+    /// The maximum amount of gas a contract view may consume.
+    view_gas_limit: u64,
+}
 
 impl RpcServer {
     pub(crate) fn new<REv>(
         config: Config,
+        chainspec: &Chainspec,
         effect_builder: EffectBuilder<REv>,
         api_version: Version,
     ) -> Result<Self, ListeningError>
@@ -105,7 +126,11 @@ impl RpcServer {
             config.qps_limit,
         ));
 
-        Ok(RpcServer {})
+        Ok(RpcServer {
+            chain_name: chainspec.network_config.name.clone(),
+            deploy_config: chainspec.deploy_config,
+            view_gas_limit: config.view_gas_limit,
+        })
     }
 }
 
@@ -141,6 +166,51 @@ impl RpcServer {
             })
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn handle_query_contract_view<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        state_root_hash: Digest,
+        block_time: Timestamp,
+        protocol_version: ProtocolVersion,
+        caller: AccountHash,
+        target: ExecutableDeployItem,
+        responder: Responder<Result<ViewResult, ContractViewError>>,
+    ) -> Effects<Event> {
+        let view_request = ViewRequest::new(
+            state_root_hash.into(),
+            protocol_version,
+            block_time.millis(),
+            caller,
+            Gas::new(U512::from(self.view_gas_limit)),
+            target,
+        );
+        effect_builder
+            .run_view(view_request)
+            .event(move |result| Event::QueryContractViewResult {
+                result,
+                main_responder: responder,
+            })
+    }
+
+    fn handle_query_keys_with_prefix<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        state_root_hash: Digest,
+        prefix: Vec<u8>,
+        cursor: Option<Key>,
+        limit: usize,
+        responder: Responder<Result<KeysWithPrefixResult, engine_state::Error>>,
+    ) -> Effects<Event> {
+        let request = KeysWithPrefixRequest::new(state_root_hash.into(), prefix, cursor, limit);
+        effect_builder
+            .get_keys_with_prefix(request)
+            .event(move |result| Event::QueryKeysWithPrefixResult {
+                result,
+                main_responder: responder,
+            })
+    }
+
     fn handle_era_validators<REv: ReactorEventT>(
         &mut self,
         effect_builder: EffectBuilder<REv>,
@@ -172,6 +242,43 @@ impl RpcServer {
                 main_responder: responder,
             })
     }
+
+    #[allow(clippy::too_many_arguments)]
+    fn handle_speculative_execute<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        state_root_hash: Digest,
+        block_time: Timestamp,
+        proposer: PublicKey,
+        protocol_version: ProtocolVersion,
+        mut deploy: Deploy,
+        trace: bool,
+        responder: Responder<
+            Result<(ExecutionResult, Option<ExecutionTrace>), SpeculativeExecutionError>,
+        >,
+    ) -> Effects<Event> {
+        // Check the deploy as the deploy acceptor would before executing it, so that we don't
+        // execute deploys with invalid approvals or outside of the configured limits.
+        if let Err(error) = deploy.is_acceptable(&self.chain_name, &self.deploy_config) {
+            return responder
+                .respond(Err(SpeculativeExecutionError::InvalidDeploy(error)))
+                .ignore();
+        }
+
+        let execute_request = ExecuteRequest::new(
+            state_root_hash.into(),
+            block_time.millis(),
+            vec![Ok(DeployItem::from(deploy))],
+            protocol_version,
+            proposer,
+        );
+        effect_builder
+            .speculative_execute(execute_request, trace)
+            .event(move |result| Event::SpeculativeExecuteResult {
+                result,
+                main_responder: responder,
+            })
+    }
 }
 
 impl<REv> Component<REv> for RpcServer
@@ -191,6 +298,24 @@ where
             Event::RpcRequest(RpcRequest::SubmitDeploy { deploy, responder }) => effect_builder
                 .announce_deploy_received(deploy, Some(responder))
                 .ignore(),
+            Event::RpcRequest(RpcRequest::SpeculativeExecute {
+                state_root_hash,
+                block_time,
+                proposer,
+                protocol_version,
+                deploy,
+                trace,
+                responder,
+            }) => self.handle_speculative_execute(
+                effect_builder,
+                state_root_hash,
+                block_time,
+                proposer,
+                protocol_version,
+                *deploy,
+                trace,
+                responder,
+            ),
             Event::RpcRequest(RpcRequest::GetBlock {
                 maybe_id: Some(BlockIdentifier::Hash(hash)),
                 responder,
@@ -241,6 +366,36 @@ where
                 path,
                 responder,
             }) => self.handle_query(effect_builder, state_root_hash, base_key, path, responder),
+            Event::RpcRequest(RpcRequest::QueryContractView {
+                state_root_hash,
+                block_time,
+                protocol_version,
+                caller,
+                target,
+                responder,
+            }) => self.handle_query_contract_view(
+                effect_builder,
+                state_root_hash,
+                block_time,
+                protocol_version,
+                caller,
+                *target,
+                responder,
+            ),
+            Event::RpcRequest(RpcRequest::QueryKeysWithPrefix {
+                state_root_hash,
+                prefix,
+                cursor,
+                limit,
+                responder,
+            }) => self.handle_query_keys_with_prefix(
+                effect_builder,
+                state_root_hash,
+                prefix,
+                cursor,
+                limit,
+                responder,
+            ),
             Event::RpcRequest(RpcRequest::QueryEraValidators {
                 state_root_hash,
                 protocol_version,
@@ -263,6 +418,40 @@ where
                     result: Box::new(result),
                     main_responder: responder,
                 }),
+            Event::RpcRequest(RpcRequest::GetAccountDeploys { query, responder }) => effect_builder
+                .get_account_deploys_from_storage(*query)
+                .event(move |result| Event::GetAccountDeploysResult {
+                    result,
+                    main_responder: responder,
+                }),
+            Event::RpcRequest(RpcRequest::GetAccountTransfers { query, responder }) => {
+                effect_builder
+                    .get_account_transfers_from_storage(*query)
+                    .event(move |result| Event::GetAccountTransfersResult {
+                        result,
+                        main_responder: responder,
+                    })
+            }
+            Event::RpcRequest(RpcRequest::GetPendingDeploys {
+                cursor,
+                limit,
+                responder,
+            }) => effect_builder
+                .get_pending_deploys_from_block_proposer(cursor, limit)
+                .event(move |result| Event::GetPendingDeploysResult {
+                    result,
+                    main_responder: responder,
+                }),
+            Event::RpcRequest(RpcRequest::GetDeployStatus {
+                deploy_hash,
+                responder,
+            }) => effect_builder
+                .get_deploy_status_from_block_proposer(deploy_hash)
+                .event(move |result| Event::GetDeployStatusResult {
+                    deploy_hash,
+                    result,
+                    main_responder: responder,
+                }),
             Event::RpcRequest(RpcRequest::GetPeers { responder }) => effect_builder
                 .network_peers()
                 .event(move |peers| Event::GetPeersResult {
@@ -303,6 +492,14 @@ where
                 result,
                 main_responder,
             } => main_responder.respond(result).ignore(),
+            Event::QueryContractViewResult {
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
+            Event::QueryKeysWithPrefixResult {
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
             Event::QueryEraValidatorsResult {
                 result,
                 main_responder,
@@ -311,11 +508,32 @@ where
                 result,
                 main_responder,
             } => main_responder.respond(result).ignore(),
+            Event::SpeculativeExecuteResult {
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
             Event::GetDeployResult {
                 hash: _,
                 result,
                 main_responder,
             } => main_responder.respond(*result).ignore(),
+            Event::GetAccountDeploysResult {
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
+            Event::GetAccountTransfersResult {
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
+            Event::GetPendingDeploysResult {
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
+            Event::GetDeployStatusResult {
+                result,
+                main_responder,
+                ..
+            } => main_responder.respond(result).ignore(),
             Event::GetPeersResult {
                 peers,
                 main_responder,
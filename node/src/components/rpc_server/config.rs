@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 const DEFAULT_ADDRESS: &str = "0.0.0.0:0";
 /// Default rate limit in qps.
 const DEFAULT_QPS_LIMIT: u64 = 100;
+/// Default maximum amount of gas a contract view may consume.
+const DEFAULT_VIEW_GAS_LIMIT: u64 = 10_000_000_000;
 
 /// JSON-RPC HTTP server configuration.
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
@@ -18,6 +20,9 @@ pub struct Config {
 
     /// Max rate limit in qps.
     pub qps_limit: u64,
+
+    /// Maximum amount of gas a contract view run via the `query_contract_view` RPC may consume.
+    pub view_gas_limit: u64,
 }
 
 impl Config {
@@ -26,6 +31,7 @@ impl Config {
         Config {
             address: DEFAULT_ADDRESS.to_string(),
             qps_limit: DEFAULT_QPS_LIMIT,
+            view_gas_limit: DEFAULT_VIEW_GAS_LIMIT,
         }
     }
 }
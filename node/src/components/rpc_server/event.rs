@@ -6,15 +6,25 @@ use std::{
 use derive_more::From;
 
 use casper_execution_engine::{
-    core::engine_state::{self, BalanceResult, GetEraValidatorsError, QueryResult},
+    core::{
+        engine_state::{
+            self, BalanceResult, GetEraValidatorsError, KeysWithPrefixResult, QueryResult,
+            ViewResult,
+        },
+        execution::ExecutionTrace,
+    },
     storage::protocol_data::ProtocolData,
 };
-use casper_types::{system::auction::EraValidators, Transfer};
+use casper_types::{system::auction::EraValidators, ExecutionResult, Transfer};
 
 use crate::{
+    components::contract_runtime::{ContractViewError, SpeculativeExecutionError},
     effect::{requests::RpcRequest, Responder},
     rpcs::chain::BlockIdentifier,
-    types::{Block, BlockHash, BlockSignatures, Deploy, DeployHash, DeployMetadata, NodeId},
+    types::{
+        AccountDeploy, AccountIndexPage, AccountTransfer, Block, BlockHash, BlockSignatures,
+        Deploy, DeployHash, DeployMetadata, DeployStatus, NodeId, PendingDeploysPage,
+    },
 };
 
 #[derive(Debug, From)]
@@ -39,6 +49,14 @@ pub enum Event {
         result: Result<QueryResult, engine_state::Error>,
         main_responder: Responder<Result<QueryResult, engine_state::Error>>,
     },
+    QueryContractViewResult {
+        result: Result<ViewResult, ContractViewError>,
+        main_responder: Responder<Result<ViewResult, ContractViewError>>,
+    },
+    QueryKeysWithPrefixResult {
+        result: Result<KeysWithPrefixResult, engine_state::Error>,
+        main_responder: Responder<Result<KeysWithPrefixResult, engine_state::Error>>,
+    },
     QueryEraValidatorsResult {
         result: Result<EraValidators, GetEraValidatorsError>,
         main_responder: Responder<Result<EraValidators, GetEraValidatorsError>>,
@@ -48,6 +66,23 @@ pub enum Event {
         result: Box<Option<(Deploy, DeployMetadata)>>,
         main_responder: Responder<Option<(Deploy, DeployMetadata)>>,
     },
+    GetAccountDeploysResult {
+        result: AccountIndexPage<AccountDeploy>,
+        main_responder: Responder<AccountIndexPage<AccountDeploy>>,
+    },
+    GetAccountTransfersResult {
+        result: AccountIndexPage<AccountTransfer>,
+        main_responder: Responder<AccountIndexPage<AccountTransfer>>,
+    },
+    GetPendingDeploysResult {
+        result: PendingDeploysPage,
+        main_responder: Responder<PendingDeploysPage>,
+    },
+    GetDeployStatusResult {
+        deploy_hash: DeployHash,
+        result: DeployStatus,
+        main_responder: Responder<DeployStatus>,
+    },
     GetPeersResult {
         peers: BTreeMap<NodeId, String>,
         main_responder: Responder<BTreeMap<NodeId, String>>,
@@ -60,6 +95,11 @@ pub enum Event {
         result: Result<BalanceResult, engine_state::Error>,
         main_responder: Responder<Result<BalanceResult, engine_state::Error>>,
     },
+    SpeculativeExecuteResult {
+        result: Result<(ExecutionResult, Option<ExecutionTrace>), SpeculativeExecutionError>,
+        main_responder:
+            Responder<Result<(ExecutionResult, Option<ExecutionTrace>), SpeculativeExecutionError>>,
+    },
 }
 
 impl Display for Event {
@@ -94,15 +134,50 @@ impl Display for Event {
             Event::QueryGlobalStateResult { result, .. } => {
                 write!(formatter, "query result: {:?}", result)
             }
+            Event::QueryContractViewResult { result, .. } => {
+                write!(formatter, "query contract view result: {:?}", result)
+            }
+            Event::QueryKeysWithPrefixResult { result, .. } => {
+                write!(formatter, "query keys with prefix result: {:?}", result)
+            }
             Event::QueryEraValidatorsResult { result, .. } => {
                 write!(formatter, "query era validators result: {:?}", result)
             }
             Event::GetBalanceResult { result, .. } => {
                 write!(formatter, "balance result: {:?}", result)
             }
+            Event::SpeculativeExecuteResult { result, .. } => {
+                write!(formatter, "speculative execute result: {:?}", result)
+            }
             Event::GetDeployResult { hash, result, .. } => {
                 write!(formatter, "get deploy result for {}: {:?}", hash, result)
             }
+            Event::GetAccountDeploysResult { result, .. } => write!(
+                formatter,
+                "get account deploys result: {} deploys",
+                result.entries.len()
+            ),
+            Event::GetAccountTransfersResult { result, .. } => write!(
+                formatter,
+                "get account transfers result: {} transfers",
+                result.entries.len()
+            ),
+            Event::GetPendingDeploysResult { result, .. } => {
+                write!(
+                    formatter,
+                    "get pending deploys result: {} deploys",
+                    result.deploys.len()
+                )
+            }
+            Event::GetDeployStatusResult {
+                deploy_hash,
+                result,
+                ..
+            } => write!(
+                formatter,
+                "get deploy status result for {}: {}",
+                deploy_hash, result
+            ),
             Event::GetPeersResult { peers, .. } => write!(formatter, "get peers: {}", peers.len()),
             Event::GetMetricsResult { text, .. } => match text {
                 Some(txt) => write!(formatter, "get metrics ({} bytes)", txt.len()),
@@ -53,15 +53,31 @@ pub(super) async fn run<REv: ReactorEventT>(
     // RPC filters.
     let rpc_put_deploy =
         rpcs::account::PutDeploy::create_filter(effect_builder, api_version.clone());
+    let rpc_speculative_exec =
+        rpcs::account::SpeculativeExec::create_filter(effect_builder, api_version.clone());
     let rpc_get_block = rpcs::chain::GetBlock::create_filter(effect_builder, api_version.clone());
     let rpc_get_block_transfers =
         rpcs::chain::GetBlockTransfers::create_filter(effect_builder, api_version.clone());
+    let rpc_get_account_transfers =
+        rpcs::chain::GetAccountTransfers::create_filter(effect_builder, api_version.clone());
     let rpc_get_state_root_hash =
         rpcs::chain::GetStateRootHash::create_filter(effect_builder, api_version.clone());
     let rpc_get_item = rpcs::state::GetItem::create_filter(effect_builder, api_version.clone());
+    let rpc_get_dictionary_item =
+        rpcs::state::GetDictionaryItem::create_filter(effect_builder, api_version.clone());
+    let rpc_get_keys_with_prefix =
+        rpcs::state::GetKeysWithPrefix::create_filter(effect_builder, api_version.clone());
     let rpc_get_balance =
         rpcs::state::GetBalance::create_filter(effect_builder, api_version.clone());
+    let rpc_query_contract_view =
+        rpcs::state::QueryContractView::create_filter(effect_builder, api_version.clone());
     let rpc_get_deploy = rpcs::info::GetDeploy::create_filter(effect_builder, api_version.clone());
+    let rpc_get_account_deploys =
+        rpcs::info::GetAccountDeploys::create_filter(effect_builder, api_version.clone());
+    let rpc_get_pending_deploys =
+        rpcs::info::GetPendingDeploys::create_filter(effect_builder, api_version.clone());
+    let rpc_get_deploy_status =
+        rpcs::info::GetDeployStatus::create_filter(effect_builder, api_version.clone());
     let rpc_get_peers = rpcs::info::GetPeers::create_filter(effect_builder, api_version.clone());
     let rpc_get_status = rpcs::info::GetStatus::create_filter(effect_builder, api_version.clone());
     let rpc_get_era_info =
@@ -90,12 +106,20 @@ pub(super) async fn run<REv: ReactorEventT>(
     //        update to or move away from warp_json_rpc.
     let service = warp_json_rpc::service(
         rpc_put_deploy
+            .or(rpc_speculative_exec)
             .or(rpc_get_block)
             .or(rpc_get_block_transfers)
+            .or(rpc_get_account_transfers)
             .or(rpc_get_state_root_hash)
             .or(rpc_get_item)
+            .or(rpc_get_dictionary_item)
+            .or(rpc_get_keys_with_prefix)
             .or(rpc_get_balance)
+            .or(rpc_query_contract_view)
             .or(rpc_get_deploy)
+            .or(rpc_get_account_deploys)
+            .or(rpc_get_pending_deploys)
+            .or(rpc_get_deploy_status)
             .or(rpc_get_peers)
             .or(rpc_get_status)
             .or(rpc_get_era_info)
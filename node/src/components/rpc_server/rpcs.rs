@@ -45,6 +45,23 @@ enum ErrorCode {
     GetBalanceFailed = -32006,
     GetBalanceFailedToExecute = -32007,
     InvalidDeploy = -32008,
+    ParseKeysPrefix = -32009,
+    ParseAccountIndexCursor = -32010,
+    ParseTransferParticipant = -32011,
+    DeployAccountNotAllowed = -32012,
+    DeployAccountDenied = -32013,
+    TooManyPendingDeploys = -32014,
+    DeployGasPriceTooLow = -32015,
+    DeployTooLarge = -32016,
+    NoSuchStateRoot = -32017,
+    SpeculativeExecBusy = -32018,
+    InvalidDictionaryIdentifier = -32019,
+    QueryContractViewFailed = -32020,
+    InvalidKeysLimit = -32021,
+    SpeculativeExecFailed = -32022,
+    QueryContractViewBusy = -32023,
+    InvalidPendingDeploysLimit = -32024,
+    InvalidAccountIndexLimit = -32025,
 }
 
 #[derive(Debug)]
@@ -276,7 +293,7 @@ pub(super) trait RpcWithOptionalParamsExt: RpcWithOptionalParams {
 }
 
 mod common {
-    use std::convert::TryFrom;
+    use std::{cmp, convert::TryFrom};
 
     use once_cell::sync::Lazy;
 
@@ -284,7 +301,10 @@ mod common {
     use casper_types::bytesrepr::ToBytes;
 
     use super::ErrorCode;
-    use crate::types::json_compatibility::StoredValue;
+    use crate::types::{json_compatibility::StoredValue, AccountIndexQuery};
+
+    /// The maximum number of entries returned by a single request for a page of an account index.
+    pub(super) const MAX_ACCOUNT_INDEX_LIMIT: u32 = 1_000;
 
     pub(super) static MERKLE_PROOF: Lazy<String> = Lazy::new(|| {
         String::from(
@@ -335,4 +355,39 @@ mod common {
 
         Ok((value_compat, proof_bytes))
     }
+    /// Creates a query for a page of an account index from the request params.
+    ///
+    /// The height range defaults to all blocks, and the limit to `MAX_ACCOUNT_INDEX_LIMIT`, which
+    /// also caps it.  A limit of zero is rejected.
+    pub(super) fn account_index_query<S>(
+        subject: S,
+        start_height: Option<u64>,
+        end_height: Option<u64>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<AccountIndexQuery<S>, (ErrorCode, String)> {
+        let cursor = match cursor.map(hex::decode).transpose() {
+            Ok(cursor) => cursor,
+            Err(error) => {
+                let error_msg = format!("failed to parse cursor: {}", error);
+                return Err((ErrorCode::ParseAccountIndexCursor, error_msg));
+            }
+        };
+        let limit = cmp::min(
+            limit.unwrap_or(MAX_ACCOUNT_INDEX_LIMIT),
+            MAX_ACCOUNT_INDEX_LIMIT,
+        ) as usize;
+        if limit == 0 {
+            let error_msg = "limit must be greater than zero".to_string();
+            return Err((ErrorCode::InvalidAccountIndexLimit, error_msg));
+        }
+
+        Ok(AccountIndexQuery {
+            subject,
+            start_height: start_height.unwrap_or_default(),
+            end_height: end_height.unwrap_or(u64::MAX),
+            cursor,
+            limit,
+        })
+    }
 }
@@ -15,15 +15,21 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
+use casper_execution_engine::core::execution::ExecutionTrace;
+use casper_types::{ExecutionResult, ProtocolVersion};
+
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     Error, ReactorEventT, RpcRequest, RpcWithParams, RpcWithParamsExt,
 };
 use crate::{
-    components::rpc_server::rpcs::ErrorCode,
+    components::{
+        contract_runtime::SpeculativeExecutionError, deploy_acceptor, rpc_server::rpcs::ErrorCode,
+    },
+    crypto::hash::Digest,
     effect::EffectBuilder,
     reactor::QueueKind,
-    types::{Deploy, DeployHash},
+    types::{Block, Deploy, DeployHash, Timestamp},
 };
 
 static PUT_DEPLOY_PARAMS: Lazy<PutDeployParams> = Lazy::new(|| PutDeployParams {
@@ -33,6 +39,17 @@ static PUT_DEPLOY_RESULT: Lazy<PutDeployResult> = Lazy::new(|| PutDeployResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     deploy_hash: *Deploy::doc_example().id(),
 });
+static SPECULATIVE_EXEC_PARAMS: Lazy<SpeculativeExecParams> = Lazy::new(|| SpeculativeExecParams {
+    deploy: Deploy::doc_example().clone(),
+    state_root_hash: Some(*Block::doc_example().header().state_root_hash()),
+    trace: false,
+});
+static SPECULATIVE_EXEC_RESULT: Lazy<SpeculativeExecResult> = Lazy::new(|| SpeculativeExecResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+    deploy_hash: *Deploy::doc_example().id(),
+    execution_result: ExecutionResult::example().clone(),
+    trace: None,
+});
 
 /// Params for "account_put_deploy" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
@@ -112,8 +129,165 @@ impl RpcWithParamsExt for PutDeploy {
                         %error,
                         "the deploy submitted by the client was invalid",
                     );
+                    let error_code = match error {
+                        deploy_acceptor::Error::AccountNotAllowed(_) => {
+                            ErrorCode::DeployAccountNotAllowed
+                        }
+                        deploy_acceptor::Error::AccountDenied(_) => ErrorCode::DeployAccountDenied,
+                        deploy_acceptor::Error::TooManyPendingDeploys { .. } => {
+                            ErrorCode::TooManyPendingDeploys
+                        }
+                        deploy_acceptor::Error::GasPriceTooLow { .. } => {
+                            ErrorCode::DeployGasPriceTooLow
+                        }
+                        deploy_acceptor::Error::DeployTooLarge { .. } => ErrorCode::DeployTooLarge,
+                        deploy_acceptor::Error::InvalidDeploy(_)
+                        | deploy_acceptor::Error::InvalidAccount
+                        | deploy_acceptor::Error::InsufficientBalance => ErrorCode::InvalidDeploy,
+                    };
+                    Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        error_code as i64,
+                        error.to_string(),
+                    ))?)
+                }
+            }
+        }
+        .boxed()
+    }
+}
+
+/// Params for "speculative_exec" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpeculativeExecParams {
+    /// The `Deploy`.
+    pub deploy: Deploy,
+    /// The state root hash to execute the deploy against.  If not provided, the state root hash
+    /// of the latest block is used.
+    #[serde(default)]
+    pub state_root_hash: Option<Digest>,
+    /// Whether to record a trace of the host functions called and the gas charged while
+    /// executing the deploy's Wasm code.
+    #[serde(default)]
+    pub trace: bool,
+}
+
+impl DocExample for SpeculativeExecParams {
+    fn doc_example() -> &'static Self {
+        &*SPECULATIVE_EXEC_PARAMS
+    }
+}
+
+/// Result for "speculative_exec" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpeculativeExecResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+    /// The result of executing the deploy.  Its effects have not been committed.
+    pub execution_result: ExecutionResult,
+    /// The trace of the deploy's execution, if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<ExecutionTrace>,
+}
+
+impl DocExample for SpeculativeExecResult {
+    fn doc_example() -> &'static Self {
+        &*SPECULATIVE_EXEC_RESULT
+    }
+}
+
+/// "speculative_exec" RPC
+pub struct SpeculativeExec {}
+
+impl RpcWithParams for SpeculativeExec {
+    const METHOD: &'static str = "speculative_exec";
+    type RequestParams = SpeculativeExecParams;
+    type ResponseResult = SpeculativeExecResult;
+}
+
+impl RpcWithParamsExt for SpeculativeExec {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let deploy_hash = *params.deploy.id();
+
+            // The latest block provides the proposer to execute with, and the state root hash if
+            // none was specified.
+            let maybe_block = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetBlock {
+                        maybe_id: None,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+            let block = match maybe_block {
+                Some((block, _)) => block,
+                None => {
+                    let error_msg = "speculative-exec failed to get last added block".to_string();
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::NoSuchBlock as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            let state_root_hash = params
+                .state_root_hash
+                .unwrap_or_else(|| *block.header().state_root_hash());
+            let protocol_version = ProtocolVersion::from_parts(
+                api_version.major as u32,
+                api_version.minor as u32,
+                api_version.patch as u32,
+            );
+
+            let execution_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::SpeculativeExecute {
+                        state_root_hash,
+                        block_time: Timestamp::now(),
+                        proposer: block.body().proposer().clone(),
+                        protocol_version,
+                        deploy: Box::new(params.deploy),
+                        trace: params.trace,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            match execution_result {
+                Ok((execution_result, trace)) => {
+                    let result = Self::ResponseResult {
+                        api_version,
+                        deploy_hash,
+                        execution_result,
+                        trace,
+                    };
+                    Ok(response_builder.success(result)?)
+                }
+                Err(error) => {
+                    info!(%deploy_hash, %error, "failed to speculatively execute deploy");
+                    let error_code = match error {
+                        SpeculativeExecutionError::Busy => ErrorCode::SpeculativeExecBusy,
+                        SpeculativeExecutionError::RootNotFound(_) => ErrorCode::NoSuchStateRoot,
+                        SpeculativeExecutionError::InvalidDeploy(_) => ErrorCode::InvalidDeploy,
+                        SpeculativeExecutionError::EngineState(_) => {
+                            ErrorCode::SpeculativeExecFailed
+                        }
+                    };
                     Ok(response_builder.error(warp_json_rpc::Error::custom(
-                        ErrorCode::InvalidDeploy as i64,
+                        error_code as i64,
                         error.to_string(),
                     ))?)
                 }
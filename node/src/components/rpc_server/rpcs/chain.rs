@@ -17,18 +17,22 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_types::{Key, Transfer};
+use casper_types::{account::AccountHash, Key, PublicKey, Transfer, URef};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithOptionalParams, RpcWithOptionalParamsExt,
+    RpcWithParams, RpcWithParamsExt,
 };
 use crate::{
     crypto::hash::Digest,
     effect::EffectBuilder,
     reactor::QueueKind,
     rpcs::common::{self},
-    types::{Block, BlockHash, BlockSignatures, Item, JsonBlock},
+    types::{
+        AccountTransfer, Block, BlockHash, BlockSignatures, Deploy, Item, JsonBlock,
+        TransferParticipant,
+    },
 };
 pub use era_summary::EraSummary;
 use era_summary::ERA_SUMMARY;
@@ -50,6 +54,26 @@ static GET_BLOCK_TRANSFERS_RESULT: Lazy<GetBlockTransfersResult> =
         block_hash: Some(Block::doc_example().id()),
         transfers: Some(vec![Transfer::default()]),
     });
+static GET_ACCOUNT_TRANSFERS_PARAMS: Lazy<GetAccountTransfersParams> =
+    Lazy::new(|| GetAccountTransfersParams {
+        participant: TransferParticipantIdentifier::PublicKey(
+            Deploy::doc_example().header().account().clone(),
+        ),
+        start_height: Some(Block::doc_example().header().height()),
+        end_height: None,
+        cursor: None,
+        limit: Some(10),
+    });
+static GET_ACCOUNT_TRANSFERS_RESULT: Lazy<GetAccountTransfersResult> =
+    Lazy::new(|| GetAccountTransfersResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        transfers: vec![AccountTransfer {
+            block_hash: Block::doc_example().id(),
+            block_height: Block::doc_example().header().height(),
+            transfer: Transfer::default(),
+        }],
+        next_cursor: None,
+    });
 static GET_STATE_ROOT_HASH_PARAMS: Lazy<GetStateRootHashParams> =
     Lazy::new(|| GetStateRootHashParams {
         block_identifier: BlockIdentifier::Height(Block::doc_example().header().height()),
@@ -250,6 +274,145 @@ impl RpcWithOptionalParamsExt for GetBlockTransfers {
     }
 }
 
+/// Identifier of the account or purse whose transfers are retrieved.
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum TransferParticipantIdentifier {
+    /// Retrieve the transfers sent by or to the account with this public key.
+    PublicKey(PublicKey),
+    /// Retrieve the transfers sent by or to the account with this formatted account hash.
+    AccountHash(String),
+    /// Retrieve the transfers debiting or crediting the purse with this formatted URef.
+    Purse(String),
+}
+
+impl TransferParticipantIdentifier {
+    fn to_participant(&self) -> Result<TransferParticipant, String> {
+        match self {
+            TransferParticipantIdentifier::PublicKey(public_key) => {
+                Ok(TransferParticipant::Account(public_key.to_account_hash()))
+            }
+            TransferParticipantIdentifier::AccountHash(account_hash) => {
+                AccountHash::from_formatted_str(account_hash)
+                    .map(TransferParticipant::Account)
+                    .map_err(|error| format!("failed to parse account hash: {}", error))
+            }
+            TransferParticipantIdentifier::Purse(uref) => URef::from_formatted_str(uref)
+                .map(TransferParticipant::Purse)
+                .map_err(|error| format!("failed to parse purse uref: {:?}", error)),
+        }
+    }
+}
+
+/// Params for "chain_get_account_transfers" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAccountTransfersParams {
+    /// The account or purse which took part in the transfers.
+    pub participant: TransferParticipantIdentifier,
+    /// The lowest height of the blocks to include.  Defaults to 0.
+    #[serde(default)]
+    pub start_height: Option<u64>,
+    /// The highest height of the blocks to include.  Defaults to the highest block.
+    #[serde(default)]
+    pub end_height: Option<u64>,
+    /// The cursor returned with the previous page, if any, as a hex-encoded string.
+    #[serde(default)]
+    pub cursor: Option<String>,
+    /// The maximum number of transfers to return.  Defaults to and is capped at 1000.
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+impl DocExample for GetAccountTransfersParams {
+    fn doc_example() -> &'static Self {
+        &*GET_ACCOUNT_TRANSFERS_PARAMS
+    }
+}
+
+/// Result for "chain_get_account_transfers" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAccountTransfersResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The transfers the account or purse took part in, in ascending block height order.
+    pub transfers: Vec<AccountTransfer>,
+    /// The cursor to request the following page with, or `None` if this is the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl DocExample for GetAccountTransfersResult {
+    fn doc_example() -> &'static Self {
+        &*GET_ACCOUNT_TRANSFERS_RESULT
+    }
+}
+
+/// "chain_get_account_transfers" RPC.
+pub struct GetAccountTransfers {}
+
+impl RpcWithParams for GetAccountTransfers {
+    const METHOD: &'static str = "chain_get_account_transfers";
+    type RequestParams = GetAccountTransfersParams;
+    type ResponseResult = GetAccountTransfersResult;
+}
+
+impl RpcWithParamsExt for GetAccountTransfers {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let participant = match params.participant.to_participant() {
+                Ok(participant) => participant,
+                Err(error_msg) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::ParseTransferParticipant as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            let query = match common::account_index_query(
+                participant,
+                params.start_height,
+                params.end_height,
+                params.cursor.as_deref(),
+                params.limit,
+            ) {
+                Ok(query) => query,
+                Err((error_code, error_msg)) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder
+                        .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
+                }
+            };
+
+            let page = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetAccountTransfers {
+                        query: Box::new(query),
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let result = Self::ResponseResult {
+                api_version,
+                transfers: page.entries,
+                next_cursor: page.next_cursor.map(hex::encode),
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
 /// Params for "chain_get_state_root_hash" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -18,10 +18,13 @@ use serde_json::{json, Value};
 use warp_json_rpc::Builder;
 
 use super::{
-    account::PutDeploy,
-    chain::{GetBlock, GetBlockTransfers, GetStateRootHash},
-    info::{GetDeploy, GetPeers, GetStatus},
-    state::{GetAuctionInfo, GetBalance, GetItem},
+    account::{PutDeploy, SpeculativeExec},
+    chain::{GetAccountTransfers, GetBlock, GetBlockTransfers, GetStateRootHash},
+    info::{GetAccountDeploys, GetDeploy, GetDeployStatus, GetPeers, GetPendingDeploys, GetStatus},
+    state::{
+        GetAuctionInfo, GetBalance, GetDictionaryItem, GetItem, GetKeysWithPrefix,
+        QueryContractView,
+    },
     Error, ReactorEventT, RpcWithOptionalParams, RpcWithParams, RpcWithoutParams,
     RpcWithoutParamsExt,
 };
@@ -67,18 +70,40 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
     };
 
     schema.push_with_params::<PutDeploy>("receives a Deploy to be executed by the network");
+    schema.push_with_params::<SpeculativeExec>(
+        "executes a Deploy against the global state without committing its effects",
+    );
     schema.push_with_params::<GetDeploy>("returns a Deploy from the network");
+    schema.push_with_params::<GetAccountDeploys>(
+        "returns a page of the Deploys sent by an account, in ascending Block height order",
+    );
+    schema.push_with_params::<GetPendingDeploys>(
+        "returns a page of the deploys pending inclusion in a Block, in ascending hash order",
+    );
+    schema.push_with_params::<GetDeployStatus>(
+        "returns the status of a Deploy, e.g. whether it is pending or has been finalized",
+    );
     schema.push_without_params::<GetPeers>("returns a list of peers connected to the node");
     schema.push_without_params::<GetStatus>("returns the current status of the node");
     schema.push_with_optional_params::<GetBlock>("returns a Block from the network");
     schema.push_with_optional_params::<GetBlockTransfers>(
         "returns all transfers for a Block from the network",
     );
+    schema.push_with_params::<GetAccountTransfers>(
+        "returns a page of the transfers an account or purse took part in",
+    );
     schema.push_with_optional_params::<GetStateRootHash>(
         "returns a state root hash at a given Block",
     );
     schema.push_with_params::<GetItem>("returns a stored value from the network");
+    schema.push_with_params::<GetDictionaryItem>("returns an item from a dictionary");
+    schema.push_with_params::<GetKeysWithPrefix>(
+        "returns a page of stored values whose keys match a prefix",
+    );
     schema.push_with_params::<GetBalance>("returns a purse's balance from the network");
+    schema.push_with_params::<QueryContractView>(
+        "returns the value computed by a read-only call of a stored contract's entry point",
+    );
     schema.push_with_optional_params::<GetEraInfoBySwitchBlock>(
         "returns an EraInfo from the network",
     );
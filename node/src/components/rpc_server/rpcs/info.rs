@@ -3,7 +3,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use std::str;
+use std::{cmp, str};
 
 use futures::{future::BoxFuture, FutureExt};
 use http::Response;
@@ -15,9 +15,10 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_types::ExecutionResult;
+use casper_types::{ExecutionResult, PublicKey};
 
 use super::{
+    common,
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithParams, RpcWithParamsExt, RpcWithoutParams,
     RpcWithoutParamsExt,
@@ -25,7 +26,10 @@ use super::{
 use crate::{
     effect::EffectBuilder,
     reactor::QueueKind,
-    types::{Block, BlockHash, Deploy, DeployHash, GetStatusResult, Item, PeersMap},
+    types::{
+        AccountDeploy, Block, BlockHash, Deploy, DeployHash, DeployStatus, GetStatusResult, Item,
+        PeersMap, PendingDeploy, PendingDeployKind, PendingDeploysPage, Timestamp,
+    },
 };
 
 static GET_DEPLOY_PARAMS: Lazy<GetDeployParams> = Lazy::new(|| GetDeployParams {
@@ -39,6 +43,51 @@ static GET_DEPLOY_RESULT: Lazy<GetDeployResult> = Lazy::new(|| GetDeployResult {
         result: ExecutionResult::example().clone(),
     }],
 });
+static GET_ACCOUNT_DEPLOYS_PARAMS: Lazy<GetAccountDeploysParams> =
+    Lazy::new(|| GetAccountDeploysParams {
+        public_key: Deploy::doc_example().header().account().clone(),
+        start_height: Some(Block::doc_example().header().height()),
+        end_height: None,
+        cursor: None,
+        limit: Some(10),
+    });
+static GET_ACCOUNT_DEPLOYS_RESULT: Lazy<GetAccountDeploysResult> =
+    Lazy::new(|| GetAccountDeploysResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        deploys: vec![AccountDeploy {
+            deploy_hash: *Deploy::doc_example().id(),
+            block_hash: Block::doc_example().id(),
+            block_height: Block::doc_example().header().height(),
+        }],
+        next_cursor: None,
+    });
+static GET_PENDING_DEPLOYS_PARAMS: Lazy<GetPendingDeploysParams> =
+    Lazy::new(|| GetPendingDeploysParams {
+        cursor: None,
+        limit: Some(10),
+    });
+static GET_PENDING_DEPLOYS_RESULT: Lazy<GetPendingDeploysResult> =
+    Lazy::new(|| GetPendingDeploysResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        deploys: vec![PendingDeploy {
+            deploy_hash: *Deploy::doc_example().id(),
+            kind: PendingDeployKind::Wasm,
+            expires: Deploy::doc_example().header().expires(),
+            gas_price: Deploy::doc_example().header().gas_price(),
+            unmet_dependencies: vec![],
+        }],
+        next_cursor: None,
+    });
+static GET_DEPLOY_STATUS_PARAMS: Lazy<GetDeployStatusParams> =
+    Lazy::new(|| GetDeployStatusParams {
+        deploy_hash: *Deploy::doc_example().id(),
+    });
+static GET_DEPLOY_STATUS_RESULT: Lazy<GetDeployStatusResult> =
+    Lazy::new(|| GetDeployStatusResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        deploy_hash: *Deploy::doc_example().id(),
+        status: DeployStatus::Pending,
+    });
 static GET_PEERS_RESULT: Lazy<GetPeersResult> = Lazy::new(|| GetPeersResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     peers: GetStatusResult::doc_example().peers.clone(),
@@ -147,6 +196,104 @@ impl RpcWithParamsExt for GetDeploy {
     }
 }
 
+/// Params for "info_get_account_deploys" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAccountDeploysParams {
+    /// The public key of the account which sent the deploys.
+    pub public_key: PublicKey,
+    /// The lowest height of the blocks to include.  Defaults to 0.
+    #[serde(default)]
+    pub start_height: Option<u64>,
+    /// The highest height of the blocks to include.  Defaults to the highest block.
+    #[serde(default)]
+    pub end_height: Option<u64>,
+    /// The cursor returned with the previous page, if any, as a hex-encoded string.
+    #[serde(default)]
+    pub cursor: Option<String>,
+    /// The maximum number of deploys to return.  Defaults to and is capped at 1000.
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+impl DocExample for GetAccountDeploysParams {
+    fn doc_example() -> &'static Self {
+        &*GET_ACCOUNT_DEPLOYS_PARAMS
+    }
+}
+
+/// Result for "info_get_account_deploys" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAccountDeploysResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The deploys sent by the account, in ascending block height order.
+    pub deploys: Vec<AccountDeploy>,
+    /// The cursor to request the following page with, or `None` if this is the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl DocExample for GetAccountDeploysResult {
+    fn doc_example() -> &'static Self {
+        &*GET_ACCOUNT_DEPLOYS_RESULT
+    }
+}
+
+/// "info_get_account_deploys" RPC.
+pub struct GetAccountDeploys {}
+
+impl RpcWithParams for GetAccountDeploys {
+    const METHOD: &'static str = "info_get_account_deploys";
+    type RequestParams = GetAccountDeploysParams;
+    type ResponseResult = GetAccountDeploysResult;
+}
+
+impl RpcWithParamsExt for GetAccountDeploys {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let query = match common::account_index_query(
+                params.public_key.to_account_hash(),
+                params.start_height,
+                params.end_height,
+                params.cursor.as_deref(),
+                params.limit,
+            ) {
+                Ok(query) => query,
+                Err((error_code, error_msg)) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder
+                        .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
+                }
+            };
+
+            let page = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetAccountDeploys {
+                        query: Box::new(query),
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let result = Self::ResponseResult {
+                api_version,
+                deploys: page.entries,
+                next_cursor: page.next_cursor.map(hex::encode),
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
 /// Result for "info_get_peers" RPC response.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -226,3 +373,198 @@ impl RpcWithoutParamsExt for GetStatus {
         .boxed()
     }
 }
+
+/// The maximum number of deploys returned by a single "info_get_pending_deploys" request.
+pub const MAX_PENDING_DEPLOYS_LIMIT: u32 = 1_000;
+
+/// Params for "info_get_pending_deploys" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetPendingDeploysParams {
+    /// The `next_cursor` returned with the previous page, if any.
+    #[serde(default)]
+    pub cursor: Option<DeployHash>,
+    /// The maximum number of deploys to return.  Defaults to and is capped at 1000.
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+impl DocExample for GetPendingDeploysParams {
+    fn doc_example() -> &'static Self {
+        &*GET_PENDING_DEPLOYS_PARAMS
+    }
+}
+
+/// Result for "info_get_pending_deploys" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetPendingDeploysResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The deploys held by the block proposer which are pending inclusion in a block, ordered by
+    /// deploy hash.
+    ///
+    /// Deploys included in proto blocks are only excluded by validators, and only as of their own
+    /// most recent proposal.
+    pub deploys: Vec<PendingDeploy>,
+    /// The cursor to pass to retrieve the next page, if there are more deploys.
+    pub next_cursor: Option<DeployHash>,
+}
+
+impl DocExample for GetPendingDeploysResult {
+    fn doc_example() -> &'static Self {
+        &*GET_PENDING_DEPLOYS_RESULT
+    }
+}
+
+/// "info_get_pending_deploys" RPC.
+pub struct GetPendingDeploys {}
+
+impl RpcWithParams for GetPendingDeploys {
+    const METHOD: &'static str = "info_get_pending_deploys";
+    type RequestParams = GetPendingDeploysParams;
+    type ResponseResult = GetPendingDeploysResult;
+}
+
+impl RpcWithParamsExt for GetPendingDeploys {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let limit = cmp::min(
+                params.limit.unwrap_or(MAX_PENDING_DEPLOYS_LIMIT),
+                MAX_PENDING_DEPLOYS_LIMIT,
+            ) as usize;
+            if limit == 0 {
+                let error_msg = "limit must be greater than zero".to_string();
+                info!("{}", error_msg);
+                return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                    ErrorCode::InvalidPendingDeploysLimit as i64,
+                    error_msg,
+                ))?);
+            }
+
+            let PendingDeploysPage {
+                deploys,
+                next_cursor,
+            } = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetPendingDeploys {
+                        cursor: params.cursor,
+                        limit,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let result = Self::ResponseResult {
+                api_version,
+                deploys,
+                next_cursor,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+/// Params for "info_get_deploy_status" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDeployStatusParams {
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+}
+
+impl DocExample for GetDeployStatusParams {
+    fn doc_example() -> &'static Self {
+        &*GET_DEPLOY_STATUS_PARAMS
+    }
+}
+
+/// Result for "info_get_deploy_status" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDeployStatusResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+    /// The status of the deploy.
+    pub status: DeployStatus,
+}
+
+impl DocExample for GetDeployStatusResult {
+    fn doc_example() -> &'static Self {
+        &*GET_DEPLOY_STATUS_RESULT
+    }
+}
+
+/// "info_get_deploy_status" RPC.
+pub struct GetDeployStatus {}
+
+impl RpcWithParams for GetDeployStatus {
+    const METHOD: &'static str = "info_get_deploy_status";
+    type RequestParams = GetDeployStatusParams;
+    type ResponseResult = GetDeployStatusResult;
+}
+
+impl RpcWithParamsExt for GetDeployStatus {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let deploy_hash = params.deploy_hash;
+            let mut status = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetDeployStatus {
+                        deploy_hash,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            // The block proposer forgets about deploys once they have expired, and may not know
+            // about deploys held in storage at all, e.g. while joining, so fall back to what is
+            // held in storage.
+            if status == DeployStatus::Unknown {
+                let maybe_deploy_and_metadata = effect_builder
+                    .make_request(
+                        |responder| RpcRequest::GetDeploy {
+                            hash: deploy_hash,
+                            responder,
+                        },
+                        QueueKind::Api,
+                    )
+                    .await;
+                if let Some((deploy, metadata)) = maybe_deploy_and_metadata {
+                    if !metadata.execution_results.is_empty() {
+                        status = DeployStatus::Finalized;
+                    } else if deploy.header().expired(Timestamp::now()) {
+                        status = DeployStatus::Expired;
+                    } else {
+                        status = DeployStatus::Pending;
+                    }
+                }
+            }
+
+            let result = Self::ResponseResult {
+                api_version,
+                deploy_hash,
+                status,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
@@ -3,7 +3,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use std::str;
+use std::{cmp, convert::TryFrom, str};
 
 use futures::{future::BoxFuture, FutureExt};
 use http::Response;
@@ -15,18 +15,21 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_execution_engine::{
-    core::engine_state::{BalanceResult, QueryResult},
-    storage::protocol_data::ProtocolData,
+use casper_execution_engine::core::engine_state::{
+    BalanceResult, ExecutableDeployItem, KeysWithPrefixResult, ViewResult,
+};
+use casper_types::{
+    bytesrepr::ToBytes, system::auction::Bids, CLValue, ContractHash, Key, KeyTag, ProtocolVersion,
+    PublicKey, RuntimeArgs, URef, U512,
 };
-use casper_types::{bytesrepr::ToBytes, CLValue, Key, ProtocolVersion, URef, U512};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithParams, RpcWithParamsExt,
 };
 use crate::{
-    crypto::hash::Digest,
+    components::contract_runtime::ContractViewError,
+    crypto::{hash::Digest, AsymmetricKeyExt},
     effect::EffectBuilder,
     reactor::QueueKind,
     rpcs::{
@@ -35,10 +38,14 @@ use crate::{
     },
     types::{
         json_compatibility::{AuctionState, StoredValue},
-        Block,
+        Block, Timestamp,
     },
 };
 
+const DOCS_EXAMPLE_SEED_UREF: &str =
+    "uref-09480c3248ef76b603d386f3f4f8a5f87f597d4eaffd475433f861af187ab5db-007";
+const DOCS_EXAMPLE_DICTIONARY_ITEM_KEY: &str = "a_unique_entry_identifier";
+
 static GET_ITEM_PARAMS: Lazy<GetItemParams> = Lazy::new(|| GetItemParams {
     state_root_hash: *Block::doc_example().header().state_root_hash(),
     key: "deploy-af684263911154d26fa05be9963171802801a0b6aff8f199b7391eacb8edc9e1".to_string(),
@@ -49,6 +56,44 @@ static GET_ITEM_RESULT: Lazy<GetItemResult> = Lazy::new(|| GetItemResult {
     stored_value: StoredValue::CLValue(CLValue::from_t(1u64).unwrap()),
     merkle_proof: MERKLE_PROOF.clone(),
 });
+static GET_DICTIONARY_ITEM_PARAMS: Lazy<GetDictionaryItemParams> =
+    Lazy::new(|| GetDictionaryItemParams {
+        state_root_hash: *Block::doc_example().header().state_root_hash(),
+        dictionary_identifier: DictionaryIdentifier::URef {
+            seed_uref: DOCS_EXAMPLE_SEED_UREF.to_string(),
+            dictionary_item_key: DOCS_EXAMPLE_DICTIONARY_ITEM_KEY.to_string(),
+        },
+    });
+static GET_DICTIONARY_ITEM_RESULT: Lazy<GetDictionaryItemResult> =
+    Lazy::new(|| GetDictionaryItemResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        dictionary_key: Key::dictionary(
+            URef::from_formatted_str(DOCS_EXAMPLE_SEED_UREF).unwrap(),
+            DOCS_EXAMPLE_DICTIONARY_ITEM_KEY.as_bytes(),
+        )
+        .to_formatted_string(),
+        stored_value: StoredValue::CLValue(CLValue::from_t(1u64).unwrap()),
+        merkle_proof: MERKLE_PROOF.clone(),
+    });
+static GET_KEYS_WITH_PREFIX_PARAMS: Lazy<GetKeysWithPrefixParams> =
+    Lazy::new(|| GetKeysWithPrefixParams {
+        state_root_hash: *Block::doc_example().header().state_root_hash(),
+        prefix: "04".to_string(),
+        cursor: None,
+        limit: Some(1),
+    });
+static GET_KEYS_WITH_PREFIX_RESULT: Lazy<GetKeysWithPrefixResult> =
+    Lazy::new(|| GetKeysWithPrefixResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        stored_values: vec![KeyAndStoredValue {
+            key: "deploy-af684263911154d26fa05be9963171802801a0b6aff8f199b7391eacb8edc9e1"
+                .to_string(),
+            stored_value: StoredValue::CLValue(CLValue::from_t(1u64).unwrap()),
+        }],
+        next_cursor: Some(
+            "deploy-af684263911154d26fa05be9963171802801a0b6aff8f199b7391eacb8edc9e1".to_string(),
+        ),
+    });
 static GET_BALANCE_PARAMS: Lazy<GetBalanceParams> = Lazy::new(|| GetBalanceParams {
     state_root_hash: *Block::doc_example().header().state_root_hash(),
     purse_uref: "uref-09480c3248ef76b603d386f3f4f8a5f87f597d4eaffd475433f861af187ab5db-007"
@@ -59,6 +104,22 @@ static GET_BALANCE_RESULT: Lazy<GetBalanceResult> = Lazy::new(|| GetBalanceResul
     balance_value: U512::from(123_456),
     merkle_proof: MERKLE_PROOF.clone(),
 });
+static QUERY_CONTRACT_VIEW_PARAMS: Lazy<QueryContractViewParams> =
+    Lazy::new(|| QueryContractViewParams {
+        state_root_hash: *Block::doc_example().header().state_root_hash(),
+        target: ExecutableDeployItem::StoredContractByHash {
+            hash: ContractHash::new([7; 32]),
+            entry_point: "get_count".to_string(),
+            args: RuntimeArgs::new(),
+        },
+        caller: PublicKey::doc_example().clone(),
+    });
+static QUERY_CONTRACT_VIEW_RESULT: Lazy<QueryContractViewResult> =
+    Lazy::new(|| QueryContractViewResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        value: CLValue::from_t(1u64).unwrap(),
+        cost: U512::from(123_456),
+    });
 static GET_AUCTION_INFO_RESULT: Lazy<GetAuctionInfoResult> = Lazy::new(|| GetAuctionInfoResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     auction_state: AuctionState::doc_example().clone(),
@@ -167,6 +228,432 @@ impl RpcWithParamsExt for GetItem {
     }
 }
 
+/// Options for identifying a dictionary item.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum DictionaryIdentifier {
+    /// Looks up a dictionary item via the named keys of an account.
+    AccountNamedKey {
+        /// The account hash of the account as a formatted string.
+        key: String,
+        /// The named key under which the dictionary's seed `URef` is stored.
+        dictionary_name: String,
+        /// The key of the dictionary item.
+        dictionary_item_key: String,
+    },
+    /// Looks up a dictionary item via the named keys of a contract.
+    ContractNamedKey {
+        /// The contract hash of the contract as a formatted string.
+        key: String,
+        /// The named key under which the dictionary's seed `URef` is stored.
+        dictionary_name: String,
+        /// The key of the dictionary item.
+        dictionary_item_key: String,
+    },
+    /// Looks up a dictionary item via the dictionary's seed `URef`.
+    URef {
+        /// The dictionary's seed `URef` as a formatted string.
+        seed_uref: String,
+        /// The key of the dictionary item.
+        dictionary_item_key: String,
+    },
+    /// Looks up a dictionary item via its `Key::Dictionary` as a formatted string.
+    Dictionary(String),
+}
+
+/// Params for "state_get_dictionary_item" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDictionaryItemParams {
+    /// Hash of the state root.
+    pub state_root_hash: Digest,
+    /// The dictionary item to look up.
+    pub dictionary_identifier: DictionaryIdentifier,
+}
+
+impl DocExample for GetDictionaryItemParams {
+    fn doc_example() -> &'static Self {
+        &*GET_DICTIONARY_ITEM_PARAMS
+    }
+}
+
+/// Result for "state_get_dictionary_item" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDictionaryItemResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The `Key::Dictionary` under which the item is stored, as a formatted string.
+    pub dictionary_key: String,
+    /// The stored value.
+    pub stored_value: StoredValue,
+    /// The merkle proof.
+    pub merkle_proof: String,
+}
+
+impl DocExample for GetDictionaryItemResult {
+    fn doc_example() -> &'static Self {
+        &*GET_DICTIONARY_ITEM_RESULT
+    }
+}
+
+/// "state_get_dictionary_item" RPC.
+pub struct GetDictionaryItem {}
+
+impl RpcWithParams for GetDictionaryItem {
+    const METHOD: &'static str = "state_get_dictionary_item";
+    type RequestParams = GetDictionaryItemParams;
+    type ResponseResult = GetDictionaryItemResult;
+}
+
+impl RpcWithParamsExt for GetDictionaryItem {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let dictionary_key = match get_dictionary_key(
+                effect_builder,
+                params.state_root_hash,
+                params.dictionary_identifier,
+            )
+            .await
+            {
+                Ok(key) => key,
+                Err((error_code, error_msg)) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder
+                        .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
+                }
+            };
+
+            let query_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::QueryGlobalState {
+                        state_root_hash: params.state_root_hash,
+                        base_key: dictionary_key,
+                        path: vec![],
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let (stored_value, proof_bytes) = match common::extract_query_result(query_result) {
+                Ok(tuple) => tuple,
+                Err((error_code, error_msg)) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder
+                        .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
+                }
+            };
+
+            let result = Self::ResponseResult {
+                api_version,
+                dictionary_key: dictionary_key.to_formatted_string(),
+                stored_value,
+                merkle_proof: hex::encode(proof_bytes),
+            };
+
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+/// Resolves the `Key::Dictionary` identified by `dictionary_identifier`, looking up the
+/// dictionary's seed `URef` in global state if it's identified via named keys.
+async fn get_dictionary_key<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+    state_root_hash: Digest,
+    dictionary_identifier: DictionaryIdentifier,
+) -> Result<Key, (ErrorCode, String)> {
+    let parse_key = |key: &str| {
+        Key::from_formatted_str(key).map_err(|error| {
+            (
+                ErrorCode::ParseQueryKey,
+                format!("failed to parse key: {:?}", error),
+            )
+        })
+    };
+
+    let (base_key, dictionary_name, dictionary_item_key) = match dictionary_identifier {
+        DictionaryIdentifier::AccountNamedKey {
+            key,
+            dictionary_name,
+            dictionary_item_key,
+        } => match parse_key(&key)? {
+            account_key @ Key::Account(_) => (account_key, dictionary_name, dictionary_item_key),
+            other => {
+                return Err((
+                    ErrorCode::InvalidDictionaryIdentifier,
+                    format!("{} is not an account key", other),
+                ))
+            }
+        },
+        DictionaryIdentifier::ContractNamedKey {
+            key,
+            dictionary_name,
+            dictionary_item_key,
+        } => match parse_key(&key)? {
+            contract_key @ Key::Hash(_) => (contract_key, dictionary_name, dictionary_item_key),
+            other => {
+                return Err((
+                    ErrorCode::InvalidDictionaryIdentifier,
+                    format!("{} is not a contract key", other),
+                ))
+            }
+        },
+        DictionaryIdentifier::URef {
+            seed_uref,
+            dictionary_item_key,
+        } => {
+            let seed_uref = URef::from_formatted_str(&seed_uref).map_err(|error| {
+                (
+                    ErrorCode::InvalidDictionaryIdentifier,
+                    format!("failed to parse seed uref: {:?}", error),
+                )
+            })?;
+            return Ok(Key::dictionary(seed_uref, dictionary_item_key.as_bytes()));
+        }
+        DictionaryIdentifier::Dictionary(dictionary_key) => {
+            return match parse_key(&dictionary_key)? {
+                dictionary_key @ Key::Dictionary(_) => Ok(dictionary_key),
+                other => Err((
+                    ErrorCode::InvalidDictionaryIdentifier,
+                    format!("{} is not a dictionary key", other),
+                )),
+            };
+        }
+    };
+
+    // Look up the dictionary's seed `URef` in the named keys of the account or contract.
+    let query_result = effect_builder
+        .make_request(
+            |responder| RpcRequest::QueryGlobalState {
+                state_root_hash,
+                base_key,
+                path: vec![],
+                responder,
+            },
+            QueueKind::Api,
+        )
+        .await;
+    let (stored_value, _proof_bytes) = common::extract_query_result(query_result)?;
+    let named_keys = match &stored_value {
+        StoredValue::Account(account) => account.named_keys(),
+        StoredValue::Contract(contract) => contract.named_keys(),
+        _ => {
+            return Err((
+                ErrorCode::InvalidDictionaryIdentifier,
+                format!("{} is neither an account nor a contract", base_key),
+            ))
+        }
+    };
+
+    let seed_uref = named_keys
+        .iter()
+        .find(|named_key| named_key.name == dictionary_name)
+        .and_then(|named_key| Key::from_formatted_str(&named_key.key).ok())
+        .and_then(Key::into_uref)
+        .ok_or_else(|| {
+            (
+                ErrorCode::InvalidDictionaryIdentifier,
+                format!("{} has no dictionary named {}", base_key, dictionary_name),
+            )
+        })?;
+    Ok(Key::dictionary(seed_uref, dictionary_item_key.as_bytes()))
+}
+
+/// The maximum number of keys returned by a single "state_get_keys_with_prefix" request.
+pub const MAX_KEYS_WITH_PREFIX_LIMIT: u32 = 1_000;
+
+/// Params for "state_get_keys_with_prefix" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetKeysWithPrefixParams {
+    /// Hash of the state root.
+    pub state_root_hash: Digest,
+    /// Hex-encoded prefix of the serialized `casper_types::Key`s to be listed, e.g. "03" for all
+    /// `Key::Transfer`s.
+    pub prefix: String,
+    /// `casper_types::Key` as formatted string.  If present, only keys after this one are
+    /// returned.  Pass the `next_cursor` of the previous response to fetch the next page.
+    #[serde(default)]
+    pub cursor: Option<String>,
+    /// The maximum number of keys to return, capped at 1000.  Defaults to 1000 if absent, and
+    /// must not be zero.
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+impl DocExample for GetKeysWithPrefixParams {
+    fn doc_example() -> &'static Self {
+        &*GET_KEYS_WITH_PREFIX_PARAMS
+    }
+}
+
+/// A key and the value stored under it.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct KeyAndStoredValue {
+    /// `casper_types::Key` as formatted string.
+    pub key: String,
+    /// The stored value.
+    pub stored_value: StoredValue,
+}
+
+/// Result for "state_get_keys_with_prefix" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetKeysWithPrefixResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The keys matching the prefix and their stored values, in ascending key order.
+    pub stored_values: Vec<KeyAndStoredValue>,
+    /// The cursor to pass in the next request if more keys match the prefix, or `None` if this
+    /// is the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl DocExample for GetKeysWithPrefixResult {
+    fn doc_example() -> &'static Self {
+        &*GET_KEYS_WITH_PREFIX_RESULT
+    }
+}
+
+/// "state_get_keys_with_prefix" RPC.
+pub struct GetKeysWithPrefix {}
+
+impl RpcWithParams for GetKeysWithPrefix {
+    const METHOD: &'static str = "state_get_keys_with_prefix";
+    type RequestParams = GetKeysWithPrefixParams;
+    type ResponseResult = GetKeysWithPrefixResult;
+}
+
+impl RpcWithParamsExt for GetKeysWithPrefix {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            // Try to parse the prefix from the params.
+            let prefix = match hex::decode(&params.prefix)
+                .map_err(|error| format!("failed to parse prefix: {}", error))
+            {
+                Ok(prefix) => prefix,
+                Err(error_msg) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::ParseKeysPrefix as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            // Try to parse the optional cursor as a `casper_types::Key`.
+            let cursor = match params.cursor.as_ref().map(|cursor| {
+                Key::from_formatted_str(cursor)
+                    .map_err(|error| format!("failed to parse cursor: {:?}", error))
+            }) {
+                None => None,
+                Some(Ok(key)) => Some(key),
+                Some(Err(error_msg)) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::ParseQueryKey as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            let limit = cmp::min(
+                params.limit.unwrap_or(MAX_KEYS_WITH_PREFIX_LIMIT),
+                MAX_KEYS_WITH_PREFIX_LIMIT,
+            ) as usize;
+            if limit == 0 {
+                let error_msg = "limit must be greater than zero".to_string();
+                info!("{}", error_msg);
+                return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                    ErrorCode::InvalidKeysLimit as i64,
+                    error_msg,
+                ))?);
+            }
+
+            // Run the query.
+            let query_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::QueryKeysWithPrefix {
+                        state_root_hash: params.state_root_hash,
+                        prefix,
+                        cursor,
+                        limit,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let (keys_and_values, next_cursor) = match query_result {
+                Ok(KeysWithPrefixResult::Success {
+                    keys_and_values,
+                    next_cursor,
+                }) => (keys_and_values, next_cursor),
+                Ok(query_result) => {
+                    let error_msg = format!("state query failed: {:?}", query_result);
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::QueryFailed as i64,
+                        error_msg,
+                    ))?);
+                }
+                Err(error) => {
+                    let error_msg = format!("state query failed to execute: {:?}", error);
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::QueryFailedToExecute as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            let mut stored_values = Vec::with_capacity(keys_and_values.len());
+            for (key, value) in keys_and_values {
+                let stored_value = match StoredValue::try_from(&value) {
+                    Ok(stored_value) => stored_value,
+                    Err(error) => {
+                        let error_msg = format!("failed to encode stored value: {:?}", error);
+                        info!("{}", error_msg);
+                        return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                            ErrorCode::QueryFailed as i64,
+                            error_msg,
+                        ))?);
+                    }
+                };
+                stored_values.push(KeyAndStoredValue {
+                    key: key.to_formatted_string(),
+                    stored_value,
+                });
+            }
+
+            let result = Self::ResponseResult {
+                api_version,
+                stored_values,
+                next_cursor: next_cursor.map(|key| key.to_formatted_string()),
+            };
+
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
 /// Params for "state_get_balance" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -192,7 +679,12 @@ pub struct GetBalanceResult {
     pub api_version: Version,
     /// The balance value.
     pub balance_value: U512,
-    /// The merkle proof.
+    /// The merkle proof of the balance stored under the purse's `Key::Balance`.
+    ///
+    /// This is a breaking change from nodes running a protocol version from before balances were
+    /// stored under `Key::Balance`, which returned a pair of proofs here: one of the purse's
+    /// balance key in the mint's named keys, followed by one of the balance itself.  Clients can
+    /// tell the formats apart by the `api_version`.
     pub merkle_proof: String,
 }
 
@@ -245,12 +737,8 @@ impl RpcWithParamsExt for GetBalance {
                 )
                 .await;
 
-            let (balance_value, purse_proof, balance_proof) = match balance_result {
-                Ok(BalanceResult::Success {
-                    motes,
-                    purse_proof,
-                    balance_proof,
-                }) => (motes, purse_proof, balance_proof),
+            let (balance_value, balance_proof) = match balance_result {
+                Ok(BalanceResult::Success { motes, proof }) => (motes, proof),
                 Ok(balance_result) => {
                     let error_msg = format!("get-balance failed: {:?}", balance_result);
                     info!("{}", error_msg);
@@ -269,7 +757,7 @@ impl RpcWithParamsExt for GetBalance {
                 }
             };
 
-            let proof_bytes = match (*purse_proof, *balance_proof).to_bytes() {
+            let proof_bytes = match balance_proof.to_bytes() {
                 Ok(proof_bytes) => proof_bytes,
                 Err(error) => {
                     info!("failed to encode stored value: {}", error);
@@ -291,6 +779,123 @@ impl RpcWithParamsExt for GetBalance {
     }
 }
 
+/// Params for "query_contract_view" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct QueryContractViewParams {
+    /// The hash of state root.
+    pub state_root_hash: Digest,
+    /// The stored contract and entry point to call, along with its runtime args.  Must be either
+    /// `StoredContractByHash` or `StoredVersionedContractByHash`.
+    pub target: ExecutableDeployItem,
+    /// The public key of the account calling the entry point.  The account must exist in the
+    /// global state under `state_root_hash`.
+    pub caller: PublicKey,
+}
+
+impl DocExample for QueryContractViewParams {
+    fn doc_example() -> &'static Self {
+        &*QUERY_CONTRACT_VIEW_PARAMS
+    }
+}
+
+/// Result for "query_contract_view" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct QueryContractViewResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The value returned by the entry point.
+    pub value: CLValue,
+    /// The gas consumed by the call.  Nothing is charged for it.
+    pub cost: U512,
+}
+
+impl DocExample for QueryContractViewResult {
+    fn doc_example() -> &'static Self {
+        &*QUERY_CONTRACT_VIEW_RESULT
+    }
+}
+
+/// "query_contract_view" RPC.
+///
+/// Calls an entry point of a stored contract without a deploy.  The call can't write to global
+/// state and its gas is capped.
+pub struct QueryContractView {}
+
+impl RpcWithParams for QueryContractView {
+    const METHOD: &'static str = "query_contract_view";
+    type RequestParams = QueryContractViewParams;
+    type ResponseResult = QueryContractViewResult;
+}
+
+impl RpcWithParamsExt for QueryContractView {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let protocol_version = ProtocolVersion::from_parts(
+                api_version.major as u32,
+                api_version.minor as u32,
+                api_version.patch as u32,
+            );
+            let caller = params.caller.to_account_hash();
+
+            // Call the entry point.
+            let view_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::QueryContractView {
+                        state_root_hash: params.state_root_hash,
+                        block_time: Timestamp::now(),
+                        protocol_version,
+                        caller,
+                        target: Box::new(params.target),
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let (value, cost) = match view_result {
+                Ok(ViewResult::Success { value, cost }) => (value, cost.value()),
+                Ok(ViewResult::RootNotFound) => {
+                    let error_msg = format!(
+                        "query-contract-view failed: state root hash {} not found",
+                        params.state_root_hash
+                    );
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::NoSuchStateRoot as i64,
+                        error_msg,
+                    ))?);
+                }
+                Err(error) => {
+                    let error_msg = format!("query-contract-view failed: {}", error);
+                    info!("{}", error_msg);
+                    let error_code = match error {
+                        ContractViewError::Busy => ErrorCode::QueryContractViewBusy,
+                        ContractViewError::EngineState(_) => ErrorCode::QueryContractViewFailed,
+                    };
+                    return Ok(response_builder
+                        .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
+                }
+            };
+
+            let result = Self::ResponseResult {
+                api_version,
+                value,
+                cost,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
 /// Result for "state_get_auction_info" RPC response.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -353,49 +958,36 @@ impl RpcWithoutParamsExt for GetAuctionInfo {
                 api_version.minor as u32,
                 api_version.patch as u32,
             );
-            let protocol_version_result = effect_builder
-                .make_request(
-                    |responder| RpcRequest::QueryProtocolData {
-                        protocol_version,
-                        responder,
-                    },
-                    QueueKind::Api,
-                )
-                .await;
-
-            let protocol_data = {
-                if let Ok(Some(protocol_data)) = protocol_version_result {
-                    protocol_data
-                } else {
-                    Box::new(ProtocolData::default())
-                }
-            };
-
-            // auction contract key
-            let base_key = protocol_data.auction().into();
-            // bids named key in auction contract
-            let path = vec![casper_types::system::auction::BIDS_KEY.to_string()];
             // the global state hash of the last block
             let state_root_hash = *block.header().state_root_hash();
             // the block height of the last added block
             let block_height = block.header().height();
 
+            // every bid is stored under its own `Key::Bid`
             let query_result = effect_builder
                 .make_request(
-                    |responder| RpcRequest::QueryGlobalState {
+                    |responder| RpcRequest::QueryKeysWithPrefix {
                         state_root_hash,
-                        base_key,
-                        path,
+                        prefix: vec![KeyTag::Bid as u8],
+                        cursor: None,
+                        limit: usize::MAX,
                         responder,
                     },
                     QueueKind::Api,
                 )
                 .await;
 
-            let bids = if let Ok(QueryResult::Success { value, .. }) = query_result {
-                value
-                    .as_cl_value()
-                    .and_then(|cl_value| cl_value.to_owned().into_t().ok())
+            let bids = if let Ok(KeysWithPrefixResult::Success {
+                keys_and_values, ..
+            }) = query_result
+            {
+                let bids: Bids = keys_and_values
+                    .into_iter()
+                    .filter_map(|(_key, value)| value.as_bid().cloned())
+                    .filter(|bid| !bid.is_empty())
+                    .map(|bid| (*bid.validator_public_key(), bid))
+                    .collect();
+                Some(bids)
             } else {
                 None
             };
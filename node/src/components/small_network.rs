@@ -31,7 +31,17 @@
 //! On losing an incoming or outgoing connection for a given peer, the other connection is closed.
 //! No explicit reconnect is attempted. Instead, if the peer is still online, the normal gossiping
 //! process will cause both peers to connect again.
-
+//!
+//! # Handshake
+//!
+//! The first message sent on every connection is a handshake carrying the network name, protocol
+//! version, chainspec hash and activation era of the sender. Peers participating in a different
+//! network, running the same protocol version with a different chainspec, or running a different
+//! protocol version outside of an upgrade are disconnected. An upgrade is only considered to be in
+//! progress until the end of its activation era, as tracked by the blocks added to the linear
+//! chain.
+
+mod chain_info;
 mod config;
 mod error;
 mod event;
@@ -82,11 +92,15 @@ use tokio_serde::{formats::SymmetricalMessagePack, SymmetricallyFramed};
 use tokio_util::codec::{Framed, LengthDelimitedCodec};
 use tracing::{debug, error, info, trace, warn};
 
-use self::error::Result;
+use self::{
+    chain_info::{ChainInfo, HandshakeRejection},
+    error::Result,
+};
 pub(crate) use self::{event::Event, gossiped_address::GossipedAddress, message::Message};
 use crate::{
     components::{
-        network::ENABLE_LIBP2P_NET_ENV_VAR, networking_metrics::NetworkingMetrics, Component,
+        consensus::EraId, network::ENABLE_LIBP2P_NET_ENV_VAR,
+        networking_metrics::NetworkingMetrics, Component,
     },
     effect::{
         announcements::NetworkAnnouncement,
@@ -96,7 +110,7 @@ use crate::{
     fatal,
     reactor::{EventQueueHandle, Finalize, QueueKind},
     tls::{self, TlsCert, ValidationError},
-    types::{NodeId, TimeDiff, Timestamp},
+    types::{Chainspec, NodeId, TimeDiff, Timestamp},
     utils, NodeRng,
 };
 pub use config::Config;
@@ -156,9 +170,12 @@ where
     pending: HashSet<SocketAddr>,
     /// The interval between each fresh round of gossiping the node's public listening address.
     gossip_interval: Duration,
-    /// Name of the network we participate in. We only remain connected to peers with the same
-    /// network name as us.
-    network_name: String,
+    /// Information about the chain we participate in. We only remain connected to peers with a
+    /// compatible chain, see `ChainInfo::check_handshake`.
+    chain_info: ChainInfo,
+    /// The most recent era of a block added to the linear chain, or the era in which our protocol
+    /// version was activated if that is later.  Used to close the upgrade window in handshakes.
+    current_era: EraId,
     /// Channel signaling a shutdown of the small network.
     // Note: This channel is closed when `SmallNetwork` is dropped, signalling the receivers that
     // they should cease operation.
@@ -196,7 +213,7 @@ where
         cfg: Config,
         registry: &Registry,
         small_network_identity: SmallNetworkIdentity,
-        network_name: String,
+        chainspec: &Chainspec,
         notify: bool,
     ) -> Result<(SmallNetwork<REv, P>, Effects<Event<P>>)> {
         // Assert we have at least one known address in the config.
@@ -209,6 +226,7 @@ where
             utils::resolve_address(&cfg.public_address).map_err(Error::ResolveAddr)?;
 
         let our_id = NodeId::from(&small_network_identity);
+        let chain_info = ChainInfo::from(chainspec);
         let secret_key = small_network_identity.secret_key;
         let certificate = small_network_identity.tls_certificate;
 
@@ -228,7 +246,8 @@ where
                 pending: HashSet::new(),
                 blocklist: HashMap::new(),
                 gossip_interval: cfg.gossip_interval,
-                network_name,
+                current_era: chain_info.activation_era,
+                chain_info,
                 shutdown_sender: None,
                 shutdown_receiver: watch::channel(()).1,
                 server_join_handle: None,
@@ -292,7 +311,8 @@ where
             pending: HashSet::new(),
             blocklist: HashMap::new(),
             gossip_interval: cfg.gossip_interval,
-            network_name,
+            current_era: chain_info.activation_era,
+            chain_info,
             shutdown_sender: Some(server_shutdown_sender),
             shutdown_receiver,
             server_join_handle: Some(server_join_handle),
@@ -441,9 +461,7 @@ where
                 debug!(our_id=%self.our_id, %peer_id, %peer_address, "established incoming connection");
                 // The sink is only used to send a single handshake message, then dropped.
                 let (mut sink, stream) = framed::<P>(transport).split();
-                let handshake = Message::Handshake {
-                    network_name: self.network_name.clone(),
-                };
+                let handshake = self.chain_info.create_handshake();
                 let mut effects = async move {
                     let _ = sink.send(handshake).await;
                 }
@@ -540,9 +558,7 @@ where
 
         let mut effects = self.check_connection_complete(effect_builder, peer_id.clone());
 
-        let handshake = Message::Handshake {
-            network_name: self.network_name.clone(),
-        };
+        let handshake = self.chain_info.create_handshake();
         let peer_id_cloned = peer_id.clone();
         effects.extend(
             message_sender(
@@ -687,15 +703,36 @@ where
         REv: From<NetworkAnnouncement<NodeId, P>>,
     {
         match msg {
-            Message::Handshake { network_name } => {
-                if network_name != self.network_name {
+            Message::Handshake {
+                network_name,
+                protocol_version,
+                chainspec_hash,
+                activation_era,
+            } => {
+                if let Err(rejection) = self.chain_info.check_handshake(
+                    &network_name,
+                    protocol_version,
+                    chainspec_hash,
+                    activation_era,
+                    self.current_era,
+                ) {
                     info!(
                         our_id=%self.our_id,
                         %peer_id,
-                        our_network=?self.network_name,
-                        their_network=?network_name,
-                        "dropping connection due to network name mismatch"
+                        %rejection,
+                        "dropping connection due to handshake mismatch"
                     );
+                    match rejection {
+                        HandshakeRejection::NetworkNameMismatch { .. } => {
+                            self.net_metrics.network_name_mismatches.inc()
+                        }
+                        HandshakeRejection::ProtocolVersionMismatch { .. } => {
+                            self.net_metrics.protocol_version_mismatches.inc()
+                        }
+                        HandshakeRejection::ChainspecHashMismatch { .. } => {
+                            self.net_metrics.chainspec_hash_mismatches.inc()
+                        }
+                    }
                     let remove = self.remove(effect_builder, &peer_id, false);
                     self.update_peers_metric();
                     return remove;
@@ -961,6 +998,10 @@ where
             Event::PeerAddressReceived(gossiped_address) => {
                 self.connect_to_peer_if_required(gossiped_address.into())
             }
+            Event::BlockAdded { era_id } => {
+                self.current_era = self.current_era.max(era_id);
+                Effects::new()
+            }
         }
     }
 }
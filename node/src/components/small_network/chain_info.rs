@@ -0,0 +1,258 @@
+use std::fmt::{self, Display, Formatter};
+
+use datasize::DataSize;
+use thiserror::Error;
+
+use casper_types::ProtocolVersion;
+
+use super::Message;
+use crate::{components::consensus::EraId, crypto::hash::Digest, types::Chainspec};
+
+/// Data about the chain a node is running, exchanged with peers in the network handshake.
+#[derive(Clone, DataSize, Debug)]
+pub(crate) struct ChainInfo {
+    /// Name of the network we participate in.
+    pub(super) network_name: String,
+    /// The protocol version we are running.
+    pub(super) protocol_version: ProtocolVersion,
+    /// The hash of the chainspec we are running.
+    pub(super) chainspec_hash: Digest,
+    /// The era in which our protocol version was activated, i.e. the genesis era or the era of the
+    /// last upgrade.
+    pub(super) activation_era: EraId,
+}
+
+impl ChainInfo {
+    /// Creates the handshake message to send to peers.
+    pub(super) fn create_handshake<P>(&self) -> Message<P> {
+        Message::Handshake {
+            network_name: self.network_name.clone(),
+            protocol_version: Some(self.protocol_version),
+            chainspec_hash: Some(self.chainspec_hash),
+            activation_era: Some(self.activation_era),
+        }
+    }
+
+    /// Checks whether a peer which sent a handshake with the given contents can remain connected.
+    ///
+    /// Handshakes sent by nodes predating versioned handshakes only carry the network name, and
+    /// are accepted if it matches.
+    ///
+    /// Peers running the same protocol version must be running the same chainspec.  Peers running
+    /// a different protocol version are only accepted if they are on the other side of an upgrade
+    /// which is in progress, i.e. the higher of the two versions must have been activated in a
+    /// later era than the lower one, and that era must not have passed yet in `current_era`.
+    pub(super) fn check_handshake(
+        &self,
+        network_name: &str,
+        protocol_version: Option<ProtocolVersion>,
+        chainspec_hash: Option<Digest>,
+        activation_era: Option<EraId>,
+        current_era: EraId,
+    ) -> Result<(), HandshakeRejection> {
+        if network_name != self.network_name {
+            return Err(HandshakeRejection::NetworkNameMismatch {
+                ours: self.network_name.clone(),
+                theirs: network_name.to_string(),
+            });
+        }
+
+        let (protocol_version, chainspec_hash, activation_era) =
+            match (protocol_version, chainspec_hash, activation_era) {
+                (Some(protocol_version), Some(chainspec_hash), Some(activation_era)) => {
+                    (protocol_version, chainspec_hash, activation_era)
+                }
+                _ => return Ok(()),
+            };
+
+        if protocol_version == self.protocol_version {
+            if chainspec_hash != self.chainspec_hash {
+                return Err(HandshakeRejection::ChainspecHashMismatch {
+                    ours: self.chainspec_hash,
+                    theirs: chainspec_hash,
+                });
+            }
+            return Ok(());
+        }
+
+        let (is_upgrade, upgrade_era) = if protocol_version > self.protocol_version {
+            (activation_era > self.activation_era, activation_era)
+        } else {
+            (activation_era < self.activation_era, self.activation_era)
+        };
+        if !is_upgrade || current_era > upgrade_era {
+            return Err(HandshakeRejection::ProtocolVersionMismatch {
+                ours: self.protocol_version,
+                our_activation_era: self.activation_era,
+                theirs: protocol_version,
+                their_activation_era: activation_era,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl From<&Chainspec> for ChainInfo {
+    fn from(chainspec: &Chainspec) -> Self {
+        let version = &chainspec.protocol_config.version;
+        ChainInfo {
+            network_name: chainspec.network_config.name.clone(),
+            protocol_version: ProtocolVersion::from_parts(
+                version.major as u32,
+                version.minor as u32,
+                version.patch as u32,
+            ),
+            chainspec_hash: chainspec.hash(),
+            activation_era: chainspec.protocol_config.activation_point.era_id,
+        }
+    }
+}
+
+impl Display for ChainInfo {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "network {}, protocol version {} activated in {}, chainspec {}",
+            self.network_name, self.protocol_version, self.activation_era, self.chainspec_hash
+        )
+    }
+}
+
+/// The reason for rejecting a peer's handshake.
+#[derive(Debug, Error, PartialEq)]
+pub(crate) enum HandshakeRejection {
+    /// The peer participates in a different network.
+    #[error("network name mismatch: ours is {ours}, theirs is {theirs}")]
+    NetworkNameMismatch { ours: String, theirs: String },
+    /// The peer runs an incompatible protocol version.
+    #[error(
+        "incompatible protocol version: ours is {ours} activated in {our_activation_era}, theirs \
+        is {theirs} activated in {their_activation_era}"
+    )]
+    ProtocolVersionMismatch {
+        ours: ProtocolVersion,
+        our_activation_era: EraId,
+        theirs: ProtocolVersion,
+        their_activation_era: EraId,
+    },
+    /// The peer runs the same protocol version as us, but with a different chainspec.
+    #[error("chainspec hash mismatch: ours is {ours}, theirs is {theirs}")]
+    ChainspecHashMismatch { ours: Digest, theirs: Digest },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain_info(version: (u32, u32, u32), activation_era: u64, hash_byte: u8) -> ChainInfo {
+        ChainInfo {
+            network_name: "test-network".to_string(),
+            protocol_version: ProtocolVersion::from_parts(version.0, version.1, version.2),
+            chainspec_hash: Digest::from([hash_byte; Digest::LENGTH]),
+            activation_era: EraId(activation_era),
+        }
+    }
+
+    fn check(ours: &ChainInfo, theirs: &ChainInfo) -> Result<(), HandshakeRejection> {
+        check_in_era(ours, theirs, ours.activation_era.0)
+    }
+
+    fn check_in_era(
+        ours: &ChainInfo,
+        theirs: &ChainInfo,
+        current_era: u64,
+    ) -> Result<(), HandshakeRejection> {
+        ours.check_handshake(
+            &theirs.network_name,
+            Some(theirs.protocol_version),
+            Some(theirs.chainspec_hash),
+            Some(theirs.activation_era),
+            EraId(current_era),
+        )
+    }
+
+    #[test]
+    fn should_accept_peer_running_same_chainspec() {
+        let ours = chain_info((1, 0, 0), 0, 1);
+        assert_eq!(check(&ours, &ours.clone()), Ok(()));
+    }
+
+    #[test]
+    fn should_reject_peer_on_other_network() {
+        let ours = chain_info((1, 0, 0), 0, 1);
+        let mut theirs = ours.clone();
+        theirs.network_name = "other-network".to_string();
+        assert!(matches!(
+            check(&ours, &theirs),
+            Err(HandshakeRejection::NetworkNameMismatch { .. })
+        ));
+        // Even legacy handshakes are checked for the network name.
+        assert!(matches!(
+            ours.check_handshake("other-network", None, None, None, EraId(0)),
+            Err(HandshakeRejection::NetworkNameMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn should_accept_legacy_handshake() {
+        let ours = chain_info((1, 0, 0), 0, 1);
+        assert_eq!(
+            ours.check_handshake("test-network", None, None, None, EraId(0)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn should_reject_peer_running_different_chainspec_with_same_version() {
+        let ours = chain_info((1, 0, 0), 0, 1);
+        let theirs = chain_info((1, 0, 0), 0, 2);
+        assert!(matches!(
+            check(&ours, &theirs),
+            Err(HandshakeRejection::ChainspecHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn should_accept_peers_on_both_sides_of_upgrade() {
+        let old = chain_info((1, 0, 0), 0, 1);
+        let new = chain_info((1, 1, 0), 10, 2);
+        assert_eq!(check(&old, &new), Ok(()));
+        assert_eq!(check(&new, &old), Ok(()));
+        // The upgrade window stays open until the end of the activation era.
+        assert_eq!(check_in_era(&old, &new, 10), Ok(()));
+        assert_eq!(check_in_era(&new, &old, 10), Ok(()));
+    }
+
+    #[test]
+    fn should_reject_peers_on_other_side_of_upgrade_after_activation_era() {
+        let old = chain_info((1, 0, 0), 0, 1);
+        let new = chain_info((1, 1, 0), 10, 2);
+        assert!(matches!(
+            check_in_era(&old, &new, 11),
+            Err(HandshakeRejection::ProtocolVersionMismatch { .. })
+        ));
+        assert!(matches!(
+            check_in_era(&new, &old, 11),
+            Err(HandshakeRejection::ProtocolVersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn should_reject_peer_running_different_version_not_matching_upgrade() {
+        let ours = chain_info((1, 1, 0), 10, 1);
+
+        // A higher version which activated no later than ours.
+        let theirs = chain_info((1, 2, 0), 10, 2);
+        assert!(matches!(
+            check(&ours, &theirs),
+            Err(HandshakeRejection::ProtocolVersionMismatch { .. })
+        ));
+
+        // A lower version which activated no earlier than ours.
+        let theirs = chain_info((1, 0, 0), 20, 2);
+        assert!(matches!(
+            check(&ours, &theirs),
+            Err(HandshakeRejection::ProtocolVersionMismatch { .. })
+        ));
+    }
+}
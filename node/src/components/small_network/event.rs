@@ -11,6 +11,7 @@ use tokio::net::TcpStream;
 
 use super::{Error, GossipedAddress, Message, NodeId, Transport};
 use crate::{
+    components::consensus::EraId,
     effect::requests::{NetworkInfoRequest, NetworkRequest},
     protocol::Message as ProtocolMessage,
 };
@@ -81,6 +82,8 @@ pub enum Event<P> {
     GossipOurAddress,
     /// We received a peer's public listening address via gossip.
     PeerAddressReceived(GossipedAddress),
+    /// A block of the given era was added to the linear chain.
+    BlockAdded { era_id: EraId },
 }
 
 impl From<NetworkRequest<NodeId, ProtocolMessage>> for Event<ProtocolMessage> {
@@ -153,6 +156,7 @@ impl<P: Display> Display for Event<P> {
             Event::PeerAddressReceived(gossiped_address) => {
                 write!(f, "received gossiped peer address {}", gossiped_address)
             }
+            Event::BlockAdded { era_id } => write!(f, "block added in {}", era_id),
         }
     }
 }
@@ -2,17 +2,111 @@ use std::fmt::{self, Debug, Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 
+use casper_types::ProtocolVersion;
+
+use crate::{components::consensus::EraId, crypto::hash::Digest};
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Message<P> {
-    Handshake { network_name: String },
+    Handshake {
+        /// Network we are connected to.
+        network_name: String,
+        /// The protocol version of the sender.
+        ///
+        /// This and the following fields are `None` if the sender predates versioned handshakes.
+        #[serde(default)]
+        protocol_version: Option<ProtocolVersion>,
+        /// The hash of the sender's chainspec.
+        #[serde(default)]
+        chainspec_hash: Option<Digest>,
+        /// The era in which the sender's protocol version was activated.
+        #[serde(default)]
+        activation_era: Option<EraId>,
+    },
     Payload(P),
 }
 
 impl<P: Display> Display for Message<P> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            Message::Handshake { network_name } => write!(f, "handshake: {}", network_name),
+            Message::Handshake {
+                network_name,
+                protocol_version,
+                chainspec_hash,
+                activation_era,
+            } => {
+                write!(f, "handshake: {}", network_name)?;
+                if let (Some(protocol_version), Some(chainspec_hash), Some(activation_era)) =
+                    (protocol_version, chainspec_hash, activation_era)
+                {
+                    write!(
+                        f,
+                        ", protocol version {} activated in {}, chainspec {}",
+                        protocol_version, activation_era, chainspec_hash
+                    )?;
+                }
+                Ok(())
+            }
             Message::Payload(payload) => write!(f, "payload: {}", payload),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use casper_types::ProtocolVersion;
+    use serde::{Deserialize, Serialize};
+
+    use super::Message;
+    use crate::{components::consensus::EraId, crypto::hash::Digest};
+
+    /// The handshake message as sent by nodes predating versioned handshakes.
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    enum BaselineMessage {
+        Handshake { network_name: String },
+        Payload(String),
+    }
+
+    const NETWORK_NAME: &str = "test-network";
+
+    #[test]
+    fn should_decode_baseline_handshake() {
+        let baseline = BaselineMessage::Handshake {
+            network_name: NETWORK_NAME.to_string(),
+        };
+        let encoded = rmp_serde::to_vec(&baseline).expect("should encode");
+        let decoded: Message<String> = rmp_serde::from_read_ref(&encoded).expect("should decode");
+        match decoded {
+            Message::Handshake {
+                network_name,
+                protocol_version,
+                chainspec_hash,
+                activation_era,
+            } => {
+                assert_eq!(network_name, NETWORK_NAME);
+                assert_eq!(protocol_version, None);
+                assert_eq!(chainspec_hash, None);
+                assert_eq!(activation_era, None);
+            }
+            Message::Payload(_) => panic!("should decode as handshake"),
+        }
+    }
+
+    #[test]
+    fn should_decode_versioned_handshake_as_baseline() {
+        let versioned: Message<String> = Message::Handshake {
+            network_name: NETWORK_NAME.to_string(),
+            protocol_version: Some(ProtocolVersion::from_parts(1, 1, 0)),
+            chainspec_hash: Some(Digest::from([1; Digest::LENGTH])),
+            activation_era: Some(EraId(10)),
+        };
+        let encoded = rmp_serde::to_vec(&versioned).expect("should encode");
+        let decoded: BaselineMessage = rmp_serde::from_read_ref(&encoded).expect("should decode");
+        assert_eq!(
+            decoded,
+            BaselineMessage::Handshake {
+                network_name: NETWORK_NAME.to_string()
+            }
+        );
+    }
+}
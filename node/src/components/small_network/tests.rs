@@ -36,7 +36,7 @@ use crate::{
         network::{Network, NetworkedReactor},
         ConditionCheckReactor,
     },
-    types::NodeId,
+    types::{Chainspec, NodeId},
     utils::Source,
     NodeRng,
 };
@@ -126,7 +126,7 @@ impl Reactor for TestReactor {
             cfg,
             registry,
             small_network_identity,
-            "test_network".to_string(),
+            &Chainspec::from_resources("local"),
             false,
         )?;
         let gossiper_config = gossiper::Config::new_with_small_timeouts();
@@ -8,7 +8,7 @@
 //! * [temporary until refactored] holding `DeployMetadata` for each deploy,
 //! * holding a read-only copy of the chainspec,
 //! * keeping an index of blocks by height and
-//! * [unimplemented] managing disk usage by pruning blocks and deploys from storage.
+//! * managing disk usage by pruning blocks and deploys from storage.
 //!
 //! Any I/O performed by the component is done on the event handling thread, this is on purpose as
 //! the assumption is that caching by LMDB will offset any gains from offloading it onto a separate
@@ -28,8 +28,39 @@
 //!
 //! ## Indices
 //!
-//! The current implementation keeps only in-memory indices, which are not persisted, based upon the
-//! estimate that they are reasonably quick to rebuild on start-up and do not take up much memory.
+//! The block height and switch block era ID indices are persisted in dedicated databases, updated
+//! in the same transaction as the blocks they refer to, and additionally held in memory for fast
+//! lookups. On start-up they are loaded from their databases, and a quick consistency check against
+//! the block store is performed. Should it fail, or if requested through the configuration, the
+//! indices are rebuilt by scanning all stored block headers.
+//!
+//! Deploys are additionally indexed by the account which sent them, and transfers by each account
+//! and purse which took part in them. These account indices are only held on disk, keyed by the
+//! account hash (or purse address) followed by the big-endian block height, so that a range of
+//! blocks can be paged through in height order. They are populated when execution results are
+//! stored, hence only cover blocks executed by this node, and are rebuilt from the stored execution
+//! results and transfers along with the block indices.
+//!
+//! ## Pruning
+//!
+//! If a retention policy is configured, blocks falling outside the retention window are removed
+//! along with their deploys, deploy metadata, transfers and finality signatures. Switch blocks are
+//! never pruned, as they are required to verify the validator sets of every era. If
+//! `retain_block_headers` is set, only the bodies, deploys, deploy metadata and transfers of such
+//! blocks are removed, while their headers and finality signatures are kept, so that the chain of
+//! block headers remains complete.
+//!
+//! Pruning is performed incrementally: whenever a block is stored, a batch of at most
+//! `prune_batch_size` blocks is pruned, and further batches are scheduled as separate events until
+//! the store is within the retention window again. This keeps the time spent on any single event
+//! bounded, even when pruning is enabled on a node with a large existing store.
+//!
+//! The height below which blocks have been pruned is persisted in the state store along with each
+//! batch. If headers are retained, pruning resumes from that height after a restart, rather than
+//! visiting every retained header again.
+//!
+//! Pages freed by pruning are reused by LMDB for subsequent writes, but the database files
+//! themselves do not shrink.
 //!
 //! ## Errors
 //!
@@ -43,23 +74,25 @@ mod tests;
 #[cfg(test)]
 use std::{collections::BTreeSet, convert::TryFrom};
 use std::{
-    collections::{btree_map::Entry, BTreeMap},
+    collections::{BTreeMap, HashMap},
+    convert::TryInto,
     fmt::{self, Display, Formatter},
     fs, io, mem,
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use datasize::DataSize;
 use derive_more::From;
 use lmdb::{
-    Cursor, Database, DatabaseFlags, Environment, EnvironmentFlags, Transaction, WriteFlags,
+    Cursor, Database, DatabaseFlags, Environment, EnvironmentFlags, RwTransaction, Transaction,
+    WriteFlags,
 };
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use static_assertions::const_assert;
 #[cfg(test)]
 use tempfile::TempDir;
 use thiserror::Error;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use super::Component;
 #[cfg(test)]
@@ -72,13 +105,17 @@ use crate::{
     },
     fatal,
     types::{
-        Block, BlockBody, BlockHash, BlockHeader, BlockSignatures, Deploy, DeployHash,
-        DeployMetadata,
+        AccountDeploy, AccountIndexPage, AccountIndexQuery, AccountTransfer, Block, BlockBody,
+        BlockHash, BlockHeader, BlockSignatures, Deploy, DeployHash, DeployMetadata,
+        TransferParticipant,
     },
     utils::WithDir,
     NodeRng,
 };
-use casper_types::{ExecutionResult, Transfer, Transform};
+use casper_types::{
+    ExecutionEffect, ExecutionResult, Operation, PublicKey, Transfer, TransferAddr, Transform,
+    TransformEntry, U512,
+};
 use lmdb_ext::{LmdbExtError, TransactionExt, WriteTransactionExt};
 
 /// Filename for the LMDB database created by the Storage component.
@@ -100,7 +137,15 @@ const DEFAULT_MAX_DEPLOY_METADATA_STORE_SIZE: usize = 300 * GIB;
 /// Default max state store size.
 const DEFAULT_MAX_STATE_STORE_SIZE: usize = 10 * GIB;
 /// Maximum number of allowed dbs.
-const MAX_DB_COUNT: u32 = 7;
+const MAX_DB_COUNT: u32 = 12;
+/// Default maximum number of blocks pruned per batch.
+const DEFAULT_PRUNE_BATCH_SIZE: u32 = 100;
+/// Key in the state store under which the height below which blocks have been pruned is persisted.
+const PRUNED_BELOW_HEIGHT_KEY: &[u8] = b"storage:pruned_below_height";
+/// Tag prefixing the keys of the account transfer index which relate to an account.
+const TRANSFER_INDEX_ACCOUNT_TAG: u8 = 0;
+/// Tag prefixing the keys of the account transfer index which relate to a purse.
+const TRANSFER_INDEX_PURSE_TAG: u8 = 1;
 
 /// OS-specific lmdb flags.
 #[cfg(not(target_os = "macos"))]
@@ -123,6 +168,8 @@ pub enum Event {
     /// Incoming state storage request.
     #[from]
     StateStoreRequest(StateStoreRequest),
+    /// Prune the next batch of blocks falling outside the retention window.
+    Prune,
 }
 
 /// A storage component initialization error.
@@ -199,10 +246,35 @@ pub struct Storage {
     /// The state storage database.
     #[data_size(skip)]
     state_store_db: Database,
+    /// The database persisting the block height index.
+    #[data_size(skip)]
+    block_height_index_db: Database,
+    /// The database persisting the switch block era ID index.
+    #[data_size(skip)]
+    switch_block_era_id_index_db: Database,
+    /// The index of deploys by the account hash of the sending account.
+    #[data_size(skip)]
+    account_deploy_index_db: Database,
+    /// The index of transfers by the accounts and purses taking part in them.
+    #[data_size(skip)]
+    account_transfer_index_db: Database,
     /// A map of block height to block ID.
     block_height_index: BTreeMap<u64, BlockHash>,
     /// A map of era ID to switch block ID.
     switch_block_era_id_index: BTreeMap<EraId, BlockHash>,
+    /// The number of most recent blocks to retain, `0` meaning all blocks are retained.
+    retain_blocks: u64,
+    /// The number of most recent eras whose blocks are retained, `0` meaning all eras are
+    /// retained.
+    retain_eras: u64,
+    /// The maximum number of blocks pruned in a single batch.
+    prune_batch_size: u32,
+    /// Whether to retain the headers and finality signatures of pruned blocks.
+    retain_block_headers: bool,
+    /// The height below which all blocks other than switch blocks have been pruned.
+    pruned_below_height: u64,
+    /// Whether a pruning batch has been scheduled and not yet been handled.
+    prune_scheduled: bool,
 }
 
 impl<REv> Component<REv> for Storage {
@@ -216,10 +288,11 @@ impl<REv> Component<REv> for Storage {
         event: Self::Event,
     ) -> Effects<Self::Event> {
         let result = match event {
-            Event::StorageRequest(req) => self.handle_storage_request::<REv>(req),
+            Event::StorageRequest(req) => self.handle_storage_request(effect_builder, req),
             Event::StateStoreRequest(req) => {
                 self.handle_state_store_request::<REv>(effect_builder, req)
             }
+            Event::Prune => self.handle_prune(effect_builder),
         };
 
         // Any error is turned into a fatal effect, the component itself does not panic. Note that
@@ -270,17 +343,180 @@ impl Storage {
         let block_header_db = env.create_db(Some("block_header"), DatabaseFlags::empty())?;
         let block_metadata_db = env.create_db(Some("block_metadata"), DatabaseFlags::empty())?;
         let deploy_db = env.create_db(Some("deploys"), DatabaseFlags::empty())?;
-        let deploy_metadata_db = env.create_db(Some("deploy_metadata"), DatabaseFlags::empty())?;
+        let legacy_deploy_metadata_db =
+            env.create_db(Some("deploy_metadata"), DatabaseFlags::empty())?;
+        let deploy_metadata_db =
+            env.create_db(Some("deploy_metadata_v2"), DatabaseFlags::empty())?;
         let transfer_db = env.create_db(Some("transfer"), DatabaseFlags::empty())?;
         let state_store_db = env.create_db(Some("state_store"), DatabaseFlags::empty())?;
         let block_body_db = env.create_db(Some("block_body"), DatabaseFlags::empty())?;
 
-        // We now need to restore the block-height index. Log messages allow timing here.
+        let block_height_index_db =
+            env.create_db(Some("block_height_index"), DatabaseFlags::empty())?;
+        let switch_block_era_id_index_db =
+            env.create_db(Some("switch_block_era_id_index"), DatabaseFlags::empty())?;
+        let account_deploy_index_db =
+            env.create_db(Some("account_deploy_index"), DatabaseFlags::empty())?;
+        let account_transfer_index_db =
+            env.create_db(Some("account_transfer_index"), DatabaseFlags::empty())?;
+
+        let mut storage = Storage {
+            root,
+            env,
+            block_header_db,
+            block_body_db,
+            block_metadata_db,
+            deploy_db,
+            deploy_metadata_db,
+            transfer_db,
+            state_store_db,
+            block_height_index_db,
+            switch_block_era_id_index_db,
+            account_deploy_index_db,
+            account_transfer_index_db,
+            block_height_index: BTreeMap::new(),
+            switch_block_era_id_index: BTreeMap::new(),
+            retain_blocks: config.retain_blocks,
+            retain_eras: config.retain_eras,
+            prune_batch_size: config.prune_batch_size.unwrap_or(DEFAULT_PRUNE_BATCH_SIZE),
+            retain_block_headers: config.retain_block_headers,
+            pruned_below_height: 0,
+            prune_scheduled: false,
+        };
+
+        storage.migrate_legacy_deploy_metadata(legacy_deploy_metadata_db)?;
+
+        if hard_reset_to_start_of_era.is_some() {
+            info!("hard reset configured, rebuilding block indices");
+            storage.rebuild_indices(hard_reset_to_start_of_era)?;
+        } else if config.rebuild_indices {
+            info!("rebuilding block indices as requested by configuration");
+            storage.rebuild_indices(None)?;
+        } else {
+            storage.load_indices()?;
+            if !storage.indices_consistent()? {
+                warn!("block indices are inconsistent with the block store, rebuilding");
+                storage.rebuild_indices(None)?;
+            }
+        }
+
+        // Without retained headers, pruned blocks are removed from the block height index, so
+        // pruning starts from the lowest indexed height.  This also removes any headers retained
+        // under a previous configuration.
+        if storage.retain_block_headers {
+            storage.pruned_below_height = storage.read_pruned_below_height()?;
+        }
+
+        Ok(storage)
+    }
+
+    /// Moves all deploy metadata from `legacy_db`, which holds it in the format written before
+    /// messages were added to execution effects, to the deploy metadata database.
+    fn migrate_legacy_deploy_metadata(&self, legacy_db: Database) -> Result<(), Error> {
+        let mut txn = self.env.begin_rw_txn()?;
+        let legacy_txn = self.env.begin_ro_txn()?;
+        let mut cursor = legacy_txn.open_ro_cursor(legacy_db)?;
+
+        let mut migrated = 0usize;
+        // Note: `iter_start` has an undocumented panic if called on an empty database. We rely on
+        //       the iterator being at the start when created.
+        for (raw_key, raw_val) in cursor.iter() {
+            let legacy_metadata: LegacyDeployMetadata = lmdb_ext::deserialize(raw_val)?;
+            let _ = txn.put_value(
+                self.deploy_metadata_db,
+                &raw_key,
+                &DeployMetadata::from(legacy_metadata),
+                false,
+            )?;
+            migrated += 1;
+        }
+        drop(cursor);
+        drop(legacy_txn);
+
+        if migrated > 0 {
+            txn.clear_db(legacy_db)?;
+            info!(%migrated, "migrated legacy deploy metadata");
+        }
+        txn.commit()?;
+        Ok(())
+    }
+
+    /// Reads the height below which blocks have been pruned, as persisted by the last pruning
+    /// batch.
+    fn read_pruned_below_height(&self) -> Result<u64, Error> {
+        let mut txn = self.env.begin_ro_txn()?;
+        let pruned_below_height: Option<u64> =
+            txn.get_value(self.state_store_db, &PRUNED_BELOW_HEIGHT_KEY)?;
+        txn.commit()?;
+        Ok(pruned_below_height.unwrap_or_default())
+    }
+
+    /// Loads the block indices from their databases.
+    fn load_indices(&mut self) -> Result<(), Error> {
+        let mut txn = self.env.begin_ro_txn()?;
+        self.block_height_index = read_index(&mut txn, self.block_height_index_db)?;
+        self.switch_block_era_id_index = read_index(&mut txn, self.switch_block_era_id_index_db)?
+            .into_iter()
+            .map(|(era_id, block_hash)| (EraId(era_id), block_hash))
+            .collect();
+        Ok(())
+    }
+
+    /// Performs a quick consistency check of the loaded block indices against the block store.
+    ///
+    /// The indices are considered consistent if they are populated whenever blocks are stored, and
+    /// if their highest entries refer to stored blocks matching the respective index keys.
+    fn indices_consistent(&self) -> Result<bool, Error> {
+        let mut txn = self.env.begin_ro_txn()?;
+        // Note: `iter_start` has an undocumented panic if called on an empty database. We rely on
+        //       the iterator being at the start when created.
+        let has_blocks = txn
+            .open_ro_cursor(self.block_header_db)?
+            .iter()
+            .next()
+            .is_some();
+        if has_blocks == self.block_height_index.is_empty() {
+            return Ok(false);
+        }
+
+        if let Some((height, block_hash)) = self.block_height_index.iter().next_back() {
+            let block_header: Option<BlockHeader> =
+                txn.get_value(self.block_header_db, block_hash)?;
+            match block_header {
+                Some(block_header) if block_header.height() == *height => (),
+                _ => return Ok(false),
+            }
+        }
+
+        if let Some((era_id, block_hash)) = self.switch_block_era_id_index.iter().next_back() {
+            let block_header: Option<BlockHeader> =
+                txn.get_value(self.block_header_db, block_hash)?;
+            match block_header {
+                Some(block_header)
+                    if block_header.is_switch_block() && block_header.era_id() == *era_id => {}
+                _ => return Ok(false),
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Rebuilds the block indices by scanning the entire block header database, replacing the
+    /// persisted indices.
+    ///
+    /// Blocks from era `hard_reset_to_start_of_era` onwards are left out of the indices.
+    fn rebuild_indices(&mut self, hard_reset_to_start_of_era: Option<EraId>) -> Result<(), Error> {
+        // Log messages allow timing here.
         info!("reindexing block store");
         let mut block_height_index = BTreeMap::new();
         let mut switch_block_era_id_index = BTreeMap::new();
-        let block_txn = env.begin_ro_txn()?;
-        let mut cursor = block_txn.open_ro_cursor(block_header_db)?;
+
+        let mut index_txn = self.env.begin_rw_txn()?;
+        index_txn.clear_db(self.block_height_index_db)?;
+        index_txn.clear_db(self.switch_block_era_id_index_db)?;
+
+        let block_txn = self.env.begin_ro_txn()?;
+        let mut cursor = block_txn.open_ro_cursor(self.block_header_db)?;
 
         // Note: `iter_start` has an undocumented panic if called on an empty database. We rely on
         //       the iterator being at the start when created.
@@ -297,29 +533,97 @@ impl Storage {
                 block.hash().as_ref(),
                 "found corrupt block in database"
             );
+            insert_to_block_header_index_dbs(
+                &mut index_txn,
+                self.block_height_index_db,
+                self.switch_block_era_id_index_db,
+                &block_height_index,
+                &switch_block_era_id_index,
+                &block,
+            )?;
             insert_to_block_header_indices(
                 &mut block_height_index,
                 &mut switch_block_era_id_index,
                 &block,
-            )?;
+            );
         }
-        info!("block store reindexing complete");
         drop(cursor);
         drop(block_txn);
+        index_txn.commit()?;
+        info!("block store reindexing complete");
 
-        Ok(Storage {
-            root,
-            env,
-            block_header_db,
-            block_body_db,
-            block_metadata_db,
-            deploy_db,
-            deploy_metadata_db,
-            transfer_db,
-            state_store_db,
-            block_height_index,
-            switch_block_era_id_index,
-        })
+        self.block_height_index = block_height_index;
+        self.switch_block_era_id_index = switch_block_era_id_index;
+        self.rebuild_account_indices()
+    }
+
+    /// Rebuilds the account indices from the stored deploy metadata and transfers, replacing the
+    /// persisted indices.
+    ///
+    /// Only the execution results and transfers of blocks in the block height index are indexed,
+    /// hence this must be run after the block indices have been rebuilt.
+    fn rebuild_account_indices(&self) -> Result<(), Error> {
+        info!("reindexing account deploys and transfers");
+        let mut txn = self.env.begin_rw_txn()?;
+        txn.clear_db(self.account_deploy_index_db)?;
+        txn.clear_db(self.account_transfer_index_db)?;
+
+        let metadata_txn = self.env.begin_ro_txn()?;
+        let mut cursor = metadata_txn.open_ro_cursor(self.deploy_metadata_db)?;
+        // Note: `iter_start` has an undocumented panic if called on an empty database. We rely on
+        //       the iterator being at the start when created.
+        for (raw_key, raw_val) in cursor.iter() {
+            let metadata: DeployMetadata = lmdb_ext::deserialize(raw_val)?;
+            let deploy: Deploy = match txn.get_value(self.deploy_db, &raw_key)? {
+                Some(deploy) => deploy,
+                None => continue,
+            };
+            for block_hash in metadata.execution_results.keys() {
+                let block_header: BlockHeader =
+                    match txn.get_value(self.block_header_db, block_hash)? {
+                        Some(block_header) => block_header,
+                        None => continue,
+                    };
+                if self.block_height_index.get(&block_header.height()) != Some(block_hash) {
+                    continue;
+                }
+                let account_deploy = AccountDeploy {
+                    deploy_hash: *deploy.id(),
+                    block_hash: *block_hash,
+                    block_height: block_header.height(),
+                };
+                let _ = txn.put_value(
+                    self.account_deploy_index_db,
+                    &account_deploy_index_key(deploy.header().account(), &account_deploy),
+                    &account_deploy,
+                    true,
+                )?;
+            }
+        }
+        drop(cursor);
+        drop(metadata_txn);
+
+        for (&block_height, block_hash) in &self.block_height_index {
+            let transfers: Option<Vec<Transfer>> = txn.get_value(self.transfer_db, block_hash)?;
+            for (position, transfer) in transfers.into_iter().flatten().enumerate() {
+                let account_transfer = AccountTransfer {
+                    block_hash: *block_hash,
+                    block_height,
+                    transfer,
+                };
+                for key in account_transfer_index_keys(&account_transfer, position) {
+                    let _ = txn.put_value(
+                        self.account_transfer_index_db,
+                        &key,
+                        &account_transfer,
+                        true,
+                    )?;
+                }
+            }
+        }
+        txn.commit()?;
+        info!("account reindexing complete");
+        Ok(())
     }
 
     /// Handles a state store request.
@@ -390,7 +694,11 @@ impl Storage {
     }
 
     /// Handles a storage request.
-    fn handle_storage_request<REv>(&mut self, req: StorageRequest) -> Result<Effects<Event>, Error>
+    fn handle_storage_request<REv>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        req: StorageRequest,
+    ) -> Result<Effects<Event>, Error>
     where
         Self: Component<REv>,
     {
@@ -415,13 +723,25 @@ impl Storage {
                     txn.abort();
                     return Ok(responder.respond(false).ignore());
                 }
+                insert_to_block_header_index_dbs(
+                    &mut txn,
+                    self.block_height_index_db,
+                    self.switch_block_era_id_index_db,
+                    &self.block_height_index,
+                    &self.switch_block_era_id_index,
+                    block.header(),
+                )?;
                 txn.commit()?;
+                // Only update the in-memory indices once the transaction has been committed, so
+                // that they never refer to blocks which failed to be stored.
                 insert_to_block_header_indices(
                     &mut self.block_height_index,
                     &mut self.switch_block_era_id_index,
                     block.header(),
-                )?;
-                responder.respond(true).ignore()
+                );
+                let mut effects = responder.respond(true).ignore();
+                effects.extend(self.schedule_prune(effect_builder)?);
+                effects
             }
             StorageRequest::GetBlock {
                 block_hash,
@@ -468,12 +788,7 @@ impl Storage {
                 block_hash,
                 responder,
             } => responder
-                // TODO: Find a solution for efficiently retrieving the blocker header without the
-                // block. Deserialization that allows trailing bytes could be a possible solution.
-                .respond(
-                    self.get_single_block(&mut self.env.begin_ro_txn()?, &block_hash)?
-                        .map(|block| block.header().clone()),
-                )
+                .respond(self.get_single_block_header(&mut self.env.begin_ro_txn()?, &block_hash)?)
                 .ignore(),
             StorageRequest::GetBlockTransfers {
                 block_hash,
@@ -512,6 +827,14 @@ impl Storage {
             } => {
                 let mut txn = self.env.begin_rw_txn()?;
 
+                // The block height is required to populate the account indices.
+                let block_header: Option<BlockHeader> =
+                    txn.get_value(self.block_header_db, &*block_hash)?;
+                let block_height = block_header.map(|block_header| block_header.height());
+                if block_height.is_none() {
+                    warn!(%block_hash, "storing execution results for unknown block, not indexing");
+                }
+
                 let mut transfers: Vec<Transfer> = vec![];
 
                 for (deploy_hash, execution_result) in execution_results {
@@ -557,6 +880,44 @@ impl Storage {
                         "failed to write deploy metadata for block_hash {} deploy_hash {}",
                         block_hash, deploy_hash
                     );
+
+                    if let Some(block_height) = block_height {
+                        let deploy: Option<Deploy> = txn.get_value(self.deploy_db, &deploy_hash)?;
+                        if let Some(deploy) = deploy {
+                            let account_deploy = AccountDeploy {
+                                deploy_hash,
+                                block_hash: *block_hash,
+                                block_height,
+                            };
+                            let _ = txn.put_value(
+                                self.account_deploy_index_db,
+                                &account_deploy_index_key(
+                                    deploy.header().account(),
+                                    &account_deploy,
+                                ),
+                                &account_deploy,
+                                true,
+                            )?;
+                        }
+                    }
+                }
+
+                if let Some(block_height) = block_height {
+                    for (position, transfer) in transfers.iter().enumerate() {
+                        let account_transfer = AccountTransfer {
+                            block_hash: *block_hash,
+                            block_height,
+                            transfer: *transfer,
+                        };
+                        for key in account_transfer_index_keys(&account_transfer, position) {
+                            let _ = txn.put_value(
+                                self.account_transfer_index_db,
+                                &key,
+                                &account_transfer,
+                                true,
+                            )?;
+                        }
+                    }
                 }
 
                 let was_written =
@@ -685,9 +1046,251 @@ impl Storage {
                     self.get_finality_signatures(&mut self.env.begin_ro_txn()?, &block_hash)?;
                 responder.respond(result).ignore()
             }
+            StorageRequest::GetAccountDeploys { query, responder } => {
+                let page = read_account_index_page(
+                    &mut self.env.begin_ro_txn()?,
+                    self.account_deploy_index_db,
+                    query.subject.as_bytes(),
+                    &query,
+                )?;
+                responder.respond(page).ignore()
+            }
+            StorageRequest::GetAccountTransfers { query, responder } => {
+                let page = read_account_index_page(
+                    &mut self.env.begin_ro_txn()?,
+                    self.account_transfer_index_db,
+                    &transfer_index_prefix(&query.subject),
+                    &query,
+                )?;
+                responder.respond(page).ignore()
+            }
+            StorageRequest::GetRetainedStateRootHashes {
+                retained_blocks,
+                responder,
+            } => responder
+                .respond(self.retained_state_root_hashes(retained_blocks)?)
+                .ignore(),
         })
     }
 
+    /// Returns the distinct state root hashes of the `retained_blocks` most recent blocks and of
+    /// all switch blocks, i.e. the global state which pruning the trie store must retain.
+    pub(crate) fn retained_state_root_hashes(
+        &self,
+        retained_blocks: u64,
+    ) -> Result<Vec<Digest>, Error> {
+        let mut txn = self.env.begin_ro_txn()?;
+        let block_hashes = self
+            .block_height_index
+            .values()
+            .rev()
+            .take(retained_blocks as usize)
+            .chain(self.switch_block_era_id_index.values());
+        let mut state_root_hashes = BTreeSet::new();
+        for block_hash in block_hashes {
+            let block_header: Option<BlockHeader> =
+                txn.get_value(self.block_header_db, block_hash)?;
+            if let Some(block_header) = block_header {
+                let _ = state_root_hashes.insert(*block_header.state_root_hash());
+            }
+        }
+        txn.commit()?;
+        Ok(state_root_hashes.into_iter().collect())
+    }
+
+    /// Handles a prune event by pruning a single batch of blocks, scheduling another batch if the
+    /// store is still not within the retention window.
+    fn handle_prune<REv>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+    ) -> Result<Effects<Event>, Error>
+    where
+        Self: Component<REv>,
+    {
+        self.prune_scheduled = false;
+        self.prune_batch()?;
+        self.schedule_prune(effect_builder)
+    }
+
+    /// Schedules a prune event if there are blocks left to prune and none is scheduled already.
+    fn schedule_prune<REv>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+    ) -> Result<Effects<Event>, Error>
+    where
+        Self: Component<REv>,
+    {
+        if self.prune_scheduled
+            || self.prune_cutoff_height(&mut self.env.begin_ro_txn()?)? <= self.pruned_below_height
+        {
+            return Ok(Effects::new());
+        }
+        self.prune_scheduled = true;
+        Ok(effect_builder.immediately().event(|()| Event::Prune))
+    }
+
+    /// Returns the height below which blocks fall outside the retention window.
+    ///
+    /// If both a block and an era based retention are configured, the one retaining more blocks
+    /// takes precedence.
+    fn prune_cutoff_height<Tx: Transaction>(&self, tx: &mut Tx) -> Result<u64, Error> {
+        let highest_height = match self.block_height_index.keys().next_back() {
+            Some(&height) => height,
+            None => return Ok(0),
+        };
+
+        let mut cutoff_heights = vec![];
+        if self.retain_blocks > 0 {
+            cutoff_heights.push((highest_height + 1).saturating_sub(self.retain_blocks));
+        }
+        if self.retain_eras > 0 {
+            // The current era is the one following the era of the latest switch block. All blocks
+            // following the switch block which ends the last era outside the retention window are
+            // retained.
+            let last_pruned_era = self
+                .switch_block_era_id_index
+                .keys()
+                .next_back()
+                .and_then(|era_id| (era_id.0 + 1).checked_sub(self.retain_eras))
+                .map(EraId);
+            let switch_block_header: Option<BlockHeader> = match last_pruned_era
+                .and_then(|era_id| self.switch_block_era_id_index.get(&era_id))
+            {
+                Some(block_hash) => tx.get_value(self.block_header_db, block_hash)?,
+                None => None,
+            };
+            cutoff_heights.push(
+                switch_block_header
+                    .map(|header| header.height() + 1)
+                    .unwrap_or_default(),
+            );
+        }
+
+        Ok(cutoff_heights.into_iter().min().unwrap_or_default())
+    }
+
+    /// Prunes up to `prune_batch_size` blocks falling outside the retention window, along with
+    /// their deploys, deploy metadata, transfers and finality signatures.
+    ///
+    /// Switch blocks are skipped and never pruned. The height up to which blocks have been pruned
+    /// is persisted in the same transaction.
+    fn prune_batch(&mut self) -> Result<(), Error> {
+        let mut txn = self.env.begin_rw_txn()?;
+        let cutoff_height = self.prune_cutoff_height(&mut txn)?;
+        if cutoff_height <= self.pruned_below_height {
+            return Ok(());
+        }
+
+        let mut pruned_heights = vec![];
+        let mut next_height = cutoff_height;
+        for (&height, block_hash) in self
+            .block_height_index
+            .range(self.pruned_below_height..cutoff_height)
+        {
+            if pruned_heights.len() >= self.prune_batch_size.max(1) as usize {
+                next_height = height;
+                break;
+            }
+            let block_header: BlockHeader = match txn.get_value(self.block_header_db, block_hash)? {
+                Some(block_header) => block_header,
+                None => continue,
+            };
+            if block_header.is_switch_block() {
+                continue;
+            }
+            self.delete_block(&mut txn, block_hash, &block_header)?;
+            pruned_heights.push(height);
+        }
+        let _ = txn.put_value(
+            self.state_store_db,
+            &PRUNED_BELOW_HEIGHT_KEY,
+            &next_height,
+            true,
+        )?;
+        txn.commit()?;
+
+        if !self.retain_block_headers {
+            for height in &pruned_heights {
+                let _ = self.block_height_index.remove(height);
+            }
+        }
+        self.pruned_below_height = next_height;
+        if !pruned_heights.is_empty() {
+            info!(
+                pruned = pruned_heights.len(),
+                pruned_below_height = self.pruned_below_height,
+                "pruned blocks from storage"
+            );
+        }
+        Ok(())
+    }
+
+    /// Deletes a block along with its deploys, deploy metadata, transfers and finality signatures.
+    ///
+    /// Bodies without any deploys are kept, as they may be shared by several blocks with the same
+    /// proposer and take up very little space. If `retain_block_headers` is set, the header and
+    /// finality signatures are kept as well.
+    fn delete_block(
+        &self,
+        txn: &mut RwTransaction<'_>,
+        block_hash: &BlockHash,
+        block_header: &BlockHeader,
+    ) -> Result<(), Error> {
+        let block_body: Option<BlockBody> =
+            txn.get_value(self.block_body_db, block_header.body_hash())?;
+        if let Some(block_body) = block_body {
+            let deploy_hashes = block_body
+                .deploy_hashes()
+                .iter()
+                .chain(block_body.transfer_hashes());
+            let mut has_deploys = false;
+            for deploy_hash in deploy_hashes {
+                has_deploys = true;
+                let deploy: Option<Deploy> = txn.get_value(self.deploy_db, deploy_hash)?;
+                if let Some(deploy) = deploy {
+                    let account_deploy = AccountDeploy {
+                        deploy_hash: *deploy_hash,
+                        block_hash: *block_hash,
+                        block_height: block_header.height(),
+                    };
+                    delete_value(
+                        txn,
+                        self.account_deploy_index_db,
+                        &account_deploy_index_key(deploy.header().account(), &account_deploy),
+                    )?;
+                }
+                delete_value(txn, self.deploy_db, deploy_hash)?;
+                delete_value(txn, self.deploy_metadata_db, deploy_hash)?;
+            }
+            if has_deploys {
+                delete_value(txn, self.block_body_db, block_header.body_hash())?;
+            }
+        }
+        let transfers: Option<Vec<Transfer>> = txn.get_value(self.transfer_db, block_hash)?;
+        for (position, transfer) in transfers.into_iter().flatten().enumerate() {
+            let account_transfer = AccountTransfer {
+                block_hash: *block_hash,
+                block_height: block_header.height(),
+                transfer,
+            };
+            for key in account_transfer_index_keys(&account_transfer, position) {
+                delete_value(txn, self.account_transfer_index_db, &key)?;
+            }
+        }
+        delete_value(txn, self.transfer_db, block_hash)?;
+        if self.retain_block_headers {
+            return Ok(());
+        }
+        delete_value(txn, self.block_metadata_db, block_hash)?;
+        delete_value(txn, self.block_header_db, block_hash)?;
+        delete_value(
+            txn,
+            self.block_height_index_db,
+            &block_header.height().to_be_bytes(),
+        )?;
+        Ok(())
+    }
+
     /// Retrieves single block by height by looking it up in the index and returning it.
     fn get_block_by_height<Tx: Transaction>(
         &self,
@@ -712,12 +1315,14 @@ impl Storage {
             .transpose()
     }
 
-    /// Retrieves a single block in a separate transaction from storage.
-    fn get_single_block<Tx: Transaction>(
+    /// Retrieves a single block header in a separate transaction from storage.
+    ///
+    /// Unlike [`Self::get_single_block`], this succeeds for blocks whose body has been pruned.
+    fn get_single_block_header<Tx: Transaction>(
         &self,
         tx: &mut Tx,
         block_hash: &BlockHash,
-    ) -> Result<Option<Block>, LmdbExtError> {
+    ) -> Result<Option<BlockHeader>, LmdbExtError> {
         let block_header: BlockHeader = match tx.get_value(self.block_header_db, &block_hash)? {
             Some(block_header) => block_header,
             None => return Ok(None),
@@ -729,6 +1334,19 @@ impl Storage {
                 found_block_header_hash,
             });
         }
+        Ok(Some(block_header))
+    }
+
+    /// Retrieves a single block in a separate transaction from storage.
+    fn get_single_block<Tx: Transaction>(
+        &self,
+        tx: &mut Tx,
+        block_hash: &BlockHash,
+    ) -> Result<Option<Block>, LmdbExtError> {
+        let block_header = match self.get_single_block_header(tx, block_hash)? {
+            Some(block_header) => block_header,
+            None => return Ok(None),
+        };
         let block_body: BlockBody =
             match tx.get_value(self.block_body_db, block_header.body_hash())? {
                 Some(block_header) => block_header,
@@ -790,6 +1408,11 @@ impl Storage {
         Ok(tx.get_value(self.block_metadata_db, block_hash)?)
     }
 
+    /// Returns the path to the folder holding the storage files.
+    pub(crate) fn root_path(&self) -> &Path {
+        &self.root
+    }
+
     /// Get the lmdb environment
     #[cfg(test)]
     pub(crate) fn env(&self) -> &Environment {
@@ -797,12 +1420,159 @@ impl Storage {
     }
 }
 
-/// Inserts the relevant entries to the two indices.
+/// Returns the key of an entry of the account deploy index.
 ///
-/// If a duplicate entry is encountered, neither index is updated and an error is returned.
-fn insert_to_block_header_indices(
-    block_height_index: &mut BTreeMap<u64, BlockHash>,
-    switch_block_era_id_index: &mut BTreeMap<EraId, BlockHash>,
+/// The key is made up of the sending account's hash, followed by the big-endian block height and
+/// the deploy hash, so that the entries of each account are ordered by block height.
+fn account_deploy_index_key(account: &PublicKey, account_deploy: &AccountDeploy) -> Vec<u8> {
+    let account_hash = account.to_account_hash();
+    let mut key = account_hash.as_bytes().to_vec();
+    key.extend_from_slice(&account_deploy.block_height.to_be_bytes());
+    key.extend_from_slice(account_deploy.deploy_hash.as_ref());
+    key
+}
+
+/// Returns the prefix of the keys of all entries of the account transfer index relating to the
+/// given participant.
+fn transfer_index_prefix(participant: &TransferParticipant) -> Vec<u8> {
+    let (tag, bytes) = match participant {
+        TransferParticipant::Account(account_hash) => {
+            (TRANSFER_INDEX_ACCOUNT_TAG, account_hash.value())
+        }
+        TransferParticipant::Purse(uref) => (TRANSFER_INDEX_PURSE_TAG, uref.addr()),
+    };
+    let mut prefix = vec![tag];
+    prefix.extend_from_slice(&bytes);
+    prefix
+}
+
+/// Returns the keys of the entries of the account transfer index for a transfer at the given
+/// position within its block, one for each distinct account and purse taking part in it.
+///
+/// Each key is made up of the participant's prefix, followed by the big-endian block height and
+/// position.
+fn account_transfer_index_keys(
+    account_transfer: &AccountTransfer,
+    position: usize,
+) -> Vec<Vec<u8>> {
+    let transfer = &account_transfer.transfer;
+    let mut participants = vec![TransferParticipant::Account(transfer.from)];
+    participants.extend(transfer.to.map(TransferParticipant::Account));
+    participants.push(TransferParticipant::Purse(transfer.source));
+    participants.push(TransferParticipant::Purse(transfer.target));
+
+    let mut keys: Vec<Vec<u8>> = vec![];
+    for participant in &participants {
+        let mut key = transfer_index_prefix(participant);
+        key.extend_from_slice(&account_transfer.block_height.to_be_bytes());
+        key.extend_from_slice(&(position as u32).to_be_bytes());
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    keys
+}
+
+/// Reads a page of entries of an account index, all of whose keys relating to the same account or
+/// purse start with `prefix`, followed by the big-endian block height.
+///
+/// The returned cursor is the remainder of the last returned entry's key following the prefix.
+fn read_account_index_page<Tx: Transaction, S, T: DeserializeOwned>(
+    tx: &mut Tx,
+    db: Database,
+    prefix: &[u8],
+    query: &AccountIndexQuery<S>,
+) -> Result<AccountIndexPage<T>, Error> {
+    let mut page = AccountIndexPage::default();
+    if query.limit == 0 || query.start_height > query.end_height {
+        return Ok(page);
+    }
+
+    let mut start_key = prefix.to_vec();
+    match &query.cursor {
+        Some(cursor) => start_key.extend_from_slice(cursor),
+        None => start_key.extend_from_slice(&query.start_height.to_be_bytes()),
+    }
+
+    let mut cursor = tx.open_ro_cursor(db)?;
+    let mut last_remainder = None;
+    for (raw_key, raw_val) in cursor.iter_from(&start_key) {
+        if !raw_key.starts_with(prefix) {
+            break;
+        }
+        let remainder = &raw_key[prefix.len()..];
+        if query.cursor.as_deref() == Some(remainder) {
+            continue;
+        }
+        let block_height = remainder
+            .get(..8)
+            .and_then(|raw_height| raw_height.try_into().ok())
+            .map(u64::from_be_bytes)
+            .ok_or_else(|| {
+                LmdbExtError::DataCorrupted(
+                    format!("invalid account index key {:?}", raw_key).into(),
+                )
+            })?;
+        if block_height < query.start_height {
+            continue;
+        }
+        if block_height > query.end_height {
+            break;
+        }
+        if page.entries.len() == query.limit {
+            page.next_cursor = last_remainder;
+            break;
+        }
+        page.entries.push(lmdb_ext::deserialize(raw_val)?);
+        last_remainder = Some(remainder.to_vec());
+    }
+    Ok(page)
+}
+
+/// Deletes the value stored under `key`, if any.
+fn delete_value<K: AsRef<[u8]>>(
+    txn: &mut RwTransaction<'_>,
+    db: Database,
+    key: &K,
+) -> Result<(), Error> {
+    match txn.del(db, key, None) {
+        Ok(()) | Err(lmdb::Error::NotFound) => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Reads all entries of an index database, keyed by big-endian encoded integers.
+fn read_index<Tx: Transaction>(
+    tx: &mut Tx,
+    db: Database,
+) -> Result<BTreeMap<u64, BlockHash>, Error> {
+    let mut cursor = tx.open_ro_cursor(db)?;
+    // Note: `iter_start` has an undocumented panic if called on an empty database. We rely on the
+    //       iterator being at the start when created.
+    cursor
+        .iter()
+        .map(|(raw_key, raw_val)| -> Result<_, Error> {
+            let key = raw_key
+                .try_into()
+                .map(u64::from_be_bytes)
+                .map_err(|err| LmdbExtError::DataCorrupted(Box::new(err)))?;
+            Ok((key, lmdb_ext::deserialize(raw_val)?))
+        })
+        .collect()
+}
+
+/// Inserts the relevant entries to the databases of the two indices as part of the given
+/// transaction, checking them against the in-memory indices.
+///
+/// If a duplicate entry is encountered, neither index database is updated and an error is
+/// returned. The in-memory indices are left untouched, and must be updated using
+/// `insert_to_block_header_indices` once the transaction has been committed.
+fn insert_to_block_header_index_dbs(
+    txn: &mut RwTransaction<'_>,
+    block_height_index_db: Database,
+    switch_block_era_id_index_db: Database,
+    block_height_index: &BTreeMap<u64, BlockHash>,
+    switch_block_era_id_index: &BTreeMap<EraId, BlockHash>,
     block_header: &BlockHeader,
 ) -> Result<(), Error> {
     let block_hash = block_header.hash();
@@ -817,15 +1587,20 @@ fn insert_to_block_header_indices(
     }
 
     if block_header.is_switch_block() {
-        match switch_block_era_id_index.entry(block_header.era_id()) {
-            Entry::Vacant(entry) => {
-                let _ = entry.insert(block_hash);
+        match switch_block_era_id_index.get(&block_header.era_id()) {
+            None => {
+                let _ = txn.put_value(
+                    switch_block_era_id_index_db,
+                    &block_header.era_id().0.to_be_bytes(),
+                    &block_hash,
+                    true,
+                )?;
             }
-            Entry::Occupied(entry) => {
-                if *entry.get() != block_hash {
+            Some(first) => {
+                if *first != block_hash {
                     return Err(Error::DuplicateEraIdIndex {
                         era_id: block_header.era_id(),
-                        first: *entry.get(),
+                        first: *first,
                         second: block_hash,
                     });
                 }
@@ -833,10 +1608,112 @@ fn insert_to_block_header_indices(
         }
     }
 
-    let _ = block_height_index.insert(block_header.height(), block_hash);
+    let _ = txn.put_value(
+        block_height_index_db,
+        &block_header.height().to_be_bytes(),
+        &block_hash,
+        true,
+    )?;
     Ok(())
 }
 
+/// Inserts the relevant entries to the two in-memory indices.
+///
+/// The entries must have been checked for duplicates by `insert_to_block_header_index_dbs`.
+fn insert_to_block_header_indices(
+    block_height_index: &mut BTreeMap<u64, BlockHash>,
+    switch_block_era_id_index: &mut BTreeMap<EraId, BlockHash>,
+    block_header: &BlockHeader,
+) {
+    let block_hash = block_header.hash();
+    if block_header.is_switch_block() {
+        let _ = switch_block_era_id_index
+            .entry(block_header.era_id())
+            .or_insert(block_hash);
+    }
+    let _ = block_height_index.insert(block_header.height(), block_hash);
+}
+
+/// Deploy metadata as serialized before messages were added to execution effects.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct LegacyDeployMetadata {
+    execution_results: HashMap<BlockHash, LegacyExecutionResult>,
+}
+
+/// An execution result as serialized before messages were added to execution effects.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+enum LegacyExecutionResult {
+    Failure {
+        effect: LegacyExecutionEffect,
+        transfers: Vec<TransferAddr>,
+        cost: U512,
+        error_message: String,
+    },
+    Success {
+        effect: LegacyExecutionEffect,
+        transfers: Vec<TransferAddr>,
+        cost: U512,
+    },
+}
+
+/// An execution effect as serialized before messages were added.
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+struct LegacyExecutionEffect {
+    operations: Vec<Operation>,
+    transforms: Vec<TransformEntry>,
+}
+
+impl From<LegacyDeployMetadata> for DeployMetadata {
+    fn from(legacy_metadata: LegacyDeployMetadata) -> Self {
+        let execution_results = legacy_metadata
+            .execution_results
+            .into_iter()
+            .map(|(block_hash, legacy_result)| (block_hash, legacy_result.into()))
+            .collect();
+        DeployMetadata { execution_results }
+    }
+}
+
+impl From<LegacyExecutionResult> for ExecutionResult {
+    fn from(legacy_result: LegacyExecutionResult) -> Self {
+        match legacy_result {
+            LegacyExecutionResult::Failure {
+                effect,
+                transfers,
+                cost,
+                error_message,
+            } => ExecutionResult::Failure {
+                effect: effect.into(),
+                transfers,
+                cost,
+                error_message,
+            },
+            LegacyExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+            } => ExecutionResult::Success {
+                effect: effect.into(),
+                transfers,
+                cost,
+            },
+        }
+    }
+}
+
+impl From<LegacyExecutionEffect> for ExecutionEffect {
+    fn from(legacy_effect: LegacyExecutionEffect) -> Self {
+        ExecutionEffect {
+            operations: legacy_effect.operations,
+            transforms: legacy_effect.transforms,
+            messages: Vec::new(),
+        }
+    }
+}
+
 /// On-disk storage configuration.
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -861,6 +1738,38 @@ pub struct Config {
     ///
     /// The size should be a multiple of the OS page size.
     max_state_store_size: usize,
+    /// The number of most recent blocks to retain.
+    ///
+    /// Older blocks other than switch blocks are pruned along with their deploys. `0` disables
+    /// block based pruning.
+    ///
+    /// Defaults to 0.
+    #[serde(default)]
+    retain_blocks: u64,
+    /// The number of most recent eras whose blocks are retained.
+    ///
+    /// Blocks of older eras other than switch blocks are pruned along with their deploys. `0`
+    /// disables era based pruning.
+    ///
+    /// Defaults to 0.
+    #[serde(default)]
+    retain_eras: u64,
+    /// The maximum number of blocks pruned in a single batch.
+    ///
+    /// Defaults to 100.
+    prune_batch_size: Option<u32>,
+    /// Whether to retain the headers and finality signatures of pruned blocks, pruning only their
+    /// bodies, deploys, deploy metadata and transfers.
+    ///
+    /// Defaults to false.
+    #[serde(default)]
+    retain_block_headers: bool,
+    /// Whether to rebuild the block indices from the stored block headers on start-up, regardless
+    /// of the outcome of their consistency check.
+    ///
+    /// Defaults to false.
+    #[serde(default)]
+    rebuild_indices: bool,
 }
 
 impl Default for Config {
@@ -872,6 +1781,11 @@ impl Default for Config {
             max_deploy_store_size: DEFAULT_MAX_DEPLOY_STORE_SIZE,
             max_deploy_metadata_store_size: DEFAULT_MAX_DEPLOY_METADATA_STORE_SIZE,
             max_state_store_size: DEFAULT_MAX_STATE_STORE_SIZE,
+            retain_blocks: 0,
+            retain_eras: 0,
+            prune_batch_size: Some(DEFAULT_PRUNE_BATCH_SIZE),
+            retain_block_headers: false,
+            rebuild_indices: false,
         }
     }
 }
@@ -897,6 +1811,7 @@ impl Display for Event {
         match self {
             Event::StorageRequest(req) => req.fmt(f),
             Event::StateStoreRequest(req) => req.fmt(f),
+            Event::Prune => write!(f, "prune"),
         }
     }
 }
@@ -2,13 +2,17 @@
 
 use std::{borrow::Cow, collections::HashMap};
 
+use lmdb::{Transaction, WriteFlags};
 use rand::{prelude::SliceRandom, Rng};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use smallvec::smallvec;
 
-use casper_types::ExecutionResult;
+use casper_types::{
+    account::AccountHash, ExecutionEffect, ExecutionResult, PublicKey, SecretKey, Transfer,
+    Transform, TransformEntry, URef, U512,
+};
 
-use super::{Config, Storage};
+use super::{Config, Event, Storage};
 use crate::{
     components::consensus::EraId,
     effect::{
@@ -16,7 +20,10 @@ use crate::{
         Multiple,
     },
     testing::{ComponentHarness, TestRng},
-    types::{Block, BlockHash, Deploy, DeployHash, DeployMetadata},
+    types::{
+        AccountDeploy, AccountIndexPage, AccountIndexQuery, AccountTransfer, Block, BlockHash,
+        BlockHeader, Deploy, DeployHash, DeployMetadata, TimeDiff, Timestamp, TransferParticipant,
+    },
     utils::WithDir,
 };
 
@@ -30,6 +37,11 @@ fn new_config(harness: &ComponentHarness<()>) -> Config {
         max_deploy_store_size: 50 * MIB,
         max_deploy_metadata_store_size: 50 * MIB,
         max_state_store_size: 50 * MIB,
+        retain_blocks: 0,
+        retain_eras: 0,
+        prune_batch_size: Some(100),
+        retain_block_headers: false,
+        rebuild_indices: false,
     }
 }
 
@@ -59,6 +71,38 @@ fn storage_fixture_with_hard_reset(harness: &ComponentHarness<()>, reset_era_id:
         .expect("could not create storage component fixture")
 }
 
+/// Storage component test fixture.
+///
+/// Creates a storage component in a temporary directory, pruning blocks according to the given
+/// retention policy.
+///
+/// # Panics
+///
+/// Panics if setting up the storage fixture fails.
+fn storage_fixture_with_retention(
+    harness: &ComponentHarness<()>,
+    retain_blocks: u64,
+    retain_eras: u64,
+    prune_batch_size: u32,
+) -> Storage {
+    let cfg = Config {
+        retain_blocks,
+        retain_eras,
+        prune_batch_size: Some(prune_batch_size),
+        ..new_config(harness)
+    };
+    Storage::new(&WithDir::new(harness.tmp.path(), cfg), None)
+        .expect("could not create storage component fixture")
+}
+
+/// Creates 8 random blocks, 0-2 in era 0, 3-5 in era 1 and 6,7 in era 2, with blocks 2 and 5 being
+/// switch blocks.
+fn random_blocks_in_three_eras(rng: &mut TestRng) -> Vec<Block> {
+    (0..8)
+        .map(|height| Block::random_with_specifics(rng, EraId(height / 3), height, height % 3 == 2))
+        .collect()
+}
+
 /// Creates a random block with a specific block height.
 fn random_block_at_height(rng: &mut TestRng, height: u64) -> Box<Block> {
     let mut block = Box::new(Block::random(rng));
@@ -96,6 +140,23 @@ fn get_block(
     response
 }
 
+/// Loads a block header from a storage component.
+fn get_block_header(
+    harness: &mut ComponentHarness<()>,
+    storage: &mut Storage,
+    block_hash: BlockHash,
+) -> Option<BlockHeader> {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::GetBlockHeader {
+            block_hash,
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
 /// Loads a set of deploys from a storage component.
 fn get_deploys(
     harness: &mut ComponentHarness<()>,
@@ -166,6 +227,23 @@ fn put_block(harness: &mut ComponentHarness<()>, storage: &mut Storage, block: B
     response
 }
 
+/// Loads the transfers of a block from a storage component.
+fn get_block_transfers(
+    harness: &mut ComponentHarness<()>,
+    storage: &mut Storage,
+    block_hash: BlockHash,
+) -> Option<Vec<Transfer>> {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::GetBlockTransfers {
+            block_hash,
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
 /// Stores a deploy in a storage component.
 fn put_deploy(
     harness: &mut ComponentHarness<()>,
@@ -198,6 +276,75 @@ fn put_execution_results(
     response
 }
 
+/// Creates a random deploy sent by the account with the given secret key.
+fn random_deploy_from(rng: &mut TestRng, secret_key: &SecretKey) -> Deploy {
+    Deploy::new(
+        Timestamp::random(rng),
+        TimeDiff::from(60_000),
+        1,
+        vec![],
+        "casper-example".to_string(),
+        rng.gen(),
+        rng.gen(),
+        secret_key,
+        rng,
+    )
+}
+
+/// Creates a successful execution result recording the given transfers.
+fn execution_result_with_transfers(transfers: Vec<Transfer>) -> ExecutionResult {
+    let transforms = transfers
+        .into_iter()
+        .map(|transfer| TransformEntry {
+            key: String::from("transfer"),
+            transform: Transform::WriteTransfer(transfer),
+        })
+        .collect();
+    ExecutionResult::Success {
+        effect: ExecutionEffect {
+            operations: vec![],
+            transforms,
+            messages: vec![],
+        },
+        transfers: vec![],
+        cost: U512::zero(),
+    }
+}
+
+/// Loads a page of the deploys sent by an account from a storage component.
+fn get_account_deploys(
+    harness: &mut ComponentHarness<()>,
+    storage: &mut Storage,
+    query: AccountIndexQuery<AccountHash>,
+) -> AccountIndexPage<AccountDeploy> {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::GetAccountDeploys {
+            query: Box::new(query),
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
+/// Loads a page of the transfers an account or purse took part in from a storage component.
+fn get_account_transfers(
+    harness: &mut ComponentHarness<()>,
+    storage: &mut Storage,
+    query: AccountIndexQuery<TransferParticipant>,
+) -> AccountIndexPage<AccountTransfer> {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::GetAccountTransfers {
+            query: Box::new(query),
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
 /// Saves state from the storage component.
 fn save_state<T>(
     harness: &mut ComponentHarness<()>,
@@ -846,3 +993,556 @@ fn should_hard_reset() {
     // Check the highest block is `None`.
     assert!(get_highest_block(&mut harness, &mut storage).is_none());
 }
+
+#[test]
+fn should_prune_blocks_outside_retention_window_in_batches() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture_with_retention(&harness, 3, 0, 2);
+
+    let blocks = random_blocks_in_three_eras(&mut harness.rng);
+    for block in &blocks {
+        assert!(put_block(
+            &mut harness,
+            &mut storage,
+            Box::new(block.clone())
+        ));
+        put_execution_results(&mut harness, &mut storage, *block.hash(), HashMap::new());
+    }
+
+    // Storing the blocks should have scheduled pruning, with nothing pruned yet.
+    assert!(storage.prune_scheduled);
+    for block in &blocks {
+        assert_eq!(
+            get_block(&mut harness, &mut storage, *block.hash()).as_ref(),
+            Some(block)
+        );
+    }
+
+    // The first batch prunes blocks 0 and 1.
+    let _ = harness.send_event(&mut storage, Event::Prune);
+    assert!(storage.prune_scheduled);
+    assert!(get_block(&mut harness, &mut storage, *blocks[0].hash()).is_none());
+    assert!(get_block_at_height(&mut harness, &mut storage, 1).is_none());
+    assert!(get_block_transfers(&mut harness, &mut storage, *blocks[1].hash()).is_none());
+    assert!(get_block(&mut harness, &mut storage, *blocks[3].hash()).is_some());
+
+    // The second batch skips switch block 2 and prunes blocks 3 and 4, leaving the last 3 blocks.
+    let _ = harness.send_event(&mut storage, Event::Prune);
+    assert!(!storage.prune_scheduled);
+    for (height, block) in blocks.iter().enumerate() {
+        let expect_retained = block.header().is_switch_block() || height >= 5;
+        assert_eq!(
+            get_block_at_height(&mut harness, &mut storage, height as u64).is_some(),
+            expect_retained,
+            "unexpected retention of block at height {}",
+            height
+        );
+        assert_eq!(
+            get_block_transfers(&mut harness, &mut storage, *block.hash()).is_some(),
+            expect_retained,
+            "unexpected retention of transfers of block at height {}",
+            height
+        );
+    }
+
+    // Pruned blocks should stay pruned after a restart.
+    drop(storage);
+    let mut storage = storage_fixture(&harness);
+    assert!(get_block(&mut harness, &mut storage, *blocks[4].hash()).is_none());
+    assert_eq!(
+        get_block_at_height(&mut harness, &mut storage, 2).as_ref(),
+        Some(&blocks[2])
+    );
+}
+
+#[test]
+fn should_retain_blocks_of_configured_number_of_eras() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture_with_retention(&harness, 0, 2, 100);
+
+    let blocks = random_blocks_in_three_eras(&mut harness.rng);
+    for block in &blocks {
+        assert!(put_block(
+            &mut harness,
+            &mut storage,
+            Box::new(block.clone())
+        ));
+    }
+    let _ = harness.send_event(&mut storage, Event::Prune);
+    assert!(!storage.prune_scheduled);
+
+    // Eras 1 and 2 are retained, as is the switch block of era 0.
+    assert!(get_block_at_height(&mut harness, &mut storage, 0).is_none());
+    assert!(get_block_at_height(&mut harness, &mut storage, 1).is_none());
+    for block in &blocks[2..] {
+        assert_eq!(
+            get_block(&mut harness, &mut storage, *block.hash()).as_ref(),
+            Some(block)
+        );
+    }
+}
+
+#[test]
+fn should_retain_headers_of_pruned_blocks_if_configured() {
+    let mut harness = ComponentHarness::default();
+    let cfg = Config {
+        retain_blocks: 3,
+        retain_block_headers: true,
+        ..new_config(&harness)
+    };
+    let mut storage = Storage::new(&WithDir::new(harness.tmp.path(), cfg.clone()), None)
+        .expect("could not create storage component fixture");
+
+    let blocks = random_blocks_in_three_eras(&mut harness.rng);
+    for block in &blocks {
+        assert!(put_block(
+            &mut harness,
+            &mut storage,
+            Box::new(block.clone())
+        ));
+        put_execution_results(&mut harness, &mut storage, *block.hash(), HashMap::new());
+    }
+    let _ = harness.send_event(&mut storage, Event::Prune);
+    assert!(!storage.prune_scheduled);
+
+    for (height, block) in blocks.iter().enumerate() {
+        let expect_retained = block.header().is_switch_block() || height >= 5;
+        assert_eq!(
+            get_block_header(&mut harness, &mut storage, *block.hash()).as_ref(),
+            Some(block.header()),
+            "header of block at height {} should be retained",
+            height
+        );
+        assert_eq!(
+            get_block_transfers(&mut harness, &mut storage, *block.hash()).is_some(),
+            expect_retained,
+            "unexpected retention of transfers of block at height {}",
+            height
+        );
+        // Bodies without deploys are always kept.
+        let has_deploys = !block.deploy_hashes().is_empty() || !block.transfer_hashes().is_empty();
+        assert_eq!(
+            get_block(&mut harness, &mut storage, *block.hash()).is_some(),
+            expect_retained || !has_deploys,
+            "unexpected retention of body of block at height {}",
+            height
+        );
+    }
+
+    // The retained headers should still be indexed by height after a restart, and pruning should
+    // resume from where it left off rather than visiting them again.
+    drop(storage);
+    let storage = Storage::new(&WithDir::new(harness.tmp.path(), cfg), None)
+        .expect("could not create storage component fixture");
+    for (height, block) in blocks.iter().enumerate() {
+        assert_eq!(
+            storage.block_height_index.get(&(height as u64)),
+            Some(block.hash())
+        );
+    }
+    assert_eq!(storage.pruned_below_height, 5);
+}
+
+#[test]
+fn should_return_state_root_hashes_of_recent_and_switch_blocks() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let blocks = random_blocks_in_three_eras(&mut harness.rng);
+    for block in &blocks {
+        assert!(put_block(
+            &mut harness,
+            &mut storage,
+            Box::new(block.clone())
+        ));
+    }
+
+    // The two most recent blocks, and the switch blocks 2 and 5.
+    let mut expected: Vec<_> = [2, 5, 6, 7]
+        .iter()
+        .map(|index| *blocks[*index].header().state_root_hash())
+        .collect();
+    expected.sort();
+    assert_eq!(storage.retained_state_root_hashes(2).unwrap(), expected);
+}
+
+#[test]
+fn should_restore_indices_and_rebuild_them_if_inconsistent() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let blocks = random_blocks_in_three_eras(&mut harness.rng);
+    for block in &blocks {
+        assert!(put_block(
+            &mut harness,
+            &mut storage,
+            Box::new(block.clone())
+        ));
+    }
+
+    let check_indices = |harness: &mut ComponentHarness<()>, storage: &mut Storage| {
+        assert_eq!(get_highest_block(harness, storage).as_ref(), blocks.last());
+        for block in &blocks {
+            assert_eq!(
+                get_block_at_height(harness, storage, block.height()).as_ref(),
+                Some(block)
+            );
+        }
+        assert_eq!(
+            storage.transactional_get_switch_block_by_era_id(1).as_ref(),
+            Some(&blocks[5])
+        );
+    };
+
+    // The indices should be loaded from their databases after a restart.
+    drop(storage);
+    let mut storage = storage_fixture(&harness);
+    check_indices(&mut harness, &mut storage);
+
+    // Add a bogus entry to the persisted block height index, which should be detected and fixed by
+    // rebuilding the indices after a restart.
+    let mut txn = storage.env().begin_rw_txn().unwrap();
+    txn.put(
+        storage.block_height_index_db,
+        &100u64.to_be_bytes(),
+        &bincode::serialize(&BlockHash::random(&mut harness.rng)).unwrap(),
+        WriteFlags::empty(),
+    )
+    .unwrap();
+    txn.commit().unwrap();
+    drop(storage);
+    let mut storage = storage_fixture(&harness);
+    assert!(get_block_at_height(&mut harness, &mut storage, 100).is_none());
+    check_indices(&mut harness, &mut storage);
+
+    // Clearing the persisted indices, as for a store created before they were introduced, should
+    // also cause them to be rebuilt.
+    let mut txn = storage.env().begin_rw_txn().unwrap();
+    txn.clear_db(storage.block_height_index_db).unwrap();
+    txn.clear_db(storage.switch_block_era_id_index_db).unwrap();
+    txn.commit().unwrap();
+    drop(storage);
+    let mut storage = storage_fixture(&harness);
+    check_indices(&mut harness, &mut storage);
+}
+
+#[test]
+fn should_migrate_legacy_deploy_metadata() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let deploy = Deploy::random(&mut harness.rng);
+    put_deploy(&mut harness, &mut storage, Box::new(deploy.clone()));
+    let block_hash = BlockHash::random(&mut harness.rng);
+    let transforms = vec![TransformEntry {
+        key: String::from("transfer"),
+        transform: Transform::AddUInt64(harness.rng.gen()),
+    }];
+    let cost = U512::from(harness.rng.gen::<u64>());
+
+    // Write the metadata the way nodes did before messages were added to execution effects.
+    let legacy_metadata = super::LegacyDeployMetadata {
+        execution_results: vec![(
+            block_hash,
+            super::LegacyExecutionResult::Success {
+                effect: super::LegacyExecutionEffect {
+                    operations: vec![],
+                    transforms: transforms.clone(),
+                },
+                transfers: vec![],
+                cost,
+            },
+        )]
+        .into_iter()
+        .collect(),
+    };
+    let legacy_db = storage.env().open_db(Some("deploy_metadata")).unwrap();
+    let mut txn = storage.env().begin_rw_txn().unwrap();
+    txn.put(
+        legacy_db,
+        deploy.id(),
+        &bincode::serialize(&legacy_metadata).unwrap(),
+        WriteFlags::empty(),
+    )
+    .unwrap();
+    txn.commit().unwrap();
+
+    // The legacy metadata should be migrated on restart.
+    drop(storage);
+    let mut storage = storage_fixture(&harness);
+    let (_, metadata) = get_deploy_and_metadata(&mut harness, &mut storage, *deploy.id())
+        .expect("should have deploy");
+    let expected_result = ExecutionResult::Success {
+        effect: ExecutionEffect {
+            operations: vec![],
+            transforms,
+            messages: vec![],
+        },
+        transfers: vec![],
+        cost,
+    };
+    assert_eq!(metadata.execution_results.len(), 1);
+    assert_eq!(metadata.execution_results[&block_hash], expected_result);
+
+    let legacy_db = storage.env().open_db(Some("deploy_metadata")).unwrap();
+    let txn = storage.env().begin_ro_txn().unwrap();
+    assert!(txn.get(legacy_db, deploy.id()).is_err());
+}
+
+#[test]
+fn should_page_through_account_deploys_and_transfers() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let secret_key = SecretKey::random(&mut harness.rng);
+    let account_hash = PublicKey::from(&secret_key).to_account_hash();
+    let source_purse: URef = harness.rng.gen();
+
+    // Store five blocks, each executing a deploy sent by the account, which transfers from its
+    // purse, and a deploy sent by some other account.
+    let mut account_deploys = vec![];
+    let mut account_transfers = vec![];
+    for height in 0..5 {
+        let block = random_block_at_height(&mut harness.rng, height);
+        let block_hash = *block.hash();
+        assert!(put_block(&mut harness, &mut storage, block));
+
+        let deploy = random_deploy_from(&mut harness.rng, &secret_key);
+        let other_deploy = Deploy::random(&mut harness.rng);
+        put_deploy(&mut harness, &mut storage, Box::new(deploy.clone()));
+        put_deploy(&mut harness, &mut storage, Box::new(other_deploy.clone()));
+
+        let transfer = Transfer::new(
+            casper_types::DeployHash::new(deploy.id().inner().to_array()),
+            account_hash,
+            None,
+            source_purse,
+            harness.rng.gen(),
+            U512::from(height),
+            U512::zero(),
+            None,
+        );
+        let mut execution_results = HashMap::new();
+        execution_results.insert(
+            *deploy.id(),
+            execution_result_with_transfers(vec![transfer]),
+        );
+        execution_results.insert(*other_deploy.id(), execution_result_with_transfers(vec![]));
+        put_execution_results(&mut harness, &mut storage, block_hash, execution_results);
+
+        account_deploys.push(AccountDeploy {
+            deploy_hash: *deploy.id(),
+            block_hash,
+            block_height: height,
+        });
+        account_transfers.push(AccountTransfer {
+            block_hash,
+            block_height: height,
+            transfer,
+        });
+    }
+
+    fn query<S>(
+        subject: S,
+        start_height: u64,
+        end_height: u64,
+        cursor: Option<Vec<u8>>,
+        limit: usize,
+    ) -> AccountIndexQuery<S> {
+        AccountIndexQuery {
+            subject,
+            start_height,
+            end_height,
+            cursor,
+            limit,
+        }
+    }
+
+    // Page through all deploys of the account, two at a time.
+    let mut cursor = None;
+    let mut paged_deploys = vec![];
+    loop {
+        let page = get_account_deploys(
+            &mut harness,
+            &mut storage,
+            query(account_hash, 0, u64::MAX, cursor, 2),
+        );
+        assert!(page.entries.len() <= 2);
+        paged_deploys.extend(page.entries);
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    assert_eq!(paged_deploys, account_deploys);
+
+    // Restrict the height range.
+    let page = get_account_deploys(
+        &mut harness,
+        &mut storage,
+        query(account_hash, 1, 3, None, 10),
+    );
+    assert_eq!(page.entries, account_deploys[1..4].to_vec());
+    assert!(page.next_cursor.is_none());
+
+    // The transfers are indexed by the sending account and by each purse.
+    let page = get_account_transfers(
+        &mut harness,
+        &mut storage,
+        query(
+            TransferParticipant::Account(account_hash),
+            0,
+            u64::MAX,
+            None,
+            3,
+        ),
+    );
+    assert_eq!(page.entries, account_transfers[..3].to_vec());
+    let page = get_account_transfers(
+        &mut harness,
+        &mut storage,
+        query(
+            TransferParticipant::Account(account_hash),
+            0,
+            u64::MAX,
+            page.next_cursor,
+            3,
+        ),
+    );
+    assert_eq!(page.entries, account_transfers[3..].to_vec());
+    assert!(page.next_cursor.is_none());
+
+    let page = get_account_transfers(
+        &mut harness,
+        &mut storage,
+        query(
+            TransferParticipant::Purse(source_purse),
+            0,
+            u64::MAX,
+            None,
+            10,
+        ),
+    );
+    assert_eq!(page.entries, account_transfers);
+
+    let target_purse = account_transfers[2].transfer.target;
+    let page = get_account_transfers(
+        &mut harness,
+        &mut storage,
+        query(
+            TransferParticipant::Purse(target_purse),
+            0,
+            u64::MAX,
+            None,
+            10,
+        ),
+    );
+    assert_eq!(page.entries, account_transfers[2..3].to_vec());
+}
+
+#[test]
+fn should_backfill_account_indices_when_rebuilding_indices() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let secret_key = SecretKey::random(&mut harness.rng);
+    let account_hash = PublicKey::from(&secret_key).to_account_hash();
+
+    let mut account_deploys = vec![];
+    let mut account_transfers = vec![];
+    for height in 0..3 {
+        let block = random_block_at_height(&mut harness.rng, height);
+        let block_hash = *block.hash();
+        assert!(put_block(&mut harness, &mut storage, block));
+
+        let deploy = random_deploy_from(&mut harness.rng, &secret_key);
+        put_deploy(&mut harness, &mut storage, Box::new(deploy.clone()));
+        let transfer = Transfer::new(
+            casper_types::DeployHash::new(deploy.id().inner().to_array()),
+            account_hash,
+            None,
+            harness.rng.gen(),
+            harness.rng.gen(),
+            U512::from(height),
+            U512::zero(),
+            None,
+        );
+        let mut execution_results = HashMap::new();
+        execution_results.insert(
+            *deploy.id(),
+            execution_result_with_transfers(vec![transfer]),
+        );
+        put_execution_results(&mut harness, &mut storage, block_hash, execution_results);
+
+        account_deploys.push(AccountDeploy {
+            deploy_hash: *deploy.id(),
+            block_hash,
+            block_height: height,
+        });
+        account_transfers.push(AccountTransfer {
+            block_hash,
+            block_height: height,
+            transfer,
+        });
+    }
+
+    // Clear the account indices, as for a store created before they were introduced.
+    let mut txn = storage.env().begin_rw_txn().unwrap();
+    txn.clear_db(storage.account_deploy_index_db).unwrap();
+    txn.clear_db(storage.account_transfer_index_db).unwrap();
+    txn.commit().unwrap();
+    drop(storage);
+
+    let cfg = Config {
+        rebuild_indices: true,
+        ..new_config(&harness)
+    };
+    let mut storage = Storage::new(&WithDir::new(harness.tmp.path(), cfg), None)
+        .expect("could not create storage component fixture");
+
+    let page = get_account_deploys(
+        &mut harness,
+        &mut storage,
+        AccountIndexQuery {
+            subject: account_hash,
+            start_height: 0,
+            end_height: u64::MAX,
+            cursor: None,
+            limit: 10,
+        },
+    );
+    assert_eq!(page.entries, account_deploys);
+
+    let page = get_account_transfers(
+        &mut harness,
+        &mut storage,
+        AccountIndexQuery {
+            subject: TransferParticipant::Account(account_hash),
+            start_height: 0,
+            end_height: u64::MAX,
+            cursor: None,
+            limit: 10,
+        },
+    );
+    assert_eq!(page.entries, account_transfers);
+}
+
+#[test]
+fn should_parse_config_written_before_pruning_was_added() {
+    let config: Config = toml::from_str(
+        r#"
+        path = "/var/lib/casper/casper-node"
+        max_block_store_size = 483_183_820_800
+        max_deploy_store_size = 322_122_547_200
+        max_deploy_metadata_store_size = 322_122_547_200
+        max_state_store_size = 10_737_418_240
+        "#,
+    )
+    .expect("should parse config without the pruning keys");
+
+    assert_eq!(config.retain_blocks, 0);
+    assert_eq!(config.retain_eras, 0);
+    assert_eq!(config.prune_batch_size, None);
+    assert!(!config.retain_block_headers);
+    assert!(!config.rebuild_indices);
+}
@@ -4,7 +4,7 @@
 #![allow(clippy::field_reassign_with_default)]
 
 use ed25519_dalek::ExpandedSecretKey;
-use k256::{ecdsa, ecdsa::signature::Verifier};
+use k256::ecdsa;
 use signature::{RandomizedSigner, Signature as _Signature};
 
 use casper_types::{PublicKey, SecretKey, Signature};
@@ -50,40 +50,7 @@ pub fn verify<T: AsRef<[u8]>>(
     signature: &Signature,
     public_key: &PublicKey,
 ) -> Result<()> {
-    match (signature, public_key) {
-        (Signature::System, _) => Err(Error::AsymmetricKey(String::from(
-            "signatures based on the system key cannot be verified",
-        ))),
-        (Signature::Ed25519(signature), PublicKey::Ed25519(public_key)) => public_key
-            .verify_strict(
-                message.as_ref(),
-                &ed25519_dalek::Signature::from_bytes(signature).map_err(|_| {
-                    Error::AsymmetricKey(format!(
-                        "failed to construct Ed25519 signature from {:?}",
-                        &signature[..]
-                    ))
-                })?,
-            )
-            .map_err(|_| Error::AsymmetricKey(String::from("failed to verify Ed25519 signature"))),
-        (Signature::Secp256k1(signature), PublicKey::Secp256k1(pub_key)) => {
-            let verifier = ecdsa::Verifier::new(pub_key).map_err(|error| {
-                Error::AsymmetricKey(format!(
-                    "failed to create secp256k1 verifier from {}: {}",
-                    public_key, error
-                ))
-            })?;
-
-            verifier
-                .verify(message.as_ref(), signature)
-                .map_err(|error| {
-                    Error::AsymmetricKey(format!("failed to verify secp256k1 signature: {}", error))
-                })
-        }
-        _ => Err(Error::AsymmetricKey(format!(
-            "type mismatch between {} and {}",
-            signature, public_key
-        ))),
-    }
+    casper_types::crypto::verify(message, signature, public_key).map_err(Error::from)
 }
 
 #[cfg(test)]
@@ -78,15 +78,21 @@ use smallvec::{smallvec, SmallVec};
 use tracing::{error, warn};
 
 use casper_execution_engine::{
-    core::engine_state::{
-        self,
-        era_validators::GetEraValidatorsError,
-        execute_request::ExecuteRequest,
-        execution_result::ExecutionResults,
-        genesis::GenesisResult,
-        step::{StepRequest, StepResult},
-        upgrade::{UpgradeConfig, UpgradeResult},
-        BalanceRequest, BalanceResult, QueryRequest, QueryResult, MAX_PAYMENT,
+    core::{
+        engine_state::{
+            self,
+            era_validators::GetEraValidatorsError,
+            execute_request::ExecuteRequest,
+            execution_result::ExecutionResults,
+            genesis::GenesisResult,
+            keys_with_prefix::{KeysWithPrefixRequest, KeysWithPrefixResult},
+            step::{StepRequest, StepResult},
+            transfer_scheduler::ExecuteTransfersResult,
+            upgrade::{UpgradeConfig, UpgradeResult},
+            BalanceRequest, BalanceResult, QueryRequest, QueryResult, ViewRequest, ViewResult,
+            MAX_PAYMENT,
+        },
+        execution::ExecutionTrace,
     },
     shared::{
         additive_map::AdditiveMap, newtypes::Blake2bHash, stored_value::StoredValue,
@@ -95,14 +101,15 @@ use casper_execution_engine::{
     storage::{global_state::CommitResult, protocol_data::ProtocolData, trie::Trie},
 };
 use casper_types::{
-    system::auction::EraValidators, ExecutionResult, Key, ProtocolVersion, PublicKey, Transfer,
+    account::AccountHash, system::auction::EraValidators, ExecutionEffect, ExecutionResult, Key,
+    ProtocolVersion, PublicKey, Transfer,
 };
 
 use crate::{
     components::{
         chainspec_loader::NextUpgrade,
         consensus::{BlockContext, EraId},
-        contract_runtime::EraValidatorsRequest,
+        contract_runtime::{ContractViewError, EraValidatorsRequest, SpeculativeExecutionError},
         deploy_acceptor,
         fetcher::FetchResult,
         small_network::GossipedAddress,
@@ -111,16 +118,17 @@ use crate::{
     effect::requests::LinearChainRequest,
     reactor::{EventQueueHandle, QueueKind},
     types::{
-        Block, BlockByHeight, BlockHash, BlockHeader, BlockLike, BlockSignatures, Chainspec,
-        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, FinalitySignature,
-        FinalizedBlock, Item, ProtoBlock, Timestamp,
+        AccountDeploy, AccountIndexPage, AccountIndexQuery, AccountTransfer, Block, BlockByHeight,
+        BlockHash, BlockHeader, BlockLike, BlockSignatures, Chainspec, ChainspecInfo, Deploy,
+        DeployHash, DeployHeader, DeployMetadata, DeployStatus, FinalitySignature, FinalizedBlock,
+        Item, PendingDeploysPage, ProtoBlock, Timestamp, TransferParticipant,
     },
     utils::Source,
 };
 use announcements::{
-    BlockExecutorAnnouncement, ChainspecLoaderAnnouncement, ConsensusAnnouncement,
-    DeployAcceptorAnnouncement, GossiperAnnouncement, LinearChainAnnouncement, NetworkAnnouncement,
-    RpcServerAnnouncement,
+    BlockExecutorAnnouncement, BlockProposerAnnouncement, ChainspecLoaderAnnouncement,
+    ConsensusAnnouncement, DeployAcceptorAnnouncement, GossiperAnnouncement,
+    LinearChainAnnouncement, NetworkAnnouncement, RpcServerAnnouncement,
 };
 use casper_execution_engine::core::engine_state::put_trie::InsertedTrieKeyAndMissingDescendants;
 use requests::{
@@ -668,6 +676,38 @@ impl<REv> EffectBuilder<REv> {
             .await
     }
 
+    /// Announce the step at the end of an era has been run and committed.
+    pub(crate) async fn announce_step_success(
+        self,
+        era_id: EraId,
+        execution_effect: ExecutionEffect,
+    ) where
+        REv: From<BlockExecutorAnnouncement>,
+    {
+        self.0
+            .schedule(
+                BlockExecutorAnnouncement::StepSuccess {
+                    era_id,
+                    execution_effect,
+                },
+                QueueKind::Regular,
+            )
+            .await
+    }
+
+    /// Announce pending deploys have been pruned from the block proposer as their TTL expired.
+    pub(crate) async fn announce_expired_deploys(self, deploy_hashes: Vec<DeployHash>)
+    where
+        REv: From<BlockProposerAnnouncement>,
+    {
+        self.0
+            .schedule(
+                BlockProposerAnnouncement::DeploysExpired(deploy_hashes),
+                QueueKind::Regular,
+            )
+            .await
+    }
+
     /// Announce upgrade activation point read.
     pub(crate) async fn announce_upgrade_activation_point_read(self, next_upgrade: NextUpgrade)
     where
@@ -759,6 +799,61 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Gets a page of the deploys sent by an account from storage.
+    pub(crate) async fn get_account_deploys_from_storage(
+        self,
+        query: AccountIndexQuery<AccountHash>,
+    ) -> AccountIndexPage<AccountDeploy>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetAccountDeploys {
+                query: Box::new(query),
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Gets a page of the transfers an account or purse took part in from storage.
+    pub(crate) async fn get_account_transfers_from_storage(
+        self,
+        query: AccountIndexQuery<TransferParticipant>,
+    ) -> AccountIndexPage<AccountTransfer>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetAccountTransfers {
+                query: Box::new(query),
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Gets the state root hashes of the `retained_blocks` most recent blocks and of all switch
+    /// blocks from storage.
+    pub(crate) async fn get_retained_state_root_hashes_from_storage(
+        self,
+        retained_blocks: u64,
+    ) -> Vec<Digest>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetRetainedStateRootHashes {
+                retained_blocks,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Requests the block at the given height.
     pub(crate) async fn get_block_at_height_from_storage(self, height: u64) -> Option<Block>
     where
@@ -1052,6 +1147,44 @@ impl<REv> EffectBuilder<REv> {
         (proto_block, block_context)
     }
 
+    /// Requests a page of the deploys pending inclusion in a block from the block proposer.
+    pub(crate) async fn get_pending_deploys_from_block_proposer(
+        self,
+        cursor: Option<DeployHash>,
+        limit: usize,
+    ) -> PendingDeploysPage
+    where
+        REv: From<BlockProposerRequest>,
+    {
+        self.make_request(
+            |responder| BlockProposerRequest::GetPendingDeploys {
+                cursor,
+                limit,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Requests the status of a deploy from the block proposer.
+    pub(crate) async fn get_deploy_status_from_block_proposer(
+        self,
+        deploy_hash: DeployHash,
+    ) -> DeployStatus
+    where
+        REv: From<BlockProposerRequest>,
+    {
+        self.make_request(
+            |responder| BlockProposerRequest::GetDeployStatus {
+                deploy_hash,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Passes a finalized proto-block to the block executor component to execute it.
     pub(crate) async fn execute_block(self, finalized_block: FinalizedBlock)
     where
@@ -1305,6 +1438,48 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Requests an execution of wasm-less transfers using Contract Runtime, committing their
+    /// effects.
+    pub(crate) async fn request_execute_transfers(
+        self,
+        execute_request: ExecuteRequest,
+    ) -> Result<ExecuteTransfersResult, engine_state::Error>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        let execute_request = Box::new(execute_request);
+        self.make_request(
+            |responder| ContractRuntimeRequest::ExecuteTransfers {
+                execute_request,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Requests an execution of a single deploy using Contract Runtime, without committing its
+    /// effects, optionally recording a trace of the execution.
+    pub(crate) async fn speculative_execute(
+        self,
+        execute_request: ExecuteRequest,
+        trace: bool,
+    ) -> Result<(ExecutionResult, Option<ExecutionTrace>), SpeculativeExecutionError>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        let execute_request = Box::new(execute_request);
+        self.make_request(
+            |responder| ContractRuntimeRequest::SpeculativeExecute {
+                execute_request,
+                trace,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Requests a commit of effects on the Contract Runtime component.
     pub(crate) async fn request_commit(
         self,
@@ -1343,6 +1518,42 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Requests a read-only call of a stored contract's entry point from the Contract Runtime
+    /// component.
+    pub(crate) async fn run_view(
+        self,
+        view_request: ViewRequest,
+    ) -> Result<ViewResult, ContractViewError>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        let view_request = Box::new(view_request);
+        self.make_request(
+            |responder| ContractRuntimeRequest::View {
+                view_request,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Requests the keys matching a prefix, along with their values, from the Contract Runtime
+    /// component.
+    pub(crate) async fn get_keys_with_prefix(
+        self,
+        request: KeysWithPrefixRequest,
+    ) -> Result<KeysWithPrefixResult, engine_state::Error>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        self.make_request(
+            |responder| ContractRuntimeRequest::GetKeysWithPrefix { request, responder },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     pub(crate) async fn is_verified_account(self, account_key: Key) -> Option<bool>
     where
         REv: From<ContractRuntimeRequest>,
@@ -10,7 +10,7 @@ use std::{
 
 use serde::Serialize;
 
-use casper_types::{ExecutionResult, PublicKey};
+use casper_types::{ExecutionEffect, ExecutionResult, PublicKey};
 
 use crate::{
     components::{
@@ -184,6 +184,13 @@ pub enum BlockExecutorAnnouncement {
         /// The results of executing the deploys in this block.
         execution_results: HashMap<DeployHash, (DeployHeader, ExecutionResult)>,
     },
+    /// The step at the end of an era was successfully run and committed.
+    StepSuccess {
+        /// The era which has ended.
+        era_id: EraId,
+        /// The effects of running the step.
+        execution_effect: ExecutionEffect,
+    },
 }
 
 impl Display for BlockExecutorAnnouncement {
@@ -192,6 +199,26 @@ impl Display for BlockExecutorAnnouncement {
             BlockExecutorAnnouncement::LinearChainBlock { block, .. } => {
                 write!(f, "created linear chain block {}", block.hash())
             }
+            BlockExecutorAnnouncement::StepSuccess { era_id, .. } => {
+                write!(f, "step completed for era {}", era_id)
+            }
+        }
+    }
+}
+
+/// A `BlockProposer` announcement.
+#[derive(Debug)]
+pub enum BlockProposerAnnouncement {
+    /// Pending deploys have been pruned from the block proposer as their TTL expired.
+    DeploysExpired(Vec<DeployHash>),
+}
+
+impl Display for BlockProposerAnnouncement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockProposerAnnouncement::DeploysExpired(deploy_hashes) => {
+                write!(f, "{} pending deploys expired", deploy_hashes.len())
+            }
         }
     }
 }
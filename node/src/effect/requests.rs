@@ -17,21 +17,29 @@ use serde::Serialize;
 use static_assertions::const_assert;
 
 use casper_execution_engine::{
-    core::engine_state::{
-        self,
-        balance::{BalanceRequest, BalanceResult},
-        era_validators::GetEraValidatorsError,
-        execute_request::ExecuteRequest,
-        execution_result::ExecutionResults,
-        genesis::GenesisResult,
-        query::{QueryRequest, QueryResult},
-        step::{StepRequest, StepResult},
-        upgrade::{UpgradeConfig, UpgradeResult},
+    core::{
+        engine_state::{
+            self,
+            balance::{BalanceRequest, BalanceResult},
+            era_validators::GetEraValidatorsError,
+            execute_request::ExecuteRequest,
+            execution_result::ExecutionResults,
+            genesis::GenesisResult,
+            keys_with_prefix::{KeysWithPrefixRequest, KeysWithPrefixResult},
+            query::{QueryRequest, QueryResult},
+            step::{StepRequest, StepResult},
+            transfer_scheduler::ExecuteTransfersResult,
+            upgrade::{UpgradeConfig, UpgradeResult},
+            view::{ViewRequest, ViewResult},
+            ExecutableDeployItem,
+        },
+        execution::ExecutionTrace,
     },
     shared::{additive_map::AdditiveMap, transform::Transform},
     storage::{global_state::CommitResult, protocol_data::ProtocolData},
 };
 use casper_types::{
+    account::AccountHash,
     system::auction::{EraValidators, ValidatorWeights},
     ExecutionResult, Key, ProtocolVersion, PublicKey, Transfer, URef,
 };
@@ -40,16 +48,20 @@ use super::Responder;
 use crate::{
     components::{
         consensus::EraId,
-        contract_runtime::{EraValidatorsRequest, ValidatorWeightsByEraIdRequest},
+        contract_runtime::{
+            ContractViewError, EraValidatorsRequest, SpeculativeExecutionError,
+            ValidatorWeightsByEraIdRequest,
+        },
         deploy_acceptor::Error,
         fetcher::FetchResult,
     },
     crypto::hash::Digest,
     rpcs::chain::BlockIdentifier,
     types::{
-        Block as LinearBlock, Block, BlockHash, BlockHeader, BlockSignatures, Chainspec,
-        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, FinalitySignature,
-        FinalizedBlock, Item, NodeId, ProtoBlock, StatusFeed, Timestamp,
+        AccountDeploy, AccountIndexPage, AccountIndexQuery, AccountTransfer, Block as LinearBlock,
+        Block, BlockHash, BlockHeader, BlockSignatures, Chainspec, ChainspecInfo, Deploy,
+        DeployHash, DeployHeader, DeployMetadata, DeployStatus, FinalitySignature, FinalizedBlock,
+        Item, NodeId, PendingDeploysPage, ProtoBlock, StatusFeed, Timestamp, TransferParticipant,
     },
     utils::DisplayIter,
 };
@@ -341,6 +353,27 @@ pub enum StorageRequest {
         /// stored.
         responder: Responder<bool>,
     },
+    /// Retrieve a page of the deploys sent by an account.
+    GetAccountDeploys {
+        /// The query, identifying the sending account by its account hash.
+        query: Box<AccountIndexQuery<AccountHash>>,
+        /// Responder to call with the result.
+        responder: Responder<AccountIndexPage<AccountDeploy>>,
+    },
+    /// Retrieve a page of the transfers an account or purse took part in.
+    GetAccountTransfers {
+        /// The query, identifying the account or purse.
+        query: Box<AccountIndexQuery<TransferParticipant>>,
+        /// Responder to call with the result.
+        responder: Responder<AccountIndexPage<AccountTransfer>>,
+    },
+    /// Retrieve the state root hashes of the most recent blocks and of all switch blocks.
+    GetRetainedStateRootHashes {
+        /// The number of most recent blocks whose state root hashes are retrieved.
+        retained_blocks: u64,
+        /// Responder to call with the result.
+        responder: Responder<Vec<Digest>>,
+    },
 }
 
 impl Display for StorageRequest {
@@ -406,6 +439,19 @@ impl Display for StorageRequest {
             StorageRequest::PutBlockSignatures { .. } => {
                 write!(formatter, "put finality signatures")
             }
+            StorageRequest::GetAccountDeploys { query, .. } => {
+                write!(formatter, "get deploys sent by account {}", query)
+            }
+            StorageRequest::GetAccountTransfers { query, .. } => {
+                write!(formatter, "get transfers of {}", query)
+            }
+            StorageRequest::GetRetainedStateRootHashes {
+                retained_blocks, ..
+            } => write!(
+                formatter,
+                "get state root hashes of the {} most recent blocks and all switch blocks",
+                retained_blocks
+            ),
         }
     }
 }
@@ -470,6 +516,22 @@ pub struct ProtoBlockRequest {
 pub enum BlockProposerRequest {
     /// Request a list of deploys to propose in a new block.
     RequestProtoBlock(ProtoBlockRequest),
+    /// Request a page of the deploys currently pending inclusion in a block.
+    GetPendingDeploys {
+        /// The hash of the last deploy of the previous page, if any.
+        cursor: Option<DeployHash>,
+        /// The maximum number of deploys to return.
+        limit: usize,
+        /// Responder to call with the result.
+        responder: Responder<PendingDeploysPage>,
+    },
+    /// Request the status of a deploy.
+    GetDeployStatus {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// Responder to call with the result.
+        responder: Responder<DeployStatus>,
+    },
 }
 
 impl Display for BlockProposerRequest {
@@ -488,6 +550,12 @@ impl Display for BlockProposerRequest {
                 past_deploys.len(),
                 next_finalized
             ),
+            BlockProposerRequest::GetPendingDeploys { .. } => {
+                write!(formatter, "get pending deploys")
+            }
+            BlockProposerRequest::GetDeployStatus { deploy_hash, .. } => {
+                write!(formatter, "get status of {}", deploy_hash)
+            }
         }
     }
 }
@@ -506,6 +574,24 @@ pub enum RpcRequest<I> {
         /// Responder to call.
         responder: Responder<Result<(), Error>>,
     },
+    /// Execute a deploy against the given global state without committing its effects.
+    SpeculativeExecute {
+        /// The state root hash to execute the deploy against.
+        state_root_hash: Digest,
+        /// The block time to execute the deploy with.
+        block_time: Timestamp,
+        /// The proposer to execute the deploy with, receiving the payment.
+        proposer: PublicKey,
+        /// The protocol version to execute the deploy with.
+        protocol_version: ProtocolVersion,
+        /// The deploy to execute.
+        deploy: Box<Deploy>,
+        /// Whether to record a trace of the deploy's execution.
+        trace: bool,
+        /// Responder to call with the result, and the trace if requested.
+        responder:
+            Responder<Result<(ExecutionResult, Option<ExecutionTrace>), SpeculativeExecutionError>>,
+    },
     /// If `maybe_identifier` is `Some`, return the specified block if it exists, else `None`.  If
     /// `maybe_identifier` is `None`, return the latest block.
     GetBlock {
@@ -532,6 +618,35 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Result<QueryResult, engine_state::Error>>,
     },
+    /// Call an entry point of a stored contract against the given global state in a read-only
+    /// context.
+    QueryContractView {
+        /// The state root hash.
+        state_root_hash: Digest,
+        /// The block time to call the entry point with.
+        block_time: Timestamp,
+        /// The protocol version to call the entry point with.
+        protocol_version: ProtocolVersion,
+        /// The account calling the entry point.
+        caller: AccountHash,
+        /// The stored contract and entry point to call.
+        target: Box<ExecutableDeployItem>,
+        /// Responder to call with the result.
+        responder: Responder<Result<ViewResult, ContractViewError>>,
+    },
+    /// Query the global state at the given root hash for all keys matching a prefix.
+    QueryKeysWithPrefix {
+        /// The state root hash.
+        state_root_hash: Digest,
+        /// The prefix of the serialized `casper_types::Key`s to be returned.
+        prefix: Vec<u8>,
+        /// If `Some`, only keys strictly greater than the cursor are returned.
+        cursor: Option<Key>,
+        /// The maximum number of keys to return.
+        limit: usize,
+        /// Responder to call with the result.
+        responder: Responder<Result<KeysWithPrefixResult, engine_state::Error>>,
+    },
     /// Query the global state at the given root hash.
     QueryEraValidators {
         /// The global state hash.
@@ -564,6 +679,36 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Option<(Deploy, DeployMetadata)>>,
     },
+    /// Return a page of the deploys sent by an account.
+    GetAccountDeploys {
+        /// The query, identifying the sending account by its account hash.
+        query: Box<AccountIndexQuery<AccountHash>>,
+        /// Responder to call with the result.
+        responder: Responder<AccountIndexPage<AccountDeploy>>,
+    },
+    /// Return a page of the transfers an account or purse took part in.
+    GetAccountTransfers {
+        /// The query, identifying the account or purse.
+        query: Box<AccountIndexQuery<TransferParticipant>>,
+        /// Responder to call with the result.
+        responder: Responder<AccountIndexPage<AccountTransfer>>,
+    },
+    /// Return a page of the deploys pending inclusion in a block.
+    GetPendingDeploys {
+        /// The hash of the last deploy of the previous page, if any.
+        cursor: Option<DeployHash>,
+        /// The maximum number of deploys to return.
+        limit: usize,
+        /// Responder to call with the result.
+        responder: Responder<PendingDeploysPage>,
+    },
+    /// Return the status of a deploy as known by the block proposer.
+    GetDeployStatus {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// Responder to call with the result.
+        responder: Responder<DeployStatus>,
+    },
     /// Return the connected peers.
     GetPeers {
         /// Responder to call with the result.
@@ -585,6 +730,16 @@ impl<I> Display for RpcRequest<I> {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         match self {
             RpcRequest::SubmitDeploy { deploy, .. } => write!(formatter, "submit {}", *deploy),
+            RpcRequest::SpeculativeExecute {
+                state_root_hash,
+                deploy,
+                ..
+            } => write!(
+                formatter,
+                "speculatively execute {} against {}",
+                deploy.id(),
+                state_root_hash
+            ),
             RpcRequest::GetBlock {
                 maybe_id: Some(BlockIdentifier::Hash(hash)),
                 ..
@@ -610,6 +765,29 @@ impl<I> Display for RpcRequest<I> {
                 "query {}, base_key: {}, path: {:?}",
                 state_root_hash, base_key, path
             ),
+            RpcRequest::QueryContractView {
+                state_root_hash,
+                target,
+                ..
+            } => write!(
+                formatter,
+                "query contract view {:?} against {}",
+                target, state_root_hash
+            ),
+            RpcRequest::QueryKeysWithPrefix {
+                state_root_hash,
+                prefix,
+                cursor,
+                limit,
+                ..
+            } => write!(
+                formatter,
+                "query keys {}, prefix: {}, cursor: {:?}, limit: {}",
+                state_root_hash,
+                HexFmt(prefix),
+                cursor,
+                limit
+            ),
             RpcRequest::QueryEraValidators {
                 state_root_hash, ..
             } => write!(formatter, "auction {}", state_root_hash),
@@ -623,6 +801,16 @@ impl<I> Display for RpcRequest<I> {
                 state_root_hash, purse_uref
             ),
             RpcRequest::GetDeploy { hash, .. } => write!(formatter, "get {}", hash),
+            RpcRequest::GetAccountDeploys { query, .. } => {
+                write!(formatter, "get deploys sent by account {}", query)
+            }
+            RpcRequest::GetAccountTransfers { query, .. } => {
+                write!(formatter, "get transfers of {}", query)
+            }
+            RpcRequest::GetPendingDeploys { .. } => write!(formatter, "get pending deploys"),
+            RpcRequest::GetDeployStatus { deploy_hash, .. } => {
+                write!(formatter, "get status of {}", deploy_hash)
+            }
             RpcRequest::GetPeers { .. } => write!(formatter, "get peers"),
             RpcRequest::GetStatus { .. } => write!(formatter, "get status"),
             RpcRequest::GetMetrics { .. } => write!(formatter, "get metrics"),
@@ -684,6 +872,27 @@ pub enum ContractRuntimeRequest {
         /// Responder to call with the execution result.
         responder: Responder<Result<ExecutionResults, engine_state::RootNotFound>>,
     },
+    /// An `ExecuteRequest` containing wasm-less transfers to be executed one after the other, and
+    /// their effects committed.
+    ExecuteTransfers {
+        /// Execution request containing the transfers.
+        #[serde(skip_serializing)]
+        execute_request: Box<ExecuteRequest>,
+        /// Responder to call with the execution results and the post state hash.
+        responder: Responder<Result<ExecuteTransfersResult, engine_state::Error>>,
+    },
+    /// An `ExecuteRequest` containing a single deploy to be executed without committing its
+    /// effects.
+    SpeculativeExecute {
+        /// Execution request containing the deploy.
+        #[serde(skip_serializing)]
+        execute_request: Box<ExecuteRequest>,
+        /// Whether to record a trace of the deploy's execution.
+        trace: bool,
+        /// Responder to call with the execution result, and the trace if requested.
+        responder:
+            Responder<Result<(ExecutionResult, Option<ExecutionTrace>), SpeculativeExecutionError>>,
+    },
     /// A request to commit existing execution transforms.
     Commit {
         /// A valid state root hash.
@@ -710,6 +919,22 @@ pub enum ContractRuntimeRequest {
         /// Responder to call with the query result.
         responder: Responder<Result<QueryResult, engine_state::Error>>,
     },
+    /// A request to call an entry point of a stored contract in a read-only context.
+    View {
+        /// View request.
+        #[serde(skip_serializing)]
+        view_request: Box<ViewRequest>,
+        /// Responder to call with the view result.
+        responder: Responder<Result<ViewResult, ContractViewError>>,
+    },
+    /// A request for the keys matching a prefix, along with their values.
+    GetKeysWithPrefix {
+        /// Keys with prefix request.
+        #[serde(skip_serializing)]
+        request: KeysWithPrefixRequest,
+        /// Responder to call with the result.
+        responder: Responder<Result<KeysWithPrefixResult, engine_state::Error>>,
+    },
     /// A balance request.
     GetBalance {
         /// Balance request.
@@ -797,6 +1022,22 @@ impl Display for ContractRuntimeRequest {
                 execute_request.parent_state_hash
             ),
 
+            ContractRuntimeRequest::ExecuteTransfers {
+                execute_request, ..
+            } => write!(
+                formatter,
+                "execute transfers request: {}",
+                execute_request.parent_state_hash
+            ),
+
+            ContractRuntimeRequest::SpeculativeExecute {
+                execute_request, ..
+            } => write!(
+                formatter,
+                "speculative execute request: {}",
+                execute_request.parent_state_hash
+            ),
+
             ContractRuntimeRequest::Commit {
                 state_root_hash,
                 effects,
@@ -815,6 +1056,14 @@ impl Display for ContractRuntimeRequest {
                 write!(formatter, "query request: {:?}", query_request)
             }
 
+            ContractRuntimeRequest::View { view_request, .. } => {
+                write!(formatter, "view request: {:?}", view_request)
+            }
+
+            ContractRuntimeRequest::GetKeysWithPrefix { request, .. } => {
+                write!(formatter, "get keys with prefix request: {:?}", request)
+            }
+
             ContractRuntimeRequest::GetBalance {
                 balance_request, ..
             } => write!(formatter, "balance request: {:?}", balance_request),
@@ -0,0 +1,80 @@
+//! Offline pruning of the global state.
+//!
+//! Deletes every trie node which isn't reachable from the state root hash of one of the most
+//! recent blocks or of a switch block, as configured by `contract_runtime.pruning_retained_blocks`.
+//! The node must be stopped while pruning.  A run which is interrupted is resumed by running the
+//! pruning again, or by starting the node with `contract_runtime.enable_pruning` set.
+
+use std::sync::Arc;
+
+use lmdb::DatabaseFlags;
+use thiserror::Error;
+use tracing::info;
+
+use casper_execution_engine::{
+    shared::newtypes::Blake2bHash,
+    storage::{
+        error::lmdb::Error as StorageLmdbError,
+        transaction_source::lmdb::LmdbEnvironment,
+        trie_store::{
+            lmdb::LmdbTrieStore,
+            pruning::{LmdbTriePruner, PruningProgress},
+        },
+    },
+};
+
+use crate::{
+    components::storage::{Error as StorageError, Storage},
+    reactor::validator::Config,
+    utils::WithDir,
+};
+
+/// Error pruning the global state.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Error opening the block store.
+    #[error("failed to open block store: {0}")]
+    Storage(#[from] StorageError),
+
+    /// Error accessing the global state.
+    #[error("failed to access global state: {0}")]
+    Lmdb(#[from] StorageLmdbError),
+}
+
+/// Prunes the global state of the node configured by `config`, retaining the global state of the
+/// most recent blocks and of all switch blocks, and returns the final progress of the run.
+pub fn prune_global_state(config: WithDir<Config>) -> Result<PruningProgress, Error> {
+    let contract_runtime_config = &config.value().contract_runtime;
+    let retained_blocks = contract_runtime_config.pruning_retained_blocks();
+
+    let storage = Storage::new(&config.map_ref(|config| config.storage.clone()), None)?;
+    let retained_roots: Vec<Blake2bHash> = storage
+        .retained_state_root_hashes(retained_blocks)?
+        .into_iter()
+        .map(Blake2bHash::from)
+        .collect();
+    info!(
+        retained_blocks,
+        retained_roots = retained_roots.len(),
+        "read state root hashes to retain"
+    );
+
+    let path = config.with_dir(config.value().storage.path.clone());
+    let environment = Arc::new(LmdbEnvironment::new(
+        path.as_path(),
+        contract_runtime_config.max_global_state_size(),
+        contract_runtime_config.max_readers(),
+    )?);
+    let trie_store = Arc::new(LmdbTrieStore::new(
+        &environment,
+        None,
+        DatabaseFlags::empty(),
+    )?);
+    let pruner = LmdbTriePruner::new(environment, trie_store, None, DatabaseFlags::empty())?;
+    pruner
+        .prune(
+            &retained_roots,
+            contract_runtime_config.pruning_batch_size(),
+        )
+        .map_err(Error::from)
+}
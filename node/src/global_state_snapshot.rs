@@ -0,0 +1,561 @@
+//! Export and import of global state snapshots.
+//!
+//! A snapshot holds every trie node reachable from a state root hash, in a format which doesn't
+//! depend on the LMDB page size of the machine it was exported on.  It consists of:
+//!
+//! * a header: the magic bytes `CSPR-GSS`, the format version as a little-endian `u32` and the
+//!   32-byte state root hash
+//! * a sequence of chunks, each being the length of its payload as a little-endian `u32`, the
+//!   payload and the 32-byte hash of the payload.  A payload is a sequence of `bytesrepr`-encoded
+//!   trie nodes.
+//! * an empty chunk, marking the end of the snapshot.
+//!
+//! Trie nodes are keyed by the hashes of their encodings, so the imported global state is verified
+//! by checking that no trie node is missing under the expected state root hash.
+//!
+//! Once verified, the state root is written again through the global state, so that an interrupted
+//! run of the trie pruning retains the imported trie rather than sweeping it when resumed.
+
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use lmdb::DatabaseFlags;
+use thiserror::Error;
+use tracing::info;
+
+use casper_execution_engine::{
+    shared::{
+        newtypes::{Blake2bHash, CorrelationId},
+        stored_value::StoredValue,
+    },
+    storage::{
+        error::lmdb::Error as StorageLmdbError,
+        global_state::{lmdb::LmdbGlobalState, StateProvider},
+        protocol_data_store::lmdb::LmdbProtocolDataStore,
+        store::Store,
+        transaction_source::{lmdb::LmdbEnvironment, Transaction, TransactionSource},
+        trie::Trie,
+        trie_store::{lmdb::LmdbTrieStore, pruning::LmdbTriePruner},
+    },
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    Key,
+};
+
+use crate::{
+    crypto::hash::{self, Digest},
+    reactor::validator::Config,
+    utils::WithDir,
+};
+
+/// The magic bytes at the start of a snapshot.
+const MAGIC: &[u8; 8] = b"CSPR-GSS";
+/// The version of the snapshot format.
+const FORMAT_VERSION: u32 = 1;
+/// The size in bytes above which the payload of a chunk is written out.
+const CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Error exporting or importing a global state snapshot.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Error creating the storage directory.
+    #[error("failed to create storage directory {}: {}", .0.display(), .1)]
+    CreateStorageDirectory(PathBuf, io::Error),
+
+    /// Error opening the snapshot file.
+    #[error("failed to open snapshot file {}: {}", .0.display(), .1)]
+    OpenSnapshot(PathBuf, io::Error),
+
+    /// Error reading or writing the snapshot.
+    #[error("failed to read or write snapshot: {0}")]
+    Io(#[from] io::Error),
+
+    /// Error accessing the global state.
+    #[error("failed to access global state: {0}")]
+    Lmdb(#[from] StorageLmdbError),
+
+    /// Error encoding or decoding a trie node.
+    #[error("failed to encode or decode trie node: {0}")]
+    BytesRepr(bytesrepr::Error),
+
+    /// The state root hash to export, or the one just imported, was not found.
+    #[error("state root hash {0} not found")]
+    RootNotFound(Digest),
+
+    /// A trie node under the state root hash to export was not found.
+    #[error("trie node {0} not found")]
+    TrieNodeNotFound(Digest),
+
+    /// The file is not a global state snapshot.
+    #[error("not a global state snapshot")]
+    InvalidMagic,
+
+    /// The snapshot has an unsupported format version.
+    #[error("unsupported snapshot format version {0}")]
+    UnsupportedVersion(u32),
+
+    /// The snapshot ends before its end marker.
+    #[error("snapshot is truncated")]
+    Truncated,
+
+    /// The checksum of a chunk doesn't match its payload.
+    #[error("checksum mismatch in snapshot chunk {0}")]
+    ChecksumMismatch(u64),
+
+    /// The snapshot is of a different state root hash than expected.
+    #[error("snapshot has state root hash {actual}, expected {expected}")]
+    RootHashMismatch {
+        /// The expected state root hash.
+        expected: Digest,
+        /// The state root hash of the snapshot.
+        actual: Digest,
+    },
+
+    /// Trie nodes are missing under the state root hash after importing the snapshot.
+    #[error("{count} trie nodes missing under state root hash {state_root_hash} after import")]
+    MissingTrieNodes {
+        /// The state root hash of the snapshot.
+        state_root_hash: Digest,
+        /// The number of missing trie nodes.
+        count: usize,
+    },
+}
+
+impl From<bytesrepr::Error> for Error {
+    fn from(error: bytesrepr::Error) -> Self {
+        Error::BytesRepr(error)
+    }
+}
+
+/// Exports the trie nodes reachable from `state_root_hash` in the global state of the node
+/// configured by `config` to a snapshot file at `path`.
+pub fn export_global_state(
+    config: WithDir<Config>,
+    state_root_hash: Digest,
+    path: &Path,
+) -> Result<(), Error> {
+    let global_state = open_global_state(config)?;
+    let file = File::create(path).map_err(|error| Error::OpenSnapshot(path.to_owned(), error))?;
+    let mut writer = BufWriter::new(file);
+    let node_count = write_snapshot(&global_state, state_root_hash, CHUNK_SIZE, &mut writer)?;
+    writer.flush()?;
+    info!(%state_root_hash, node_count, path = %path.display(), "exported global state");
+    Ok(())
+}
+
+/// Imports the trie nodes of the snapshot file at `path` into the global state of the node
+/// configured by `config`, verifying that the snapshot is complete and of `state_root_hash`.
+pub fn import_global_state(
+    config: WithDir<Config>,
+    state_root_hash: Digest,
+    path: &Path,
+) -> Result<(), Error> {
+    let global_state = open_global_state(config)?;
+    let file = File::open(path).map_err(|error| Error::OpenSnapshot(path.to_owned(), error))?;
+    let node_count = read_snapshot(&global_state, state_root_hash, &mut BufReader::new(file))?;
+    info!(%state_root_hash, node_count, path = %path.display(), "imported global state");
+    Ok(())
+}
+
+/// Opens the global state of the node configured by `config`.
+fn open_global_state(config: WithDir<Config>) -> Result<LmdbGlobalState, Error> {
+    let path = config.with_dir(config.value().storage.path.clone());
+    if !path.exists() {
+        fs::create_dir_all(&path)
+            .map_err(|error| Error::CreateStorageDirectory(path.clone(), error))?;
+    }
+    let contract_runtime_config = &config.value().contract_runtime;
+    let environment = Arc::new(LmdbEnvironment::new(
+        path.as_path(),
+        contract_runtime_config.max_global_state_size(),
+        contract_runtime_config.max_readers(),
+    )?);
+    let trie_store = Arc::new(LmdbTrieStore::new(
+        &environment,
+        None,
+        DatabaseFlags::empty(),
+    )?);
+    let protocol_data_store = Arc::new(LmdbProtocolDataStore::new(
+        &environment,
+        None,
+        DatabaseFlags::empty(),
+    )?);
+    // As in the contract runtime, the pruner is attached so that an interrupted pruning run
+    // retains what is imported.
+    let pruner = Arc::new(LmdbTriePruner::new(
+        Arc::clone(&environment),
+        Arc::clone(&trie_store),
+        None,
+        DatabaseFlags::empty(),
+    )?);
+    Ok(LmdbGlobalState::empty(environment, trie_store, protocol_data_store)?.with_pruner(pruner))
+}
+
+/// Writes a snapshot of the trie nodes reachable from `state_root_hash`, returning the number of
+/// trie nodes written.
+fn write_snapshot<W: Write>(
+    global_state: &LmdbGlobalState,
+    state_root_hash: Digest,
+    chunk_size: usize,
+    writer: &mut W,
+) -> Result<u64, Error> {
+    let txn = global_state
+        .environment
+        .create_read_txn()
+        .map_err(StorageLmdbError::from)?;
+    let root_key = Blake2bHash::from(state_root_hash);
+    let maybe_root: Option<Trie<Key, StoredValue>> =
+        global_state.trie_store.get(&txn, &root_key)?;
+    if maybe_root.is_none() {
+        return Err(Error::RootNotFound(state_root_hash));
+    }
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+    writer.write_all(state_root_hash.as_ref())?;
+
+    let mut node_count = 0;
+    let mut payload = Vec::new();
+    let mut trie_keys_to_visit = vec![root_key];
+    while let Some(trie_key) = trie_keys_to_visit.pop() {
+        let trie: Trie<Key, StoredValue> = global_state
+            .trie_store
+            .get(&txn, &trie_key)?
+            .ok_or_else(|| Error::TrieNodeNotFound(trie_key.into()))?;
+        match &trie {
+            Trie::Leaf { .. } => (),
+            Trie::Node { pointer_block } => trie_keys_to_visit.extend(
+                pointer_block
+                    .to_indexed_pointers()
+                    .map(|(_, pointer)| pointer.into_hash()),
+            ),
+            Trie::Extension { pointer, .. } => trie_keys_to_visit.push(pointer.into_hash()),
+        }
+        payload.extend(trie.to_bytes()?);
+        node_count += 1;
+        if payload.len() >= chunk_size {
+            write_chunk(writer, &payload)?;
+            payload.clear();
+        }
+    }
+    if !payload.is_empty() {
+        write_chunk(writer, &payload)?;
+    }
+    // The end marker.
+    write_chunk(writer, &[])?;
+
+    txn.commit().map_err(StorageLmdbError::from)?;
+    Ok(node_count)
+}
+
+fn write_chunk<W: Write>(writer: &mut W, payload: &[u8]) -> Result<(), Error> {
+    writer.write_all(&(payload.len() as u32).to_le_bytes())?;
+    writer.write_all(payload)?;
+    writer.write_all(hash::hash(payload).as_ref())?;
+    Ok(())
+}
+
+/// Reads a snapshot of `state_root_hash` into the global state and verifies it, returning the
+/// number of trie nodes read.
+///
+/// Each chunk is written in a transaction of its own.  Once all trie nodes are written and
+/// verified, the state root is written again via `put_trie`, which retains the imported trie if a
+/// pruning run is in progress.
+fn read_snapshot<R: Read>(
+    global_state: &LmdbGlobalState,
+    state_root_hash: Digest,
+    reader: &mut R,
+) -> Result<u64, Error> {
+    let mut magic = [0; MAGIC.len()];
+    read_exact(reader, &mut magic)?;
+    if &magic != MAGIC {
+        return Err(Error::InvalidMagic);
+    }
+    let mut version = [0; 4];
+    read_exact(reader, &mut version)?;
+    let version = u32::from_le_bytes(version);
+    if version != FORMAT_VERSION {
+        return Err(Error::UnsupportedVersion(version));
+    }
+    let mut snapshot_root_hash = [0; Digest::LENGTH];
+    read_exact(reader, &mut snapshot_root_hash)?;
+    let snapshot_root_hash = Digest::from(snapshot_root_hash);
+    if snapshot_root_hash != state_root_hash {
+        return Err(Error::RootHashMismatch {
+            expected: state_root_hash,
+            actual: snapshot_root_hash,
+        });
+    }
+
+    let mut node_count = 0;
+    for chunk_index in 0.. {
+        let payload = read_chunk(reader, chunk_index)?;
+        if payload.is_empty() {
+            break;
+        }
+        let mut txn = global_state
+            .environment
+            .create_read_write_txn()
+            .map_err(StorageLmdbError::from)?;
+        let mut remainder = payload.as_slice();
+        while !remainder.is_empty() {
+            let (trie, rest) = Trie::<Key, StoredValue>::from_bytes(remainder)?;
+            let trie_key = Blake2bHash::new(&remainder[..remainder.len() - rest.len()]);
+            global_state.trie_store.put(&mut txn, &trie_key, &trie)?;
+            remainder = rest;
+            node_count += 1;
+        }
+        txn.commit().map_err(StorageLmdbError::from)?;
+    }
+
+    let correlation_id = CorrelationId::new();
+    let missing_trie_keys =
+        global_state.missing_trie_keys(correlation_id, state_root_hash.into())?;
+    if !missing_trie_keys.is_empty() {
+        return Err(Error::MissingTrieNodes {
+            state_root_hash,
+            count: missing_trie_keys.len(),
+        });
+    }
+
+    let root = global_state
+        .read_trie(correlation_id, &state_root_hash.into())?
+        .ok_or(Error::RootNotFound(state_root_hash))?;
+    global_state.put_trie(correlation_id, &root)?;
+    Ok(node_count)
+}
+
+fn read_chunk<R: Read>(reader: &mut R, chunk_index: u64) -> Result<Vec<u8>, Error> {
+    let mut length = [0; 4];
+    read_exact(reader, &mut length)?;
+    let mut payload = vec![0; u32::from_le_bytes(length) as usize];
+    read_exact(reader, &mut payload)?;
+    let mut checksum = [0; Digest::LENGTH];
+    read_exact(reader, &mut checksum)?;
+    if hash::hash(&payload) != Digest::from(checksum) {
+        return Err(Error::ChecksumMismatch(chunk_index));
+    }
+    Ok(payload)
+}
+
+fn read_exact<R: Read>(reader: &mut R, buffer: &mut [u8]) -> Result<(), Error> {
+    reader.read_exact(buffer).map_err(|error| {
+        if error.kind() == io::ErrorKind::UnexpectedEof {
+            Error::Truncated
+        } else {
+            Error::Io(error)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use tempfile::TempDir;
+
+    use casper_execution_engine::{
+        shared::{additive_map::AdditiveMap, transform::Transform},
+        storage::{
+            global_state::{CommitResult, StateReader},
+            trie_store::pruning::PruningPhase,
+        },
+    };
+    use casper_types::{account::AccountHash, CLValue};
+
+    use super::*;
+
+    const TEST_MAX_DB_SIZE: usize = 52_428_800;
+    const TEST_MAX_READERS: u32 = 512;
+    const TEST_CHUNK_SIZE: usize = 256;
+
+    fn new_global_state() -> (TempDir, LmdbGlobalState) {
+        let (tempdir, global_state, _pruner) = new_global_state_with_pruner();
+        (tempdir, global_state)
+    }
+
+    fn new_global_state_with_pruner() -> (TempDir, LmdbGlobalState, Arc<LmdbTriePruner>) {
+        let tempdir = tempfile::tempdir().unwrap();
+        let environment = Arc::new(
+            LmdbEnvironment::new(tempdir.path(), TEST_MAX_DB_SIZE, TEST_MAX_READERS).unwrap(),
+        );
+        let trie_store =
+            Arc::new(LmdbTrieStore::new(&environment, None, DatabaseFlags::empty()).unwrap());
+        let protocol_data_store = Arc::new(
+            LmdbProtocolDataStore::new(&environment, None, DatabaseFlags::empty()).unwrap(),
+        );
+        let pruner = Arc::new(
+            LmdbTriePruner::new(
+                Arc::clone(&environment),
+                Arc::clone(&trie_store),
+                None,
+                DatabaseFlags::empty(),
+            )
+            .unwrap(),
+        );
+        let global_state = LmdbGlobalState::empty(environment, trie_store, protocol_data_store)
+            .unwrap()
+            .with_pruner(Arc::clone(&pruner));
+        (tempdir, global_state, pruner)
+    }
+
+    fn account_key(index: u8) -> Key {
+        Key::Account(AccountHash::new([index; 32]))
+    }
+
+    /// Writes values under the account keys with the given indices to an empty trie, returning
+    /// its state root hash.
+    fn populate(global_state: &LmdbGlobalState, indices: Range<u8>) -> Digest {
+        let mut effects = AdditiveMap::new();
+        for index in indices {
+            let value = StoredValue::CLValue(CLValue::from_t(u32::from(index)).unwrap());
+            effects.insert(account_key(index), Transform::Write(value));
+        }
+        match global_state
+            .commit(CorrelationId::new(), global_state.empty_root(), effects)
+            .unwrap()
+        {
+            CommitResult::Success { state_root } => state_root.into(),
+            result => panic!("commit failed: {}", result),
+        }
+    }
+
+    /// Returns a global state with values written under a number of account keys, and its state
+    /// root hash.
+    fn populated_global_state() -> (TempDir, LmdbGlobalState, Digest) {
+        let (tempdir, global_state) = new_global_state();
+        let state_root_hash = populate(&global_state, 0..20);
+        (tempdir, global_state, state_root_hash)
+    }
+
+    fn export(global_state: &LmdbGlobalState, state_root_hash: Digest) -> Vec<u8> {
+        let mut snapshot = Vec::new();
+        write_snapshot(
+            global_state,
+            state_root_hash,
+            TEST_CHUNK_SIZE,
+            &mut snapshot,
+        )
+        .unwrap();
+        snapshot
+    }
+
+    #[test]
+    fn should_export_and_import() {
+        let (_source_dir, source, state_root_hash) = populated_global_state();
+        let snapshot = export(&source, state_root_hash);
+
+        let (_target_dir, target) = new_global_state();
+        let node_count = read_snapshot(&target, state_root_hash, &mut snapshot.as_slice()).unwrap();
+        assert!(node_count > 20, "should import leaves and nodes");
+
+        let source_view = source.checkout(state_root_hash.into()).unwrap().unwrap();
+        let target_view = target.checkout(state_root_hash.into()).unwrap().unwrap();
+        for index in 0..20 {
+            let key = account_key(index);
+            let correlation_id = CorrelationId::new();
+            assert_eq!(
+                target_view.read(correlation_id, &key).unwrap(),
+                source_view.read(correlation_id, &key).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn should_retain_imported_trie_during_interrupted_pruning_run() {
+        let (_source_dir, source, state_root_hash) = populated_global_state();
+        let snapshot = export(&source, state_root_hash);
+
+        // Start a pruning run on the target which only retains a root of its own, as if it had
+        // been interrupted before the import.
+        let (_target_dir, target, pruner) = new_global_state_with_pruner();
+        let other_root = populate(&target, 100..105);
+        pruner.start(&[other_root.into()]).unwrap();
+
+        read_snapshot(&target, state_root_hash, &mut snapshot.as_slice()).unwrap();
+
+        // Resuming the run must not sweep the imported trie.
+        let progress = pruner.prune(&[], TEST_CHUNK_SIZE).unwrap();
+        assert_eq!(progress.phase, PruningPhase::Idle);
+        let correlation_id = CorrelationId::new();
+        assert!(target
+            .missing_trie_keys(correlation_id, state_root_hash.into())
+            .unwrap()
+            .is_empty());
+        let target_view = target.checkout(state_root_hash.into()).unwrap().unwrap();
+        let value = StoredValue::CLValue(CLValue::from_t(7u32).unwrap());
+        assert_eq!(
+            target_view.read(correlation_id, &account_key(7)).unwrap(),
+            Some(value)
+        );
+    }
+
+    #[test]
+    fn should_fail_to_export_unknown_root() {
+        let (_dir, global_state) = new_global_state();
+        let unknown_root = hash::hash(b"unknown");
+        assert!(matches!(
+            write_snapshot(&global_state, unknown_root, TEST_CHUNK_SIZE, &mut Vec::new()),
+            Err(Error::RootNotFound(root)) if root == unknown_root
+        ));
+    }
+
+    #[test]
+    fn should_reject_invalid_snapshots() {
+        let (_source_dir, source, state_root_hash) = populated_global_state();
+        let snapshot = export(&source, state_root_hash);
+        let (_target_dir, target) = new_global_state();
+
+        let other_root = hash::hash(b"other");
+        assert!(matches!(
+            read_snapshot(&target, other_root, &mut snapshot.as_slice()),
+            Err(Error::RootHashMismatch { .. })
+        ));
+
+        let mut corrupted = snapshot.clone();
+        corrupted[MAGIC.len() + 4 + Digest::LENGTH + 4] ^= 1;
+        assert!(matches!(
+            read_snapshot(&target, state_root_hash, &mut corrupted.as_slice()),
+            Err(Error::ChecksumMismatch(0))
+        ));
+
+        // Without its end marker, the snapshot is truncated.
+        let truncated = &snapshot[..snapshot.len() - 4 - Digest::LENGTH];
+        assert!(matches!(
+            read_snapshot(&target, state_root_hash, &mut &truncated[..]),
+            Err(Error::Truncated)
+        ));
+    }
+
+    #[test]
+    fn should_fail_to_import_incomplete_snapshot() {
+        let (_source_dir, source, state_root_hash) = populated_global_state();
+        let snapshot = export(&source, state_root_hash);
+
+        // Drop the last chunk before the end marker, keeping the snapshot well-formed.
+        let mut chunk_starts = Vec::new();
+        let mut offset = MAGIC.len() + 4 + Digest::LENGTH;
+        while offset < snapshot.len() {
+            chunk_starts.push(offset);
+            let mut length = [0; 4];
+            length.copy_from_slice(&snapshot[offset..offset + 4]);
+            offset += 4 + u32::from_le_bytes(length) as usize + Digest::LENGTH;
+        }
+        assert!(chunk_starts.len() > 2, "should export several chunks");
+        let last_chunk_start = chunk_starts[chunk_starts.len() - 2];
+        let end_marker_start = chunk_starts[chunk_starts.len() - 1];
+        let mut incomplete = snapshot[..last_chunk_start].to_vec();
+        incomplete.extend_from_slice(&snapshot[end_marker_start..]);
+
+        let (_target_dir, target) = new_global_state();
+        assert!(matches!(
+            read_snapshot(&target, state_root_hash, &mut incomplete.as_slice()),
+            Err(Error::MissingTrieNodes { .. })
+        ));
+    }
+}
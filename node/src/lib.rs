@@ -29,6 +29,8 @@ mod config_migration;
 pub mod crypto;
 mod data_migration;
 pub mod effect;
+mod global_state_pruning;
+mod global_state_snapshot;
 pub mod logging;
 pub mod protocol;
 pub mod reactor;
@@ -66,6 +68,10 @@ pub use components::{
 };
 pub use config_migration::{migrate_config, Error as ConfigMigrationError};
 pub use data_migration::{migrate_data, Error as DataMigrationError};
+pub use global_state_pruning::{prune_global_state, Error as GlobalStatePruningError};
+pub use global_state_snapshot::{
+    export_global_state, import_global_state, Error as GlobalStateSnapshotError,
+};
 pub use types::NodeRng;
 pub use utils::OS_PAGE_SIZE;
 
@@ -408,13 +408,12 @@ impl reactor::Reactor for Reactor {
             chainspec_loader.chainspec(),
             false,
         )?;
-        let network_name = chainspec_loader.chainspec().network_config.name.clone();
         let (small_network, small_network_effects) = SmallNetwork::new(
             event_queue,
             config.network.clone(),
             registry,
             small_network_identity,
-            network_name,
+            chainspec_loader.chainspec(),
             false,
         )?;
 
@@ -462,8 +461,11 @@ impl reactor::Reactor for Reactor {
             protocol_version.clone(),
         )?;
 
-        let event_stream_server =
-            EventStreamServer::new(config.event_stream_server.clone(), protocol_version.clone())?;
+        let event_stream_server = EventStreamServer::new(
+            config.event_stream_server.clone(),
+            storage.root_path(),
+            protocol_version.clone(),
+        )?;
 
         let block_validator = BlockValidator::new(Arc::clone(&chainspec_loader.chainspec()));
 
@@ -471,8 +473,10 @@ impl reactor::Reactor for Reactor {
 
         let block_by_height_fetcher = Fetcher::new("block_by_height", config.fetcher, &registry)?;
 
-        let deploy_acceptor =
-            DeployAcceptor::new(config.deploy_acceptor, &*chainspec_loader.chainspec());
+        let deploy_acceptor = DeployAcceptor::new(
+            config.deploy_acceptor.clone(),
+            &*chainspec_loader.chainspec(),
+        );
 
         let genesis_state_root_hash = chainspec_loader.genesis_state_root_hash();
         let block_executor = BlockExecutor::new(
@@ -745,6 +749,8 @@ impl reactor::Reactor for Reactor {
             }) => {
                 let mut effects = Effects::new();
                 let block_hash = *block.hash();
+                let era_ended_event =
+                    event_stream_server::Event::era_ended(&block).map(Event::EventStreamServer);
 
                 // send to linear chain
                 let reactor_event = Event::LinearChain(linear_chain::Event::LinearChainBlock {
@@ -767,9 +773,22 @@ impl reactor::Reactor for Reactor {
                         });
                     effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
                 }
+                if let Some(reactor_event) = era_ended_event {
+                    effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
+                }
 
                 effects
             }
+            Event::BlockExecutorAnnouncement(BlockExecutorAnnouncement::StepSuccess {
+                era_id,
+                execution_effect,
+            }) => {
+                let reactor_event = Event::EventStreamServer(event_stream_server::Event::Step {
+                    era_id,
+                    execution_effect: Box::new(execution_effect),
+                });
+                self.dispatch_event(effect_builder, rng, reactor_event)
+            }
             Event::LinearChain(event) => reactor::wrap_effects(
                 Event::LinearChain,
                 self.linear_chain.handle_event(effect_builder, rng, event),
@@ -843,17 +862,24 @@ impl reactor::Reactor for Reactor {
             Event::LinearChainAnnouncement(LinearChainAnnouncement::BlockAdded {
                 block_hash,
                 block_header,
-            }) => reactor::wrap_effects(
-                Event::EventStreamServer,
-                self.event_stream_server.handle_event(
-                    effect_builder,
-                    rng,
-                    event_stream_server::Event::BlockAdded {
-                        block_hash,
-                        block_header,
-                    },
-                ),
-            ),
+            }) => {
+                let reactor_event = Event::SmallNetwork(small_network::Event::BlockAdded {
+                    era_id: block_header.era_id(),
+                });
+                let mut effects = self.dispatch_event(effect_builder, rng, reactor_event);
+                effects.extend(reactor::wrap_effects(
+                    Event::EventStreamServer,
+                    self.event_stream_server.handle_event(
+                        effect_builder,
+                        rng,
+                        event_stream_server::Event::BlockAdded {
+                            block_hash,
+                            block_header,
+                        },
+                    ),
+                ));
+                effects
+            }
             Event::LinearChainAnnouncement(LinearChainAnnouncement::NewFinalitySignature(fs)) => {
                 let reactor_event =
                     Event::EventStreamServer(event_stream_server::Event::FinalitySignature(fs));
@@ -46,9 +46,9 @@ use crate::{
     },
     effect::{
         announcements::{
-            BlockExecutorAnnouncement, ChainspecLoaderAnnouncement, ConsensusAnnouncement,
-            DeployAcceptorAnnouncement, GossiperAnnouncement, LinearChainAnnouncement,
-            NetworkAnnouncement, RpcServerAnnouncement,
+            BlockExecutorAnnouncement, BlockProposerAnnouncement, ChainspecLoaderAnnouncement,
+            ConsensusAnnouncement, DeployAcceptorAnnouncement, GossiperAnnouncement,
+            LinearChainAnnouncement, NetworkAnnouncement, RpcServerAnnouncement,
         },
         requests::{
             BlockExecutorRequest, BlockProposerRequest, BlockValidationRequest,
@@ -187,6 +187,9 @@ pub enum Event {
     /// Chainspec loader announcement.
     #[from]
     ChainspecLoaderAnnouncement(#[serde(skip_serializing)] ChainspecLoaderAnnouncement),
+    /// Block proposer announcement.
+    #[from]
+    BlockProposerAnnouncement(#[serde(skip_serializing)] BlockProposerAnnouncement),
 }
 
 impl From<RpcRequest<NodeId>> for Event {
@@ -286,6 +289,9 @@ impl Display for Event {
             Event::ChainspecLoaderAnnouncement(ann) => {
                 write!(f, "chainspec loader announcement: {}", ann)
             }
+            Event::BlockProposerAnnouncement(ann) => {
+                write!(f, "block proposer announcement: {}", ann)
+            }
         }
     }
 }
@@ -406,13 +412,12 @@ impl reactor::Reactor for Reactor {
             chainspec_loader.chainspec(),
             true,
         )?;
-        let network_name = chainspec_loader.chainspec().network_config.name.clone();
         let (small_network, small_network_effects) = SmallNetwork::new(
             event_queue,
             config.network,
             registry,
             small_network_identity,
-            network_name,
+            chainspec_loader.chainspec(),
             true,
         )?;
 
@@ -422,6 +427,7 @@ impl reactor::Reactor for Reactor {
         let protocol_version = &chainspec_loader.chainspec().protocol_config.version;
         let rpc_server = RpcServer::new(
             config.rpc_server.clone(),
+            chainspec_loader.chainspec(),
             effect_builder,
             protocol_version.clone(),
         )?;
@@ -431,8 +437,10 @@ impl reactor::Reactor for Reactor {
             protocol_version.clone(),
         )?;
 
-        let deploy_acceptor =
-            DeployAcceptor::new(config.deploy_acceptor, &*chainspec_loader.chainspec());
+        let deploy_acceptor = DeployAcceptor::new(
+            config.deploy_acceptor.clone(),
+            &*chainspec_loader.chainspec(),
+        );
         let deploy_fetcher = Fetcher::new("deploy", config.fetcher, &registry)?;
         let deploy_gossiper = Gossiper::new_for_partial_items(
             "deploy_gossiper",
@@ -817,6 +825,13 @@ impl reactor::Reactor for Reactor {
                     Event::DeployGossiper(event),
                 ));
 
+                let event = event_stream_server::Event::DeployAccepted(deploy.clone());
+                effects.extend(self.dispatch_event(
+                    effect_builder,
+                    rng,
+                    Event::EventStreamServer(event),
+                ));
+
                 let event = fetcher::Event::GotRemotely {
                     item: deploy,
                     source,
@@ -836,12 +851,25 @@ impl reactor::Reactor for Reactor {
             Event::ConsensusAnnouncement(consensus_announcement) => {
                 match consensus_announcement {
                     ConsensusAnnouncement::Finalized(block) => {
+                        let proto_block = block.proto_block();
+                        let deploy_hashes = proto_block
+                            .wasm_deploys()
+                            .iter()
+                            .chain(proto_block.transfers())
+                            .copied()
+                            .collect();
+                        let reactor_event =
+                            Event::DeployAcceptor(deploy_acceptor::Event::DeploysFinalized {
+                                deploy_hashes,
+                            });
+                        let mut effects = self.dispatch_event(effect_builder, rng, reactor_event);
+
                         let reactor_event =
                             Event::BlockProposer(block_proposer::Event::FinalizedProtoBlock {
                                 block: block.proto_block().clone(),
                                 height: block.height(),
                             });
-                        let mut effects = self.dispatch_event(effect_builder, rng, reactor_event);
+                        effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
 
                         let reactor_event =
                             Event::ChainspecLoader(chainspec_loader::Event::CheckForNextUpgrade);
@@ -878,6 +906,15 @@ impl reactor::Reactor for Reactor {
             }) => {
                 let mut effects = Effects::new();
                 let block_hash = *block.hash();
+                let era_ended_event =
+                    event_stream_server::Event::era_ended(&block).map(Event::EventStreamServer);
+
+                // let the contract runtime schedule pruning of the global state
+                let reactor_event = Event::ContractRuntime(contract_runtime::Event::BlockAdded {
+                    block_height: block.height(),
+                    state_root_hash: *block.state_root_hash(),
+                });
+                effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
 
                 // send to linear chain
                 let reactor_event = Event::LinearChain(linear_chain::Event::LinearChainBlock {
@@ -900,9 +937,34 @@ impl reactor::Reactor for Reactor {
                         });
                     effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
                 }
+                if let Some(reactor_event) = era_ended_event {
+                    effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
+                }
 
                 effects
             }
+            Event::BlockExecutorAnnouncement(BlockExecutorAnnouncement::StepSuccess {
+                era_id,
+                execution_effect,
+            }) => {
+                let reactor_event = Event::EventStreamServer(event_stream_server::Event::Step {
+                    era_id,
+                    execution_effect: Box::new(execution_effect),
+                });
+                self.dispatch_event(effect_builder, rng, reactor_event)
+            }
+            Event::BlockProposerAnnouncement(BlockProposerAnnouncement::DeploysExpired(
+                deploy_hashes,
+            )) => {
+                let mut effects = Effects::new();
+                for deploy_hash in deploy_hashes {
+                    let reactor_event = Event::EventStreamServer(
+                        event_stream_server::Event::DeployExpired(deploy_hash),
+                    );
+                    effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
+                }
+                effects
+            }
             Event::DeployGossiperAnnouncement(_ann) => {
                 unreachable!("the deploy gossiper should never make an announcement")
             }
@@ -917,12 +979,18 @@ impl reactor::Reactor for Reactor {
                 block_hash,
                 block_header,
             }) => {
+                let reactor_event = Event::SmallNetwork(small_network::Event::BlockAdded {
+                    era_id: block_header.era_id(),
+                });
+                let mut effects = self.dispatch_event(effect_builder, rng, reactor_event);
+
                 let reactor_event =
                     Event::EventStreamServer(event_stream_server::Event::BlockAdded {
                         block_hash,
                         block_header,
                     });
-                self.dispatch_event(effect_builder, rng, reactor_event)
+                effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
+                effects
             }
             Event::LinearChainAnnouncement(LinearChainAnnouncement::NewFinalitySignature(fs)) => {
                 let reactor_event =
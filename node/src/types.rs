@@ -1,8 +1,10 @@
 //! Common types used across multiple components.
 
+mod account_index;
 mod block;
 pub mod chainspec;
 mod deploy;
+mod deploy_status;
 mod exit_code;
 mod item;
 pub mod json_compatibility;
@@ -16,17 +18,21 @@ use rand::{CryptoRng, RngCore};
 #[cfg(not(test))]
 use rand_chacha::ChaCha20Rng;
 
+pub use account_index::{
+    AccountDeploy, AccountIndexPage, AccountIndexQuery, AccountTransfer, TransferParticipant,
+};
 pub use block::{
     json_compatibility::JsonBlock, Block, BlockBody, BlockHash, BlockHeader, BlockSignatures,
     BlockValidationError, FinalitySignature,
 };
-pub(crate) use block::{BlockByHeight, BlockLike, FinalizedBlock, ProtoBlock};
+pub(crate) use block::{BlockByHeight, BlockLike, EraReport, FinalizedBlock, ProtoBlock};
 pub(crate) use chainspec::ActivationPoint;
 pub use chainspec::Chainspec;
 pub use deploy::{
     Approval, Deploy, DeployHash, DeployHeader, DeployMetadata, DeployValidationFailure,
     Error as DeployError,
 };
+pub use deploy_status::{DeployStatus, PendingDeploy, PendingDeployKind, PendingDeploysPage};
 pub use exit_code::ExitCode;
 pub use item::{Item, Tag};
 pub use node_config::NodeConfig;
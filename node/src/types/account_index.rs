@@ -0,0 +1,105 @@
+//! Types used to query the account-keyed indices held by the storage component.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use std::fmt::{self, Display, Formatter};
+
+use hex_fmt::HexFmt;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{account::AccountHash, Transfer, URef};
+
+use crate::types::{BlockHash, DeployHash};
+
+/// An account or purse taking part in transfers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransferParticipant {
+    /// An account, matching transfers sent by or to it.
+    Account(AccountHash),
+    /// A purse, matching transfers debiting or crediting it.
+    Purse(URef),
+}
+
+impl Display for TransferParticipant {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TransferParticipant::Account(account_hash) => {
+                write!(formatter, "{}", account_hash.to_formatted_string())
+            }
+            TransferParticipant::Purse(uref) => write!(formatter, "{}", uref.to_formatted_string()),
+        }
+    }
+}
+
+/// A query for a page of entries of an account-keyed index.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountIndexQuery<S> {
+    /// The account or purse the entries relate to.
+    pub subject: S,
+    /// The lowest block height to include.
+    pub start_height: u64,
+    /// The highest block height to include.
+    pub end_height: u64,
+    /// If present, only entries following the one this cursor was returned for are included.
+    pub cursor: Option<Vec<u8>>,
+    /// The maximum number of entries to return.
+    pub limit: usize,
+}
+
+impl<S: Display> Display for AccountIndexQuery<S> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{} in blocks {} to {}, cursor: {:?}, limit: {}",
+            self.subject,
+            self.start_height,
+            self.end_height,
+            self.cursor.as_ref().map(HexFmt),
+            self.limit
+        )
+    }
+}
+
+/// A page of entries read from an account-keyed index, in ascending block height order.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountIndexPage<T> {
+    /// The entries.
+    pub entries: Vec<T>,
+    /// The cursor to query the following page with, or `None` if this is the last page.
+    pub next_cursor: Option<Vec<u8>>,
+}
+
+impl<T> Default for AccountIndexPage<T> {
+    fn default() -> Self {
+        AccountIndexPage {
+            entries: vec![],
+            next_cursor: None,
+        }
+    }
+}
+
+/// A deploy sent by an account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccountDeploy {
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+    /// The hash of the block the deploy was executed in.
+    pub block_hash: BlockHash,
+    /// The height of the block the deploy was executed in.
+    pub block_height: u64,
+}
+
+/// A transfer an account or purse took part in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccountTransfer {
+    /// The hash of the block the transfer was executed in.
+    pub block_hash: BlockHash,
+    /// The height of the block the transfer was executed in.
+    pub block_height: u64,
+    /// The transfer.
+    pub transfer: Transfer,
+}
@@ -12,6 +12,7 @@ mod protocol_config;
 use std::{fmt::Debug, path::Path};
 
 use datasize::DataSize;
+use num::rational::Ratio;
 #[cfg(test)]
 use rand::Rng;
 use serde::Serialize;
@@ -73,6 +74,13 @@ impl Chainspec {
             warn!("era duration is less than minimum era height * round length!");
         }
 
+        if self.core_config.slashing_rate > Ratio::new(1, 1) {
+            panic!(
+                "Slashing rate is not in the range [0, 1]! Slashing rate: {rate}",
+                rate = self.core_config.slashing_rate
+            );
+        }
+
         self.highway_config.validate_config();
     }
 
@@ -180,6 +188,10 @@ impl From<&Chainspec> for ExecConfig {
             chainspec.core_config.locked_funds_period.millis(),
             chainspec.core_config.round_seigniorage_rate,
             chainspec.core_config.unbonding_delay,
+            chainspec.core_config.redelegation_delay,
+            chainspec.core_config.minimum_delegation_amount,
+            chainspec.core_config.max_delegators_per_validator,
+            chainspec.core_config.slashing_rate,
             chainspec.network_config.timestamp.millis(),
         )
     }
@@ -247,6 +259,13 @@ mod tests {
             remove_contract_user_group_urefs: HostFunction::new(131, [0, 1, 2, 3, 4, 5]),
             print: HostFunction::new(123, [0, 1]),
             blake2b: HostFunction::new(133, [0, 1, 2, 3]),
+            emit_message: HostFunction::new(134, [0, 1, 2, 3]),
+            verify_signature: HostFunction::new(142, [0, 1, 2, 3, 4, 5]),
+            sha256: HostFunction::new(143, [0, 1, 2, 3]),
+            keccak256: HostFunction::new(144, [0, 1, 2, 3]),
+            new_dictionary: HostFunction::new(145, [0]),
+            dictionary_get: HostFunction::new(146, [0, 1, 2, 3, 4]),
+            dictionary_put: HostFunction::new(147, [0, 1, 2, 3, 4, 5]),
         });
     static EXPECTED_GENESIS_WASM_COSTS: Lazy<WasmConfig> = Lazy::new(|| {
         WasmConfig::new(
@@ -304,6 +323,10 @@ mod tests {
 
         assert_eq!(spec.core_config.era_duration, TimeDiff::from(180000));
         assert_eq!(spec.core_config.minimum_era_height, 9);
+        assert_eq!(spec.core_config.redelegation_delay, 7);
+        assert_eq!(spec.core_config.minimum_delegation_amount, 1_000);
+        assert_eq!(spec.core_config.max_delegators_per_validator, 100);
+        assert_eq!(spec.core_config.slashing_rate, Ratio::new(1, 10));
         assert_eq!(
             spec.highway_config.finality_threshold_fraction,
             Ratio::new(2, 25)
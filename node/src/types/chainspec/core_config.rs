@@ -25,9 +25,18 @@ pub struct CoreConfig {
     pub(crate) locked_funds_period: TimeDiff,
     /// The delay in number of eras for paying out the the unbonding amount.
     pub(crate) unbonding_delay: u64,
+    /// The delay in number of eras before redelegated funds are bonded to the new validator.
+    pub(crate) redelegation_delay: u64,
+    /// The minimum amount of motes a delegator has to keep delegated to a validator.
+    pub(crate) minimum_delegation_amount: u64,
+    /// The maximum number of delegators a single validator accepts.
+    pub(crate) max_delegators_per_validator: u32,
     /// Round seigniorage rate represented as a fractional number.
     #[data_size(skip)]
     pub(crate) round_seigniorage_rate: Ratio<u64>,
+    /// Fraction of the stake taken from a slashed validator and its delegators.
+    #[data_size(skip)]
+    pub(crate) slashing_rate: Ratio<u64>,
 }
 
 #[cfg(test)]
@@ -40,10 +49,15 @@ impl CoreConfig {
         let auction_delay = rng.gen::<u32>() as u64;
         let locked_funds_period = TimeDiff::from(rng.gen_range(600_000, 604_800_000));
         let unbonding_delay = rng.gen_range(1, 1_000_000_000);
+        let redelegation_delay = rng.gen_range(1, 1_000_000_000);
+        let minimum_delegation_amount = rng.gen::<u32>() as u64;
+        let max_delegators_per_validator = rng.gen();
         let round_seigniorage_rate = Ratio::new(
             rng.gen_range(1, 1_000_000_000),
             rng.gen_range(1, 1_000_000_000),
         );
+        let slashing_denom = rng.gen_range(1, 1_000_000_000);
+        let slashing_rate = Ratio::new(rng.gen_range(0, slashing_denom + 1), slashing_denom);
 
         CoreConfig {
             era_duration,
@@ -52,7 +66,11 @@ impl CoreConfig {
             auction_delay,
             locked_funds_period,
             unbonding_delay,
+            redelegation_delay,
+            minimum_delegation_amount,
+            max_delegators_per_validator,
             round_seigniorage_rate,
+            slashing_rate,
         }
     }
 }
@@ -66,7 +84,11 @@ impl ToBytes for CoreConfig {
         buffer.extend(self.auction_delay.to_bytes()?);
         buffer.extend(self.locked_funds_period.to_bytes()?);
         buffer.extend(self.unbonding_delay.to_bytes()?);
+        buffer.extend(self.redelegation_delay.to_bytes()?);
+        buffer.extend(self.minimum_delegation_amount.to_bytes()?);
+        buffer.extend(self.max_delegators_per_validator.to_bytes()?);
         buffer.extend(self.round_seigniorage_rate.to_bytes()?);
+        buffer.extend(self.slashing_rate.to_bytes()?);
         Ok(buffer)
     }
 
@@ -77,7 +99,11 @@ impl ToBytes for CoreConfig {
             + self.auction_delay.serialized_length()
             + self.locked_funds_period.serialized_length()
             + self.unbonding_delay.serialized_length()
+            + self.redelegation_delay.serialized_length()
+            + self.minimum_delegation_amount.serialized_length()
+            + self.max_delegators_per_validator.serialized_length()
             + self.round_seigniorage_rate.serialized_length()
+            + self.slashing_rate.serialized_length()
     }
 }
 
@@ -89,7 +115,11 @@ impl FromBytes for CoreConfig {
         let (auction_delay, remainder) = u64::from_bytes(remainder)?;
         let (locked_funds_period, remainder) = TimeDiff::from_bytes(remainder)?;
         let (unbonding_delay, remainder) = u64::from_bytes(remainder)?;
+        let (redelegation_delay, remainder) = u64::from_bytes(remainder)?;
+        let (minimum_delegation_amount, remainder) = u64::from_bytes(remainder)?;
+        let (max_delegators_per_validator, remainder) = u32::from_bytes(remainder)?;
         let (round_seigniorage_rate, remainder) = Ratio::<u64>::from_bytes(remainder)?;
+        let (slashing_rate, remainder) = Ratio::<u64>::from_bytes(remainder)?;
         let config = CoreConfig {
             era_duration,
             minimum_era_height,
@@ -97,7 +127,11 @@ impl FromBytes for CoreConfig {
             auction_delay,
             locked_funds_period,
             unbonding_delay,
+            redelegation_delay,
+            minimum_delegation_amount,
+            max_delegators_per_validator,
             round_seigniorage_rate,
+            slashing_rate,
         };
         Ok((config, remainder))
     }
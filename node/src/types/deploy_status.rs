@@ -0,0 +1,77 @@
+//! Types describing the progress of deploys through the block proposer.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use std::fmt::{self, Display, Formatter};
+
+use datasize::DataSize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{DeployHash, Timestamp};
+
+/// The kind of a deploy pending inclusion in a block.
+#[derive(Clone, Copy, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum PendingDeployKind {
+    /// A native transfer, executed without Wasm.
+    Transfer,
+    /// A deploy executing Wasm.
+    Wasm,
+}
+
+/// A deploy pending inclusion in a block.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PendingDeploy {
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+    /// The kind of the deploy.
+    pub kind: PendingDeployKind,
+    /// The time at which the deploy's TTL expires.
+    pub expires: Timestamp,
+    /// The gas price of the deploy.
+    pub gas_price: u64,
+    /// The dependencies of the deploy which have not been finalized yet.
+    pub unmet_dependencies: Vec<DeployHash>,
+}
+
+/// A page of the deploys pending inclusion in a block.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq)]
+pub struct PendingDeploysPage {
+    /// The pending deploys, in ascending deploy hash order.
+    pub deploys: Vec<PendingDeploy>,
+    /// The hash of the last deploy of this page if further deploys follow, to pass as the cursor
+    /// when requesting the next page.
+    pub next_cursor: Option<DeployHash>,
+}
+
+/// The status of a deploy, as known by this node.
+#[derive(Clone, Copy, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum DeployStatus {
+    /// The deploy is not known.
+    Unknown,
+    /// The deploy is waiting to be included in a block.
+    Pending,
+    /// The deploy is included in a proto block which has not been finalized yet.
+    ///
+    /// Proto blocks are only tracked by validators, as of the last proto block they proposed
+    /// themselves.  Other nodes report such deploys as `Pending` until they are finalized.
+    InProtoBlock,
+    /// The deploy is included in a finalized block.
+    Finalized,
+    /// The deploy expired before being included in a finalized block.
+    Expired,
+}
+
+impl Display for DeployStatus {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DeployStatus::Unknown => write!(formatter, "unknown"),
+            DeployStatus::Pending => write!(formatter, "pending"),
+            DeployStatus::InProtoBlock => write!(formatter, "in proto block"),
+            DeployStatus::Finalized => write!(formatter, "finalized"),
+            DeployStatus::Expired => write!(formatter, "expired"),
+        }
+    }
+}
@@ -57,3 +57,10 @@ impl From<&ExecutionEngineAccount> for Account {
         }
     }
 }
+
+impl Account {
+    /// Returns the named keys of the account.
+    pub fn named_keys(&self) -> &[NamedKey] {
+        &self.named_keys
+    }
+}
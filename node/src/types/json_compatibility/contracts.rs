@@ -67,6 +67,13 @@ impl From<&DomainContract> for Contract {
     }
 }
 
+impl Contract {
+    /// Returns the named keys of the contract.
+    pub fn named_keys(&self) -> &[NamedKey] {
+        &self.named_keys
+    }
+}
+
 /// Contract definition, metadata, and security container.
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, DataSize, JsonSchema)]
 #[serde(deny_unknown_fields)]
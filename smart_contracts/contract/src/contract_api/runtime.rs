@@ -12,7 +12,8 @@ use casper_types::{
     bytesrepr::{self, FromBytes},
     contracts::{ContractVersion, NamedKeys},
     ApiError, BlockTime, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, Phase,
-    RuntimeArgs, URef, BLAKE2B_DIGEST_LENGTH, BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
+    PublicKey, RuntimeArgs, Signature, URef, BLAKE2B_DIGEST_LENGTH, BLOCKTIME_SERIALIZED_LENGTH,
+    PHASE_SERIALIZED_LENGTH,
 };
 
 use crate::{contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
@@ -320,6 +321,86 @@ pub fn blake2b<T: AsRef<[u8]>>(input: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
     ret
 }
 
+/// The length in bytes of a SHA-256 digest.
+pub const SHA256_DIGEST_LENGTH: usize = 32;
+
+/// The length in bytes of a Keccak-256 digest.
+pub const KECCAK256_DIGEST_LENGTH: usize = 32;
+
+/// Returns a 32-byte SHA-256 digest
+pub fn sha256<T: AsRef<[u8]>>(input: T) -> [u8; SHA256_DIGEST_LENGTH] {
+    let mut ret = [0; SHA256_DIGEST_LENGTH];
+    let result = unsafe {
+        ext_ffi::casper_sha256(
+            input.as_ref().as_ptr(),
+            input.as_ref().len(),
+            ret.as_mut_ptr(),
+            SHA256_DIGEST_LENGTH,
+        )
+    };
+    api_error::result_from(result).unwrap_or_revert();
+    ret
+}
+
+/// Returns a 32-byte Keccak-256 digest, as used by Ethereum
+pub fn keccak256<T: AsRef<[u8]>>(input: T) -> [u8; KECCAK256_DIGEST_LENGTH] {
+    let mut ret = [0; KECCAK256_DIGEST_LENGTH];
+    let result = unsafe {
+        ext_ffi::casper_keccak256(
+            input.as_ref().as_ptr(),
+            input.as_ref().len(),
+            ret.as_mut_ptr(),
+            KECCAK256_DIGEST_LENGTH,
+        )
+    };
+    api_error::result_from(result).unwrap_or_revert();
+    ret
+}
+
+/// Verifies the signature of the given message against the given public key.
+///
+/// Returns `true` if the signature is valid, and `false` otherwise.
+pub fn verify_signature<T: AsRef<[u8]>>(
+    public_key: &PublicKey,
+    message: T,
+    signature: &Signature,
+) -> bool {
+    let (public_key_ptr, public_key_size, _public_key_bytes) = contract_api::to_ptr(*public_key);
+    let (signature_ptr, signature_size, _signature_bytes) = contract_api::to_ptr(*signature);
+    let result = unsafe {
+        ext_ffi::casper_verify_signature(
+            public_key_ptr,
+            public_key_size,
+            message.as_ref().as_ptr(),
+            message.as_ref().len(),
+            signature_ptr,
+            signature_size,
+        )
+    };
+    match api_error::result_from(result) {
+        Ok(()) => true,
+        Err(ApiError::InvalidSignature) => false,
+        Err(error) => revert(error),
+    }
+}
+
+/// Emits a message with the given topic and payload.
+///
+/// The message is recorded in the execution results of the current deploy.  Messages emitted by
+/// session code which fails are discarded along with its other effects.  The topic must not be
+/// empty.
+pub fn emit_message<T: AsRef<[u8]>>(topic: &str, payload: T) -> Result<(), ApiError> {
+    let result = unsafe {
+        ext_ffi::casper_emit_message(
+            topic.as_ptr(),
+            topic.len(),
+            payload.as_ref().as_ptr(),
+            payload.as_ref().len(),
+        )
+    };
+    api_error::result_from(result)
+}
+
 fn read_host_buffer_into(dest: &mut [u8]) -> Result<usize, ApiError> {
     let mut bytes_written = MaybeUninit::uninit();
     let ret = unsafe {
@@ -86,6 +86,84 @@ pub fn new_uref<T: CLTyped + ToBytes>(init: T) -> URef {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Creates a new dictionary and stores its seed [`URef`] in the current context's named keys under
+/// `name`.  Returns [`ApiError::DuplicateKey`] if `name` is empty or already in use.
+pub fn new_dictionary(name: &str) -> Result<URef, ApiError> {
+    if name.is_empty() || runtime::has_key(name) {
+        return Err(ApiError::DuplicateKey);
+    }
+
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::casper_new_dictionary(value_size.as_mut_ptr()) };
+        api_error::result_from(ret)?;
+        unsafe { value_size.assume_init() }
+    };
+    let value_bytes = runtime::read_host_buffer(value_size)?;
+    let seed_uref: URef = bytesrepr::deserialize(value_bytes)?;
+    runtime::put_key(name, Key::from(seed_uref));
+    Ok(seed_uref)
+}
+
+/// Reads the item under `dictionary_item_key` from the dictionary identified by `seed_uref`.
+///
+/// Returns `Ok(None)` if the dictionary has no such item.  The key may be at most
+/// [`DICTIONARY_ITEM_KEY_MAX_LENGTH`](casper_types::DICTIONARY_ITEM_KEY_MAX_LENGTH) bytes long.
+pub fn dictionary_get<V: CLTyped + FromBytes>(
+    seed_uref: URef,
+    dictionary_item_key: &str,
+) -> Result<Option<V>, ApiError> {
+    let (uref_ptr, uref_size, _bytes) = contract_api::to_ptr(seed_uref);
+
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::casper_dictionary_get(
+                uref_ptr,
+                uref_size,
+                dictionary_item_key.as_ptr(),
+                dictionary_item_key.len(),
+                value_size.as_mut_ptr(),
+            )
+        };
+        match api_error::result_from(ret) {
+            Ok(_) => unsafe { value_size.assume_init() },
+            Err(ApiError::ValueNotFound) => return Ok(None),
+            Err(e) => return Err(e),
+        }
+    };
+
+    let value_bytes = runtime::read_host_buffer(value_size)?;
+    Ok(Some(bytesrepr::deserialize(value_bytes)?))
+}
+
+/// Writes `value` under `dictionary_item_key` to the dictionary identified by `seed_uref`.
+///
+/// The key may be at most
+/// [`DICTIONARY_ITEM_KEY_MAX_LENGTH`](casper_types::DICTIONARY_ITEM_KEY_MAX_LENGTH) bytes long.
+pub fn dictionary_put<V: CLTyped + ToBytes>(
+    seed_uref: URef,
+    dictionary_item_key: &str,
+    value: V,
+) -> Result<(), ApiError> {
+    let (uref_ptr, uref_size, _bytes1) = contract_api::to_ptr(seed_uref);
+
+    let cl_value = CLValue::from_t(value)?;
+    let (cl_value_ptr, cl_value_size, _bytes2) = contract_api::to_ptr(cl_value);
+
+    let ret = unsafe {
+        ext_ffi::casper_dictionary_put(
+            uref_ptr,
+            uref_size,
+            dictionary_item_key.as_ptr(),
+            dictionary_item_key.len(),
+            cl_value_ptr,
+            cl_value_size,
+        )
+    };
+    api_error::result_from(ret)
+}
+
 /// Create a new contract stored under a Key::Hash at version 1. You may upgrade this contract in
 /// the future; if you want a contract that is locked (i.e. cannot be upgraded) call
 /// `new_locked_contract` instead.
@@ -700,6 +700,111 @@ extern "C" {
         out_ptr: *mut u8,
         out_size: usize,
     ) -> i32;
+    /// Emits a message with the given topic and payload, which is recorded in the execution
+    /// results of the current deploy.
+    ///
+    /// # Arguments
+    ///
+    /// * `topic_ptr` - pointer to the UTF-8 encoded topic name
+    /// * `topic_size` - size of the topic name
+    /// * `payload_ptr` - pointer to the message payload
+    /// * `payload_size` - size of the message payload
+    pub fn casper_emit_message(
+        topic_ptr: *const u8,
+        topic_size: usize,
+        payload_ptr: *const u8,
+        payload_size: usize,
+    ) -> i32;
+    /// Verifies the signature of the given message against the given public key.  Returns `0` if
+    /// the signature is valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `public_key_ptr` - pointer to the serialized public key
+    /// * `public_key_size` - size of the serialized public key
+    /// * `message_ptr` - pointer to the signed message bytes
+    /// * `message_size` - size of the signed message
+    /// * `signature_ptr` - pointer to the serialized signature
+    /// * `signature_size` - size of the serialized signature
+    pub fn casper_verify_signature(
+        public_key_ptr: *const u8,
+        public_key_size: usize,
+        message_ptr: *const u8,
+        message_size: usize,
+        signature_ptr: *const u8,
+        signature_size: usize,
+    ) -> i32;
+    /// Returns a 32-byte SHA-256 hash digest from the given input bytes
+    ///
+    /// # Arguments
+    /// * `in_ptr` - pointer to bytes
+    /// * `in_size` - length of bytes
+    /// * `out_ptr` - pointer to the location where the digest will be copied from the host side
+    /// * `out_size` - size of output pointer
+    pub fn casper_sha256(
+        in_ptr: *const u8,
+        in_size: usize,
+        out_ptr: *mut u8,
+        out_size: usize,
+    ) -> i32;
+    /// Returns a 32-byte Keccak-256 hash digest from the given input bytes
+    ///
+    /// # Arguments
+    /// * `in_ptr` - pointer to bytes
+    /// * `in_size` - length of bytes
+    /// * `out_ptr` - pointer to the location where the digest will be copied from the host side
+    /// * `out_size` - size of output pointer
+    pub fn casper_keccak256(
+        in_ptr: *const u8,
+        in_size: usize,
+        out_ptr: *mut u8,
+        out_size: usize,
+    ) -> i32;
+    /// Creates a new dictionary, identified by a new seed [`URef`](casper_types::URef).  The
+    /// serialized seed `URef` is written to the host buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_size_ptr` - pointer to a value where the size of the serialized seed `URef` will
+    ///   be written
+    pub fn casper_new_dictionary(output_size_ptr: *mut usize) -> i32;
+    /// Reads the item with the given key from the dictionary identified by the given seed
+    /// [`URef`](casper_types::URef).  The serialized value is written to the host buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to the serialized seed `URef`
+    /// * `uref_size` - size of the serialized seed `URef`
+    /// * `key_bytes_ptr` - pointer to the UTF-8 encoded dictionary item key
+    /// * `key_bytes_size` - size of the dictionary item key
+    /// * `output_size_ptr` - pointer to a value where the size of the serialized value will be
+    ///   written
+    pub fn casper_dictionary_get(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        key_bytes_ptr: *const u8,
+        key_bytes_size: usize,
+        output_size_ptr: *mut usize,
+    ) -> i32;
+    /// Writes the given value under the given key to the dictionary identified by the given seed
+    /// [`URef`](casper_types::URef).
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to the serialized seed `URef`
+    /// * `uref_size` - size of the serialized seed `URef`
+    /// * `key_bytes_ptr` - pointer to the UTF-8 encoded dictionary item key
+    /// * `key_bytes_size` - size of the dictionary item key
+    /// * `value_ptr` - pointer to the serialized [`CLValue`](casper_types::CLValue) to write
+    /// * `value_size` - size of the serialized `CLValue`
+    pub fn casper_dictionary_put(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        key_bytes_ptr: *const u8,
+        key_bytes_size: usize,
+        value_ptr: *const u8,
+        value_size: usize,
+    ) -> i32;
     /// Prints data directly to stanadard output on the host.
     ///
     /// # Arguments
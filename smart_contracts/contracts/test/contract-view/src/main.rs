@@ -0,0 +1,85 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys},
+    CLType, CLValue, EntryPointAccess, EntryPointType, URef,
+};
+
+const ENTRY_POINT_GET_COUNT: &str = "get_count";
+const ENTRY_POINT_INCREMENT: &str = "increment";
+const COUNT_KEY_NAME: &str = "count";
+const HASH_KEY_NAME: &str = "contract_view_hash";
+const PACKAGE_HASH_KEY_NAME: &str = "contract_view_package_hash";
+const INITIAL_COUNT: u64 = 7;
+
+fn count_uref() -> URef {
+    runtime::get_key(COUNT_KEY_NAME)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+fn read_count() -> u64 {
+    storage::read(count_uref())
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+#[no_mangle]
+pub extern "C" fn get_count() {
+    let count = read_count();
+    runtime::ret(CLValue::from_t(count).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn increment() {
+    let count = read_count() + 1;
+    storage::write(count_uref(), count);
+    runtime::ret(CLValue::from_t(count).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_GET_COUNT.to_string(),
+            Vec::new(),
+            CLType::U64,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_INCREMENT.to_string(),
+            Vec::new(),
+            CLType::U64,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points
+    };
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        COUNT_KEY_NAME.to_string(),
+        storage::new_uref(INITIAL_COUNT).into(),
+    );
+
+    let (contract_hash, _contract_version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(PACKAGE_HASH_KEY_NAME.to_string()),
+        None,
+    );
+
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
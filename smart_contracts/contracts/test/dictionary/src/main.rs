@@ -0,0 +1,53 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ApiError, URef};
+
+const DICTIONARY_NAME: &str = "dictionary";
+const ARG_ITEM_KEY: &str = "item_key";
+const ARG_VALUE: &str = "value";
+const ARG_READ_ONLY: &str = "read_only";
+
+#[repr(u16)]
+enum Error {
+    UnexpectedValue = 0,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let item_key: String = runtime::get_named_arg(ARG_ITEM_KEY);
+    let value: u64 = runtime::get_named_arg(ARG_VALUE);
+    let read_only: bool = runtime::get_named_arg(ARG_READ_ONLY);
+
+    let seed_uref: URef = match runtime::get_key(DICTIONARY_NAME) {
+        Some(key) => key.into_uref().unwrap_or_revert(),
+        None => storage::new_dictionary(DICTIONARY_NAME).unwrap_or_revert(),
+    };
+    let seed_uref = if read_only {
+        seed_uref.into_read()
+    } else {
+        seed_uref
+    };
+
+    storage::dictionary_put(seed_uref, &item_key, value).unwrap_or_revert();
+
+    let stored_value: Option<u64> =
+        storage::dictionary_get(seed_uref, &item_key).unwrap_or_revert();
+    if stored_value != Some(value) {
+        runtime::revert(Error::UnexpectedValue);
+    }
+}
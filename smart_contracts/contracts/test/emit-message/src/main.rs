@@ -0,0 +1,26 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::ApiError;
+
+const ARG_TOPIC: &str = "topic";
+const ARG_PAYLOAD: &str = "payload";
+const ARG_REVERT: &str = "revert";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let topic: String = runtime::get_named_arg(ARG_TOPIC);
+    let payload: Vec<u8> = runtime::get_named_arg(ARG_PAYLOAD);
+    let revert: bool = runtime::get_named_arg(ARG_REVERT);
+
+    runtime::emit_message(&topic, payload).unwrap_or_revert();
+
+    if revert {
+        runtime::revert(ApiError::User(0));
+    }
+}
@@ -0,0 +1,26 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use casper_contract::contract_api::{runtime, storage};
+
+const ARG_BYTES: &str = "bytes";
+
+const SHA256_RESULT: &str = "sha256_result";
+const KECCAK256_RESULT: &str = "keccak256_result";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let bytes: Vec<u8> = runtime::get_named_arg(ARG_BYTES);
+
+    let sha256 = runtime::sha256(&bytes);
+    let uref = storage::new_uref(sha256);
+    runtime::put_key(SHA256_RESULT, uref.into());
+
+    let keccak256 = runtime::keccak256(&bytes);
+    let uref = storage::new_uref(keccak256);
+    runtime::put_key(KECCAK256_RESULT, uref.into());
+}
@@ -191,6 +191,15 @@ const AUCTION_ERROR_MAX: u32 = AUCTION_ERROR_OFFSET + u8::MAX as u32;
 /// # show_and_check!(
 /// 34 => HostBufferFull
 /// # );
+/// # show_and_check!(
+/// 35 => AllocLayout
+/// # );
+/// # show_and_check!(
+/// 36 => InvalidSignature
+/// # );
+/// # show_and_check!(
+/// 37 => DictionaryItemKeyExceedsLength
+/// # );
 /// // Auction errors:
 /// use casper_types::system::auction::Error as AuctionError;
 /// # show_and_check!(
@@ -536,6 +545,10 @@ pub enum ApiError {
     HostBufferFull,
     /// Could not lay out an array in memory
     AllocLayout,
+    /// A signature could not be verified against the given public key and message.
+    InvalidSignature,
+    /// The key of a dictionary item exceeds the maximum allowed length.
+    DictionaryItemKeyExceedsLength,
     /// Error specific to Auction contract.
     AuctionError(u8),
     /// Contract header errors.
@@ -686,6 +699,8 @@ impl From<ApiError> for u32 {
             ApiError::HostBufferEmpty => 33,
             ApiError::HostBufferFull => 34,
             ApiError::AllocLayout => 35,
+            ApiError::InvalidSignature => 36,
+            ApiError::DictionaryItemKeyExceedsLength => 37,
             ApiError::AuctionError(value) => AUCTION_ERROR_OFFSET + u32::from(value),
             ApiError::ContractHeader(value) => HEADER_ERROR_OFFSET + u32::from(value),
             ApiError::Mint(value) => MINT_ERROR_OFFSET + u32::from(value),
@@ -733,6 +748,8 @@ impl From<u32> for ApiError {
             33 => ApiError::HostBufferEmpty,
             34 => ApiError::HostBufferFull,
             35 => ApiError::AllocLayout,
+            36 => ApiError::InvalidSignature,
+            37 => ApiError::DictionaryItemKeyExceedsLength,
             USER_ERROR_MIN..=USER_ERROR_MAX => ApiError::User(value as u16),
             POS_ERROR_MIN..=POS_ERROR_MAX => ApiError::ProofOfStake(value as u8),
             MINT_ERROR_MIN..=MINT_ERROR_MAX => ApiError::Mint(value as u8),
@@ -783,6 +800,10 @@ impl Debug for ApiError {
             ApiError::HostBufferEmpty => write!(f, "ApiError::HostBufferEmpty")?,
             ApiError::HostBufferFull => write!(f, "ApiError::HostBufferFull")?,
             ApiError::AllocLayout => write!(f, "ApiError::AllocLayout")?,
+            ApiError::InvalidSignature => write!(f, "ApiError::InvalidSignature")?,
+            ApiError::DictionaryItemKeyExceedsLength => {
+                write!(f, "ApiError::DictionaryItemKeyExceedsLength")?
+            }
             ApiError::AuctionError(value) => write!(f, "ApiError::AuctionError({})", value)?,
             ApiError::ContractHeader(value) => write!(f, "ApiError::ContractHeader({})", value)?,
             ApiError::Mint(value) => write!(f, "ApiError::Mint({})", value)?,
@@ -948,6 +969,8 @@ mod tests {
         round_trip(Err(ApiError::HostBufferEmpty));
         round_trip(Err(ApiError::HostBufferFull));
         round_trip(Err(ApiError::AllocLayout));
+        round_trip(Err(ApiError::InvalidSignature));
+        round_trip(Err(ApiError::DictionaryItemKeyExceedsLength));
         round_trip(Err(ApiError::ContractHeader(0)));
         round_trip(Err(ApiError::ContractHeader(u8::MAX)));
         round_trip(Err(ApiError::Mint(0)));
@@ -16,7 +16,10 @@ use core::{
 use datasize::DataSize;
 use ed25519_dalek::ed25519::signature::Signature as _Signature;
 use hex_fmt::HexFmt;
-use k256::{self, ecdsa};
+use k256::{
+    self,
+    ecdsa::{self, signature::Verifier as _Verifier},
+};
 #[cfg(feature = "std")]
 use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -817,6 +820,48 @@ impl JsonSchema for Signature {
     }
 }
 
+/// Verifies the signature of the given message against the given public key.
+pub fn verify<T: AsRef<[u8]>>(
+    message: T,
+    signature: &Signature,
+    public_key: &PublicKey,
+) -> Result<(), Error> {
+    match (signature, public_key) {
+        (Signature::System, _) => Err(Error::AsymmetricKey(String::from(
+            "signatures based on the system key cannot be verified",
+        ))),
+        (Signature::Ed25519(signature), PublicKey::Ed25519(public_key)) => public_key
+            .verify_strict(
+                message.as_ref(),
+                &ed25519_dalek::Signature::from_bytes(signature).map_err(|_| {
+                    Error::AsymmetricKey(format!(
+                        "failed to construct Ed25519 signature from {:?}",
+                        &signature[..]
+                    ))
+                })?,
+            )
+            .map_err(|_| Error::AsymmetricKey(String::from("failed to verify Ed25519 signature"))),
+        (Signature::Secp256k1(signature), PublicKey::Secp256k1(pub_key)) => {
+            let verifier = ecdsa::Verifier::new(pub_key).map_err(|error| {
+                Error::AsymmetricKey(format!(
+                    "failed to create secp256k1 verifier from {}: {}",
+                    public_key, error
+                ))
+            })?;
+
+            verifier
+                .verify(message.as_ref(), signature)
+                .map_err(|error| {
+                    Error::AsymmetricKey(format!("failed to verify secp256k1 signature: {}", error))
+                })
+        }
+        _ => Err(Error::AsymmetricKey(format!(
+            "type mismatch between {} and {}",
+            signature, public_key
+        ))),
+    }
+}
+
 mod detail {
     use alloc::string::String;
 
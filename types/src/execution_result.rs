@@ -8,6 +8,7 @@
 #![allow(clippy::field_reassign_with_default)]
 
 use alloc::{
+    boxed::Box,
     format,
     string::{String, ToString},
     vec,
@@ -30,13 +31,18 @@ use crate::KEY_HASH_LENGTH;
 use crate::{
     account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
-    system::auction::EraInfo,
+    system::auction::{Bid, EraInfo, UnbondingPurse},
     CLValue, DeployInfo, NamedKey, Transfer, TransferAddr, U128, U256, U512,
 };
 
 /// Constants to track ExecutionResult serialization.
+///
+/// Results whose effect contains no messages are serialized with the original tags and without
+/// the messages, exactly as before messages were added, so that such encodings remain unchanged.
 const EXECUTION_RESULT_FAILURE_TAG: u8 = 0;
 const EXECUTION_RESULT_SUCCESS_TAG: u8 = 1;
+const EXECUTION_RESULT_FAILURE_WITH_MESSAGES_TAG: u8 = 2;
+const EXECUTION_RESULT_SUCCESS_WITH_MESSAGES_TAG: u8 = 3;
 
 /// Constants to track operation serialization.
 const OP_READ_TAG: u8 = 0;
@@ -61,6 +67,9 @@ const TRANSFORM_ADD_UINT256_TAG: u8 = 12;
 const TRANSFORM_ADD_UINT512_TAG: u8 = 13;
 const TRANSFORM_ADD_KEYS_TAG: u8 = 14;
 const TRANSFORM_FAILURE_TAG: u8 = 15;
+const TRANSFORM_WRITE_BID_TAG: u8 = 16;
+const TRANSFORM_WRITE_WITHDRAW_TAG: u8 = 17;
+const TRANSFORM_PRUNE_TAG: u8 = 18;
 
 #[cfg(feature = "std")]
 static EXECUTION_RESULT: Lazy<ExecutionResult> = Lazy::new(|| {
@@ -86,9 +95,17 @@ static EXECUTION_RESULT: Lazy<ExecutionResult> = Lazy::new(|| {
         transform: Transform::Identity,
     });
 
+    let messages = vec![Message {
+        emitter: "hash-2c4a11c062a8a337bfc97e27fd66291caeb2c65865dcb5d3ef3759c4c97efecb"
+            .to_string(),
+        topic: "transfer".to_string(),
+        payload: "0400e1f505".to_string(),
+    }];
+
     let effect = ExecutionEffect {
         operations,
         transforms,
+        messages,
     };
 
     let transfers = vec![
@@ -162,9 +179,16 @@ impl Distribution<ExecutionResult> for Standard {
             });
         }
 
+        let message_count = rng.gen_range(0, 6);
+        let mut messages = Vec::new();
+        for _ in 0..message_count {
+            messages.push(rng.gen());
+        }
+
         let effect = ExecutionEffect {
             operations,
             transforms,
+            messages,
         };
 
         let transfer_count = rng.gen_range(0, 6);
@@ -200,8 +224,13 @@ impl ToBytes for ExecutionResult {
                 cost,
                 error_message,
             } => {
-                buffer.push(EXECUTION_RESULT_FAILURE_TAG);
-                buffer.extend(effect.to_bytes()?);
+                if effect.messages.is_empty() {
+                    buffer.push(EXECUTION_RESULT_FAILURE_TAG);
+                    buffer.extend(effect.to_legacy_bytes()?);
+                } else {
+                    buffer.push(EXECUTION_RESULT_FAILURE_WITH_MESSAGES_TAG);
+                    buffer.extend(effect.to_bytes()?);
+                }
                 buffer.extend(transfers.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
                 buffer.extend(error_message.to_bytes()?);
@@ -211,8 +240,13 @@ impl ToBytes for ExecutionResult {
                 transfers,
                 cost,
             } => {
-                buffer.push(EXECUTION_RESULT_SUCCESS_TAG);
-                buffer.extend(effect.to_bytes()?);
+                if effect.messages.is_empty() {
+                    buffer.push(EXECUTION_RESULT_SUCCESS_TAG);
+                    buffer.extend(effect.to_legacy_bytes()?);
+                } else {
+                    buffer.push(EXECUTION_RESULT_SUCCESS_WITH_MESSAGES_TAG);
+                    buffer.extend(effect.to_bytes()?);
+                }
                 buffer.extend(transfers.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
             }
@@ -229,7 +263,7 @@ impl ToBytes for ExecutionResult {
                     cost,
                     error_message,
                 } => {
-                    effect.serialized_length()
+                    effect.tagged_serialized_length()
                         + transfers.serialized_length()
                         + cost.serialized_length()
                         + error_message.serialized_length()
@@ -239,7 +273,7 @@ impl ToBytes for ExecutionResult {
                     transfers,
                     cost,
                 } => {
-                    effect.serialized_length()
+                    effect.tagged_serialized_length()
                         + transfers.serialized_length()
                         + cost.serialized_length()
                 }
@@ -250,9 +284,16 @@ impl ToBytes for ExecutionResult {
 impl FromBytes for ExecutionResult {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (tag, remainder) = u8::from_bytes(bytes)?;
+        let (effect, remainder) = match tag {
+            EXECUTION_RESULT_FAILURE_TAG | EXECUTION_RESULT_SUCCESS_TAG => {
+                ExecutionEffect::from_legacy_bytes(remainder)?
+            }
+            EXECUTION_RESULT_FAILURE_WITH_MESSAGES_TAG
+            | EXECUTION_RESULT_SUCCESS_WITH_MESSAGES_TAG => ExecutionEffect::from_bytes(remainder)?,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
         match tag {
-            EXECUTION_RESULT_FAILURE_TAG => {
-                let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
+            EXECUTION_RESULT_FAILURE_TAG | EXECUTION_RESULT_FAILURE_WITH_MESSAGES_TAG => {
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
                 let (error_message, remainder) = String::from_bytes(remainder)?;
@@ -264,8 +305,7 @@ impl FromBytes for ExecutionResult {
                 };
                 Ok((execution_result, remainder))
             }
-            EXECUTION_RESULT_SUCCESS_TAG => {
-                let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
+            _ => {
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
                 let execution_result = ExecutionResult::Success {
@@ -275,7 +315,6 @@ impl FromBytes for ExecutionResult {
                 };
                 Ok((execution_result, remainder))
             }
-            _ => Err(bytesrepr::Error::Formatting),
         }
     }
 }
@@ -289,6 +328,41 @@ pub struct ExecutionEffect {
     pub operations: Vec<Operation>,
     /// The resulting transformations.
     pub transforms: Vec<TransformEntry>,
+    /// The messages emitted by contracts, in the order in which they were emitted.
+    #[serde(default)]
+    pub messages: Vec<Message>,
+}
+
+impl ExecutionEffect {
+    /// Serializes the effect without its messages, as done before messages were added.
+    fn to_legacy_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.operations.to_bytes()?);
+        buffer.extend(self.transforms.to_bytes()?);
+        Ok(buffer)
+    }
+
+    /// Deserializes an effect serialized by `to_legacy_bytes`, which has no messages.
+    fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (operations, remainder) = Vec::<Operation>::from_bytes(bytes)?;
+        let (transforms, remainder) = Vec::<TransformEntry>::from_bytes(remainder)?;
+        let execution_effect = ExecutionEffect {
+            operations,
+            transforms,
+            messages: Vec::new(),
+        };
+        Ok((execution_effect, remainder))
+    }
+
+    /// The serialized length of the effect within an `ExecutionResult`, which omits the messages
+    /// if there are none.
+    fn tagged_serialized_length(&self) -> usize {
+        if self.messages.is_empty() {
+            self.operations.serialized_length() + self.transforms.serialized_length()
+        } else {
+            self.serialized_length()
+        }
+    }
 }
 
 impl ToBytes for ExecutionEffect {
@@ -296,11 +370,14 @@ impl ToBytes for ExecutionEffect {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
         buffer.extend(self.operations.to_bytes()?);
         buffer.extend(self.transforms.to_bytes()?);
+        buffer.extend(self.messages.to_bytes()?);
         Ok(buffer)
     }
 
     fn serialized_length(&self) -> usize {
-        self.operations.serialized_length() + self.transforms.serialized_length()
+        self.operations.serialized_length()
+            + self.transforms.serialized_length()
+            + self.messages.serialized_length()
     }
 }
 
@@ -308,14 +385,71 @@ impl FromBytes for ExecutionEffect {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (operations, remainder) = Vec::<Operation>::from_bytes(bytes)?;
         let (transforms, remainder) = Vec::<TransformEntry>::from_bytes(remainder)?;
+        let (messages, remainder) = Vec::<Message>::from_bytes(remainder)?;
         let execution_effect = ExecutionEffect {
             operations,
             transforms,
+            messages,
         };
         Ok((execution_effect, remainder))
     }
 }
 
+/// A message emitted by a contract while executing a deploy.
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct Message {
+    /// The formatted string of the `Key` of the account or contract which emitted the message.
+    pub emitter: String,
+    /// The topic of the message.
+    pub topic: String,
+    /// The hex-encoded payload of the message.
+    pub payload: String,
+}
+
+impl ToBytes for Message {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.emitter.to_bytes()?);
+        buffer.extend(self.topic.to_bytes()?);
+        buffer.extend(self.payload.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.emitter.serialized_length()
+            + self.topic.serialized_length()
+            + self.payload.serialized_length()
+    }
+}
+
+impl FromBytes for Message {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (emitter, remainder) = String::from_bytes(bytes)?;
+        let (topic, remainder) = String::from_bytes(remainder)?;
+        let (payload, remainder) = String::from_bytes(remainder)?;
+        let message = Message {
+            emitter,
+            topic,
+            payload,
+        };
+        Ok((message, remainder))
+    }
+}
+
+impl Distribution<Message> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Message {
+        let payload_size = rng.gen_range(0, 32);
+        let payload: Vec<u8> = (0..payload_size).map(|_| rng.gen()).collect();
+        Message {
+            emitter: rng.gen::<u64>().to_string(),
+            topic: format!("topic {}", rng.gen::<u64>()),
+            payload: base16::encode_lower(&payload),
+        }
+    }
+}
+
 /// An operation performed while executing a deploy.
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -462,6 +596,12 @@ pub enum Transform {
     AddKeys(Vec<NamedKey>),
     /// A failed transformation, containing an error message.
     Failure(String),
+    /// Writes the given Bid to global state.
+    WriteBid(Box<Bid>),
+    /// Writes the given Withdraw to global state.
+    WriteWithdraw(Vec<UnbondingPurse>),
+    /// Removes the value from global state.
+    Prune,
 }
 
 impl ToBytes for Transform {
@@ -494,6 +634,14 @@ impl ToBytes for Transform {
                 buffer.insert(0, TRANSFORM_WRITE_TRANSFER_TAG);
                 buffer.extend(transfer.to_bytes()?);
             }
+            Transform::WriteBid(bid) => {
+                buffer.insert(0, TRANSFORM_WRITE_BID_TAG);
+                buffer.extend(bid.to_bytes()?);
+            }
+            Transform::WriteWithdraw(unbonding_purses) => {
+                buffer.insert(0, TRANSFORM_WRITE_WITHDRAW_TAG);
+                buffer.extend(unbonding_purses.to_bytes()?);
+            }
             Transform::AddInt32(value) => {
                 buffer.insert(0, TRANSFORM_ADD_INT32_TAG);
                 buffer.extend(value.to_bytes()?);
@@ -522,6 +670,7 @@ impl ToBytes for Transform {
                 buffer.insert(0, TRANSFORM_FAILURE_TAG);
                 buffer.extend(value.to_bytes()?);
             }
+            Transform::Prune => buffer.insert(0, TRANSFORM_PRUNE_TAG),
         }
         Ok(buffer)
     }
@@ -533,6 +682,8 @@ impl ToBytes for Transform {
             Transform::WriteDeployInfo(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
             Transform::WriteEraInfo(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
             Transform::WriteTransfer(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
+            Transform::WriteBid(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
+            Transform::WriteWithdraw(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
             Transform::AddInt32(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
             Transform::AddUInt64(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
             Transform::AddUInt128(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
@@ -575,6 +726,14 @@ impl FromBytes for Transform {
                 let (transfer, remainder) = Transfer::from_bytes(remainder)?;
                 Ok((Transform::WriteTransfer(transfer), remainder))
             }
+            TRANSFORM_WRITE_BID_TAG => {
+                let (bid, remainder) = Bid::from_bytes(remainder)?;
+                Ok((Transform::WriteBid(Box::new(bid)), remainder))
+            }
+            TRANSFORM_WRITE_WITHDRAW_TAG => {
+                let (unbonding_purses, remainder) = Vec::<UnbondingPurse>::from_bytes(remainder)?;
+                Ok((Transform::WriteWithdraw(unbonding_purses), remainder))
+            }
             TRANSFORM_ADD_INT32_TAG => {
                 let (value_i32, remainder) = i32::from_bytes(remainder)?;
                 Ok((Transform::AddInt32(value_i32), remainder))
@@ -603,6 +762,7 @@ impl FromBytes for Transform {
                 let (value, remainder) = String::from_bytes(remainder)?;
                 Ok((Transform::Failure(value), remainder))
             }
+            TRANSFORM_PRUNE_TAG => Ok((Transform::Prune, remainder)),
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
@@ -611,7 +771,7 @@ impl FromBytes for Transform {
 impl Distribution<Transform> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Transform {
         // TODO - include WriteDeployInfo and WriteTransfer as options
-        match rng.gen_range(0, 13) {
+        match rng.gen_range(0, 14) {
             0 => Transform::Identity,
             1 => Transform::WriteCLValue(CLValue::from_t(true).unwrap()),
             2 => Transform::WriteAccount(AccountHash::new(rng.gen())),
@@ -634,6 +794,7 @@ impl Distribution<Transform> for Standard {
                 Transform::AddKeys(named_keys)
             }
             12 => Transform::Failure(rng.gen::<u64>().to_string()),
+            13 => Transform::Prune,
             _ => unreachable!(),
         }
     }
@@ -658,10 +819,55 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&transform);
     }
 
+    #[test]
+    fn bytesrepr_test_message() {
+        let mut rng = get_rng();
+        let message: Message = rng.gen();
+        bytesrepr::test_serialization_roundtrip(&message);
+    }
+
     #[test]
     fn bytesrepr_test_execution_result() {
         let mut rng = get_rng();
         let execution_result: ExecutionResult = rng.gen();
         bytesrepr::test_serialization_roundtrip(&execution_result);
     }
+
+    #[test]
+    fn bytesrepr_test_legacy_execution_result() {
+        let effect = ExecutionEffect {
+            operations: vec![Operation {
+                key:
+                    "account-hash-2c4a11c062a8a337bfc97e27fd66291caeb2c65865dcb5d3ef3759c4c97efecb"
+                        .to_string(),
+                kind: OpKind::Write,
+            }],
+            transforms: vec![TransformEntry {
+                key: "uref-2c4a11c062a8a337bfc97e27fd66291caeb2c65865dcb5d3ef3759c4c97efecb-007"
+                    .to_string(),
+                transform: Transform::AddUInt64(8u64),
+            }],
+            messages: vec![],
+        };
+        let transfers = vec![TransferAddr::new([1; 32])];
+        let cost = U512::from(123_456);
+
+        // The encoding used before messages were added to the execution effect.
+        let mut legacy_bytes = vec![EXECUTION_RESULT_SUCCESS_TAG];
+        legacy_bytes.extend(effect.operations.to_bytes().unwrap());
+        legacy_bytes.extend(effect.transforms.to_bytes().unwrap());
+        legacy_bytes.extend(transfers.to_bytes().unwrap());
+        legacy_bytes.extend(cost.to_bytes().unwrap());
+
+        let execution_result = ExecutionResult::Success {
+            effect,
+            transfers,
+            cost,
+        };
+        assert_eq!(
+            bytesrepr::deserialize::<ExecutionResult>(legacy_bytes.clone()).unwrap(),
+            execution_result
+        );
+        assert_eq!(execution_result.to_bytes().unwrap(), legacy_bytes);
+    }
 }
@@ -20,12 +20,12 @@ use rand::{
 use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
-    account::{self, AccountHash, TryFromSliceForAccountHashError},
+    account::{self, blake2b, AccountHash, TryFromSliceForAccountHashError},
     bytesrepr::{self, Error, FromBytes, ToBytes, U64_SERIALIZED_LENGTH},
     contract_wasm::ContractWasmHash,
     contracts::{ContractHash, ContractPackageHash},
     system::auction::EraId,
-    uref::{self, URef, UREF_SERIALIZED_LENGTH},
+    uref::{self, URef, URefAddr, UREF_ADDR_LENGTH, UREF_SERIALIZED_LENGTH},
     DeployHash, TransferAddr, DEPLOY_HASH_LENGTH, TRANSFER_ADDR_LENGTH,
 };
 
@@ -35,10 +35,18 @@ const UREF_ID: u8 = 2;
 const TRANSFER_ID: u8 = 3;
 const DEPLOY_INFO_ID: u8 = 4;
 const ERA_INFO_ID: u8 = 5;
+const DICTIONARY_ID: u8 = 6;
+const BID_ID: u8 = 7;
+const WITHDRAW_ID: u8 = 8;
+const BALANCE_ID: u8 = 9;
 
 const HASH_PREFIX: &str = "hash-";
 const DEPLOY_INFO_PREFIX: &str = "deploy-";
 const ERA_INFO_PREFIX: &str = "era-";
+const DICTIONARY_PREFIX: &str = "dictionary-";
+const BID_PREFIX: &str = "bid-";
+const WITHDRAW_PREFIX: &str = "withdraw-";
+const BALANCE_PREFIX: &str = "balance-";
 
 /// The number of bytes in a Blake2b hash
 pub const BLAKE2B_DIGEST_LENGTH: usize = 32;
@@ -48,6 +56,10 @@ pub const KEY_HASH_LENGTH: usize = 32;
 pub const KEY_TRANSFER_LENGTH: usize = TRANSFER_ADDR_LENGTH;
 /// The number of bytes in a [`Key::DeployInfo`].
 pub const KEY_DEPLOY_INFO_LENGTH: usize = DEPLOY_HASH_LENGTH;
+/// The number of bytes in a [`Key::Dictionary`].
+pub const KEY_DICTIONARY_LENGTH: usize = 32;
+/// The maximum length of a dictionary item key, in bytes.
+pub const DICTIONARY_ITEM_KEY_MAX_LENGTH: usize = 64;
 
 const KEY_ID_SERIALIZED_LENGTH: usize = 1;
 // u8 used to determine the ID
@@ -56,10 +68,44 @@ const KEY_UREF_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + UREF_SERIAL
 const KEY_TRANSFER_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_TRANSFER_LENGTH;
 const KEY_DEPLOY_INFO_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_DEPLOY_INFO_LENGTH;
 const KEY_ERA_INFO_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + U64_SERIALIZED_LENGTH;
+const KEY_DICTIONARY_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_DICTIONARY_LENGTH;
+const KEY_BALANCE_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + UREF_ADDR_LENGTH;
 
 /// An alias for [`Key`]s hash variant.
 pub type HashAddr = [u8; KEY_HASH_LENGTH];
 
+/// An alias for [`Key`]s dictionary variant.
+pub type DictionaryAddr = [u8; KEY_DICTIONARY_LENGTH];
+
+/// The type of a [`Key`], encoded as the first byte of its serialized form.
+///
+/// All keys of the same type share this byte as a prefix, so it can be used to find all keys of a
+/// given type in global state.
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+pub enum KeyTag {
+    /// The tag of a [`Key::Account`].
+    Account = ACCOUNT_ID,
+    /// The tag of a [`Key::Hash`].
+    Hash = HASH_ID,
+    /// The tag of a [`Key::URef`].
+    URef = UREF_ID,
+    /// The tag of a [`Key::Transfer`].
+    Transfer = TRANSFER_ID,
+    /// The tag of a [`Key::DeployInfo`].
+    DeployInfo = DEPLOY_INFO_ID,
+    /// The tag of a [`Key::EraInfo`].
+    EraInfo = ERA_INFO_ID,
+    /// The tag of a [`Key::Dictionary`].
+    Dictionary = DICTIONARY_ID,
+    /// The tag of a [`Key::Bid`].
+    Bid = BID_ID,
+    /// The tag of a [`Key::Withdraw`].
+    Withdraw = WITHDRAW_ID,
+    /// The tag of a [`Key::Balance`].
+    Balance = BALANCE_ID,
+}
+
 impl From<HashAddr> for Key {
     fn from(addr: HashAddr) -> Self {
         Key::Hash(addr)
@@ -84,6 +130,16 @@ pub enum Key {
     DeployInfo(DeployHash),
     /// A `Key` under which we store an era info.
     EraInfo(EraId),
+    /// A `Key` under which we store an item of a dictionary, addressed by the hash of the
+    /// dictionary's seed [`URef`] and the item's key.
+    Dictionary(DictionaryAddr),
+    /// A `Key` under which we store the bid of the validator with the given account hash.
+    Bid(AccountHash),
+    /// A `Key` under which we store the unbonding purses created from the bid of the validator
+    /// with the given account hash.
+    Withdraw(AccountHash),
+    /// A `Key` under which we store the balance of the purse with the given address.
+    Balance(URefAddr),
 }
 
 #[derive(Debug)]
@@ -160,6 +216,26 @@ impl Key {
             Key::Transfer(_) => String::from("Key::Transfer"),
             Key::DeployInfo(_) => String::from("Key::DeployInfo"),
             Key::EraInfo(_) => String::from("Key::EraInfo"),
+            Key::Dictionary(_) => String::from("Key::Dictionary"),
+            Key::Bid(_) => String::from("Key::Bid"),
+            Key::Withdraw(_) => String::from("Key::Withdraw"),
+            Key::Balance(_) => String::from("Key::Balance"),
+        }
+    }
+
+    /// Returns the type of `self`.
+    pub fn tag(&self) -> KeyTag {
+        match self {
+            Key::Account(_) => KeyTag::Account,
+            Key::Hash(_) => KeyTag::Hash,
+            Key::URef(_) => KeyTag::URef,
+            Key::Transfer(_) => KeyTag::Transfer,
+            Key::DeployInfo(_) => KeyTag::DeployInfo,
+            Key::EraInfo(_) => KeyTag::EraInfo,
+            Key::Dictionary(_) => KeyTag::Dictionary,
+            Key::Bid(_) => KeyTag::Bid,
+            Key::Withdraw(_) => KeyTag::Withdraw,
+            Key::Balance(_) => KeyTag::Balance,
         }
     }
 
@@ -168,6 +244,17 @@ impl Key {
         KEY_UREF_SERIALIZED_LENGTH
     }
 
+    /// Creates a [`Key::Dictionary`] under which the item with the given key of the dictionary
+    /// identified by `seed_uref` is stored.
+    ///
+    /// The access rights of `seed_uref` do not affect the resulting key.
+    pub fn dictionary(seed_uref: URef, dictionary_item_key: &[u8]) -> Key {
+        let mut preimage = Vec::with_capacity(UREF_ADDR_LENGTH + dictionary_item_key.len());
+        preimage.extend_from_slice(&seed_uref.addr());
+        preimage.extend_from_slice(dictionary_item_key);
+        Key::Dictionary(blake2b(preimage))
+    }
+
     /// If `self` is of type [`Key::URef`], returns `self` with the
     /// [`AccessRights`](crate::AccessRights) stripped from the wrapped [`URef`], otherwise
     /// returns `self` unmodified.
@@ -195,6 +282,18 @@ impl Key {
             Key::EraInfo(era_id) => {
                 format!("{}{}", ERA_INFO_PREFIX, era_id.to_string())
             }
+            Key::Dictionary(addr) => {
+                format!("{}{}", DICTIONARY_PREFIX, base16::encode_lower(addr))
+            }
+            Key::Bid(account_hash) => {
+                format!("{}{}", BID_PREFIX, base16::encode_lower(account_hash))
+            }
+            Key::Withdraw(account_hash) => {
+                format!("{}{}", WITHDRAW_PREFIX, base16::encode_lower(account_hash))
+            }
+            Key::Balance(addr) => {
+                format!("{}{}", BALANCE_PREFIX, base16::encode_lower(addr))
+            }
         }
     }
 
@@ -216,6 +315,22 @@ impl Key {
             Ok(Key::URef(uref))
         } else if let Some(era_id_str) = input.strip_prefix(ERA_INFO_PREFIX) {
             Ok(Key::EraInfo(u64::from_str(era_id_str)?))
+        } else if let Some(hex) = input.strip_prefix(DICTIONARY_PREFIX) {
+            Ok(Key::Dictionary(DictionaryAddr::try_from(
+                base16::decode(hex)?.as_ref(),
+            )?))
+        } else if let Some(hex) = input.strip_prefix(BID_PREFIX) {
+            Ok(Key::Bid(AccountHash::try_from(
+                base16::decode(hex)?.as_ref(),
+            )?))
+        } else if let Some(hex) = input.strip_prefix(WITHDRAW_PREFIX) {
+            Ok(Key::Withdraw(AccountHash::try_from(
+                base16::decode(hex)?.as_ref(),
+            )?))
+        } else if let Some(hex) = input.strip_prefix(BALANCE_PREFIX) {
+            Ok(Key::Balance(URefAddr::try_from(
+                base16::decode(hex)?.as_ref(),
+            )?))
         } else {
             Err(FromStrError::InvalidPrefix)
         }
@@ -256,6 +371,42 @@ impl Key {
         }
     }
 
+    /// Returns the inner bytes of `self` if `self` is of type [`Key::Dictionary`], otherwise
+    /// returns `None`.
+    pub fn into_dictionary(self) -> Option<DictionaryAddr> {
+        match self {
+            Key::Dictionary(addr) => Some(addr),
+            _ => None,
+        }
+    }
+
+    /// Returns the inner [`AccountHash`] if `self` is of type [`Key::Bid`], otherwise returns
+    /// `None`.
+    pub fn into_bid(self) -> Option<AccountHash> {
+        match self {
+            Key::Bid(account_hash) => Some(account_hash),
+            _ => None,
+        }
+    }
+
+    /// Returns the inner [`AccountHash`] if `self` is of type [`Key::Withdraw`], otherwise
+    /// returns `None`.
+    pub fn into_withdraw(self) -> Option<AccountHash> {
+        match self {
+            Key::Withdraw(account_hash) => Some(account_hash),
+            _ => None,
+        }
+    }
+
+    /// Returns the inner [`URefAddr`] if `self` is of type [`Key::Balance`], otherwise returns
+    /// `None`.
+    pub fn into_balance(self) -> Option<URefAddr> {
+        match self {
+            Key::Balance(addr) => Some(addr),
+            _ => None,
+        }
+    }
+
     /// Casts a [`Key::URef`] to a [`Key::Hash`]
     pub fn uref_to_hash(&self) -> Option<Key> {
         let uref = self.as_uref()?;
@@ -273,6 +424,10 @@ impl Display for Key {
             Key::Transfer(transfer_addr) => write!(f, "Key::Transfer({})", transfer_addr),
             Key::DeployInfo(addr) => write!(f, "Key::DeployInfo({})", HexFmt(addr.as_bytes())),
             Key::EraInfo(era_id) => write!(f, "Key::EraInfo({})", era_id),
+            Key::Dictionary(addr) => write!(f, "Key::Dictionary({})", HexFmt(addr)),
+            Key::Bid(account_hash) => write!(f, "Key::Bid({})", account_hash),
+            Key::Withdraw(account_hash) => write!(f, "Key::Withdraw({})", account_hash),
+            Key::Balance(addr) => write!(f, "Key::Balance({})", HexFmt(addr)),
         }
     }
 }
@@ -347,13 +502,29 @@ impl ToBytes for Key {
                 result.push(ERA_INFO_ID);
                 result.append(&mut era_id.to_bytes()?);
             }
+            Key::Dictionary(addr) => {
+                result.push(DICTIONARY_ID);
+                result.append(&mut addr.to_bytes()?);
+            }
+            Key::Bid(account_hash) => {
+                result.push(BID_ID);
+                result.append(&mut account_hash.to_bytes()?);
+            }
+            Key::Withdraw(account_hash) => {
+                result.push(WITHDRAW_ID);
+                result.append(&mut account_hash.to_bytes()?);
+            }
+            Key::Balance(addr) => {
+                result.push(BALANCE_ID);
+                result.append(&mut addr.to_bytes()?);
+            }
         }
         Ok(result)
     }
 
     fn serialized_length(&self) -> usize {
         match self {
-            Key::Account(account_hash) => {
+            Key::Account(account_hash) | Key::Bid(account_hash) | Key::Withdraw(account_hash) => {
                 KEY_ID_SERIALIZED_LENGTH + account_hash.serialized_length()
             }
             Key::Hash(_) => KEY_HASH_SERIALIZED_LENGTH,
@@ -361,6 +532,8 @@ impl ToBytes for Key {
             Key::Transfer(_) => KEY_TRANSFER_SERIALIZED_LENGTH,
             Key::DeployInfo(_) => KEY_DEPLOY_INFO_SERIALIZED_LENGTH,
             Key::EraInfo(_) => KEY_ERA_INFO_SERIALIZED_LENGTH,
+            Key::Dictionary(_) => KEY_DICTIONARY_SERIALIZED_LENGTH,
+            Key::Balance(_) => KEY_BALANCE_SERIALIZED_LENGTH,
         }
     }
 }
@@ -393,6 +566,22 @@ impl FromBytes for Key {
                 let (era_id, rem) = FromBytes::from_bytes(remainder)?;
                 Ok((Key::EraInfo(era_id), rem))
             }
+            DICTIONARY_ID => {
+                let (addr, rem) = FromBytes::from_bytes(remainder)?;
+                Ok((Key::Dictionary(addr), rem))
+            }
+            BID_ID => {
+                let (account_hash, rem) = AccountHash::from_bytes(remainder)?;
+                Ok((Key::Bid(account_hash), rem))
+            }
+            WITHDRAW_ID => {
+                let (account_hash, rem) = AccountHash::from_bytes(remainder)?;
+                Ok((Key::Withdraw(account_hash), rem))
+            }
+            BALANCE_ID => {
+                let (addr, rem) = FromBytes::from_bytes(remainder)?;
+                Ok((Key::Balance(addr), rem))
+            }
             _ => Err(Error::Formatting),
         }
     }
@@ -400,13 +589,17 @@ impl FromBytes for Key {
 
 impl Distribution<Key> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Key {
-        match rng.gen_range(0, 6) {
+        match rng.gen_range(0, 10) {
             0 => Key::Account(rng.gen()),
             1 => Key::Hash(rng.gen()),
             2 => Key::URef(rng.gen()),
             3 => Key::Transfer(rng.gen()),
             4 => Key::DeployInfo(rng.gen()),
             5 => Key::EraInfo(rng.gen()),
+            6 => Key::Dictionary(rng.gen()),
+            7 => Key::Bid(rng.gen()),
+            8 => Key::Withdraw(rng.gen()),
+            9 => Key::Balance(rng.gen()),
             _ => unreachable!(),
         }
     }
@@ -423,6 +616,10 @@ mod serde_helpers {
         Transfer(String),
         DeployInfo(String),
         EraInfo(String),
+        Dictionary(String),
+        Bid(String),
+        Withdraw(String),
+        Balance(String),
     }
 
     impl From<&Key> for HumanReadable {
@@ -435,6 +632,10 @@ mod serde_helpers {
                 Key::Transfer(_) => HumanReadable::Transfer(formatted_string),
                 Key::DeployInfo(_) => HumanReadable::DeployInfo(formatted_string),
                 Key::EraInfo(_) => HumanReadable::EraInfo(formatted_string),
+                Key::Dictionary(_) => HumanReadable::Dictionary(formatted_string),
+                Key::Bid(_) => HumanReadable::Bid(formatted_string),
+                Key::Withdraw(_) => HumanReadable::Withdraw(formatted_string),
+                Key::Balance(_) => HumanReadable::Balance(formatted_string),
             }
         }
     }
@@ -449,7 +650,11 @@ mod serde_helpers {
                 | HumanReadable::URef(formatted_string)
                 | HumanReadable::Transfer(formatted_string)
                 | HumanReadable::DeployInfo(formatted_string)
-                | HumanReadable::EraInfo(formatted_string) => {
+                | HumanReadable::EraInfo(formatted_string)
+                | HumanReadable::Dictionary(formatted_string)
+                | HumanReadable::Bid(formatted_string)
+                | HumanReadable::Withdraw(formatted_string)
+                | HumanReadable::Balance(formatted_string) => {
                     Key::from_formatted_str(&formatted_string)
                 }
             }
@@ -464,6 +669,10 @@ mod serde_helpers {
         Transfer(&'a TransferAddr),
         DeployInfo(&'a DeployHash),
         EraInfo(&'a u64),
+        Dictionary(&'a DictionaryAddr),
+        Bid(&'a AccountHash),
+        Withdraw(&'a AccountHash),
+        Balance(&'a URefAddr),
     }
 
     impl<'a> From<&'a Key> for BinarySerHelper<'a> {
@@ -475,6 +684,10 @@ mod serde_helpers {
                 Key::Transfer(transfer_addr) => BinarySerHelper::Transfer(transfer_addr),
                 Key::DeployInfo(deploy_hash) => BinarySerHelper::DeployInfo(deploy_hash),
                 Key::EraInfo(era_id) => BinarySerHelper::EraInfo(era_id),
+                Key::Dictionary(addr) => BinarySerHelper::Dictionary(addr),
+                Key::Bid(account_hash) => BinarySerHelper::Bid(account_hash),
+                Key::Withdraw(account_hash) => BinarySerHelper::Withdraw(account_hash),
+                Key::Balance(addr) => BinarySerHelper::Balance(addr),
             }
         }
     }
@@ -487,6 +700,10 @@ mod serde_helpers {
         Transfer(TransferAddr),
         DeployInfo(DeployHash),
         EraInfo(EraId),
+        Dictionary(DictionaryAddr),
+        Bid(AccountHash),
+        Withdraw(AccountHash),
+        Balance(URefAddr),
     }
 
     impl From<BinaryDeserHelper> for Key {
@@ -498,6 +715,10 @@ mod serde_helpers {
                 BinaryDeserHelper::Transfer(transfer_addr) => Key::Transfer(transfer_addr),
                 BinaryDeserHelper::DeployInfo(deploy_hash) => Key::DeployInfo(deploy_hash),
                 BinaryDeserHelper::EraInfo(era_id) => Key::EraInfo(era_id),
+                BinaryDeserHelper::Dictionary(addr) => Key::Dictionary(addr),
+                BinaryDeserHelper::Bid(account_hash) => Key::Bid(account_hash),
+                BinaryDeserHelper::Withdraw(account_hash) => Key::Withdraw(account_hash),
+                BinaryDeserHelper::Balance(addr) => Key::Balance(addr),
             }
         }
     }
@@ -610,6 +831,26 @@ mod tests {
         );
         let era_info_key = Key::EraInfo(42);
         assert_eq!(format!("{}", era_info_key), "Key::EraInfo(42)".to_string());
+        let dictionary_key = Key::Dictionary(addr_array);
+        assert_eq!(
+            format!("{}", dictionary_key),
+            format!("Key::Dictionary({})", expected_hash)
+        );
+        let bid_key = Key::Bid(account_hash);
+        assert_eq!(
+            format!("{}", bid_key),
+            format!("Key::Bid({})", expected_hash)
+        );
+        let withdraw_key = Key::Withdraw(account_hash);
+        assert_eq!(
+            format!("{}", withdraw_key),
+            format!("Key::Withdraw({})", expected_hash)
+        );
+        let balance_key = Key::Balance(addr_array);
+        assert_eq!(
+            format!("{}", balance_key),
+            format!("Key::Balance({})", expected_hash)
+        );
     }
 
     #[test]
@@ -670,6 +911,18 @@ mod tests {
 
         let key_era_info = Key::EraInfo(42);
         assert!(key_era_info.serialized_length() <= Key::max_serialized_length());
+
+        let key_dictionary = Key::Dictionary([42; KEY_DICTIONARY_LENGTH]);
+        assert!(key_dictionary.serialized_length() <= Key::max_serialized_length());
+
+        let key_bid = Key::Bid(AccountHash::new([42; BLAKE2B_DIGEST_LENGTH]));
+        assert!(key_bid.serialized_length() <= Key::max_serialized_length());
+
+        let key_withdraw = Key::Withdraw(AccountHash::new([42; BLAKE2B_DIGEST_LENGTH]));
+        assert!(key_withdraw.serialized_length() <= Key::max_serialized_length());
+
+        let key_balance = Key::Balance([42; UREF_ADDR_LENGTH]);
+        assert!(key_balance.serialized_length() <= Key::max_serialized_length());
     }
 
     fn to_string_round_trip(key: Key) {
@@ -689,6 +942,10 @@ mod tests {
         to_string_round_trip(Key::Transfer(TransferAddr::new([42; KEY_HASH_LENGTH])));
         to_string_round_trip(Key::DeployInfo(DeployHash::new([42; KEY_HASH_LENGTH])));
         to_string_round_trip(Key::EraInfo(42));
+        to_string_round_trip(Key::Dictionary([42; KEY_DICTIONARY_LENGTH]));
+        to_string_round_trip(Key::Bid(AccountHash::new([42; BLAKE2B_DIGEST_LENGTH])));
+        to_string_round_trip(Key::Withdraw(AccountHash::new([42; BLAKE2B_DIGEST_LENGTH])));
+        to_string_round_trip(Key::Balance([42; UREF_ADDR_LENGTH]));
 
         let invalid_prefix = "a-0000000000000000000000000000000000000000000000000000000000000000";
         assert!(Key::from_formatted_str(invalid_prefix).is_err());
@@ -746,6 +1003,30 @@ mod tests {
             serde_json::to_string(&key_era_info).unwrap(),
             r#"{"EraInfo":"era-42"}"#.to_string()
         );
+
+        let key_dictionary = Key::Dictionary(array);
+        assert_eq!(
+            serde_json::to_string(&key_dictionary).unwrap(),
+            format!(r#"{{"Dictionary":"dictionary-{}"}}"#, hex_bytes)
+        );
+
+        let key_bid = Key::Bid(AccountHash::new(array));
+        assert_eq!(
+            serde_json::to_string(&key_bid).unwrap(),
+            format!(r#"{{"Bid":"bid-{}"}}"#, hex_bytes)
+        );
+
+        let key_withdraw = Key::Withdraw(AccountHash::new(array));
+        assert_eq!(
+            serde_json::to_string(&key_withdraw).unwrap(),
+            format!(r#"{{"Withdraw":"withdraw-{}"}}"#, hex_bytes)
+        );
+
+        let key_balance = Key::Balance(array);
+        assert_eq!(
+            serde_json::to_string(&key_balance).unwrap(),
+            format!(r#"{{"Balance":"balance-{}"}}"#, hex_bytes)
+        );
     }
 
     #[test]
@@ -764,6 +1045,10 @@ mod tests {
         round_trip(&Key::Transfer(TransferAddr::new(array)));
         round_trip(&Key::DeployInfo(DeployHash::new(array)));
         round_trip(&Key::EraInfo(42));
+        round_trip(&Key::Dictionary(array));
+        round_trip(&Key::Bid(AccountHash::new(array)));
+        round_trip(&Key::Withdraw(AccountHash::new(array)));
+        round_trip(&Key::Balance(array));
     }
 
     #[test]
@@ -782,5 +1067,51 @@ mod tests {
         round_trip(&Key::Transfer(TransferAddr::new(array)));
         round_trip(&Key::DeployInfo(DeployHash::new(array)));
         round_trip(&Key::EraInfo(42));
+        round_trip(&Key::Dictionary(array));
+        round_trip(&Key::Bid(AccountHash::new(array)));
+        round_trip(&Key::Withdraw(AccountHash::new(array)));
+        round_trip(&Key::Balance(array));
+    }
+
+    #[test]
+    fn key_tag_should_be_serialized_prefix() {
+        let array = [42; BLAKE2B_DIGEST_LENGTH];
+        let keys = [
+            Key::Account(AccountHash::new(array)),
+            Key::Hash(array),
+            Key::URef(URef::new(array, AccessRights::READ)),
+            Key::Transfer(TransferAddr::new(array)),
+            Key::DeployInfo(DeployHash::new(array)),
+            Key::EraInfo(42),
+            Key::Dictionary(array),
+            Key::Bid(AccountHash::new(array)),
+            Key::Withdraw(AccountHash::new(array)),
+            Key::Balance(array),
+        ];
+        for key in keys.iter() {
+            assert_eq!(key.to_bytes().unwrap()[0], key.tag() as u8);
+        }
+    }
+
+    #[test]
+    fn dictionary_key_should_ignore_access_rights() {
+        let addr = [42; UREF_ADDR_LENGTH];
+        let item_key = b"item";
+        let expected = Key::dictionary(URef::new(addr, AccessRights::READ_ADD_WRITE), item_key);
+        assert_eq!(
+            Key::dictionary(URef::new(addr, AccessRights::READ), item_key),
+            expected
+        );
+        assert_ne!(
+            Key::dictionary(URef::new(addr, AccessRights::READ), b"other item"),
+            expected
+        );
+        assert_ne!(
+            Key::dictionary(
+                URef::new([43; UREF_ADDR_LENGTH], AccessRights::READ),
+                item_key
+            ),
+            expected
+        );
     }
 }
@@ -1,7 +1,12 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
 mod vesting;
 
 use alloc::{collections::BTreeMap, vec::Vec};
 
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -13,8 +18,11 @@ use crate::{
 pub use vesting::VestingSchedule;
 
 /// An entry in a founding validator map.
-#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
 pub struct Bid {
+    /// Validator public key
+    validator_public_key: PublicKey,
     /// The purse that was used for bonding.
     bonding_purse: URef,
     /// The amount of tokens staked by a validator (not including delegators).
@@ -31,12 +39,18 @@ pub struct Bid {
 
 impl Bid {
     /// Creates new instance of a bid with locked funds.
-    pub fn locked(bonding_purse: URef, staked_amount: U512, release_timestamp_millis: u64) -> Self {
+    pub fn locked(
+        validator_public_key: PublicKey,
+        bonding_purse: URef,
+        staked_amount: U512,
+        release_timestamp_millis: u64,
+    ) -> Self {
         let delegation_rate = 0;
         let vesting_schedule = Some(VestingSchedule::new(release_timestamp_millis));
         let delegators = BTreeMap::new();
         let inactive = false;
         Self {
+            validator_public_key,
             bonding_purse,
             staked_amount,
             delegation_rate,
@@ -48,6 +62,7 @@ impl Bid {
 
     /// Creates new instance of a bid with unlocked funds.
     pub fn unlocked(
+        validator_public_key: PublicKey,
         bonding_purse: URef,
         staked_amount: U512,
         delegation_rate: DelegationRate,
@@ -56,6 +71,7 @@ impl Bid {
         let delegators = BTreeMap::new();
         let inactive = false;
         Self {
+            validator_public_key,
             bonding_purse,
             staked_amount,
             delegation_rate,
@@ -65,6 +81,32 @@ impl Bid {
         }
     }
 
+    /// Creates new instance of an inactive bid without any stake or delegators.
+    ///
+    /// A withdrawn or slashed bid is replaced by an empty one, which is removed from global state
+    /// by the next auction.
+    pub fn empty(validator_public_key: PublicKey, bonding_purse: URef) -> Self {
+        let staked_amount = U512::zero();
+        let delegation_rate = 0;
+        let vesting_schedule = None;
+        let delegators = BTreeMap::new();
+        let inactive = true;
+        Self {
+            validator_public_key,
+            bonding_purse,
+            staked_amount,
+            delegation_rate,
+            vesting_schedule,
+            delegators,
+            inactive,
+        }
+    }
+
+    /// Gets the validator public key of the provided bid
+    pub fn validator_public_key(&self) -> &PublicKey {
+        &self.validator_public_key
+    }
+
     /// Gets the bonding purse of the provided bid
     pub fn bonding_purse(&self) -> &URef {
         &self.bonding_purse
@@ -107,6 +149,12 @@ impl Bid {
         self.inactive
     }
 
+    /// Returns `true` if the bid is inactive and neither the validator nor any delegators have
+    /// stake in it, i.e. if it has been withdrawn or slashed.
+    pub fn is_empty(&self) -> bool {
+        self.inactive && self.staked_amount.is_zero() && self.delegators.is_empty()
+    }
+
     /// Decreases the stake of the provided bid
     pub fn decrease_stake(
         &mut self,
@@ -142,6 +190,20 @@ impl Bid {
         }
     }
 
+    /// Decreases the stake of the provided bid by a slashed amount.
+    ///
+    /// Unlike [`Bid::decrease_stake`], this ignores the vesting schedule.
+    pub fn slash_stake(&mut self, amount: U512) -> Result<U512, Error> {
+        let updated_staked_amount = self
+            .staked_amount
+            .checked_sub(amount)
+            .ok_or(Error::InvalidAmount)?;
+
+        self.staked_amount = updated_staked_amount;
+
+        Ok(updated_staked_amount)
+    }
+
     /// Increases the stake of the provided bid
     pub fn increase_stake(&mut self, amount: U512) -> Result<U512, Error> {
         let updated_staked_amount = self
@@ -211,6 +273,7 @@ impl CLTyped for Bid {
 impl ToBytes for Bid {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.validator_public_key.to_bytes()?);
         result.extend(self.bonding_purse.to_bytes()?);
         result.extend(self.staked_amount.to_bytes()?);
         result.extend(self.delegation_rate.to_bytes()?);
@@ -221,7 +284,8 @@ impl ToBytes for Bid {
     }
 
     fn serialized_length(&self) -> usize {
-        self.bonding_purse.serialized_length()
+        self.validator_public_key.serialized_length()
+            + self.bonding_purse.serialized_length()
             + self.staked_amount.serialized_length()
             + self.delegation_rate.serialized_length()
             + self.vesting_schedule.serialized_length()
@@ -232,6 +296,7 @@ impl ToBytes for Bid {
 
 impl FromBytes for Bid {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (validator_public_key, bytes) = FromBytes::from_bytes(bytes)?;
         let (bonding_purse, bytes) = FromBytes::from_bytes(bytes)?;
         let (staked_amount, bytes) = FromBytes::from_bytes(bytes)?;
         let (delegation_rate, bytes) = FromBytes::from_bytes(bytes)?;
@@ -240,6 +305,7 @@ impl FromBytes for Bid {
         let (inactive, bytes) = FromBytes::from_bytes(bytes)?;
         Ok((
             Bid {
+                validator_public_key,
                 bonding_purse,
                 staked_amount,
                 delegation_rate,
@@ -259,12 +325,15 @@ mod tests {
     use crate::{
         bytesrepr,
         system::auction::{bid::VestingSchedule, Bid, DelegationRate},
-        AccessRights, URef, U512,
+        AccessRights, PublicKey, SecretKey, URef, U512,
     };
 
     #[test]
     fn serialization_roundtrip() {
         let founding_validator = Bid {
+            validator_public_key: PublicKey::from(SecretKey::ed25519(
+                [42; SecretKey::ED25519_LENGTH],
+            )),
             bonding_purse: URef::new([42; 32], AccessRights::READ_ADD_WRITE),
             staked_amount: U512::one(),
             delegation_rate: DelegationRate::max_value(),
@@ -57,6 +57,8 @@ pub const ARG_UNBONDING_DELAY: &str = "unbonding_delay";
 pub const ARG_ERA_END_TIMESTAMP_MILLIS: &str = "era_end_timestamp_millis";
 /// Named constant for `evicted_validators`;
 pub const ARG_EVICTED_VALIDATORS: &str = "evicted_validators";
+/// Named constant for `new_validator`.
+pub const ARG_NEW_VALIDATOR: &str = "new_validator";
 
 /// Named constant for method `get_era_validators`.
 pub const METHOD_GET_ERA_VALIDATORS: &str = "get_era_validators";
@@ -80,10 +82,12 @@ pub const METHOD_DISTRIBUTE: &str = "distribute";
 pub const METHOD_READ_ERA_ID: &str = "read_era_id";
 /// Named constant for method `activate_bid`.
 pub const METHOD_ACTIVATE_BID: &str = "activate_bid";
+/// Named constant for method `redelegate`.
+pub const METHOD_REDELEGATE: &str = "redelegate";
 
-/// Storage for `UnbondingPurses`
+/// Legacy storage for `UnbondingPurses`, superseded by [`Key::Withdraw`](crate::Key::Withdraw).
 pub const UNBONDING_PURSES_KEY: &str = "unbonding_purses";
-/// Storage for `Bids`.
+/// Legacy storage for `Bids`, superseded by [`Key::Bid`](crate::Key::Bid).
 pub const BIDS_KEY: &str = "bids";
 /// Storage for `EraId`.
 pub const ERA_ID_KEY: &str = "era_id";
@@ -99,3 +103,11 @@ pub const AUCTION_DELAY_KEY: &str = "auction_delay";
 pub const LOCKED_FUNDS_PERIOD_KEY: &str = "locked_funds_period";
 /// Unbonding delay expressed in eras.
 pub const UNBONDING_DELAY_KEY: &str = "unbonding_delay";
+/// Redelegation delay expressed in eras.
+pub const REDELEGATION_DELAY_KEY: &str = "redelegation_delay";
+/// Minimum amount of motes a delegator has to keep delegated to a validator.
+pub const MINIMUM_DELEGATION_AMOUNT_KEY: &str = "minimum_delegation_amount";
+/// Maximum number of delegators a single validator accepts.
+pub const MAX_DELEGATORS_PER_VALIDATOR_KEY: &str = "max_delegators_per_validator";
+/// Fraction of the stake taken from a slashed validator and its delegators.
+pub const SLASHING_RATE_KEY: &str = "slashing_rate";
@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 use core::convert::TryInto;
 
 use num_rational::Ratio;
@@ -7,10 +7,11 @@ use crate::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::auction::{
-        constants::*, Auction, Bids, EraId, Error, RuntimeProvider, SeigniorageAllocation,
-        SeigniorageRecipientsSnapshot, StorageProvider, UnbondingPurse, UnbondingPurses,
+        constants::*, Auction, Bid, Bids, Delegator, EraId, Error, MintProvider, RuntimeProvider,
+        SeigniorageAllocation, SeigniorageRecipientsSnapshot, SlashInfo, StorageProvider,
+        UnbondingPurse,
     },
-    CLTyped, PublicKey, URef, U512,
+    CLTyped, KeyTag, PublicKey, URef, U512,
 };
 
 fn read_from<P, T>(provider: &mut P, name: &str) -> Result<T, Error>
@@ -35,35 +36,87 @@ where
     Ok(())
 }
 
+/// Returns the bid of the given validator, or `None` if it doesn't exist or is empty.
+pub fn get_bid<P>(provider: &mut P, validator_public_key: &PublicKey) -> Result<Option<Bid>, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    let account_hash = AccountHash::from_public_key(validator_public_key, |x| provider.blake2b(x));
+    let maybe_bid = provider.read_bid(&account_hash)?;
+    Ok(maybe_bid.filter(|bid| !bid.is_empty()))
+}
+
+pub fn set_bid<P>(provider: &mut P, bid: Bid) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    let account_hash =
+        AccountHash::from_public_key(bid.validator_public_key(), |x| provider.blake2b(x));
+    provider.write_bid(account_hash, bid)
+}
+
+/// Returns all bids which are not empty.
 pub fn get_bids<P>(provider: &mut P) -> Result<Bids, Error>
 where
     P: StorageProvider + RuntimeProvider + ?Sized,
 {
-    Ok(read_from(provider, BIDS_KEY)?)
+    read_bids(provider, false)
+}
+
+/// Returns all bids which are not empty, and removes the empty ones left behind by withdrawn or
+/// slashed validators from global state.
+///
+/// This function can be called by the system only.
+pub(crate) fn get_bids_pruning_empty<P>(provider: &mut P) -> Result<Bids, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    if provider.get_caller() != SYSTEM_ACCOUNT {
+        return Err(Error::InvalidCaller);
+    }
+    read_bids(provider, true)
 }
 
-pub fn set_bids<P>(provider: &mut P, validators: Bids) -> Result<(), Error>
+fn read_bids<P>(provider: &mut P, prune_empty: bool) -> Result<Bids, Error>
 where
     P: StorageProvider + RuntimeProvider + ?Sized,
 {
-    write_to(provider, BIDS_KEY, validators)
+    let mut bids = Bids::new();
+    for key in provider.get_keys(&KeyTag::Bid)? {
+        let account_hash = key.into_bid().ok_or(Error::InvalidKeyVariant)?;
+        let bid = provider
+            .read_bid(&account_hash)?
+            .ok_or(Error::MissingValue)?;
+        if !bid.is_empty() {
+            bids.insert(*bid.validator_public_key(), bid);
+        } else if prune_empty {
+            provider.prune_bid(account_hash)?;
+        }
+    }
+    Ok(bids)
 }
 
-pub fn get_unbonding_purses<P>(provider: &mut P) -> Result<UnbondingPurses, Error>
+pub fn get_unbonding_purses<P>(
+    provider: &mut P,
+    validator_public_key: &PublicKey,
+) -> Result<Vec<UnbondingPurse>, Error>
 where
     P: StorageProvider + RuntimeProvider + ?Sized,
 {
-    Ok(read_from(provider, UNBONDING_PURSES_KEY)?)
+    let account_hash = AccountHash::from_public_key(validator_public_key, |x| provider.blake2b(x));
+    provider.read_withdraw(&account_hash)
 }
 
 pub fn set_unbonding_purses<P>(
     provider: &mut P,
-    unbonding_purses: UnbondingPurses,
+    validator_public_key: &PublicKey,
+    unbonding_purses: Vec<UnbondingPurse>,
 ) -> Result<(), Error>
 where
     P: StorageProvider + RuntimeProvider + ?Sized,
 {
-    write_to(provider, UNBONDING_PURSES_KEY, unbonding_purses)
+    let account_hash = AccountHash::from_public_key(validator_public_key, |x| provider.blake2b(x));
+    provider.write_withdraw(account_hash, unbonding_purses)
 }
 
 pub fn get_era_id<P>(provider: &mut P) -> Result<EraId, Error>
@@ -146,73 +199,296 @@ where
     read_from(provider, UNBONDING_DELAY_KEY)
 }
 
+fn get_redelegation_delay<P>(provider: &mut P) -> Result<u64, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    read_from(provider, REDELEGATION_DELAY_KEY)
+}
+
+pub fn get_minimum_delegation_amount<P>(provider: &mut P) -> Result<U512, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    let minimum_delegation_amount: u64 = read_from(provider, MINIMUM_DELEGATION_AMOUNT_KEY)?;
+    Ok(U512::from(minimum_delegation_amount))
+}
+
+pub fn get_max_delegators_per_validator<P>(provider: &mut P) -> Result<usize, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    let max_delegators_per_validator: u32 = read_from(provider, MAX_DELEGATORS_PER_VALIDATOR_KEY)?;
+    let max_delegators_per_validator = max_delegators_per_validator
+        .try_into()
+        .map_err(|_| Error::ExceededDelegatorSizeLimit)?;
+    Ok(max_delegators_per_validator)
+}
+
+pub fn get_slashing_rate<P>(provider: &mut P) -> Result<Ratio<u64>, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    read_from(provider, SLASHING_RATE_KEY)
+}
+
 /// Iterates over unbonding entries and checks if a locked amount can be paid already if
 /// a specific era is reached.
 ///
+/// Matured redelegation requests are bonded to their new validator instead, or paid out like any
+/// other unbonding request if that validator can't accept them.
+///
 /// This function can be called by the system only.
 pub(crate) fn process_unbond_requests<P: Auction + ?Sized>(provider: &mut P) -> Result<(), Error> {
     if provider.get_caller() != SYSTEM_ACCOUNT {
         return Err(Error::InvalidCaller);
     }
 
-    // Update `unbonding_purses` data
-    let mut unbonding_purses: UnbondingPurses = get_unbonding_purses(provider)?;
-
     let current_era_id = provider.read_era_id()?;
 
     let unbonding_delay = get_unbonding_delay(provider)?;
 
-    for unbonding_list in unbonding_purses.values_mut() {
+    // Only read the redelegation delay once it's needed, as contracts installed before
+    // redelegation was introduced don't have it.
+    let mut redelegation_delay = None;
+
+    for key in provider.get_keys(&KeyTag::Withdraw)? {
+        let account_hash = key.into_withdraw().ok_or(Error::InvalidKeyVariant)?;
+        let unbonding_list = provider.read_withdraw(&account_hash)?;
+        if unbonding_list.is_empty() {
+            provider.prune_withdraw(account_hash)?;
+            continue;
+        }
+
         let mut new_unbonding_list = Vec::new();
         for unbonding_purse in unbonding_list.iter() {
             // Since `process_unbond_requests` is run before `run_auction`, we should check if
             // current era id + unbonding delay is equal or greater than the `era_of_creation` that
             // was calculated on `unbond` attempt.
-            if current_era_id >= unbonding_purse.era_of_creation() + unbonding_delay {
-                let account_hash =
-                    AccountHash::from_public_key(unbonding_purse.unbonder_public_key(), |x| {
-                        provider.blake2b(x)
-                    });
-
-                // Move funds from bid purse to unbonding purse
-                provider
-                    .transfer_purse_to_account(
-                        *unbonding_purse.bonding_purse(),
-                        account_hash,
-                        *unbonding_purse.amount(),
-                    )
-                    .map_err(|_| Error::TransferToUnbondingPurse)?;
-            } else {
+            let delay = match unbonding_purse.new_validator() {
+                Some(_) => match redelegation_delay {
+                    Some(redelegation_delay) => redelegation_delay,
+                    None => *redelegation_delay.get_or_insert(get_redelegation_delay(provider)?),
+                },
+                None => unbonding_delay,
+            };
+            if current_era_id < unbonding_purse.era_of_creation() + delay {
                 new_unbonding_list.push(*unbonding_purse);
+                continue;
             }
+
+            if bond_redelegated_amount(provider, unbonding_purse)? {
+                continue;
+            }
+
+            let account_hash =
+                AccountHash::from_public_key(unbonding_purse.unbonder_public_key(), |x| {
+                    provider.blake2b(x)
+                });
+
+            // Move funds from bid purse to unbonding purse
+            provider
+                .transfer_purse_to_account(
+                    *unbonding_purse.bonding_purse(),
+                    account_hash,
+                    *unbonding_purse.amount(),
+                )
+                .map_err(|_| Error::TransferToUnbondingPurse)?;
         }
-        *unbonding_list = new_unbonding_list;
-    }
 
-    // Prune empty entries
-    let unbonding_purses = unbonding_purses
-        .into_iter()
-        .filter(|(_k, unbonding_purses)| !unbonding_purses.is_empty())
-        .collect();
+        if new_unbonding_list.is_empty() {
+            provider.prune_withdraw(account_hash)?;
+        } else if new_unbonding_list.len() != unbonding_list.len() {
+            provider.write_withdraw(account_hash, new_unbonding_list)?;
+        }
+    }
 
-    set_unbonding_purses(provider, unbonding_purses)?;
     Ok(())
 }
 
+/// Checks that withdrawing `amount` from `delegator` either removes the delegation entirely or
+/// leaves at least `minimum_delegation_amount` delegated.
+pub(crate) fn check_remaining_delegation(
+    delegator: &Delegator,
+    amount: U512,
+    minimum_delegation_amount: U512,
+) -> Result<(), Error> {
+    match delegator.staked_amount().checked_sub(amount) {
+        Some(remaining) if !remaining.is_zero() && remaining < minimum_delegation_amount => {
+            Err(Error::DelegationAmountTooSmall)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Moves the amount of a matured redelegation request into the delegator's entry in the new
+/// validator's bid.
+///
+/// Returns `false` if the new validator no longer has a bid, or if it would need a new delegator
+/// entry but is already at the maximum number of delegators, in which case the amount should be
+/// paid out instead.
+fn bond_redelegated_amount<P: Auction + ?Sized>(
+    provider: &mut P,
+    unbonding_purse: &UnbondingPurse,
+) -> Result<bool, Error> {
+    let new_validator = match unbonding_purse.new_validator() {
+        Some(new_validator) => *new_validator,
+        None => return Ok(false),
+    };
+
+    let mut bid = match get_bid(provider, &new_validator)? {
+        Some(bid) => bid,
+        None => return Ok(false),
+    };
+
+    let max_delegators_per_validator = get_max_delegators_per_validator(provider)?;
+    let amount = *unbonding_purse.amount();
+    let delegators = bid.delegators_mut();
+
+    match delegators.get_mut(unbonding_purse.unbonder_public_key()) {
+        Some(delegator) => {
+            provider
+                .transfer_purse_to_purse(
+                    *unbonding_purse.bonding_purse(),
+                    *delegator.bonding_purse(),
+                    amount,
+                )
+                .map_err(|_| Error::TransferToDelegatorPurse)?;
+            delegator.increase_stake(amount)?;
+        }
+        None if delegators.len() < max_delegators_per_validator => {
+            let bonding_purse = provider.create_purse()?;
+            provider
+                .transfer_purse_to_purse(*unbonding_purse.bonding_purse(), bonding_purse, amount)
+                .map_err(|_| Error::TransferToDelegatorPurse)?;
+            let delegator = Delegator::new(amount, bonding_purse, new_validator);
+            delegators.insert(*unbonding_purse.unbonder_public_key(), delegator);
+        }
+        None => return Ok(false),
+    }
+
+    set_bid(provider, bid)?;
+
+    Ok(true)
+}
+
+/// Returns the part of `amount` taken away by slashing at `slashing_rate`, never more than
+/// `amount` itself.
+fn slashed_amount(amount: U512, slashing_rate: Ratio<u64>) -> Result<U512, Error> {
+    if slashing_rate.numer() >= slashing_rate.denom() {
+        return Ok(amount);
+    }
+    let slashed = amount
+        .checked_mul(U512::from(*slashing_rate.numer()))
+        .ok_or(Error::InvalidAmount)?
+        / U512::from(*slashing_rate.denom());
+    Ok(slashed)
+}
+
+/// Slashes `slashing_rate` of the stake held by the given validator and its delegators, including
+/// their pending unbonding purses, and returns how much was taken from each of them.
+///
+/// The slashed motes are burned from the bonding purses holding them, so that the purses keep
+/// backing exactly the remaining stakes and unbonding amounts.  The caller is responsible for
+/// reducing the total supply by the sum of the returned amounts.
+///
+/// The validator's bid is deactivated, and delegators and unbonding purses left with nothing are
+/// removed.
+pub(crate) fn slash_validator<P>(
+    provider: &mut P,
+    validator_public_key: PublicKey,
+    slashing_rate: Ratio<u64>,
+) -> Result<Vec<SlashInfo>, Error>
+where
+    P: StorageProvider + RuntimeProvider + MintProvider + ?Sized,
+{
+    let mut slashed_amounts: BTreeMap<PublicKey, U512> = BTreeMap::new();
+
+    if let Some(mut bid) = get_bid(provider, &validator_public_key)? {
+        let validator_slash = slashed_amount(*bid.staked_amount(), slashing_rate)?;
+        bid.slash_stake(validator_slash)?;
+        provider.burn(*bid.bonding_purse(), validator_slash)?;
+        *slashed_amounts.entry(validator_public_key).or_default() += validator_slash;
+
+        let delegators = bid.delegators_mut();
+        for (delegator_public_key, delegator) in delegators.iter_mut() {
+            let delegator_slash = slashed_amount(*delegator.staked_amount(), slashing_rate)?;
+            delegator.decrease_stake(delegator_slash)?;
+            provider.burn(*delegator.bonding_purse(), delegator_slash)?;
+            *slashed_amounts.entry(*delegator_public_key).or_default() += delegator_slash;
+        }
+        let emptied_delegators: Vec<PublicKey> = delegators
+            .iter()
+            .filter(|(_, delegator)| delegator.staked_amount().is_zero())
+            .map(|(delegator_public_key, _)| *delegator_public_key)
+            .collect();
+        for delegator_public_key in emptied_delegators {
+            delegators.remove(&delegator_public_key);
+        }
+
+        bid.deactivate();
+        set_bid(provider, bid)?;
+    }
+
+    let unbonding_purses = get_unbonding_purses(provider, &validator_public_key)?;
+    if !unbonding_purses.is_empty() {
+        let mut remaining_purses = Vec::with_capacity(unbonding_purses.len());
+        for unbonding_purse in unbonding_purses {
+            let purse_slash = slashed_amount(*unbonding_purse.amount(), slashing_rate)?;
+            provider.burn(*unbonding_purse.bonding_purse(), purse_slash)?;
+            *slashed_amounts
+                .entry(*unbonding_purse.unbonder_public_key())
+                .or_default() += purse_slash;
+
+            let remaining_amount = *unbonding_purse.amount() - purse_slash;
+            if !remaining_amount.is_zero() {
+                remaining_purses.push(UnbondingPurse::new(
+                    *unbonding_purse.bonding_purse(),
+                    *unbonding_purse.validator_public_key(),
+                    *unbonding_purse.unbonder_public_key(),
+                    unbonding_purse.era_of_creation(),
+                    remaining_amount,
+                    unbonding_purse.new_validator().copied(),
+                ));
+            }
+        }
+        set_unbonding_purses(provider, &validator_public_key, remaining_purses)?;
+    }
+
+    let mut slashes = Vec::new();
+    if let Some(amount) = slashed_amounts.remove(&validator_public_key) {
+        if !amount.is_zero() {
+            slashes.push(SlashInfo::validator(validator_public_key, amount));
+        }
+    }
+    slashes.extend(
+        slashed_amounts
+            .into_iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .map(|(delegator_public_key, amount)| {
+                SlashInfo::delegator(delegator_public_key, validator_public_key, amount)
+            }),
+    );
+    Ok(slashes)
+}
+
 /// Creates a new purse in unbonding_purses given a validator's key, amount, and a destination
 /// unbonding purse. Returns the amount of motes remaining in the validator's bid purse.
+///
+/// If `new_validator` is set, the request is a redelegation to that validator.
 pub(crate) fn create_unbonding_purse<P: Auction + ?Sized>(
     provider: &mut P,
     validator_public_key: PublicKey,
     unbonder_public_key: PublicKey,
     bonding_purse: URef,
     amount: U512,
+    new_validator: Option<PublicKey>,
 ) -> Result<(), Error> {
     if provider.get_balance(bonding_purse)?.unwrap_or_default() < amount {
         return Err(Error::UnbondTooLarge);
     }
 
-    let mut unbonding_purses: UnbondingPurses = get_unbonding_purses(provider)?;
+    let mut unbonding_purses = get_unbonding_purses(provider, &validator_public_key)?;
     let era_of_creation = provider.read_era_id()?;
     let new_unbonding_purse = UnbondingPurse::new(
         bonding_purse,
@@ -220,26 +496,27 @@ pub(crate) fn create_unbonding_purse<P: Auction + ?Sized>(
         unbonder_public_key,
         era_of_creation,
         amount,
+        new_validator,
     );
-    unbonding_purses
-        .entry(validator_public_key)
-        .or_default()
-        .push(new_unbonding_purse);
-    set_unbonding_purses(provider, unbonding_purses)?;
+    unbonding_purses.push(new_unbonding_purse);
+    set_unbonding_purses(provider, &validator_public_key, unbonding_purses)?;
 
     Ok(())
 }
 
 /// Reinvests delegator reward by increasing its stake.
-pub fn reinvest_delegator_rewards(
-    bids: &mut Bids,
+pub fn reinvest_delegator_rewards<P>(
+    provider: &mut P,
     seigniorage_allocations: &mut Vec<SeigniorageAllocation>,
     validator_public_key: PublicKey,
     rewards: impl Iterator<Item = (PublicKey, Ratio<U512>)>,
-) -> Result<Vec<(U512, URef)>, Error> {
+) -> Result<Vec<(U512, URef)>, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
     let mut delegator_payouts = Vec::new();
 
-    let bid = match bids.get_mut(&validator_public_key) {
+    let mut bid = match get_bid(provider, &validator_public_key)? {
         Some(bid) => bid,
         None => {
             // Validator has been slashed
@@ -270,17 +547,22 @@ pub fn reinvest_delegator_rewards(
         seigniorage_allocations.push(allocation);
     }
 
+    set_bid(provider, bid)?;
+
     Ok(delegator_payouts)
 }
 
 /// Reinvests validator reward by increasing its stake and returns its bonding purse.
-pub fn reinvest_validator_reward(
-    bids: &mut Bids,
+pub fn reinvest_validator_reward<P>(
+    provider: &mut P,
     seigniorage_allocations: &mut Vec<SeigniorageAllocation>,
     validator_public_key: PublicKey,
     amount: U512,
-) -> Result<Option<URef>, Error> {
-    let bid = match bids.get_mut(&validator_public_key) {
+) -> Result<Option<URef>, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    let mut bid = match get_bid(provider, &validator_public_key)? {
         Some(bid) => bid,
         None => {
             // Validator has been slashed
@@ -294,5 +576,8 @@ pub fn reinvest_validator_reward(
 
     seigniorage_allocations.push(allocation);
 
-    Ok(Some(*bid.bonding_purse()))
+    let bonding_purse = *bid.bonding_purse();
+    set_bid(provider, bid)?;
+
+    Ok(Some(bonding_purse))
 }
@@ -1,7 +1,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use alloc::{boxed::Box, vec::Vec};
+use alloc::vec::Vec;
 
 #[cfg(feature = "std")]
 use schemars::JsonSchema;
@@ -14,6 +14,8 @@ use crate::{
 
 const SEIGNIORAGE_ALLOCATION_VALIDATOR_TAG: u8 = 0;
 const SEIGNIORAGE_ALLOCATION_DELEGATOR_TAG: u8 = 1;
+const SLASH_INFO_VALIDATOR_TAG: u8 = 0;
+const SLASH_INFO_DELEGATOR_TAG: u8 = 1;
 
 /// Information about a seigniorage allocation
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
@@ -157,20 +159,159 @@ impl CLTyped for SeigniorageAllocation {
     }
 }
 
+/// Information about an amount slashed from a validator's or a delegator's stake
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub enum SlashInfo {
+    /// Info about an amount slashed from a validator
+    Validator {
+        /// Validator's public key
+        validator_public_key: PublicKey,
+        /// Slashed amount
+        amount: U512,
+    },
+    /// Info about an amount slashed from a delegator of a slashed validator
+    Delegator {
+        /// Delegator's public key
+        delegator_public_key: PublicKey,
+        /// Validator's public key
+        validator_public_key: PublicKey,
+        /// Slashed amount
+        amount: U512,
+    },
+}
+
+impl SlashInfo {
+    /// Constructs a [`SlashInfo::Validator`]
+    pub const fn validator(validator_public_key: PublicKey, amount: U512) -> Self {
+        SlashInfo::Validator {
+            validator_public_key,
+            amount,
+        }
+    }
+
+    /// Constructs a [`SlashInfo::Delegator`]
+    pub const fn delegator(
+        delegator_public_key: PublicKey,
+        validator_public_key: PublicKey,
+        amount: U512,
+    ) -> Self {
+        SlashInfo::Delegator {
+            delegator_public_key,
+            validator_public_key,
+            amount,
+        }
+    }
+
+    /// Returns the slashed amount
+    pub fn amount(&self) -> &U512 {
+        match self {
+            SlashInfo::Validator { amount, .. } => amount,
+            SlashInfo::Delegator { amount, .. } => amount,
+        }
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            SlashInfo::Validator { .. } => SLASH_INFO_VALIDATOR_TAG,
+            SlashInfo::Delegator { .. } => SLASH_INFO_DELEGATOR_TAG,
+        }
+    }
+}
+
+impl ToBytes for SlashInfo {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.append(&mut self.tag().to_bytes()?);
+        match self {
+            SlashInfo::Validator {
+                validator_public_key,
+                amount,
+            } => {
+                buffer.append(&mut validator_public_key.to_bytes()?);
+                buffer.append(&mut amount.to_bytes()?);
+            }
+            SlashInfo::Delegator {
+                delegator_public_key,
+                validator_public_key,
+                amount,
+            } => {
+                buffer.append(&mut delegator_public_key.to_bytes()?);
+                buffer.append(&mut validator_public_key.to_bytes()?);
+                buffer.append(&mut amount.to_bytes()?);
+            }
+        }
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.tag().serialized_length()
+            + match self {
+                SlashInfo::Validator {
+                    validator_public_key,
+                    amount,
+                } => validator_public_key.serialized_length() + amount.serialized_length(),
+                SlashInfo::Delegator {
+                    delegator_public_key,
+                    validator_public_key,
+                    amount,
+                } => {
+                    delegator_public_key.serialized_length()
+                        + validator_public_key.serialized_length()
+                        + amount.serialized_length()
+                }
+            }
+    }
+}
+
+impl FromBytes for SlashInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, rem) = <u8>::from_bytes(bytes)?;
+        match tag {
+            SLASH_INFO_VALIDATOR_TAG => {
+                let (validator_public_key, rem) = PublicKey::from_bytes(rem)?;
+                let (amount, rem) = U512::from_bytes(rem)?;
+                Ok((SlashInfo::validator(validator_public_key, amount), rem))
+            }
+            SLASH_INFO_DELEGATOR_TAG => {
+                let (delegator_public_key, rem) = PublicKey::from_bytes(rem)?;
+                let (validator_public_key, rem) = PublicKey::from_bytes(rem)?;
+                let (amount, rem) = U512::from_bytes(rem)?;
+                Ok((
+                    SlashInfo::delegator(delegator_public_key, validator_public_key, amount),
+                    rem,
+                ))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+impl CLTyped for SlashInfo {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
 /// Auction metadata.  Intended to be recorded at each era.
 #[derive(Debug, Default, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct EraInfo {
     seigniorage_allocations: Vec<SeigniorageAllocation>,
+    #[serde(default)]
+    slashes: Vec<SlashInfo>,
 }
 
 impl EraInfo {
     /// Constructs a [`EraInfo`].
     pub fn new() -> Self {
         let seigniorage_allocations = Vec::new();
+        let slashes = Vec::new();
         EraInfo {
             seigniorage_allocations,
+            slashes,
         }
     }
 
@@ -184,6 +325,16 @@ impl EraInfo {
         &mut self.seigniorage_allocations
     }
 
+    /// Returns a reference to the slashes recorded in this era
+    pub fn slashes(&self) -> &Vec<SlashInfo> {
+        &self.slashes
+    }
+
+    /// Returns a mutable reference to the slashes collection
+    pub fn slashes_mut(&mut self) -> &mut Vec<SlashInfo> {
+        &mut self.slashes
+    }
+
     /// Returns all seigniorage allocations that match the provided public key
     /// using the following criteria:
     /// * If the match candidate is a validator allocation, the provided public key is matched
@@ -208,20 +359,30 @@ impl EraInfo {
 
 impl ToBytes for EraInfo {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
-        self.seigniorage_allocations.to_bytes()
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.append(&mut self.seigniorage_allocations.to_bytes()?);
+        buffer.append(&mut self.slashes.to_bytes()?);
+        Ok(buffer)
     }
 
     fn serialized_length(&self) -> usize {
-        self.seigniorage_allocations.serialized_length()
+        self.seigniorage_allocations.serialized_length() + self.slashes.serialized_length()
     }
 }
 
 impl FromBytes for EraInfo {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (seigniorage_allocations, rem) = Vec::<SeigniorageAllocation>::from_bytes(bytes)?;
+        // Era info recorded before slashes were tracked ends right after the allocations.
+        let (slashes, rem) = if rem.is_empty() {
+            (Vec::new(), rem)
+        } else {
+            Vec::<SlashInfo>::from_bytes(rem)?
+        };
         Ok((
             EraInfo {
                 seigniorage_allocations,
+                slashes,
             },
             rem,
         ))
@@ -230,7 +391,7 @@ impl FromBytes for EraInfo {
 
 impl CLTyped for EraInfo {
     fn cl_type() -> CLType {
-        CLType::List(Box::new(SeigniorageAllocation::cl_type()))
+        CLType::Any
     }
 }
 
@@ -246,7 +407,7 @@ pub mod gens {
     use crate::{
         crypto::gens::public_key_arb,
         gens::u512_arb,
-        system::auction::{EraInfo, SeigniorageAllocation},
+        system::auction::{EraInfo, SeigniorageAllocation, SlashInfo},
     };
 
     fn seigniorage_allocation_validator_arb() -> impl Strategy<Value = SeigniorageAllocation> {
@@ -271,13 +432,38 @@ pub mod gens {
         ]
     }
 
+    fn slash_info_validator_arb() -> impl Strategy<Value = SlashInfo> {
+        (public_key_arb(), u512_arb()).prop_map(|(validator_public_key, amount)| {
+            SlashInfo::validator(validator_public_key, amount)
+        })
+    }
+
+    fn slash_info_delegator_arb() -> impl Strategy<Value = SlashInfo> {
+        (public_key_arb(), public_key_arb(), u512_arb()).prop_map(
+            |(delegator_public_key, validator_public_key, amount)| {
+                SlashInfo::delegator(delegator_public_key, validator_public_key, amount)
+            },
+        )
+    }
+
+    /// Creates an arbitrary [`SlashInfo`](crate::system::auction::SlashInfo)
+    pub fn slash_info_arb() -> impl Strategy<Value = SlashInfo> {
+        prop_oneof![slash_info_validator_arb(), slash_info_delegator_arb()]
+    }
+
     /// Creates an arbitrary [`EraInfo`]
     pub fn era_info_arb(size: impl Into<SizeRange>) -> impl Strategy<Value = EraInfo> {
-        collection::vec(seigniorage_allocation_arb(), size).prop_map(|allocations| {
-            let mut era_info = EraInfo::new();
-            *era_info.seigniorage_allocations_mut() = allocations;
-            era_info
-        })
+        let size = size.into();
+        (
+            collection::vec(seigniorage_allocation_arb(), size.clone()),
+            collection::vec(slash_info_arb(), size),
+        )
+            .prop_map(|(allocations, slashes)| {
+                let mut era_info = EraInfo::new();
+                *era_info.seigniorage_allocations_mut() = allocations;
+                *era_info.slashes_mut() = slashes;
+                era_info
+            })
     }
 }
 
@@ -285,14 +471,25 @@ pub mod gens {
 mod tests {
     use proptest::prelude::*;
 
-    use crate::bytesrepr;
+    use crate::bytesrepr::{self, ToBytes};
 
-    use super::gens;
+    use super::{gens, EraInfo};
 
     proptest! {
         #[test]
         fn test_serialization_roundtrip(era_info in gens::era_info_arb(0..32)) {
             bytesrepr::test_serialization_roundtrip(&era_info)
         }
+
+        #[test]
+        fn should_deserialize_era_info_without_slashes(era_info in gens::era_info_arb(0..32)) {
+            let legacy_bytes = era_info.seigniorage_allocations().to_bytes().unwrap();
+            let legacy_era_info: EraInfo = bytesrepr::deserialize(legacy_bytes).unwrap();
+            assert_eq!(
+                legacy_era_info.seigniorage_allocations(),
+                era_info.seigniorage_allocations()
+            );
+            assert!(legacy_era_info.slashes().is_empty());
+        }
     }
 }
@@ -139,6 +139,19 @@ pub enum Error {
     #[fail(display = "GasLimit")]
     GasLimit = 37,
 
+    /// Validator to redelegate to was not found.
+    #[fail(display = "Redelegation validator not found")]
+    RedelegationValidatorNotFound = 38,
+    /// Attempted to redelegate to the validator currently delegated to.
+    #[fail(display = "Redelegation to the same validator")]
+    RedelegationToSameValidator = 39,
+    /// Delegated amount is below the minimum delegation amount.
+    #[fail(display = "Delegation amount too small")]
+    DelegationAmountTooSmall = 40,
+    /// Validator already has the maximum number of delegators.
+    #[fail(display = "Exceeded delegator size limit")]
+    ExceededDelegatorSizeLimit = 41,
+
     #[cfg(test)]
     #[doc(hidden)]
     #[fail(display = "Sentinel error")]
@@ -210,6 +223,16 @@ impl TryFrom<u8> for Error {
             }
             d if d == Error::Transfer as u8 => Ok(Error::Transfer),
             d if d == Error::GasLimit as u8 => Ok(Error::GasLimit),
+            d if d == Error::RedelegationValidatorNotFound as u8 => {
+                Ok(Error::RedelegationValidatorNotFound)
+            }
+            d if d == Error::RedelegationToSameValidator as u8 => {
+                Ok(Error::RedelegationToSameValidator)
+            }
+            d if d == Error::DelegationAmountTooSmall as u8 => Ok(Error::DelegationAmountTooSmall),
+            d if d == Error::ExceededDelegatorSizeLimit as u8 => {
+                Ok(Error::ExceededDelegatorSizeLimit)
+            }
             _ => Err(TryFromU8ForError(())),
         }
     }
@@ -109,24 +109,25 @@ pub trait Auction:
         let source = self.get_main_purse()?;
 
         // Update bids or stakes
-        let mut validators = detail::get_bids(self)?;
-        let new_amount = match validators.get_mut(&public_key) {
-            Some(bid) => {
+        let new_amount = match detail::get_bid(self, &public_key)? {
+            Some(mut bid) => {
                 self.transfer_purse_to_purse(source, *bid.bonding_purse(), amount)
                     .map_err(|_| Error::TransferToBidPurse)?;
-                bid.with_delegation_rate(delegation_rate)
-                    .increase_stake(amount)?
+                let new_amount = bid
+                    .with_delegation_rate(delegation_rate)
+                    .increase_stake(amount)?;
+                detail::set_bid(self, bid)?;
+                new_amount
             }
             None => {
                 let bonding_purse = self.create_purse()?;
                 self.transfer_purse_to_purse(source, bonding_purse, amount)
                     .map_err(|_| Error::TransferToBidPurse)?;
-                let bid = Bid::unlocked(bonding_purse, amount, delegation_rate);
-                validators.insert(public_key, bid);
+                let bid = Bid::unlocked(public_key, bonding_purse, amount, delegation_rate);
+                detail::set_bid(self, bid)?;
                 amount
             }
         };
-        detail::set_bids(self, validators)?;
 
         Ok(new_amount)
     }
@@ -146,9 +147,7 @@ pub trait Auction:
         }
 
         // Update bids or stakes
-        let mut bids = detail::get_bids(self)?;
-
-        let bid = bids.get_mut(&public_key).ok_or(Error::ValidatorNotFound)?;
+        let mut bid = detail::get_bid(self, &public_key)?.ok_or(Error::ValidatorNotFound)?;
 
         let era_end_timestamp_millis = detail::get_era_end_timestamp_millis(self)?;
 
@@ -162,6 +161,7 @@ pub trait Auction:
             public_key, // validator is the unbonder
             *bid.bonding_purse(),
             amount,
+            None,
         )?;
 
         if new_amount.is_zero() {
@@ -173,14 +173,14 @@ pub trait Auction:
                     *delegator_public_key,
                     *delegator.bonding_purse(),
                     *delegator.staked_amount(),
+                    None,
                 )?;
             }
 
-            // NOTE: Assumed safe as we're checking for existence above
-            bids.remove(&public_key).unwrap();
+            bid = Bid::empty(public_key, *bid.bonding_purse());
         }
 
-        detail::set_bids(self, bids)?;
+        detail::set_bid(self, bid)?;
 
         Ok(new_amount)
     }
@@ -190,6 +190,9 @@ pub trait Auction:
     ///
     /// The function calls bond in the Mint contract to transfer motes to the validator's purse and
     /// returns a tuple of that purse and the amount of motes contained in it after the transfer.
+    ///
+    /// Fails if the resulting delegation is below the minimum delegation amount, or if a new
+    /// delegator would exceed the validator's maximum number of delegators.
     fn delegate(
         &mut self,
         delegator_public_key: PublicKey,
@@ -207,24 +210,35 @@ pub trait Auction:
 
         let source = self.get_main_purse()?;
 
-        let mut bids = detail::get_bids(self)?;
+        let minimum_delegation_amount = detail::get_minimum_delegation_amount(self)?;
+        let max_delegators_per_validator = detail::get_max_delegators_per_validator(self)?;
 
-        let delegators = match bids.get_mut(&validator_public_key) {
-            Some(bid) => bid.delegators_mut(),
+        let mut bid = match detail::get_bid(self, &validator_public_key)? {
+            Some(bid) => bid,
             None => {
                 // Return early if target validator is not in `bids`
                 return Err(Error::ValidatorNotFound);
             }
         };
+        let delegators = bid.delegators_mut();
 
         let new_delegation_amount = match delegators.get_mut(&delegator_public_key) {
             Some(delegator) => {
+                if *delegator.staked_amount() + amount < minimum_delegation_amount {
+                    return Err(Error::DelegationAmountTooSmall);
+                }
                 self.transfer_purse_to_purse(source, *delegator.bonding_purse(), amount)
                     .map_err(|_| Error::TransferToDelegatorPurse)?;
                 delegator.increase_stake(amount)?;
                 *delegator.staked_amount()
             }
             None => {
+                if amount < minimum_delegation_amount {
+                    return Err(Error::DelegationAmountTooSmall);
+                }
+                if delegators.len() >= max_delegators_per_validator {
+                    return Err(Error::ExceededDelegatorSizeLimit);
+                }
                 let bonding_purse = self.create_purse()?;
                 self.transfer_purse_to_purse(source, bonding_purse, amount)
                     .map_err(|_| Error::TransferToDelegatorPurse)?;
@@ -234,7 +248,7 @@ pub trait Auction:
             }
         };
 
-        detail::set_bids(self, bids)?;
+        detail::set_bid(self, bid)?;
 
         Ok(new_delegation_amount)
     }
@@ -245,6 +259,9 @@ pub trait Auction:
     ///
     /// The arguments are the delegator’s key, the validator key and quantity of motes and
     /// returns a tuple of the unbonding purse along with the remaining bid amount.
+    ///
+    /// Fails if the remaining delegation would be non-zero but below the minimum delegation
+    /// amount.
     fn undelegate(
         &mut self,
         delegator_public_key: PublicKey,
@@ -256,24 +273,103 @@ pub trait Auction:
             return Err(Error::InvalidPublicKey);
         }
 
-        let mut bids = detail::get_bids(self)?;
+        let minimum_delegation_amount = detail::get_minimum_delegation_amount(self)?;
 
-        let delegators = match bids.get_mut(&validator_public_key) {
-            Some(bid) => bid.delegators_mut(),
+        let mut bid = match detail::get_bid(self, &validator_public_key)? {
+            Some(bid) => bid,
             None => {
                 // Return early if target validator is not in `bids`
                 return Err(Error::ValidatorNotFound);
             }
         };
+        let delegators = bid.delegators_mut();
+
+        let new_amount = match delegators.get_mut(&delegator_public_key) {
+            Some(delegator) => {
+                detail::check_remaining_delegation(delegator, amount, minimum_delegation_amount)?;
+                detail::create_unbonding_purse(
+                    self,
+                    validator_public_key,
+                    delegator_public_key,
+                    *delegator.bonding_purse(),
+                    amount,
+                    None,
+                )?;
+                let updated_stake = delegator.decrease_stake(amount)?;
+                if updated_stake == U512::zero() {
+                    delegators.remove(&delegator_public_key);
+                };
+                updated_stake
+            }
+            None => return Err(Error::DelegatorNotFound),
+        };
+
+        detail::set_bid(self, bid)?;
+
+        Ok(new_amount)
+    }
+
+    /// Removes an amount of motes (or the entry altogether, if the remaining amount is 0) from
+    /// the delegator's entry of one validator's bid and schedules it to be delegated to another
+    /// validator once the redelegation delay has passed, without unbonding it in between.
+    ///
+    /// The arguments are the delegator's key, the current validator's key, the quantity of motes
+    /// and the new validator's key. Returns the remaining amount delegated to the current
+    /// validator.
+    fn redelegate(
+        &mut self,
+        delegator_public_key: PublicKey,
+        validator_public_key: PublicKey,
+        amount: U512,
+        new_validator: PublicKey,
+    ) -> Result<U512, Error> {
+        let account_hash = AccountHash::from_public_key(&delegator_public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        if validator_public_key == new_validator {
+            return Err(Error::RedelegationToSameValidator);
+        }
+
+        let minimum_delegation_amount = detail::get_minimum_delegation_amount(self)?;
+        let max_delegators_per_validator = detail::get_max_delegators_per_validator(self)?;
+
+        match detail::get_bid(self, &new_validator)? {
+            Some(new_bid) => {
+                // The redelegated amount has to be a valid delegation to the new validator on its
+                // own, unless it tops up an existing one.
+                if !new_bid.delegators().contains_key(&delegator_public_key) {
+                    if amount < minimum_delegation_amount {
+                        return Err(Error::DelegationAmountTooSmall);
+                    }
+                    if new_bid.delegators().len() >= max_delegators_per_validator {
+                        return Err(Error::ExceededDelegatorSizeLimit);
+                    }
+                }
+            }
+            None => return Err(Error::RedelegationValidatorNotFound),
+        }
+
+        let mut bid = match detail::get_bid(self, &validator_public_key)? {
+            Some(bid) => bid,
+            None => {
+                // Return early if source validator is not in `bids`
+                return Err(Error::ValidatorNotFound);
+            }
+        };
+        let delegators = bid.delegators_mut();
 
         let new_amount = match delegators.get_mut(&delegator_public_key) {
             Some(delegator) => {
+                detail::check_remaining_delegation(delegator, amount, minimum_delegation_amount)?;
                 detail::create_unbonding_purse(
                     self,
                     validator_public_key,
                     delegator_public_key,
                     *delegator.bonding_purse(),
                     amount,
+                    Some(new_validator),
                 )?;
                 let updated_stake = delegator.decrease_stake(amount)?;
                 if updated_stake == U512::zero() {
@@ -284,53 +380,44 @@ pub trait Auction:
             None => return Err(Error::DelegatorNotFound),
         };
 
-        detail::set_bids(self, bids)?;
+        detail::set_bid(self, bid)?;
 
         Ok(new_amount)
     }
 
     /// Slashes each validator.
     ///
+    /// Takes the configured slashing rate of the stake of each validator and its delegators,
+    /// including their pending unbonding purses, burns it from their bonding purses, reduces the
+    /// total supply accordingly and records the slashed amounts in the current era's [`EraInfo`].
+    ///
     /// This can be only invoked through a system call.
     fn slash(&mut self, validator_public_keys: Vec<PublicKey>) -> Result<(), Error> {
         if self.get_caller() != SYSTEM_ACCOUNT {
             return Err(Error::InvalidCaller);
         }
 
-        let mut burned_amount: U512 = U512::zero();
-
-        let mut bids = detail::get_bids(self)?;
-        let mut bids_modified = false;
+        let slashing_rate = detail::get_slashing_rate(self)?;
 
-        let mut unbonding_purses: UnbondingPurses = detail::get_unbonding_purses(self)?;
-        let mut unbonding_purses_modified = false;
+        let mut slashes = Vec::new();
         for validator_public_key in validator_public_keys {
-            // Remove bid for given validator, saving its delegators
-            if let Some(bid) = bids.remove(&validator_public_key) {
-                burned_amount += *bid.staked_amount();
-                bids_modified = true;
-            };
-
-            // Update unbonding entries for given validator
-            if let Some(unbonding_list) = unbonding_purses.remove(&validator_public_key) {
-                burned_amount += unbonding_list
-                    .into_iter()
-                    .map(|unbonding_purse| *unbonding_purse.amount())
-                    .sum();
-                unbonding_purses_modified = true;
-            }
+            slashes.extend(detail::slash_validator(
+                self,
+                validator_public_key,
+                slashing_rate,
+            )?);
         }
 
-        if unbonding_purses_modified {
-            detail::set_unbonding_purses(self, unbonding_purses)?;
-        }
+        let burned_amount: U512 = slashes.iter().map(|slash| *slash.amount()).sum();
+        self.reduce_total_supply(burned_amount)?;
 
-        if bids_modified {
-            detail::set_bids(self, bids)?;
+        if !slashes.is_empty() {
+            let era_id = detail::get_era_id(self)?;
+            let mut era_info = self.read_era_info(era_id)?.unwrap_or_default();
+            era_info.slashes_mut().extend(slashes);
+            self.record_era_info(era_id, era_info)?;
         }
 
-        self.reduce_total_supply(burned_amount)?;
-
         Ok(())
     }
 
@@ -352,18 +439,20 @@ pub trait Auction:
         let auction_delay = detail::get_auction_delay(self)?;
         let snapshot_size = auction_delay as usize + 1;
         let mut era_id = detail::get_era_id(self)?;
-        let mut bids = detail::get_bids(self)?;
 
-        // Process unbond requests
+        // Process unbond requests before reading the bids, as maturing redelegations are bonded to
+        // their new validators' bids.
         detail::process_unbond_requests(self)?;
 
+        let mut bids = detail::get_bids_pruning_empty(self)?;
+
         // Process bids
-        let mut bids_modified = false;
         for (validator_public_key, bid) in bids.iter_mut() {
-            bids_modified = bid.process(era_end_timestamp_millis);
+            let processed = bid.process(era_end_timestamp_millis);
+            let evicted = evicted_validators.contains(validator_public_key) && bid.deactivate();
 
-            if evicted_validators.contains(validator_public_key) {
-                bids_modified = bid.deactivate()
+            if processed || evicted {
+                detail::set_bid(self, bid.clone())?;
             }
         }
 
@@ -431,10 +520,6 @@ pub trait Auction:
         detail::set_era_id(self, era_id)?;
         detail::set_era_end_timestamp_millis(self, era_end_timestamp_millis)?;
 
-        if bids_modified {
-            detail::set_bids(self, bids)?;
-        }
-
         Ok(())
     }
 
@@ -453,11 +538,13 @@ pub trait Auction:
             return Err(Error::MismatchedEraValidators);
         }
 
+        // Keep the slashes already recorded for this era.
         let mut era_info = EraInfo::new();
+        if let Some(recorded_era_info) = self.read_era_info(era_id)? {
+            *era_info.slashes_mut() = recorded_era_info.slashes().clone();
+        }
         let mut seigniorage_allocations = era_info.seigniorage_allocations_mut();
 
-        let mut bids = detail::get_bids(self)?;
-
         for (public_key, reward_factor) in reward_factors {
             let recipient = seigniorage_recipients
                 .get(&public_key)
@@ -498,7 +585,7 @@ pub trait Auction:
                         (*delegator_key, reward)
                     });
             let delegator_payouts = detail::reinvest_delegator_rewards(
-                &mut bids,
+                self,
                 &mut seigniorage_allocations,
                 public_key,
                 delegator_rewards,
@@ -511,7 +598,7 @@ pub trait Auction:
             let validators_part: Ratio<U512> = total_reward - Ratio::from(total_delegator_payout);
             let validator_reward = validators_part.to_integer();
             if let Some(validator_bonding_purse) = detail::reinvest_validator_reward(
-                &mut bids,
+                self,
                 &mut seigniorage_allocations,
                 public_key,
                 validator_reward,
@@ -542,7 +629,6 @@ pub trait Auction:
         }
 
         self.record_era_info(era_id, era_info)?;
-        detail::set_bids(self, bids)?;
 
         Ok(())
     }
@@ -560,16 +646,14 @@ pub trait Auction:
             return Err(Error::InvalidPublicKey);
         }
 
-        let mut bids = detail::get_bids(self)?;
-
-        let bid = match bids.get_mut(&validator_public_key) {
+        let mut bid = match detail::get_bid(self, &validator_public_key)? {
             Some(bid) => bid,
             None => return Err(Error::ValidatorNotFound),
         };
 
         bid.activate();
 
-        detail::set_bids(self, bids)?;
+        detail::set_bid(self, bid)?;
 
         Ok(())
     }
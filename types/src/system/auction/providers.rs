@@ -1,8 +1,10 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
 use crate::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
-    system::auction::{EraId, EraInfo, Error},
-    CLTyped, Key, TransferredTo, URef, BLAKE2B_DIGEST_LENGTH, U512,
+    system::auction::{Bid, EraId, EraInfo, Error, UnbondingPurse},
+    CLTyped, Key, KeyTag, TransferredTo, URef, BLAKE2B_DIGEST_LENGTH, U512,
 };
 
 /// Provider of runtime host functionality.
@@ -24,6 +26,35 @@ pub trait StorageProvider {
 
     /// Writes data to [`URef].
     fn write<T: ToBytes + CLTyped>(&mut self, uref: URef, value: T) -> Result<(), Error>;
+
+    /// Returns all keys of the given type.
+    fn get_keys(&mut self, key_tag: &KeyTag) -> Result<BTreeSet<Key>, Error>;
+
+    /// Reads the bid stored under [`Key::Bid`] for the given validator.
+    fn read_bid(&mut self, account_hash: &AccountHash) -> Result<Option<Bid>, Error>;
+
+    /// Writes the bid under [`Key::Bid`] for the given validator.
+    fn write_bid(&mut self, account_hash: AccountHash, bid: Bid) -> Result<(), Error>;
+
+    /// Removes the bid stored under [`Key::Bid`] for the given validator from global state.
+    fn prune_bid(&mut self, account_hash: AccountHash) -> Result<(), Error>;
+
+    /// Reads the unbonding purses stored under [`Key::Withdraw`] for the given validator.
+    fn read_withdraw(&mut self, account_hash: &AccountHash) -> Result<Vec<UnbondingPurse>, Error>;
+
+    /// Writes the unbonding purses under [`Key::Withdraw`] for the given validator.
+    fn write_withdraw(
+        &mut self,
+        account_hash: AccountHash,
+        unbonding_purses: Vec<UnbondingPurse>,
+    ) -> Result<(), Error>;
+
+    /// Removes the unbonding purses stored under [`Key::Withdraw`] for the given validator from
+    /// global state.
+    fn prune_withdraw(&mut self, account_hash: AccountHash) -> Result<(), Error>;
+
+    /// Reads the era info stored under [`Key::EraInfo`] for the given era.
+    fn read_era_info(&mut self, era_id: EraId) -> Result<Option<EraInfo>, Error>;
 }
 
 /// Provides functionality of a system module.
@@ -77,6 +108,10 @@ pub trait MintProvider {
     /// Reduce total supply by `amount`. Returns unit on success, otherwise
     /// an error.
     fn reduce_total_supply(&mut self, amount: U512) -> Result<(), Error>;
+
+    /// Burns `amount` from the balance of `purse`.  The total supply is not changed, it has to be
+    /// reduced separately via `reduce_total_supply`.
+    fn burn(&mut self, purse: URef, amount: U512) -> Result<(), Error>;
 }
 
 /// Provider of an account related functionality.
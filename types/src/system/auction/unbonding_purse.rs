@@ -1,5 +1,12 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
 use alloc::vec::Vec;
 
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
     system::auction::EraId,
@@ -7,7 +14,9 @@ use crate::{
 };
 
 /// Unbonding purse.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
 pub struct UnbondingPurse {
     /// Bonding Purse
     bonding_purse: URef,
@@ -19,16 +28,22 @@ pub struct UnbondingPurse {
     era_of_creation: EraId,
     /// Unbonding Amount.
     amount: U512,
+    /// The validator public key to redelegate to, if this is a redelegation request.
+    new_validator: Option<PublicKey>,
 }
 
 impl UnbondingPurse {
     /// Creates [`UnbondingPurse`] instance for an unbonding request.
+    ///
+    /// If `new_validator` is set, the amount is bonded to that validator instead of being paid
+    /// out once the request is processed.
     pub const fn new(
         bonding_purse: URef,
         validator_public_key: PublicKey,
         unbonder_public_key: PublicKey,
         era_of_creation: EraId,
         amount: U512,
+        new_validator: Option<PublicKey>,
     ) -> Self {
         Self {
             bonding_purse,
@@ -36,6 +51,7 @@ impl UnbondingPurse {
             unbonder_public_key,
             era_of_creation,
             amount,
+            new_validator,
         }
     }
 
@@ -73,6 +89,11 @@ impl UnbondingPurse {
     pub fn amount(&self) -> &U512 {
         &self.amount
     }
+
+    /// Returns the public key of the validator the amount is redelegated to, if any.
+    pub fn new_validator(&self) -> Option<&PublicKey> {
+        self.new_validator.as_ref()
+    }
 }
 
 impl ToBytes for UnbondingPurse {
@@ -83,6 +104,7 @@ impl ToBytes for UnbondingPurse {
         result.extend(&self.unbonder_public_key.to_bytes()?);
         result.extend(&self.era_of_creation.to_bytes()?);
         result.extend(&self.amount.to_bytes()?);
+        result.extend(&self.new_validator.to_bytes()?);
         Ok(result)
     }
     fn serialized_length(&self) -> usize {
@@ -91,6 +113,7 @@ impl ToBytes for UnbondingPurse {
             + self.unbonder_public_key.serialized_length()
             + self.era_of_creation.serialized_length()
             + self.amount.serialized_length()
+            + self.new_validator.serialized_length()
     }
 }
 
@@ -101,6 +124,7 @@ impl FromBytes for UnbondingPurse {
         let (unbonder_public_key, bytes) = FromBytes::from_bytes(bytes)?;
         let (era_of_creation, bytes) = FromBytes::from_bytes(bytes)?;
         let (amount, bytes) = FromBytes::from_bytes(bytes)?;
+        let (new_validator, bytes) = FromBytes::from_bytes(bytes)?;
         Ok((
             UnbondingPurse {
                 bonding_purse,
@@ -108,6 +132,7 @@ impl FromBytes for UnbondingPurse {
                 unbonder_public_key,
                 era_of_creation,
                 amount,
+                new_validator,
             },
             bytes,
         ))
@@ -147,10 +172,26 @@ mod tests {
             unbonder_public_key: *UNBONDER_PUBLIC_KEY,
             era_of_creation: ERA_OF_WITHDRAWAL,
             amount: *AMOUNT,
+            new_validator: None,
         };
 
         bytesrepr::test_serialization_roundtrip(&unbonding_purse);
     }
+
+    #[test]
+    fn redelegation_serialization_roundtrip() {
+        let unbonding_purse = UnbondingPurse::new(
+            BONDING_PURSE,
+            *VALIDATOR_PUBLIC_KEY,
+            *UNBONDER_PUBLIC_KEY,
+            ERA_OF_WITHDRAWAL,
+            *AMOUNT,
+            Some(*VALIDATOR_PUBLIC_KEY),
+        );
+
+        bytesrepr::test_serialization_roundtrip(&unbonding_purse);
+    }
+
     #[test]
     fn should_be_validator_condition() {
         let validator_unbonding_purse = UnbondingPurse::new(
@@ -159,6 +200,7 @@ mod tests {
             *VALIDATOR_PUBLIC_KEY,
             ERA_OF_WITHDRAWAL,
             *AMOUNT,
+            None,
         );
         assert!(validator_unbonding_purse.is_validator());
     }
@@ -171,6 +213,7 @@ mod tests {
             *UNBONDER_PUBLIC_KEY,
             ERA_OF_WITHDRAWAL,
             *AMOUNT,
+            None,
         );
         assert!(!delegator_unbonding_purse.is_validator());
     }
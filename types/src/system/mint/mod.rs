@@ -5,8 +5,6 @@ mod runtime_provider;
 mod storage_provider;
 mod system_provider;
 
-use core::convert::TryFrom;
-
 use num_rational::Ratio;
 
 use crate::{account::AccountHash, Key, URef, U512};
@@ -29,15 +27,10 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
             return Err(Error::InvalidNonEmptyPurseCreation);
         }
 
-        let balance_uref: URef = self.new_uref(initial_balance)?;
         let purse_uref: URef = self.new_uref(())?;
-        let purse_uref_name = purse_uref.remove_access_rights().to_formatted_string();
-
-        // store balance uref so that the runtime knows the mint has full access
-        self.put_key(&purse_uref_name, balance_uref.into())?;
 
-        // store association between purse id and balance uref
-        self.write_balance_entry(purse_uref, balance_uref)?;
+        // store the balance under its own key rather than in the mint's named keys
+        self.write_balance(purse_uref, initial_balance)?;
 
         if !is_empty_purse {
             // get total supply uref if exists, otherwise create it.
@@ -92,14 +85,7 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
 
     /// Read balance of given `purse`.
     fn balance(&mut self, purse: URef) -> Result<Option<U512>, Error> {
-        let balance_uref: URef = match self.read_balance_entry(&purse)? {
-            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
-            None => return Ok(None),
-        };
-        match self.read(balance_uref)? {
-            some @ Some(_) => Ok(some),
-            None => Err(Error::PurseNotFound),
-        }
+        self.read_balance(&purse)
     }
 
     /// Transfers `amount` of tokens from `source` purse to a `target` purse.
@@ -114,23 +100,18 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
         if !source.is_writeable() || !target.is_addable() {
             return Err(Error::InvalidAccessRights);
         }
-        let source_balance: URef = match self.read_balance_entry(&source)? {
-            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
-            None => return Err(Error::SourceNotFound),
-        };
-        let source_value: U512 = match self.read(source_balance)? {
+        let source_value: U512 = match self.read_balance(&source)? {
             Some(source_value) => source_value,
             None => return Err(Error::SourceNotFound),
         };
         if amount > source_value {
             return Err(Error::InsufficientFunds);
         }
-        let target_balance: URef = match self.read_balance_entry(&target)? {
-            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
-            None => return Err(Error::DestNotFound),
-        };
-        self.write(source_balance, source_value - amount)?;
-        self.add(target_balance, amount)?;
+        if self.read_balance(&target)?.is_none() {
+            return Err(Error::DestNotFound);
+        }
+        self.write_balance(source, source_value - amount)?;
+        self.add_balance(target, amount)?;
         self.record_transfer(maybe_to, source, target, amount, id)?;
         Ok(())
     }
@@ -1,7 +1,7 @@
 use crate::{
     bytesrepr::{FromBytes, ToBytes},
     system::mint::Error,
-    CLTyped, Key, URef,
+    CLTyped, URef, U512,
 };
 
 /// Provides functionality of a contract storage.
@@ -9,11 +9,14 @@ pub trait StorageProvider {
     /// Create new [`URef`].
     fn new_uref<T: CLTyped + ToBytes>(&mut self, init: T) -> Result<URef, Error>;
 
-    /// Write data to a local key.
-    fn write_balance_entry(&mut self, purse_uref: URef, balance_uref: URef) -> Result<(), Error>;
+    /// Write the balance of a purse.
+    fn write_balance(&mut self, purse_uref: URef, balance: U512) -> Result<(), Error>;
 
-    /// Read data from a local key.
-    fn read_balance_entry(&mut self, purse_uref: &URef) -> Result<Option<Key>, Error>;
+    /// Read the balance of a purse.
+    fn read_balance(&mut self, purse_uref: &URef) -> Result<Option<U512>, Error>;
+
+    /// Add to the balance of a purse.
+    fn add_balance(&mut self, purse_uref: URef, amount: U512) -> Result<(), Error>;
 
     /// Read data from [`URef`].
     fn read<T: CLTyped + FromBytes>(&mut self, uref: URef) -> Result<Option<T>, Error>;